@@ -0,0 +1,8 @@
+	.text
+	.globl	long_fn
+long_fn:
+	ret
+	.data
+	.globl	shared_data
+shared_data:
+	.quad	0
@@ -0,0 +1,11 @@
+#!/bin/bash
+# GNU, BSD and thin archives of the same two objects, one of which has a name too long for
+# the 16-byte ar member header.
+set -e
+llvm-mc -triple=x86_64-linux-gnu -filetype=obj archive.short.s -o short.o
+llvm-mc -triple=x86_64-linux-gnu -filetype=obj archive.a_really_long_member_name.s -o a_really_long_member_name.o
+rm -f archive.gnu.a archive.bsd.a archive.thin.a
+ar rcs archive.gnu.a short.o a_really_long_member_name.o
+llvm-ar --format=bsd rcs archive.bsd.a short.o a_really_long_member_name.o
+ar rcsT archive.thin.a short.o a_really_long_member_name.o
+rm -f short.o a_really_long_member_name.o
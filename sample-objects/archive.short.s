@@ -0,0 +1,4 @@
+	.text
+	.globl	short_fn
+short_fn:
+	ret
@@ -0,0 +1,2 @@
+#!/bin/bash
+llvm-mc -triple=x86_64-linux-gnu -filetype=obj demangle.x86_64.s -o demangle.x86_64.o
@@ -0,0 +1,13 @@
+	.text
+	.globl	_ZN4core3fmt5Write9write_fmt17h0123456789abcdefE
+_ZN4core3fmt5Write9write_fmt17h0123456789abcdefE:
+	ret
+	.globl	_RNvCs1234_7mycrate3foo
+_RNvCs1234_7mycrate3foo:
+	ret
+	.globl	_ZNSt6vectorIiSaIiEE9push_backERKi
+_ZNSt6vectorIiSaIiEE9push_backERKi:
+	ret
+	.globl	main
+main:
+	ret
@@ -0,0 +1,34 @@
+	.syntax unified
+	.arm
+	.text
+	.globl	leaf
+	.type	leaf,%function
+leaf:
+	.fnstart
+	.cantunwind
+	bx	lr
+	.fnend
+
+	.globl	small
+	.type	small,%function
+small:
+	.fnstart
+	.save	{r4, lr}
+	push	{r4, lr}
+	pop	{r4, pc}
+	.fnend
+
+	.globl	big
+	.type	big,%function
+big:
+	.fnstart
+	.save	{r4-r11, lr}
+	push	{r4-r11, lr}
+	.vsave	{d8-d9}
+	vpush	{d8-d9}
+	.pad	#1024
+	sub	sp, sp, #1024
+	add	sp, sp, #1024
+	vpop	{d8-d9}
+	pop	{r4-r11, pc}
+	.fnend
@@ -0,0 +1,2 @@
+#!/bin/bash
+llvm-mc -triple=armv7-linux-gnueabihf -filetype=obj exidx.s -o exidx.armhf.o
@@ -0,0 +1,12 @@
+// A small program whose hardening depends entirely on how it's built: a local buffer for the
+// stack protector to guard, and a copy of unknown length for FORTIFY_SOURCE to check.
+#include <stdio.h>
+#include <string.h>
+
+int main(int argc, char **argv)
+{
+    char buf[64];
+    strcpy(buf, argc > 1 ? argv[1] : "hello");
+    printf("%s\n", buf);
+    return 0;
+}
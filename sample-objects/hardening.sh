@@ -0,0 +1,4 @@
+#!/bin/bash
+gcc -O2 -fPIE -pie -fstack-protector-strong -D_FORTIFY_SOURCE=2 -fcf-protection=full -Wl,-z,relro,-z,now,-z,ibt,-z,shstk -Wl,-z,noexecstack -o hardening.hardened.x86_64 hardening.c
+gcc -O2 -fno-pie -no-pie -fno-stack-protector -U_FORTIFY_SOURCE -fcf-protection=none -Wl,-z,norelro -Wl,-z,execstack -Wl,-rpath,/opt/lib -Wl,--disable-new-dtags -o hardening.unhardened.x86_64 hardening.c
+gcc -O2 -static -nostdlib -fno-pie -no-pie -fno-stack-protector -fcf-protection=none -o hardening.static.x86_64 hardening_static.c
@@ -0,0 +1,23 @@
+// Stands in for a statically linked program: without a libc to import them from, the stack
+// protector and FORTIFY_SOURCE functions are defined in the binary itself.
+void __stack_chk_fail(void)
+{
+    for (;;)
+        ;
+}
+
+char *__strcpy_chk(char *dest, const char *src, unsigned long destlen)
+{
+    char *d = dest;
+    while (destlen-- && (*d++ = *src++))
+        ;
+    return dest;
+}
+
+void _start(void)
+{
+    char buf[64];
+    __strcpy_chk(buf, "hello", sizeof(buf));
+    for (;;)
+        ;
+}
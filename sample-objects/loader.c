@@ -0,0 +1,15 @@
+// A tiny freestanding static-pie for exercising the loader: it has relative relocations to
+// apply, a .bss to zero and a TLS template.
+static int counter = 5;
+int *pointer = &counter;
+int *pointers[4] = {&counter, &counter, 0, &counter};
+__thread int tls_value = 42;
+__thread int tls_zero;
+char bss[0x100];
+
+void _start(void)
+{
+    *pointer += tls_value + tls_zero + bss[0] + *pointers[3];
+    for (;;) {
+    }
+}
@@ -0,0 +1,3 @@
+#!/bin/bash
+gcc -Os -fPIE -static-pie -nostdlib -ffreestanding -Wl,-z,nopack-relative-relocs -Wl,--build-id=none -o loader.x86_64 loader.c
+gcc -Os -fPIE -static-pie -nostdlib -ffreestanding -Wl,-z,pack-relative-relocs -Wl,--build-id=none -o loader.relr.x86_64 loader.c
@@ -0,0 +1,14 @@
+#include <stdio.h>
+
+__attribute__((noinline)) static int helper(int x) {
+    return x * 2 + 1;
+}
+
+int exported_fn(int x) {
+    return helper(x) + 3;
+}
+
+int main(int argc, char **argv) {
+    printf("%d\n", exported_fn(argc));
+    return 0;
+}
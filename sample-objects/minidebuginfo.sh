@@ -0,0 +1,17 @@
+#!/bin/bash
+# Build a stripped binary with an embedded MiniDebugInfo .gnu_debugdata section,
+# following the recipe used by Fedora's find-debuginfo.
+set -e
+gcc -O1 -o minidebuginfo.x86_64 minidebuginfo.c -rdynamic -Wl,--hash-style=gnu
+
+nm -D minidebuginfo.x86_64 --format=posix --defined-only | awk '{ print $1 }' | sort > dynsyms
+nm minidebuginfo.x86_64 --format=posix --defined-only | awk '{ if ($2 == "T" || $2 == "t" || $2 == "D") print $1 }' | sort > funcsyms
+comm -13 dynsyms funcsyms > keep_symbols
+
+objcopy --only-keep-debug minidebuginfo.x86_64 debug
+objcopy -S --remove-section .gdb_index --remove-section .comment --keep-symbols=keep_symbols debug mini_debuginfo
+strip --strip-all -R .comment minidebuginfo.x86_64
+xz --force mini_debuginfo
+objcopy --add-section .gnu_debugdata=mini_debuginfo.xz minidebuginfo.x86_64
+
+rm -f dynsyms funcsyms keep_symbols debug mini_debuginfo.xz
@@ -0,0 +1,22 @@
+	.abicalls
+	.option	pic2
+	.text
+	.globl	caller
+	.type	caller,@function
+	.ent	caller
+caller:
+	.set	noreorder
+	.set	noat
+	.cpsetup	$25, $1, caller
+	ld	$25, %call16(helper)($gp)
+	jalr	$25
+	nop
+	.set	at
+	.set	reorder
+	.end	caller
+	.size	caller, .-caller
+
+	.data
+	.globl	target
+target:
+	.8byte	caller
@@ -0,0 +1,3 @@
+#!/bin/bash
+llvm-mc -triple=mips64el-linux-gnuabi64 -filetype=obj mips64.s -o mips64el.o
+llvm-mc -triple=mips64-linux-gnuabi64 -filetype=obj mips64.s -o mips64.o
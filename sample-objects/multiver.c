@@ -0,0 +1,13 @@
+#include <string.h>
+
+int lookup_v1(void) { return 1; }
+int lookup_v2(void) { return 2; }
+int lookup_v3(void) { return 3; }
+
+void *copy(void *dst, const void *src, size_t n) {
+    return memcpy(dst, src, n);
+}
+
+__asm__(".symver lookup_v1, lookup@MULTI_1.0, remove");
+__asm__(".symver lookup_v2, lookup@MULTI_2.0, remove");
+__asm__(".symver lookup_v3, lookup@@MULTI_3.0, remove");
@@ -0,0 +1,3 @@
+#!/bin/bash
+# A shared object which defines several versions of the same symbol name
+gcc -O1 -o multiver.x86_64.so multiver.c -shared -fPIC -Xlinker --hash-style=both -Wl,--version-script=multiver.ver
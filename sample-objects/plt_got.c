@@ -0,0 +1,16 @@
+#include <string.h>
+
+/* Taking the functions' addresses gives them GLOB_DAT relocations, so the calls to them go
+ * through .plt.got entries instead of lazily bound .plt entries */
+void *memset_address(void) {
+    return (void *)&memset;
+}
+
+void *memcpy_address(void) {
+    return (void *)&memcpy;
+}
+
+void use_both(char *dst, const char *src, size_t n) {
+    memset(dst, 0, n);
+    memcpy(dst, src, n);
+}
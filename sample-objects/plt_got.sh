@@ -0,0 +1,2 @@
+#!/bin/bash
+gcc -o plt_got.x86_64.so plt_got.c -shared -fPIC -fno-builtin -fcf-protection=none
@@ -0,0 +1,23 @@
+    .text
+    .globl _start
+_start:
+    bl func
+    b ext_func
+    adrp x0, data_var
+    add x0, x0, :lo12:data_var
+    ldr x1, [x0, :lo12:data_var]
+    adr x2, data_var
+    b.eq func
+    tbz x0, #3, func
+    movz x3, #:abs_g1:ext_var
+    movk x3, #:abs_g0_nc:ext_var
+    ldr x4, ext_var
+
+    .globl func
+func:
+    ret
+
+    .data
+data_var:
+    .xword ext_var
+    .word ext_var - .
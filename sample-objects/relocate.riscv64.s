@@ -0,0 +1,25 @@
+    .text
+    .globl _start
+_start:
+    call ext_func
+    tail func
+1:
+    auipc a0, %pcrel_hi(data_var)
+    addi a0, a0, %pcrel_lo(1b)
+    lui a1, %hi(ext_var)
+    addi a1, a1, %lo(ext_var)
+    sw a2, %lo(ext_var)(a1)
+    beq a0, a1, func
+    jal func
+    c.beqz a0, func
+    c.j func
+
+    .globl func
+func:
+    ret
+
+    .data
+data_var:
+    .dword ext_var
+    .word ext_var - .
+    .word func - _start
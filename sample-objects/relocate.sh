@@ -0,0 +1,4 @@
+#!/bin/bash
+llvm-mc -triple=x86_64-linux-gnu -filetype=obj relocate.x86_64.s -o relocate.x86_64.o
+llvm-mc -triple=aarch64-linux-gnu -filetype=obj relocate.aarch64.s -o relocate.aarch64.o
+llvm-mc -triple=riscv64-linux-gnu -mattr=+c,+relax -filetype=obj relocate.riscv64.s -o relocate.riscv64.o
@@ -0,0 +1,22 @@
+    .text
+    .globl _start
+_start:
+    call func
+    call ext_func
+    lea data_var(%rip), %rax
+    movq ext_var@GOTPCREL(%rip), %rax
+    call *ext_func@GOTPCREL(%rip)
+    jmp *ext_func@GOTPCREL(%rip)
+    movl $data_var, %ecx
+    movq $data_var, %rcx
+    movabsq $ext_var, %rdx
+
+    .globl func
+func:
+    ret
+
+    .data
+data_var:
+    .quad func
+    .long ext_var - .
+    .quad ext_func - .
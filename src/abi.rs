@@ -624,6 +624,8 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 /// Extended symbol table section index
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// Section data contains a table of compressed relative relocations (`Elf_Relr`)
+pub const SHT_RELR: u32 = 19;
 /// Values in [SHT_LOOS, SHT_HIOS] are reserved for operating system-specific semantics.
 pub const SHT_LOOS: u32 = 0x60000000;
 /// Object attributes
@@ -656,6 +658,8 @@ pub const SHT_HIUSER: u32 = 0x8fffffff;
 /// This value marks an undefined, missing, irrelevant, or otherwise meaningless
 /// section reference.
 pub const SHN_UNDEF: u16 = 0;
+/// The lower bound of the range of reserved section indexes, which don't refer to a section.
+pub const SHN_LORESERVE: u16 = 0xff00;
 /// Symbols with st_shndx=SHN_ABS are absolute and are not affected by relocation.
 pub const SHN_ABS: u16 = 0xfff1;
 /// Symbols with st_shndx=SHN_COMMON are sometimes used for unallocated C external variables.
@@ -956,6 +960,14 @@ pub const DT_PREINIT_ARRAYSZ: i64 = 33;
 /// This element holds the address of the SHT_SYMTAB_SHNDX section associated
 /// with the dynamic symbol table referenced by the DT_SYMTAB element.
 pub const DT_SYMTAB_SHNDX: i64 = 34;
+/// This element holds the total size, in bytes, of the DT_RELR relocation table.
+pub const DT_RELRSZ: i64 = 35;
+/// This element holds the address of a table of compressed relative relocations
+/// (`Elf_Relr`). If this element is present, the dynamic structure must also have
+/// DT_RELRSZ and DT_RELRENT elements.
+pub const DT_RELR: i64 = 36;
+/// This element holds the size, in bytes, of the DT_RELR relocation entry.
+pub const DT_RELRENT: i64 = 37;
 /// Guile offset of GC roots
 pub const DT_GUILE_GC_ROOT: i64 = 0x37146000;
 /// Guile size in machine words of GC roots
@@ -1289,6 +1301,61 @@ pub const ELF_NOTE_GNU_ABI_TAG_OS_GNU: u32 = 1;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_SOLARIS2: u32 = 2;
 pub const ELF_NOTE_GNU_ABI_TAG_OS_FREEBSD: u32 = 3;
 
+// Build attributes sections (SHT_GNU_ATTRIBUTES, SHT_ARM_ATTRIBUTES, SHT_RISCV_ATTRIBUTES)
+// are made up of vendor subsections which contain tagged sub-subsections
+// which apply to either the whole file, a list of sections, or a list of symbols.
+// See: https://github.com/ARM-software/abi-aa/blob/main/addenda32/addenda32.rst#build-attributes
+
+/// The first byte of an attributes section, which identifies the format version
+pub const ELF_ATTR_FORMAT_VERSION: u8 = b'A';
+/// Sub-subsection tag for attributes which apply to the whole file
+pub const TAG_FILE: u8 = 1;
+/// Sub-subsection tag for attributes which apply to a list of sections
+pub const TAG_SECTION: u8 = 2;
+/// Sub-subsection tag for attributes which apply to a list of symbols
+pub const TAG_SYMBOL: u8 = 3;
+/// Vendor-generic attribute whose value is a ULEB128 flag followed by a NUL-terminated string
+pub const TAG_COMPATIBILITY: u64 = 32;
+
+// Pointer encodings used in .eh_frame and .eh_frame_hdr sections.
+// The low nibble describes the format of the value, the next 3 bits describe
+// what the value is relative to, and the high bit indicates that the value
+// is the address of the pointer rather than the pointer itself.
+// See: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html
+
+/// The value is an address-sized absolute pointer
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+/// The value is an unsigned LEB128-encoded integer
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+/// The value is an unsigned 2-byte integer
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+/// The value is an unsigned 4-byte integer
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+/// The value is an unsigned 8-byte integer
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+/// The value is a signed LEB128-encoded integer
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+/// The value is a signed 2-byte integer
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+/// The value is a signed 4-byte integer
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+/// The value is a signed 8-byte integer
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+/// The value is relative to the address of the encoded value itself
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+/// The value is relative to the start of the .text section
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+/// The value is relative to the start of the data section (the .eh_frame_hdr section, for values in it)
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+/// The value is relative to the start of the function
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+/// The value is aligned to an address-sized boundary
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+/// The value is the address at which the real pointer is stored
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+/// No value is present
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
 //     _    ____  __  __
 //    / \  |  _ \|  \/  |
 //   / _ \ | |_) | |\/| |
@@ -1340,6 +1407,74 @@ pub const SHT_ARM_DEBUGOVERLAY: u32 = 0x70000004;
 /// See <https://github.com/ARM-software/abi-aa/blob/main/dbgovl32/dbgovl32.rst>
 pub const SHT_ARM_OVERLAYSECTION: u32 = 0x70000005;
 
+/// The vendor name for ARM EABI attributes in a SHT_ARM_ATTRIBUTES section
+pub const ELF_ATTR_VENDOR_AEABI: &str = "aeabi";
+
+// ARM EABI build attribute tags.
+// See: https://github.com/ARM-software/abi-aa/blob/main/addenda32/addenda32.rst#public-aeabi-attribute-tags
+pub const TAG_ARM_CPU_RAW_NAME: u64 = 4;
+pub const TAG_ARM_CPU_NAME: u64 = 5;
+pub const TAG_ARM_CPU_ARCH: u64 = 6;
+pub const TAG_ARM_CPU_ARCH_PROFILE: u64 = 7;
+pub const TAG_ARM_ARM_ISA_USE: u64 = 8;
+pub const TAG_ARM_THUMB_ISA_USE: u64 = 9;
+pub const TAG_ARM_FP_ARCH: u64 = 10;
+pub const TAG_ARM_WMMX_ARCH: u64 = 11;
+pub const TAG_ARM_ADVANCED_SIMD_ARCH: u64 = 12;
+pub const TAG_ARM_PCS_CONFIG: u64 = 13;
+pub const TAG_ARM_ABI_PCS_R9_USE: u64 = 14;
+pub const TAG_ARM_ABI_PCS_RW_DATA: u64 = 15;
+pub const TAG_ARM_ABI_PCS_RO_DATA: u64 = 16;
+pub const TAG_ARM_ABI_PCS_GOT_USE: u64 = 17;
+pub const TAG_ARM_ABI_PCS_WCHAR_T: u64 = 18;
+pub const TAG_ARM_ABI_FP_ROUNDING: u64 = 19;
+pub const TAG_ARM_ABI_FP_DENORMAL: u64 = 20;
+pub const TAG_ARM_ABI_FP_EXCEPTIONS: u64 = 21;
+pub const TAG_ARM_ABI_FP_USER_EXCEPTIONS: u64 = 22;
+pub const TAG_ARM_ABI_FP_NUMBER_MODEL: u64 = 23;
+pub const TAG_ARM_ABI_ALIGN_NEEDED: u64 = 24;
+pub const TAG_ARM_ABI_ALIGN_PRESERVED: u64 = 25;
+pub const TAG_ARM_ABI_ENUM_SIZE: u64 = 26;
+pub const TAG_ARM_ABI_HARDFP_USE: u64 = 27;
+/// Describes the float ABI in use: 0 for base (soft-float) AAPCS, 1 for VFP registers
+pub const TAG_ARM_ABI_VFP_ARGS: u64 = 28;
+pub const TAG_ARM_ABI_WMMX_ARGS: u64 = 29;
+pub const TAG_ARM_ABI_OPTIMIZATION_GOALS: u64 = 30;
+pub const TAG_ARM_ABI_FP_OPTIMIZATION_GOALS: u64 = 31;
+pub const TAG_ARM_CPU_UNALIGNED_ACCESS: u64 = 34;
+pub const TAG_ARM_FP_HP_EXTENSION: u64 = 36;
+pub const TAG_ARM_ABI_FP_16BIT_FORMAT: u64 = 38;
+pub const TAG_ARM_MPEXTENSION_USE: u64 = 42;
+pub const TAG_ARM_DIV_USE: u64 = 44;
+pub const TAG_ARM_DSP_EXTENSION: u64 = 46;
+pub const TAG_ARM_MVE_ARCH: u64 = 48;
+pub const TAG_ARM_PAC_EXTENSION: u64 = 50;
+pub const TAG_ARM_BTI_EXTENSION: u64 = 52;
+pub const TAG_ARM_NODEFAULTS: u64 = 64;
+pub const TAG_ARM_ALSO_COMPATIBLE_WITH: u64 = 65;
+pub const TAG_ARM_T2EE_USE: u64 = 66;
+pub const TAG_ARM_CONFORMANCE: u64 = 67;
+pub const TAG_ARM_VIRTUALIZATION_USE: u64 = 68;
+pub const TAG_ARM_FRAMEPOINTER_USE: u64 = 72;
+pub const TAG_ARM_BTI_USE: u64 = 74;
+pub const TAG_ARM_PACRET_USE: u64 = 76;
+
+// ARM EHABI exception index table (.ARM.exidx) and exception table (.ARM.extab) values.
+// See: https://github.com/ARM-software/abi-aa/blob/main/ehabi32/ehabi32.rst
+
+/// The second word of an exception index table entry with this value means
+/// the function cannot be unwound
+pub const EXIDX_CANTUNWIND: u32 = 0x1;
+/// Compact model personality routine index for __aeabi_unwind_cpp_pr0:
+/// Short frame unwinding description with up to 3 unwinding instructions in a single word
+pub const ARM_EHABI_PERSONALITY_SU16: u8 = 0;
+/// Compact model personality routine index for __aeabi_unwind_cpp_pr1:
+/// Long frame unwinding description with 16-bit scope descriptors
+pub const ARM_EHABI_PERSONALITY_LU16: u8 = 1;
+/// Compact model personality routine index for __aeabi_unwind_cpp_pr2:
+/// Long frame unwinding description with 32-bit scope descriptors
+pub const ARM_EHABI_PERSONALITY_LU32: u8 = 2;
+
 /// The contents of this section contains only program instructions and no program data.
 ///
 /// If any section contained by a segment does not have the SHF_ARM_PURECODE
@@ -1974,6 +2109,736 @@ pub const R_AARCH64_TLSDESC: u32 = 1031;
 /// STT_GNU_IFUNC relocation.
 pub const R_AARCH64_IRELATIVE: u32 = 1032;
 
+//  _   _
+// | | | | _____  ____ _  __ _  ___  _ __
+// | |_| |/ _ \ \/ / _` |/ _` |/ _ \| '_ \
+// |  _  |  __/>  < (_| | (_| | (_) | | | |
+// |_| |_|\___/_/\_\__,_|\__, |\___/|_| |_|
+//                       |___/
+//
+// Qualcomm Hexagon, which uses e_machine EM_QDSP6.
+// See: https://github.com/llvm/llvm-project/blob/main/llvm/include/llvm/BinaryFormat/ELF.h
+
+// Hexagon processor version, in the low bits of e_flags
+pub const EF_HEXAGON_MACH_V2: u32 = 0x00000001;
+pub const EF_HEXAGON_MACH_V3: u32 = 0x00000002;
+pub const EF_HEXAGON_MACH_V4: u32 = 0x00000003;
+pub const EF_HEXAGON_MACH_V5: u32 = 0x00000004;
+pub const EF_HEXAGON_MACH_V55: u32 = 0x00000005;
+pub const EF_HEXAGON_MACH_V60: u32 = 0x00000060;
+pub const EF_HEXAGON_MACH_V62: u32 = 0x00000062;
+pub const EF_HEXAGON_MACH_V65: u32 = 0x00000065;
+pub const EF_HEXAGON_MACH_V66: u32 = 0x00000066;
+pub const EF_HEXAGON_MACH_V67: u32 = 0x00000067;
+pub const EF_HEXAGON_MACH_V67T: u32 = 0x00008067;
+pub const EF_HEXAGON_MACH_V68: u32 = 0x00000068;
+pub const EF_HEXAGON_MACH_V69: u32 = 0x00000069;
+/// Mask for the EF_HEXAGON_MACH_* processor version
+pub const EF_HEXAGON_MACH: u32 = 0x000003ff;
+
+// Hexagon ISA version, in the low bits of e_flags
+/// Same as the processor version in EF_HEXAGON_MACH
+pub const EF_HEXAGON_ISA_MACH: u32 = 0x00000000;
+pub const EF_HEXAGON_ISA_V2: u32 = 0x00000010;
+pub const EF_HEXAGON_ISA_V3: u32 = 0x00000020;
+pub const EF_HEXAGON_ISA_V4: u32 = 0x00000030;
+pub const EF_HEXAGON_ISA_V5: u32 = 0x00000040;
+pub const EF_HEXAGON_ISA_V55: u32 = 0x00000050;
+pub const EF_HEXAGON_ISA_V60: u32 = 0x00000060;
+pub const EF_HEXAGON_ISA_V62: u32 = 0x00000062;
+pub const EF_HEXAGON_ISA_V65: u32 = 0x00000065;
+pub const EF_HEXAGON_ISA_V66: u32 = 0x00000066;
+pub const EF_HEXAGON_ISA_V67: u32 = 0x00000067;
+pub const EF_HEXAGON_ISA_V68: u32 = 0x00000068;
+pub const EF_HEXAGON_ISA_V69: u32 = 0x00000069;
+/// Mask for the EF_HEXAGON_ISA_* ISA version
+pub const EF_HEXAGON_ISA: u32 = 0x000003ff;
+
+/// Link editor is to sort the entries in this section based on their sizes
+pub const SHT_HEX_ORDERED: u32 = 0x70000000; // SHT_LOPROC + 0;
+/// Section is in the small data area reachable from GP
+pub const SHF_HEX_GPREL: u64 = 0x10000000;
+
+// Hexagon relocation types
+pub const R_HEX_NONE: u32 = 0;
+pub const R_HEX_B22_PCREL: u32 = 1;
+pub const R_HEX_B15_PCREL: u32 = 2;
+pub const R_HEX_B7_PCREL: u32 = 3;
+pub const R_HEX_LO16: u32 = 4;
+pub const R_HEX_HI16: u32 = 5;
+pub const R_HEX_32: u32 = 6;
+pub const R_HEX_16: u32 = 7;
+pub const R_HEX_8: u32 = 8;
+pub const R_HEX_GPREL16_0: u32 = 9;
+pub const R_HEX_GPREL16_1: u32 = 10;
+pub const R_HEX_GPREL16_2: u32 = 11;
+pub const R_HEX_GPREL16_3: u32 = 12;
+pub const R_HEX_HL16: u32 = 13;
+pub const R_HEX_B13_PCREL: u32 = 14;
+pub const R_HEX_B9_PCREL: u32 = 15;
+pub const R_HEX_B32_PCREL_X: u32 = 16;
+pub const R_HEX_32_6_X: u32 = 17;
+pub const R_HEX_B22_PCREL_X: u32 = 18;
+pub const R_HEX_B15_PCREL_X: u32 = 19;
+pub const R_HEX_B13_PCREL_X: u32 = 20;
+pub const R_HEX_B9_PCREL_X: u32 = 21;
+pub const R_HEX_B7_PCREL_X: u32 = 22;
+pub const R_HEX_16_X: u32 = 23;
+pub const R_HEX_12_X: u32 = 24;
+pub const R_HEX_11_X: u32 = 25;
+pub const R_HEX_10_X: u32 = 26;
+pub const R_HEX_9_X: u32 = 27;
+pub const R_HEX_8_X: u32 = 28;
+pub const R_HEX_7_X: u32 = 29;
+pub const R_HEX_6_X: u32 = 30;
+pub const R_HEX_32_PCREL: u32 = 31;
+pub const R_HEX_COPY: u32 = 32;
+pub const R_HEX_GLOB_DAT: u32 = 33;
+pub const R_HEX_JMP_SLOT: u32 = 34;
+pub const R_HEX_RELATIVE: u32 = 35;
+pub const R_HEX_PLT_B22_PCREL: u32 = 36;
+pub const R_HEX_GOTREL_LO16: u32 = 37;
+pub const R_HEX_GOTREL_HI16: u32 = 38;
+pub const R_HEX_GOTREL_32: u32 = 39;
+pub const R_HEX_GOT_LO16: u32 = 40;
+pub const R_HEX_GOT_HI16: u32 = 41;
+pub const R_HEX_GOT_32: u32 = 42;
+pub const R_HEX_GOT_16: u32 = 43;
+pub const R_HEX_DTPMOD_32: u32 = 44;
+pub const R_HEX_DTPREL_LO16: u32 = 45;
+pub const R_HEX_DTPREL_HI16: u32 = 46;
+pub const R_HEX_DTPREL_32: u32 = 47;
+pub const R_HEX_DTPREL_16: u32 = 48;
+pub const R_HEX_GD_PLT_B22_PCREL: u32 = 49;
+pub const R_HEX_GD_GOT_LO16: u32 = 50;
+pub const R_HEX_GD_GOT_HI16: u32 = 51;
+pub const R_HEX_GD_GOT_32: u32 = 52;
+pub const R_HEX_GD_GOT_16: u32 = 53;
+pub const R_HEX_IE_LO16: u32 = 54;
+pub const R_HEX_IE_HI16: u32 = 55;
+pub const R_HEX_IE_32: u32 = 56;
+pub const R_HEX_IE_GOT_LO16: u32 = 57;
+pub const R_HEX_IE_GOT_HI16: u32 = 58;
+pub const R_HEX_IE_GOT_32: u32 = 59;
+pub const R_HEX_IE_GOT_16: u32 = 60;
+pub const R_HEX_TPREL_LO16: u32 = 61;
+pub const R_HEX_TPREL_HI16: u32 = 62;
+pub const R_HEX_TPREL_32: u32 = 63;
+pub const R_HEX_TPREL_16: u32 = 64;
+pub const R_HEX_6_PCREL_X: u32 = 65;
+pub const R_HEX_GOTREL_32_6_X: u32 = 66;
+pub const R_HEX_GOTREL_16_X: u32 = 67;
+pub const R_HEX_GOTREL_11_X: u32 = 68;
+pub const R_HEX_GOT_32_6_X: u32 = 69;
+pub const R_HEX_GOT_16_X: u32 = 70;
+pub const R_HEX_GOT_11_X: u32 = 71;
+pub const R_HEX_DTPREL_32_6_X: u32 = 72;
+pub const R_HEX_DTPREL_16_X: u32 = 73;
+pub const R_HEX_DTPREL_11_X: u32 = 74;
+pub const R_HEX_GD_GOT_32_6_X: u32 = 75;
+pub const R_HEX_GD_GOT_16_X: u32 = 76;
+pub const R_HEX_GD_GOT_11_X: u32 = 77;
+pub const R_HEX_IE_32_6_X: u32 = 78;
+pub const R_HEX_IE_16_X: u32 = 79;
+pub const R_HEX_IE_GOT_32_6_X: u32 = 80;
+pub const R_HEX_IE_GOT_16_X: u32 = 81;
+pub const R_HEX_IE_GOT_11_X: u32 = 82;
+pub const R_HEX_TPREL_32_6_X: u32 = 83;
+pub const R_HEX_TPREL_16_X: u32 = 84;
+pub const R_HEX_TPREL_11_X: u32 = 85;
+pub const R_HEX_LD_PLT_B22_PCREL: u32 = 86;
+pub const R_HEX_LD_GOT_LO16: u32 = 87;
+pub const R_HEX_LD_GOT_HI16: u32 = 88;
+pub const R_HEX_LD_GOT_32: u32 = 89;
+pub const R_HEX_LD_GOT_16: u32 = 90;
+pub const R_HEX_LD_GOT_32_6_X: u32 = 91;
+pub const R_HEX_LD_GOT_16_X: u32 = 92;
+pub const R_HEX_LD_GOT_11_X: u32 = 93;
+pub const R_HEX_23_REG: u32 = 94;
+pub const R_HEX_GD_PLT_B22_PCREL_X: u32 = 95;
+pub const R_HEX_GD_PLT_B32_PCREL_X: u32 = 96;
+pub const R_HEX_LD_PLT_B22_PCREL_X: u32 = 97;
+pub const R_HEX_LD_PLT_B32_PCREL_X: u32 = 98;
+pub const R_HEX_27_REG: u32 = 99;
+
+//  _ _____  ___   __
+// (_)___ / ( _ ) / /_
+// | | |_ \ / _ \| '_ \
+// | |___) | (_) | (_) |
+// |_|____/ \___/ \___/
+//
+// See: https://gitlab.com/x86-psABIs/i386-ABI
+
+// i386 relocs
+/// No reloc
+pub const R_386_NONE: u32 = 0;
+/// Direct 32 bit
+pub const R_386_32: u32 = 1;
+/// PC relative 32 bit
+pub const R_386_PC32: u32 = 2;
+/// 32 bit GOT entry
+pub const R_386_GOT32: u32 = 3;
+/// 32 bit PLT address
+pub const R_386_PLT32: u32 = 4;
+/// Copy symbol at runtime
+pub const R_386_COPY: u32 = 5;
+/// Create GOT entry
+pub const R_386_GLOB_DAT: u32 = 6;
+/// Create PLT entry
+pub const R_386_JMP_SLOT: u32 = 7;
+/// Adjust by program base
+pub const R_386_RELATIVE: u32 = 8;
+/// 32 bit offset to GOT
+pub const R_386_GOTOFF: u32 = 9;
+/// 32 bit PC relative offset to GOT
+pub const R_386_GOTPC: u32 = 10;
+pub const R_386_32PLT: u32 = 11;
+/// Offset in static TLS block
+pub const R_386_TLS_TPOFF: u32 = 14;
+/// Address of GOT entry for static TLS block offset
+pub const R_386_TLS_IE: u32 = 15;
+/// GOT entry for static TLS block offset
+pub const R_386_TLS_GOTIE: u32 = 16;
+/// Offset relative to static TLS block
+pub const R_386_TLS_LE: u32 = 17;
+/// Direct 32 bit for GNU version of general dynamic thread local data
+pub const R_386_TLS_GD: u32 = 18;
+/// Direct 32 bit for GNU version of local dynamic thread local data in LE code
+pub const R_386_TLS_LDM: u32 = 19;
+pub const R_386_16: u32 = 20;
+pub const R_386_PC16: u32 = 21;
+pub const R_386_8: u32 = 22;
+pub const R_386_PC8: u32 = 23;
+/// Direct 32 bit for general dynamic thread local data
+pub const R_386_TLS_GD_32: u32 = 24;
+/// Tag for pushl in GD TLS code
+pub const R_386_TLS_GD_PUSH: u32 = 25;
+/// Relocation for call to __tls_get_addr()
+pub const R_386_TLS_GD_CALL: u32 = 26;
+/// Tag for popl in GD TLS code
+pub const R_386_TLS_GD_POP: u32 = 27;
+/// Direct 32 bit for local dynamic thread local data in LE code
+pub const R_386_TLS_LDM_32: u32 = 28;
+/// Tag for pushl in LDM TLS code
+pub const R_386_TLS_LDM_PUSH: u32 = 29;
+/// Relocation for call to __tls_get_addr() in LDM code
+pub const R_386_TLS_LDM_CALL: u32 = 30;
+/// Tag for popl in LDM TLS code
+pub const R_386_TLS_LDM_POP: u32 = 31;
+/// Offset relative to TLS block
+pub const R_386_TLS_LDO_32: u32 = 32;
+/// GOT entry for negated static TLS block offset
+pub const R_386_TLS_IE_32: u32 = 33;
+/// Negated offset relative to static TLS block
+pub const R_386_TLS_LE_32: u32 = 34;
+/// ID of module containing symbol
+pub const R_386_TLS_DTPMOD32: u32 = 35;
+/// Offset in TLS block
+pub const R_386_TLS_DTPOFF32: u32 = 36;
+/// Negated offset in static TLS block
+pub const R_386_TLS_TPOFF32: u32 = 37;
+/// 32-bit symbol size
+pub const R_386_SIZE32: u32 = 38;
+/// GOT offset for TLS descriptor
+pub const R_386_TLS_GOTDESC: u32 = 39;
+/// Marker of call through TLS descriptor for relaxation
+pub const R_386_TLS_DESC_CALL: u32 = 40;
+/// TLS descriptor containing pointer to code and to argument, returning the TLS offset for the
+/// symbol
+pub const R_386_TLS_DESC: u32 = 41;
+/// Adjust indirectly by program base
+pub const R_386_IRELATIVE: u32 = 42;
+/// Load from 32 bit GOT entry, relaxable
+pub const R_386_GOT32X: u32 = 43;
+
+//  _                                _             _
+// | |    ___   ___  _ __   __ _    / \   _ __ ___| |__
+// | |   / _ \ / _ \| '_ \ / _` |  / _ \ | '__/ __| '_ \
+// | |__| (_) | (_) | | | | (_| | / ___ \| | | (__| | | |
+// |_____\___/ \___/|_| |_|\__, |/_/   \_\_|  \___|_| |_|
+//                         |___/
+//
+// See: https://github.com/loongson/la-abi-specs/blob/release/laelf.adoc
+
+/// LoongArch ELF Flags
+pub const EF_LARCH_ABI_MODIFIER_MASK: u32 = 0x07;
+pub const EF_LARCH_ABI_SOFT_FLOAT: u32 = 0x01;
+pub const EF_LARCH_ABI_SINGLE_FLOAT: u32 = 0x02;
+pub const EF_LARCH_ABI_DOUBLE_FLOAT: u32 = 0x03;
+pub const EF_LARCH_OBJABI_V1: u32 = 0x40;
+
+/// LoongArch specific dynamic relocations
+pub const R_LARCH_NONE: u32 = 0;
+pub const R_LARCH_32: u32 = 1;
+pub const R_LARCH_64: u32 = 2;
+pub const R_LARCH_RELATIVE: u32 = 3;
+pub const R_LARCH_COPY: u32 = 4;
+pub const R_LARCH_JUMP_SLOT: u32 = 5;
+pub const R_LARCH_TLS_DTPMOD32: u32 = 6;
+pub const R_LARCH_TLS_DTPMOD64: u32 = 7;
+pub const R_LARCH_TLS_DTPREL32: u32 = 8;
+pub const R_LARCH_TLS_DTPREL64: u32 = 9;
+pub const R_LARCH_TLS_TPREL32: u32 = 10;
+pub const R_LARCH_TLS_TPREL64: u32 = 11;
+pub const R_LARCH_IRELATIVE: u32 = 12;
+pub const R_LARCH_TLS_DESC32: u32 = 13;
+pub const R_LARCH_TLS_DESC64: u32 = 14;
+
+// Reserved for future relocs that the dynamic linker must understand
+/// used by the static linker for relocating .text
+pub const R_LARCH_MARK_LA: u32 = 20;
+pub const R_LARCH_MARK_PCREL: u32 = 21;
+pub const R_LARCH_SOP_PUSH_PCREL: u32 = 22;
+pub const R_LARCH_SOP_PUSH_ABSOLUTE: u32 = 23;
+pub const R_LARCH_SOP_PUSH_DUP: u32 = 24;
+pub const R_LARCH_SOP_PUSH_GPREL: u32 = 25;
+pub const R_LARCH_SOP_PUSH_TLS_TPREL: u32 = 26;
+pub const R_LARCH_SOP_PUSH_TLS_GOT: u32 = 27;
+pub const R_LARCH_SOP_PUSH_TLS_GD: u32 = 28;
+pub const R_LARCH_SOP_PUSH_PLT_PCREL: u32 = 29;
+pub const R_LARCH_SOP_ASSERT: u32 = 30;
+pub const R_LARCH_SOP_NOT: u32 = 31;
+pub const R_LARCH_SOP_SUB: u32 = 32;
+pub const R_LARCH_SOP_SL: u32 = 33;
+pub const R_LARCH_SOP_SR: u32 = 34;
+pub const R_LARCH_SOP_ADD: u32 = 35;
+pub const R_LARCH_SOP_AND: u32 = 36;
+pub const R_LARCH_SOP_IF_ELSE: u32 = 37;
+pub const R_LARCH_SOP_POP_32_S_10_5: u32 = 38;
+pub const R_LARCH_SOP_POP_32_U_10_12: u32 = 39;
+pub const R_LARCH_SOP_POP_32_S_10_12: u32 = 40;
+pub const R_LARCH_SOP_POP_32_S_10_16: u32 = 41;
+pub const R_LARCH_SOP_POP_32_S_10_16_S2: u32 = 42;
+pub const R_LARCH_SOP_POP_32_S_5_20: u32 = 43;
+pub const R_LARCH_SOP_POP_32_S_0_5_10_16_S2: u32 = 44;
+pub const R_LARCH_SOP_POP_32_S_0_10_10_16_S2: u32 = 45;
+pub const R_LARCH_SOP_POP_32_U: u32 = 46;
+
+/// used by the static linker for relocating non .text
+pub const R_LARCH_ADD8: u32 = 47;
+pub const R_LARCH_ADD16: u32 = 48;
+pub const R_LARCH_ADD24: u32 = 49;
+pub const R_LARCH_ADD32: u32 = 50;
+pub const R_LARCH_ADD64: u32 = 51;
+pub const R_LARCH_SUB8: u32 = 52;
+pub const R_LARCH_SUB16: u32 = 53;
+pub const R_LARCH_SUB24: u32 = 54;
+pub const R_LARCH_SUB32: u32 = 55;
+pub const R_LARCH_SUB64: u32 = 56;
+pub const R_LARCH_GNU_VTINHERIT: u32 = 57;
+pub const R_LARCH_GNU_VTENTRY: u32 = 58;
+
+// Relocations added in version 2 of the LoongArch psABI
+pub const R_LARCH_B16: u32 = 64;
+pub const R_LARCH_B21: u32 = 65;
+pub const R_LARCH_B26: u32 = 66;
+pub const R_LARCH_ABS_HI20: u32 = 67;
+pub const R_LARCH_ABS_LO12: u32 = 68;
+pub const R_LARCH_ABS64_LO20: u32 = 69;
+pub const R_LARCH_ABS64_HI12: u32 = 70;
+pub const R_LARCH_PCALA_HI20: u32 = 71;
+pub const R_LARCH_PCALA_LO12: u32 = 72;
+pub const R_LARCH_PCALA64_LO20: u32 = 73;
+pub const R_LARCH_PCALA64_HI12: u32 = 74;
+pub const R_LARCH_GOT_PC_HI20: u32 = 75;
+pub const R_LARCH_GOT_PC_LO12: u32 = 76;
+pub const R_LARCH_GOT64_PC_LO20: u32 = 77;
+pub const R_LARCH_GOT64_PC_HI12: u32 = 78;
+pub const R_LARCH_GOT_HI20: u32 = 79;
+pub const R_LARCH_GOT_LO12: u32 = 80;
+pub const R_LARCH_GOT64_LO20: u32 = 81;
+pub const R_LARCH_GOT64_HI12: u32 = 82;
+pub const R_LARCH_TLS_LE_HI20: u32 = 83;
+pub const R_LARCH_TLS_LE_LO12: u32 = 84;
+pub const R_LARCH_TLS_LE64_LO20: u32 = 85;
+pub const R_LARCH_TLS_LE64_HI12: u32 = 86;
+pub const R_LARCH_TLS_IE_PC_HI20: u32 = 87;
+pub const R_LARCH_TLS_IE_PC_LO12: u32 = 88;
+pub const R_LARCH_TLS_IE64_PC_LO20: u32 = 89;
+pub const R_LARCH_TLS_IE64_PC_HI12: u32 = 90;
+pub const R_LARCH_TLS_IE_HI20: u32 = 91;
+pub const R_LARCH_TLS_IE_LO12: u32 = 92;
+pub const R_LARCH_TLS_IE64_LO20: u32 = 93;
+pub const R_LARCH_TLS_IE64_HI12: u32 = 94;
+pub const R_LARCH_TLS_LD_PC_HI20: u32 = 95;
+pub const R_LARCH_TLS_LD_HI20: u32 = 96;
+pub const R_LARCH_TLS_GD_PC_HI20: u32 = 97;
+pub const R_LARCH_TLS_GD_HI20: u32 = 98;
+pub const R_LARCH_32_PCREL: u32 = 99;
+pub const R_LARCH_RELAX: u32 = 100;
+pub const R_LARCH_ALIGN: u32 = 102;
+pub const R_LARCH_PCREL20_S2: u32 = 103;
+pub const R_LARCH_ADD6: u32 = 105;
+pub const R_LARCH_SUB6: u32 = 106;
+pub const R_LARCH_ADD_ULEB128: u32 = 107;
+pub const R_LARCH_SUB_ULEB128: u32 = 108;
+pub const R_LARCH_64_PCREL: u32 = 109;
+pub const R_LARCH_CALL36: u32 = 110;
+pub const R_LARCH_TLS_DESC_PC_HI20: u32 = 111;
+pub const R_LARCH_TLS_DESC_PC_LO12: u32 = 112;
+pub const R_LARCH_TLS_DESC64_PC_LO20: u32 = 113;
+pub const R_LARCH_TLS_DESC64_PC_HI12: u32 = 114;
+pub const R_LARCH_TLS_DESC_HI20: u32 = 115;
+pub const R_LARCH_TLS_DESC_LO12: u32 = 116;
+pub const R_LARCH_TLS_DESC64_LO20: u32 = 117;
+pub const R_LARCH_TLS_DESC64_HI12: u32 = 118;
+pub const R_LARCH_TLS_DESC_LD: u32 = 119;
+pub const R_LARCH_TLS_DESC_CALL: u32 = 120;
+pub const R_LARCH_TLS_LE_HI20_R: u32 = 121;
+pub const R_LARCH_TLS_LE_ADD_R: u32 = 122;
+pub const R_LARCH_TLS_LE_LO12_R: u32 = 123;
+pub const R_LARCH_TLS_LD_PCREL20_S2: u32 = 124;
+pub const R_LARCH_TLS_GD_PCREL20_S2: u32 = 125;
+pub const R_LARCH_TLS_DESC_PCREL20_S2: u32 = 126;
+
+//  __  __ ___ ____  ____
+// |  \/  |_ _|  _ \/ ___|
+// | |\/| || || |_) \___ \
+// | |  | || ||  __/ ___) |
+// |_|  |_|___|_|   |____/
+//
+// See: https://refspecs.linuxfoundation.org/elf/mipsabi.pdf
+
+// Legal values for e_flags field of Elf32_Ehdr
+/// A .noreorder directive was used
+pub const EF_MIPS_NOREORDER: u32 = 1;
+/// Contains PIC code
+pub const EF_MIPS_PIC: u32 = 2;
+/// Uses PIC calling sequence
+pub const EF_MIPS_CPIC: u32 = 4;
+pub const EF_MIPS_XGOT: u32 = 8;
+pub const EF_MIPS_64BIT_WHIRL: u32 = 16;
+pub const EF_MIPS_ABI2: u32 = 32;
+pub const EF_MIPS_ABI_ON32: u32 = 64;
+/// Uses FP64 (12 callee-saved)
+pub const EF_MIPS_FP64: u32 = 512;
+/// Uses IEEE 754-2008 NaN encoding
+pub const EF_MIPS_NAN2008: u32 = 1024;
+/// MIPS architecture level
+pub const EF_MIPS_ARCH: u32 = 0xf0000000;
+
+/// Mask for selecting the EF_MIPS_ABI_* variant
+pub const EF_MIPS_ABI: u32 = 0x0000f000;
+/// The original 32-bit MIPS ABI
+pub const EF_MIPS_ABI_O32: u32 = 0x00001000;
+/// The O32 ABI extended for 64-bit architectures
+pub const EF_MIPS_ABI_O64: u32 = 0x00002000;
+/// EABI in 32-bit mode
+pub const EF_MIPS_ABI_EABI32: u32 = 0x00003000;
+/// EABI in 64-bit mode
+pub const EF_MIPS_ABI_EABI64: u32 = 0x00004000;
+/// Mask for the EF_MIPS_ARCH_ASE_* flags
+pub const EF_MIPS_ARCH_ASE: u32 = 0x0f000000;
+/// Has MDMX multimedia extensions
+pub const EF_MIPS_ARCH_ASE_MDMX: u32 = 0x08000000;
+/// Has MIPS-16 ISA extensions
+pub const EF_MIPS_ARCH_ASE_M16: u32 = 0x04000000;
+/// microMIPS
+pub const EF_MIPS_MICROMIPS: u32 = 0x02000000;
+
+// Legal values for MIPS architecture level
+/// -mips1 code
+pub const EF_MIPS_ARCH_1: u32 = 0x00000000;
+/// -mips2 code
+pub const EF_MIPS_ARCH_2: u32 = 0x10000000;
+/// -mips3 code
+pub const EF_MIPS_ARCH_3: u32 = 0x20000000;
+/// -mips4 code
+pub const EF_MIPS_ARCH_4: u32 = 0x30000000;
+/// -mips5 code
+pub const EF_MIPS_ARCH_5: u32 = 0x40000000;
+/// MIPS32 code
+pub const EF_MIPS_ARCH_32: u32 = 0x50000000;
+/// MIPS64 code
+pub const EF_MIPS_ARCH_64: u32 = 0x60000000;
+/// MIPS32r2 code
+pub const EF_MIPS_ARCH_32R2: u32 = 0x70000000;
+/// MIPS64r2 code
+pub const EF_MIPS_ARCH_64R2: u32 = 0x80000000;
+/// MIPS32r6 code
+pub const EF_MIPS_ARCH_32R6: u32 = 0x90000000;
+/// MIPS64r6 code
+pub const EF_MIPS_ARCH_64R6: u32 = 0xa0000000;
+
+// Special section indices
+/// Allocated common symbols
+pub const SHN_MIPS_ACOMMON: u16 = 0xff00;
+/// Allocated test symbols
+pub const SHN_MIPS_TEXT: u16 = 0xff01;
+/// Allocated data symbols
+pub const SHN_MIPS_DATA: u16 = 0xff02;
+/// Small common symbols
+pub const SHN_MIPS_SCOMMON: u16 = 0xff03;
+/// Small undefined symbols
+pub const SHN_MIPS_SUNDEFINED: u16 = 0xff04;
+
+// Legal values for sh_type field of Elf32_Shdr
+/// Shared objects used in link
+pub const SHT_MIPS_LIBLIST: u32 = 0x70000000;
+pub const SHT_MIPS_MSYM: u32 = 0x70000001;
+/// Conflicting symbols
+pub const SHT_MIPS_CONFLICT: u32 = 0x70000002;
+/// Global data area sizes
+pub const SHT_MIPS_GPTAB: u32 = 0x70000003;
+/// Reserved for SGI/MIPS compilers
+pub const SHT_MIPS_UCODE: u32 = 0x70000004;
+/// MIPS ECOFF debugging info
+pub const SHT_MIPS_DEBUG: u32 = 0x70000005;
+/// Register usage information
+pub const SHT_MIPS_REGINFO: u32 = 0x70000006;
+pub const SHT_MIPS_PACKAGE: u32 = 0x70000007;
+pub const SHT_MIPS_PACKSYM: u32 = 0x70000008;
+pub const SHT_MIPS_RELD: u32 = 0x70000009;
+pub const SHT_MIPS_IFACE: u32 = 0x7000000b;
+pub const SHT_MIPS_CONTENT: u32 = 0x7000000c;
+/// Miscellaneous options
+pub const SHT_MIPS_OPTIONS: u32 = 0x7000000d;
+pub const SHT_MIPS_SHDR: u32 = 0x70000010;
+pub const SHT_MIPS_FDESC: u32 = 0x70000011;
+pub const SHT_MIPS_EXTSYM: u32 = 0x70000012;
+pub const SHT_MIPS_DENSE: u32 = 0x70000013;
+pub const SHT_MIPS_PDESC: u32 = 0x70000014;
+pub const SHT_MIPS_LOCSYM: u32 = 0x70000015;
+pub const SHT_MIPS_AUXSYM: u32 = 0x70000016;
+pub const SHT_MIPS_OPTSYM: u32 = 0x70000017;
+pub const SHT_MIPS_LOCSTR: u32 = 0x70000018;
+pub const SHT_MIPS_LINE: u32 = 0x70000019;
+pub const SHT_MIPS_RFDESC: u32 = 0x7000001a;
+pub const SHT_MIPS_DELTASYM: u32 = 0x7000001b;
+pub const SHT_MIPS_DELTAINST: u32 = 0x7000001c;
+pub const SHT_MIPS_DELTACLASS: u32 = 0x7000001d;
+/// DWARF debugging information
+pub const SHT_MIPS_DWARF: u32 = 0x7000001e;
+pub const SHT_MIPS_DELTADECL: u32 = 0x7000001f;
+pub const SHT_MIPS_SYMBOL_LIB: u32 = 0x70000020;
+/// Event section
+pub const SHT_MIPS_EVENTS: u32 = 0x70000021;
+pub const SHT_MIPS_TRANSLATE: u32 = 0x70000022;
+pub const SHT_MIPS_PIXIE: u32 = 0x70000023;
+pub const SHT_MIPS_XLATE: u32 = 0x70000024;
+pub const SHT_MIPS_XLATE_DEBUG: u32 = 0x70000025;
+pub const SHT_MIPS_WHIRL: u32 = 0x70000026;
+pub const SHT_MIPS_EH_REGION: u32 = 0x70000027;
+pub const SHT_MIPS_XLATE_OLD: u32 = 0x70000028;
+pub const SHT_MIPS_PDR_EXCEPTION: u32 = 0x70000029;
+pub const SHT_MIPS_XHASH: u32 = 0x7000002b;
+/// ABI information
+pub const SHT_MIPS_ABIFLAGS: u32 = 0x7000002a;
+
+// Legal values for sh_flags field of Elf32_Shdr
+/// Must be in global data area
+pub const SHF_MIPS_GPREL: u64 = 0x10000000;
+pub const SHF_MIPS_MERGE: u64 = 0x20000000;
+pub const SHF_MIPS_ADDR: u64 = 0x40000000;
+pub const SHF_MIPS_STRINGS: u64 = 0x80000000;
+pub const SHF_MIPS_NOSTRIP: u64 = 0x08000000;
+pub const SHF_MIPS_LOCAL: u64 = 0x04000000;
+pub const SHF_MIPS_NAMES: u64 = 0x02000000;
+pub const SHF_MIPS_NODUPE: u64 = 0x01000000;
+
+// MIPS specific values for `st_other`
+pub const STO_MIPS_DEFAULT: u8 = 0x0;
+pub const STO_MIPS_INTERNAL: u8 = 0x1;
+pub const STO_MIPS_HIDDEN: u8 = 0x2;
+pub const STO_MIPS_PROTECTED: u8 = 0x3;
+pub const STO_MIPS_PLT: u8 = 0x8;
+pub const STO_MIPS_SC_ALIGN_UNUSED: u8 = 0xff;
+
+// MIPS specific values for `st_info`
+pub const STB_MIPS_SPLIT_COMMON: u8 = 13;
+
+// MIPS relocs
+/// No reloc
+pub const R_MIPS_NONE: u32 = 0;
+/// Direct 16 bit
+pub const R_MIPS_16: u32 = 1;
+/// Direct 32 bit
+pub const R_MIPS_32: u32 = 2;
+/// PC relative 32 bit
+pub const R_MIPS_REL32: u32 = 3;
+/// Direct 26 bit shifted
+pub const R_MIPS_26: u32 = 4;
+/// High 16 bit
+pub const R_MIPS_HI16: u32 = 5;
+/// Low 16 bit
+pub const R_MIPS_LO16: u32 = 6;
+/// GP relative 16 bit
+pub const R_MIPS_GPREL16: u32 = 7;
+/// 16 bit literal entry
+pub const R_MIPS_LITERAL: u32 = 8;
+/// 16 bit GOT entry
+pub const R_MIPS_GOT16: u32 = 9;
+/// PC relative 16 bit
+pub const R_MIPS_PC16: u32 = 10;
+/// 16 bit GOT entry for function
+pub const R_MIPS_CALL16: u32 = 11;
+/// GP relative 32 bit
+pub const R_MIPS_GPREL32: u32 = 12;
+
+pub const R_MIPS_SHIFT5: u32 = 16;
+pub const R_MIPS_SHIFT6: u32 = 17;
+pub const R_MIPS_64: u32 = 18;
+pub const R_MIPS_GOT_DISP: u32 = 19;
+pub const R_MIPS_GOT_PAGE: u32 = 20;
+pub const R_MIPS_GOT_OFST: u32 = 21;
+pub const R_MIPS_GOT_HI16: u32 = 22;
+pub const R_MIPS_GOT_LO16: u32 = 23;
+pub const R_MIPS_SUB: u32 = 24;
+pub const R_MIPS_INSERT_A: u32 = 25;
+pub const R_MIPS_INSERT_B: u32 = 26;
+pub const R_MIPS_DELETE: u32 = 27;
+pub const R_MIPS_HIGHER: u32 = 28;
+pub const R_MIPS_HIGHEST: u32 = 29;
+pub const R_MIPS_CALL_HI16: u32 = 30;
+pub const R_MIPS_CALL_LO16: u32 = 31;
+pub const R_MIPS_SCN_DISP: u32 = 32;
+pub const R_MIPS_REL16: u32 = 33;
+pub const R_MIPS_ADD_IMMEDIATE: u32 = 34;
+pub const R_MIPS_PJUMP: u32 = 35;
+pub const R_MIPS_RELGOT: u32 = 36;
+pub const R_MIPS_JALR: u32 = 37;
+/// Module number 32 bit
+pub const R_MIPS_TLS_DTPMOD32: u32 = 38;
+/// Module-relative offset 32 bit
+pub const R_MIPS_TLS_DTPREL32: u32 = 39;
+/// Module number 64 bit
+pub const R_MIPS_TLS_DTPMOD64: u32 = 40;
+/// Module-relative offset 64 bit
+pub const R_MIPS_TLS_DTPREL64: u32 = 41;
+/// 16 bit GOT offset for GD
+pub const R_MIPS_TLS_GD: u32 = 42;
+/// 16 bit GOT offset for LDM
+pub const R_MIPS_TLS_LDM: u32 = 43;
+/// Module-relative offset, high 16 bits
+pub const R_MIPS_TLS_DTPREL_HI16: u32 = 44;
+/// Module-relative offset, low 16 bits
+pub const R_MIPS_TLS_DTPREL_LO16: u32 = 45;
+/// 16 bit GOT offset for IE
+pub const R_MIPS_TLS_GOTTPREL: u32 = 46;
+/// TP-relative offset, 32 bit
+pub const R_MIPS_TLS_TPREL32: u32 = 47;
+/// TP-relative offset, 64 bit
+pub const R_MIPS_TLS_TPREL64: u32 = 48;
+/// TP-relative offset, high 16 bits
+pub const R_MIPS_TLS_TPREL_HI16: u32 = 49;
+/// TP-relative offset, low 16 bits
+pub const R_MIPS_TLS_TPREL_LO16: u32 = 50;
+pub const R_MIPS_GLOB_DAT: u32 = 51;
+pub const R_MIPS_COPY: u32 = 126;
+pub const R_MIPS_JUMP_SLOT: u32 = 127;
+
+// Special symbols (r_ssym) for the second relocation type of a MIPS64 relocation
+/// No special symbol
+pub const RSS_UNDEF: u8 = 0;
+/// The value of gp
+pub const RSS_GP: u8 = 1;
+/// The value of gp used to create the relocatable object
+pub const RSS_GP0: u8 = 2;
+/// The address of the location being relocated
+pub const RSS_LOC: u8 = 3;
+
+// Legal values for p_type field of Elf32_Phdr
+/// Register usage information
+pub const PT_MIPS_REGINFO: u32 = 0x70000000;
+/// Runtime procedure table
+pub const PT_MIPS_RTPROC: u32 = 0x70000001;
+pub const PT_MIPS_OPTIONS: u32 = 0x70000002;
+/// FP mode requirement
+pub const PT_MIPS_ABIFLAGS: u32 = 0x70000003;
+
+// Special program header types
+pub const PF_MIPS_LOCAL: u32 = 0x10000000;
+
+// Legal values for d_tag field of Elf32_Dyn
+/// Runtime linker interface version
+pub const DT_MIPS_RLD_VERSION: i64 = 0x70000001;
+/// Timestamp
+pub const DT_MIPS_TIME_STAMP: i64 = 0x70000002;
+/// Checksum
+pub const DT_MIPS_ICHECKSUM: i64 = 0x70000003;
+/// Version string (string tbl index)
+pub const DT_MIPS_IVERSION: i64 = 0x70000004;
+/// Flags
+pub const DT_MIPS_FLAGS: i64 = 0x70000005;
+/// Base address
+pub const DT_MIPS_BASE_ADDRESS: i64 = 0x70000006;
+pub const DT_MIPS_MSYM: i64 = 0x70000007;
+/// Address of CONFLICT section
+pub const DT_MIPS_CONFLICT: i64 = 0x70000008;
+/// Address of LIBLIST section
+pub const DT_MIPS_LIBLIST: i64 = 0x70000009;
+/// Number of local GOT entries
+pub const DT_MIPS_LOCAL_GOTNO: i64 = 0x7000000a;
+/// Number of CONFLICT entries
+pub const DT_MIPS_CONFLICTNO: i64 = 0x7000000b;
+/// Number of LIBLIST entries
+pub const DT_MIPS_LIBLISTNO: i64 = 0x70000010;
+/// Number of DYNSYM entries
+pub const DT_MIPS_SYMTABNO: i64 = 0x70000011;
+/// First external DYNSYM
+pub const DT_MIPS_UNREFEXTNO: i64 = 0x70000012;
+/// First GOT entry in DYNSYM
+pub const DT_MIPS_GOTSYM: i64 = 0x70000013;
+/// Number of GOT page table entries
+pub const DT_MIPS_HIPAGENO: i64 = 0x70000014;
+/// Address of run time loader map
+pub const DT_MIPS_RLD_MAP: i64 = 0x70000016;
+/// Delta C++ class definition
+pub const DT_MIPS_DELTA_CLASS: i64 = 0x70000017;
+/// Number of entries in DT_MIPS_DELTA_CLASS
+pub const DT_MIPS_DELTA_CLASS_NO: i64 = 0x70000018;
+/// Delta C++ class instances
+pub const DT_MIPS_DELTA_INSTANCE: i64 = 0x70000019;
+/// Number of entries in DT_MIPS_DELTA_INSTANCE
+pub const DT_MIPS_DELTA_INSTANCE_NO: i64 = 0x7000001a;
+/// Delta relocations
+pub const DT_MIPS_DELTA_RELOC: i64 = 0x7000001b;
+/// Number of entries in DT_MIPS_DELTA_RELOC
+pub const DT_MIPS_DELTA_RELOC_NO: i64 = 0x7000001c;
+/// Delta symbols that Delta relocations refer to
+pub const DT_MIPS_DELTA_SYM: i64 = 0x7000001d;
+/// Number of entries in DT_MIPS_DELTA_SYM
+pub const DT_MIPS_DELTA_SYM_NO: i64 = 0x7000001e;
+/// Delta symbols that hold the class declaration
+pub const DT_MIPS_DELTA_CLASSSYM: i64 = 0x70000020;
+/// Number of entries in DT_MIPS_DELTA_CLASSSYM
+pub const DT_MIPS_DELTA_CLASSSYM_NO: i64 = 0x70000021;
+/// Flags indicating for C++ flavor
+pub const DT_MIPS_CXX_FLAGS: i64 = 0x70000022;
+pub const DT_MIPS_PIXIE_INIT: i64 = 0x70000023;
+pub const DT_MIPS_SYMBOL_LIB: i64 = 0x70000024;
+pub const DT_MIPS_LOCALPAGE_GOTIDX: i64 = 0x70000025;
+pub const DT_MIPS_LOCAL_GOTIDX: i64 = 0x70000026;
+pub const DT_MIPS_HIDDEN_GOTIDX: i64 = 0x70000027;
+pub const DT_MIPS_PROTECTED_GOTIDX: i64 = 0x70000028;
+/// Address of .options
+pub const DT_MIPS_OPTIONS: i64 = 0x70000029;
+/// Address of .interface
+pub const DT_MIPS_INTERFACE: i64 = 0x7000002a;
+pub const DT_MIPS_DYNSTR_ALIGN: i64 = 0x7000002b;
+/// Size of the .interface section
+pub const DT_MIPS_INTERFACE_SIZE: i64 = 0x7000002c;
+/// Address of rld_text_rsolve function stored in GOT
+pub const DT_MIPS_RLD_TEXT_RESOLVE_ADDR: i64 = 0x7000002d;
+/// Default suffix of dso to be added by rld on dlopen() calls
+pub const DT_MIPS_PERF_SUFFIX: i64 = 0x7000002e;
+/// (O32)Size of compact rel section
+pub const DT_MIPS_COMPACT_SIZE: i64 = 0x7000002f;
+/// GP value for aux GOTs
+pub const DT_MIPS_GP_VALUE: i64 = 0x70000030;
+/// Address of aux .dynamic
+pub const DT_MIPS_AUX_DYNAMIC: i64 = 0x70000031;
+/// The address of .got.plt in an executable using the new non-PIC ABI
+pub const DT_MIPS_PLTGOT: i64 = 0x70000032;
+/// The base of the PLT in an executable using the new non-PIC ABI if that PLT is writable. For a
+/// non-writable PLT, this is omitted or has a zero value
+pub const DT_MIPS_RWPLT: i64 = 0x70000034;
+/// An alternative description of the classic MIPS RLD_MAP that is usable in a PIE as it stores a
+/// relative offset from the address of the tag rather than an absolute address
+pub const DT_MIPS_RLD_MAP_REL: i64 = 0x70000035;
+/// GNU-style hash table with xlat
+pub const DT_MIPS_XHASH: i64 = 0x70000036;
+
 //  ____                        ____   ____
 // |  _ \ _____      _____ _ __|  _ \ / ___|
 // | |_) / _ \ \ /\ / / _ \ '__| |_) | |
@@ -2326,7 +3191,7 @@ pub const R_PPC64_DTPMOD64: u32 = 68;
 /// `@tprel`
 pub const R_PPC64_TPREL16: u32 = 69;
 /// `#lo(@tprel)`
-pub const R_PPC64_TPREL16_LO: u32 = 60;
+pub const R_PPC64_TPREL16_LO: u32 = 70;
 /// `#hi(@tprel)`
 pub const R_PPC64_TPREL16_HI: u32 = 71;
 /// `#ha(@tprel)`
@@ -2447,6 +3312,22 @@ pub const EF_RISCV_TSO: u32 = 0x0010;
 
 pub const SHT_RISCV_ATTRIBUTES: u32 = 0x70000003; // SHT_LOPROC + 3;
 pub const SHT_RISCV_ATTRIBUTES_SECTION_NAME: &str = ".riscv.attributes";
+/// The vendor name for RISC-V attributes in a SHT_RISCV_ATTRIBUTES section
+pub const ELF_ATTR_VENDOR_RISCV: &str = "riscv";
+
+// RISC-V build attribute tags.
+// See: https://github.com/riscv-non-isa/riscv-elf-psabi-doc/blob/master/riscv-elf.adoc#attributes
+/// The stack alignment requirement in bytes
+pub const TAG_RISCV_STACK_ALIGN: u64 = 4;
+/// The target architecture of the object as an ISA string, e.g. "rv64i2p1_m2p0_a2p1"
+pub const TAG_RISCV_ARCH: u64 = 5;
+/// Whether the object may make misaligned memory accesses
+pub const TAG_RISCV_UNALIGNED_ACCESS: u64 = 6;
+pub const TAG_RISCV_PRIV_SPEC: u64 = 8;
+pub const TAG_RISCV_PRIV_SPEC_MINOR: u64 = 10;
+pub const TAG_RISCV_PRIV_SPEC_REVISION: u64 = 12;
+pub const TAG_RISCV_ATOMIC_ABI: u64 = 14;
+pub const TAG_RISCV_X3_REG_USAGE: u64 = 16;
 
 pub const PT_RISCV_ATTRIBUTES: u32 = 0x70000003;
 
@@ -2571,6 +3452,330 @@ pub const R_RISCV_32_PCREL: u32 = 57;
 /// Relocation against a non-preemptible ifunc symbolifunc_resolver: `(B + A)`
 pub const R_RISCV_IRELATIVE: u32 = 58;
 
+//      _____  ___   ___
+//  ___|___ / / _ \ / _ \__  __
+// / __| |_ \| (_) | | | \ \/ /
+// \__ \___) |\__, | |_| |>  <
+// |___/____/   /_/ \___//_/\_\
+//
+// See: https://github.com/IBM/s390x-abi
+
+// Valid values for the e_flags field
+/// High GPRs kernel facility needed
+pub const EF_S390_HIGH_GPRS: u32 = 0x00000001;
+
+// s390 relocs
+/// No reloc
+pub const R_390_NONE: u32 = 0;
+/// Direct 8 bit
+pub const R_390_8: u32 = 1;
+/// Direct 12 bit
+pub const R_390_12: u32 = 2;
+/// Direct 16 bit
+pub const R_390_16: u32 = 3;
+/// Direct 32 bit
+pub const R_390_32: u32 = 4;
+/// PC relative 32 bit
+pub const R_390_PC32: u32 = 5;
+/// 12 bit GOT offset
+pub const R_390_GOT12: u32 = 6;
+/// 32 bit GOT offset
+pub const R_390_GOT32: u32 = 7;
+/// 32 bit PC relative PLT address
+pub const R_390_PLT32: u32 = 8;
+/// Copy symbol at runtime
+pub const R_390_COPY: u32 = 9;
+/// Create GOT entry
+pub const R_390_GLOB_DAT: u32 = 10;
+/// Create PLT entry
+pub const R_390_JMP_SLOT: u32 = 11;
+/// Adjust by program base
+pub const R_390_RELATIVE: u32 = 12;
+/// 32 bit offset to GOT
+pub const R_390_GOTOFF32: u32 = 13;
+/// 32 bit PC relative offset to GOT
+pub const R_390_GOTPC: u32 = 14;
+/// 16 bit GOT offset
+pub const R_390_GOT16: u32 = 15;
+/// PC relative 16 bit
+pub const R_390_PC16: u32 = 16;
+/// PC relative 16 bit shifted by 1
+pub const R_390_PC16DBL: u32 = 17;
+/// 16 bit PC rel. PLT shifted by 1
+pub const R_390_PLT16DBL: u32 = 18;
+/// PC relative 32 bit shifted by 1
+pub const R_390_PC32DBL: u32 = 19;
+/// 32 bit PC rel. PLT shifted by 1
+pub const R_390_PLT32DBL: u32 = 20;
+/// 32 bit PC rel. GOT shifted by 1
+pub const R_390_GOTPCDBL: u32 = 21;
+/// Direct 64 bit
+pub const R_390_64: u32 = 22;
+/// PC relative 64 bit
+pub const R_390_PC64: u32 = 23;
+/// 64 bit GOT offset
+pub const R_390_GOT64: u32 = 24;
+/// 64 bit PC relative PLT address
+pub const R_390_PLT64: u32 = 25;
+/// 32 bit PC rel. to GOT entry >> 1
+pub const R_390_GOTENT: u32 = 26;
+/// 16 bit offset to GOT
+pub const R_390_GOTOFF16: u32 = 27;
+/// 64 bit offset to GOT
+pub const R_390_GOTOFF64: u32 = 28;
+/// 12 bit offset to jump slot
+pub const R_390_GOTPLT12: u32 = 29;
+/// 16 bit offset to jump slot
+pub const R_390_GOTPLT16: u32 = 30;
+/// 32 bit offset to jump slot
+pub const R_390_GOTPLT32: u32 = 31;
+/// 64 bit offset to jump slot
+pub const R_390_GOTPLT64: u32 = 32;
+/// 32 bit rel. offset to jump slot
+pub const R_390_GOTPLTENT: u32 = 33;
+/// 16 bit offset from GOT to PLT
+pub const R_390_PLTOFF16: u32 = 34;
+/// 32 bit offset from GOT to PLT
+pub const R_390_PLTOFF32: u32 = 35;
+/// 16 bit offset from GOT to PLT
+pub const R_390_PLTOFF64: u32 = 36;
+/// Tag for load insn in TLS code
+pub const R_390_TLS_LOAD: u32 = 37;
+/// Tag for function call in general dynamic TLS code
+pub const R_390_TLS_GDCALL: u32 = 38;
+/// Tag for function call in local dynamic TLS code
+pub const R_390_TLS_LDCALL: u32 = 39;
+/// Direct 32 bit for general dynamic thread local data
+pub const R_390_TLS_GD32: u32 = 40;
+/// Direct 64 bit for general dynamic thread local data
+pub const R_390_TLS_GD64: u32 = 41;
+/// 12 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE12: u32 = 42;
+/// 32 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE32: u32 = 43;
+/// 64 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE64: u32 = 44;
+/// Direct 32 bit for local dynamic thread local data in LE code
+pub const R_390_TLS_LDM32: u32 = 45;
+/// Direct 64 bit for local dynamic thread local data in LE code
+pub const R_390_TLS_LDM64: u32 = 46;
+/// 32 bit address of GOT entry for negated static TLS block offset
+pub const R_390_TLS_IE32: u32 = 47;
+/// 64 bit address of GOT entry for negated static TLS block offset
+pub const R_390_TLS_IE64: u32 = 48;
+/// 32 bit rel. offset to GOT entry for negated static TLS block offset
+pub const R_390_TLS_IEENT: u32 = 49;
+/// 32 bit negated offset relative to static TLS block
+pub const R_390_TLS_LE32: u32 = 50;
+/// 64 bit negated offset relative to static TLS block
+pub const R_390_TLS_LE64: u32 = 51;
+/// 32 bit offset relative to TLS block
+pub const R_390_TLS_LDO32: u32 = 52;
+/// 64 bit offset relative to TLS block
+pub const R_390_TLS_LDO64: u32 = 53;
+/// ID of module containing symbol
+pub const R_390_TLS_DTPMOD: u32 = 54;
+/// Offset in TLS block
+pub const R_390_TLS_DTPOFF: u32 = 55;
+/// Negated offset in static TLS block
+pub const R_390_TLS_TPOFF: u32 = 56;
+/// Direct 20 bit
+pub const R_390_20: u32 = 57;
+/// 20 bit GOT offset
+pub const R_390_GOT20: u32 = 58;
+/// 20 bit offset to jump slot
+pub const R_390_GOTPLT20: u32 = 59;
+/// 20 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE20: u32 = 60;
+/// STT_GNU_IFUNC relocation
+pub const R_390_IRELATIVE: u32 = 61;
+
+//  ____  ____   _    ____   ____
+// / ___||  _ \ / \  |  _ \ / ___|
+// \___ \| |_) / _ \ | |_) | |
+//  ___) |  __/ ___ \|  _ <| |___
+// |____/|_| /_/   \_\_| \_\\____|
+//
+// See: https://sparc.org/technical-documents/
+
+// Legal values for ST_TYPE subfield of st_info (symbol type)
+/// Global register reserved to app
+pub const STT_SPARC_REGISTER: u8 = 13;
+
+// Values for Elf64_Ehdr.e_flags
+pub const EF_SPARCV9_MM: u32 = 3;
+pub const EF_SPARCV9_TSO: u32 = 0;
+pub const EF_SPARCV9_PSO: u32 = 1;
+pub const EF_SPARCV9_RMO: u32 = 2;
+/// little endian data
+pub const EF_SPARC_LEDATA: u32 = 0x800000;
+pub const EF_SPARC_EXT_MASK: u32 = 0xFFFF00;
+/// generic V8+ features
+pub const EF_SPARC_32PLUS: u32 = 0x000100;
+/// Sun UltraSPARC1 extensions
+pub const EF_SPARC_SUN_US1: u32 = 0x000200;
+/// HAL R1 extensions
+pub const EF_SPARC_HAL_R1: u32 = 0x000400;
+/// Sun UltraSPARCIII extensions
+pub const EF_SPARC_SUN_US3: u32 = 0x000800;
+
+// SPARC relocs
+/// No reloc
+pub const R_SPARC_NONE: u32 = 0;
+/// Direct 8 bit
+pub const R_SPARC_8: u32 = 1;
+/// Direct 16 bit
+pub const R_SPARC_16: u32 = 2;
+/// Direct 32 bit
+pub const R_SPARC_32: u32 = 3;
+/// PC relative 8 bit
+pub const R_SPARC_DISP8: u32 = 4;
+/// PC relative 16 bit
+pub const R_SPARC_DISP16: u32 = 5;
+/// PC relative 32 bit
+pub const R_SPARC_DISP32: u32 = 6;
+/// PC relative 30 bit shifted
+pub const R_SPARC_WDISP30: u32 = 7;
+/// PC relative 22 bit shifted
+pub const R_SPARC_WDISP22: u32 = 8;
+/// High 22 bit
+pub const R_SPARC_HI22: u32 = 9;
+/// Direct 22 bit
+pub const R_SPARC_22: u32 = 10;
+/// Direct 13 bit
+pub const R_SPARC_13: u32 = 11;
+/// Truncated 10 bit
+pub const R_SPARC_LO10: u32 = 12;
+/// Truncated 10 bit GOT entry
+pub const R_SPARC_GOT10: u32 = 13;
+/// 13 bit GOT entry
+pub const R_SPARC_GOT13: u32 = 14;
+/// 22 bit GOT entry shifted
+pub const R_SPARC_GOT22: u32 = 15;
+/// PC relative 10 bit truncated
+pub const R_SPARC_PC10: u32 = 16;
+/// PC relative 22 bit shifted
+pub const R_SPARC_PC22: u32 = 17;
+/// 30 bit PC relative PLT address
+pub const R_SPARC_WPLT30: u32 = 18;
+/// Copy symbol at runtime
+pub const R_SPARC_COPY: u32 = 19;
+/// Create GOT entry
+pub const R_SPARC_GLOB_DAT: u32 = 20;
+/// Create PLT entry
+pub const R_SPARC_JMP_SLOT: u32 = 21;
+/// Adjust by program base
+pub const R_SPARC_RELATIVE: u32 = 22;
+/// Direct 32 bit unaligned
+pub const R_SPARC_UA32: u32 = 23;
+
+// Additional Sparc64 relocs
+/// Direct 32 bit ref to PLT entry
+pub const R_SPARC_PLT32: u32 = 24;
+/// High 22 bit PLT entry
+pub const R_SPARC_HIPLT22: u32 = 25;
+/// Truncated 10 bit PLT entry
+pub const R_SPARC_LOPLT10: u32 = 26;
+/// PC rel 32 bit ref to PLT entry
+pub const R_SPARC_PCPLT32: u32 = 27;
+/// PC rel high 22 bit PLT entry
+pub const R_SPARC_PCPLT22: u32 = 28;
+/// PC rel trunc 10 bit PLT entry
+pub const R_SPARC_PCPLT10: u32 = 29;
+/// Direct 10 bit
+pub const R_SPARC_10: u32 = 30;
+/// Direct 11 bit
+pub const R_SPARC_11: u32 = 31;
+/// Direct 64 bit
+pub const R_SPARC_64: u32 = 32;
+/// 10bit with secondary 13bit addend
+pub const R_SPARC_OLO10: u32 = 33;
+/// Top 22 bits of direct 64 bit
+pub const R_SPARC_HH22: u32 = 34;
+/// High middle 10 bits of ..
+pub const R_SPARC_HM10: u32 = 35;
+/// Low middle 22 bits of ..
+pub const R_SPARC_LM22: u32 = 36;
+/// Top 22 bits of pc rel 64 bit
+pub const R_SPARC_PC_HH22: u32 = 37;
+/// High middle 10 bit of ..
+pub const R_SPARC_PC_HM10: u32 = 38;
+/// Low miggle 22 bits of ..
+pub const R_SPARC_PC_LM22: u32 = 39;
+/// PC relative 16 bit shifted
+pub const R_SPARC_WDISP16: u32 = 40;
+/// PC relative 19 bit shifted
+pub const R_SPARC_WDISP19: u32 = 41;
+/// was part of v9 ABI but was removed
+pub const R_SPARC_GLOB_JMP: u32 = 42;
+/// Direct 7 bit
+pub const R_SPARC_7: u32 = 43;
+/// Direct 5 bit
+pub const R_SPARC_5: u32 = 44;
+/// Direct 6 bit
+pub const R_SPARC_6: u32 = 45;
+/// PC relative 64 bit
+pub const R_SPARC_DISP64: u32 = 46;
+/// Direct 64 bit ref to PLT entry
+pub const R_SPARC_PLT64: u32 = 47;
+/// High 22 bit complemented
+pub const R_SPARC_HIX22: u32 = 48;
+/// Truncated 11 bit complemented
+pub const R_SPARC_LOX10: u32 = 49;
+/// Direct high 12 of 44 bit
+pub const R_SPARC_H44: u32 = 50;
+/// Direct mid 22 of 44 bit
+pub const R_SPARC_M44: u32 = 51;
+/// Direct low 10 of 44 bit
+pub const R_SPARC_L44: u32 = 52;
+/// Global register usage
+pub const R_SPARC_REGISTER: u32 = 53;
+/// Direct 64 bit unaligned
+pub const R_SPARC_UA64: u32 = 54;
+/// Direct 16 bit unaligned
+pub const R_SPARC_UA16: u32 = 55;
+pub const R_SPARC_TLS_GD_HI22: u32 = 56;
+pub const R_SPARC_TLS_GD_LO10: u32 = 57;
+pub const R_SPARC_TLS_GD_ADD: u32 = 58;
+pub const R_SPARC_TLS_GD_CALL: u32 = 59;
+pub const R_SPARC_TLS_LDM_HI22: u32 = 60;
+pub const R_SPARC_TLS_LDM_LO10: u32 = 61;
+pub const R_SPARC_TLS_LDM_ADD: u32 = 62;
+pub const R_SPARC_TLS_LDM_CALL: u32 = 63;
+pub const R_SPARC_TLS_LDO_HIX22: u32 = 64;
+pub const R_SPARC_TLS_LDO_LOX10: u32 = 65;
+pub const R_SPARC_TLS_LDO_ADD: u32 = 66;
+pub const R_SPARC_TLS_IE_HI22: u32 = 67;
+pub const R_SPARC_TLS_IE_LO10: u32 = 68;
+pub const R_SPARC_TLS_IE_LD: u32 = 69;
+pub const R_SPARC_TLS_IE_LDX: u32 = 70;
+pub const R_SPARC_TLS_IE_ADD: u32 = 71;
+pub const R_SPARC_TLS_LE_HIX22: u32 = 72;
+pub const R_SPARC_TLS_LE_LOX10: u32 = 73;
+pub const R_SPARC_TLS_DTPMOD32: u32 = 74;
+pub const R_SPARC_TLS_DTPMOD64: u32 = 75;
+pub const R_SPARC_TLS_DTPOFF32: u32 = 76;
+pub const R_SPARC_TLS_DTPOFF64: u32 = 77;
+pub const R_SPARC_TLS_TPOFF32: u32 = 78;
+pub const R_SPARC_TLS_TPOFF64: u32 = 79;
+pub const R_SPARC_GOTDATA_HIX22: u32 = 80;
+pub const R_SPARC_GOTDATA_LOX10: u32 = 81;
+pub const R_SPARC_GOTDATA_OP_HIX22: u32 = 82;
+pub const R_SPARC_GOTDATA_OP_LOX10: u32 = 83;
+pub const R_SPARC_GOTDATA_OP: u32 = 84;
+pub const R_SPARC_H34: u32 = 85;
+pub const R_SPARC_SIZE32: u32 = 86;
+pub const R_SPARC_SIZE64: u32 = 87;
+pub const R_SPARC_WDISP10: u32 = 88;
+pub const R_SPARC_JMP_IREL: u32 = 248;
+pub const R_SPARC_IRELATIVE: u32 = 249;
+pub const R_SPARC_GNU_VTINHERIT: u32 = 250;
+pub const R_SPARC_GNU_VTENTRY: u32 = 251;
+pub const R_SPARC_REV32: u32 = 252;
+
+// For Sparc64, legal values for d_tag of Elf64_Dyn
+pub const DT_SPARC_REGISTER: i64 = 0x70000001;
+
 //       ___   __      __   _  _
 // __  _( _ ) / /_    / /_ | || |
 // \ \/ / _ \| '_ \  | '_ \| || |_
@@ -2587,6 +3792,13 @@ pub const SHF_X86_64_LARGE: u64 = 0x10000000;
 /// This section contains unwind function table entries for stack unwinding.
 pub const SHT_X86_64_UNWIND: u32 = 0x70000001; // SHT_LOPROC + 1;
 
+/// Program property holding the x86 features that all of the input objects were built to support
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+/// Indirect Branch Tracking (CET)
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x1;
+/// Shadow Stack (CET)
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x2;
+
 // x86_64 reloc types
 //
 // A Represents the addend used to compute the value of the relocatable field.
@@ -0,0 +1,622 @@
+//! Parsing static `ar` archives (`.a` files) and the ELF objects they contain
+//!
+//! An archive is the magic string `!<arch>\n` followed by a sequence of members, each made up
+//! of a 60-byte text header and the member's contents (padded to an even length). The header
+//! holds the member's name, modification date, owner, mode and size.
+//!
+//! This module handles the variants produced by the common toolchains:
+//! * System V/GNU: Names longer than 15 bytes are stored in the `//` long name table and
+//!   referred to as `/<offset>`. The `/` (or `/SYM64/`, for archives larger than 4GiB) member
+//!   is a symbol index which maps each defined global symbol to the member defining it.
+//! * BSD: Names longer than 16 bytes (or containing spaces) are written as `#1/<length>`, with the
+//!   name stored at the start of the member's contents. The BSD `__.SYMDEF` symbol index
+//!   member is skipped when iterating, and isn't parsed by [Archive::symbols].
+//! * Thin archives (`!<thin>\n`): Members are only headers which refer to files outside the
+//!   archive by their (possibly relative) path, so [ArchiveMember::data] is `None`.
+//!
+//! Member contents are zero-copy sub-slices of the archive data, which can be parsed by
+//! [ArchiveMember::elf] with [ElfBytes::minimal_parse].
+//!
+//! Example for finding the object which defines a symbol:
+//! ```
+//! use elf::archive::Archive;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/archive.gnu.a");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let archive = Archive::parse(file_data.as_slice()).expect("Open archive");
+//!
+//! let member = archive
+//!     .find_symbol("long_fn")
+//!     .expect("symbol index should parse")
+//!     .expect("symbol should be defined");
+//! assert_eq!(member.name, "a_really_long_member_name.o");
+//!
+//! let file = member
+//!     .elf::<AnyEndian>()
+//!     .expect("member should parse")
+//!     .expect("member should have contents");
+//! let (symtab, strtab) = file
+//!     .symbol_table()
+//!     .expect("symtab should parse")
+//!     .expect("object should have a .symtab");
+//! assert!(symtab
+//!     .iter()
+//!     .any(|sym| strtab.get(sym.st_name as usize).unwrap() == "long_fn"));
+//! ```
+use crate::elf_bytes::ElfBytes;
+use crate::endian::EndianParse;
+use crate::parse::{ParseError, ReadBytesExt};
+
+/// The magic string at the start of a regular archive
+pub const ARMAG: &[u8; 8] = b"!<arch>\n";
+/// The magic string at the start of a thin archive
+pub const THINMAG: &[u8; 8] = b"!<thin>\n";
+/// The string which terminates each member header
+pub const ARFMAG: &[u8; 2] = b"`\n";
+
+/// The size of a member header
+const HEADER_SIZE: usize = 60;
+
+/// The format of an archive's symbol index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IndexWidth {
+    /// The `/` index, with 32-bit big-endian counts and offsets
+    Bits32,
+    /// The `/SYM64/` index, with 64-bit big-endian counts and offsets
+    Bits64,
+}
+
+/// A parsed static archive.
+#[derive(Debug, Clone, Copy)]
+pub struct Archive<'data> {
+    data: &'data [u8],
+    thin: bool,
+    symbol_index: Option<(IndexWidth, &'data [u8])>,
+    long_names: Option<&'data [u8]>,
+}
+
+impl<'data> Archive<'data> {
+    /// Parse the archive magic and locate its symbol index and long name table.
+    pub fn parse(data: &'data [u8]) -> Result<Self, ParseError> {
+        let magic = data.get_bytes(0..ARMAG.len())?;
+        let thin = match magic {
+            m if m == ARMAG => false,
+            m if m == THINMAG => true,
+            _ => return Err(ParseError::BadArchiveHeader(0)),
+        };
+
+        let mut archive = Archive {
+            data,
+            thin,
+            symbol_index: None,
+            long_names: None,
+        };
+
+        // The special members come before any regular ones
+        let mut offset = ARMAG.len();
+        while offset < data.len() {
+            let header = MemberHeader::parse_at(offset, data)?;
+            let contents = match header.name {
+                b"/" | b"/SYM64/" | b"//" => data.get_bytes(header.contents_range(offset)?)?,
+                _ => break,
+            };
+            match header.name {
+                b"/" => archive.symbol_index = Some((IndexWidth::Bits32, contents)),
+                b"/SYM64/" => archive.symbol_index = Some((IndexWidth::Bits64, contents)),
+                _ => archive.long_names = Some(contents),
+            }
+            offset = next_member_offset(offset, contents.len())?;
+        }
+        Ok(archive)
+    }
+
+    /// Returns whether this is a thin archive, whose members' contents are stored in
+    /// separate files.
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// Get an iterator over the archive's members, skipping its symbol index and long name table.
+    pub fn members(&self) -> ArchiveMemberIterator<'data> {
+        ArchiveMemberIterator {
+            archive: *self,
+            offset: ARMAG.len(),
+        }
+    }
+
+    /// Parse the member whose header starts at the given file offset, as found in the
+    /// archive's symbol index.
+    pub fn member_at(&self, offset: u64) -> Result<ArchiveMember<'data>, ParseError> {
+        let offset: usize = offset.try_into()?;
+        self.parse_member_at(offset).map(|(member, _)| member)
+    }
+
+    /// Get an iterator over the archive's symbol index, or None if it doesn't have a
+    /// GNU-style `/` or `/SYM64/` index.
+    pub fn symbols(&self) -> Result<Option<ArchiveSymbolIterator<'data>>, ParseError> {
+        let (width, data) = match self.symbol_index {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let word_size = match width {
+            IndexWidth::Bits32 => 4,
+            IndexWidth::Bits64 => 8,
+        };
+
+        let count: usize = read_be(width, 0, data)?.try_into()?;
+        let offsets_end = count
+            .checked_add(1)
+            .and_then(|words| words.checked_mul(word_size))
+            .ok_or(ParseError::IntegerOverflow)?;
+        let offsets = data.get_bytes(word_size..offsets_end)?;
+        let names = data.get_bytes(offsets_end..data.len())?;
+        Ok(Some(ArchiveSymbolIterator {
+            width,
+            offsets,
+            names,
+            index: 0,
+            count,
+            name_offset: 0,
+        }))
+    }
+
+    /// Find the member which defines the given symbol according to the archive's symbol index.
+    ///
+    /// Returns Ok(None) if the archive has no symbol index or the symbol isn't in it.
+    pub fn find_symbol(&self, name: &str) -> Result<Option<ArchiveMember<'data>>, ParseError> {
+        let symbols = match self.symbols()? {
+            Some(symbols) => symbols,
+            None => return Ok(None),
+        };
+        for symbol in symbols {
+            let symbol = symbol?;
+            if symbol.name == name {
+                return self.member_at(symbol.member_offset).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Parse the member at `offset`, returning it along with the offset of the member after it.
+    fn parse_member_at(&self, offset: usize) -> Result<(ArchiveMember<'data>, usize), ParseError> {
+        let header = MemberHeader::parse_at(offset, self.data)?;
+        let size = header.size;
+
+        // Thin archives store the contents of all but their special members elsewhere
+        let special = matches!(header.name, b"/" | b"/SYM64/" | b"//");
+        let mut contents = if self.thin && !special {
+            None
+        } else {
+            Some(self.data.get_bytes(header.contents_range(offset)?)?)
+        };
+        let next = next_member_offset(offset, contents.map_or(0, |data| data.len()))?;
+
+        let name = match header.name {
+            [b'#', b'1', b'/', len @ ..] => {
+                // BSD: The name is at the start of the contents
+                let len: usize = parse_decimal(len, offset)?.try_into()?;
+                let data = contents.ok_or(ParseError::BadArchiveHeader(offset as u64))?;
+                let name = data.get_bytes(0..len)?;
+                contents = Some(data.get_bytes(len..data.len())?);
+                let end = name.iter().position(|&b| b == 0).unwrap_or(len);
+                &name[..end]
+            }
+            [b'/', start @ ..] if !special => {
+                // GNU: The name is in the long name table, terminated by "/\n"
+                let start: usize = parse_decimal(start, offset)?.try_into()?;
+                let table = self
+                    .long_names
+                    .ok_or(ParseError::BadArchiveHeader(offset as u64))?;
+                let name = table.get_bytes(start..table.len())?;
+                let end = name
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .ok_or(ParseError::BadArchiveHeader(offset as u64))?;
+                name[..end].strip_suffix(b"/").unwrap_or(&name[..end])
+            }
+            name if special => name,
+            // GNU short names end with a '/', so that they can contain spaces
+            name => name.strip_suffix(b"/").unwrap_or(name),
+        };
+
+        let member = ArchiveMember {
+            name: core::str::from_utf8(name)?,
+            offset: offset as u64,
+            date: header.date,
+            uid: header.uid as u32,
+            gid: header.gid as u32,
+            mode: header.mode as u32,
+            size: contents.map_or(size, |data| data.len() as u64),
+            data: contents,
+        };
+        Ok((member, next))
+    }
+}
+
+/// Returns whether a member is a symbol index or long name table rather than a regular file.
+fn is_special_member(name: &str) -> bool {
+    matches!(
+        name,
+        "/" | "/SYM64/"
+            | "//"
+            | "__.SYMDEF"
+            | "__.SYMDEF SORTED"
+            | "__.SYMDEF_64"
+            | "__.SYMDEF_64 SORTED"
+    )
+}
+
+/// Get the offset of the header following a member whose contents are `len` bytes long.
+fn next_member_offset(offset: usize, len: usize) -> Result<usize, ParseError> {
+    // Member contents are padded to an even length
+    offset
+        .checked_add(HEADER_SIZE)
+        .and_then(|end| end.checked_add(len))
+        .and_then(|end| end.checked_add(len & 1))
+        .ok_or(ParseError::IntegerOverflow)
+}
+
+/// Read a big-endian symbol index word at the given word index.
+fn read_be(width: IndexWidth, index: usize, data: &[u8]) -> Result<u64, ParseError> {
+    match width {
+        IndexWidth::Bits32 => {
+            let start = index.checked_mul(4).ok_or(ParseError::IntegerOverflow)?;
+            let end = start.checked_add(4).ok_or(ParseError::IntegerOverflow)?;
+            let bytes = data.get_bytes(start..end)?;
+            Ok(u32::from_be_bytes(bytes.try_into()?) as u64)
+        }
+        IndexWidth::Bits64 => {
+            let start = index.checked_mul(8).ok_or(ParseError::IntegerOverflow)?;
+            let end = start.checked_add(8).ok_or(ParseError::IntegerOverflow)?;
+            let bytes = data.get_bytes(start..end)?;
+            Ok(u64::from_be_bytes(bytes.try_into()?))
+        }
+    }
+}
+
+/// Parse a space-padded header field in the given radix. Empty fields are 0.
+fn parse_field(field: &[u8], radix: u32, offset: usize) -> Result<u64, ParseError> {
+    let bad = ParseError::BadArchiveHeader(offset as u64);
+    let end = field.iter().position(|&b| b == b' ').unwrap_or(field.len());
+    if field[end..].iter().any(|&b| b != b' ') {
+        return Err(bad);
+    }
+    let digits = &field[..end];
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    core::str::from_utf8(digits)
+        .ok()
+        .and_then(|digits| u64::from_str_radix(digits, radix).ok())
+        .ok_or(bad)
+}
+
+fn parse_decimal(field: &[u8], offset: usize) -> Result<u64, ParseError> {
+    parse_field(field, 10, offset)
+}
+
+/// The fields of a member header, with the name still in its raw on-disk form.
+#[derive(Debug, Clone, Copy)]
+struct MemberHeader<'data> {
+    name: &'data [u8],
+    date: u64,
+    uid: u64,
+    gid: u64,
+    mode: u64,
+    size: u64,
+}
+
+impl<'data> MemberHeader<'data> {
+    fn parse_at(offset: usize, data: &'data [u8]) -> Result<Self, ParseError> {
+        let end = offset
+            .checked_add(HEADER_SIZE)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let header = data.get_bytes(offset..end)?;
+        if &header[58..60] != ARFMAG {
+            return Err(ParseError::BadArchiveHeader(offset as u64));
+        }
+
+        let name = &header[0..16];
+        let name_end = name
+            .iter()
+            .rposition(|&b| b != b' ')
+            .map_or(0, |last| last + 1);
+        Ok(MemberHeader {
+            name: &name[..name_end],
+            date: parse_decimal(&header[16..28], offset)?,
+            uid: parse_decimal(&header[28..34], offset)?,
+            gid: parse_decimal(&header[34..40], offset)?,
+            mode: parse_field(&header[40..48], 8, offset)?,
+            size: parse_decimal(&header[48..58], offset)?,
+        })
+    }
+
+    /// Get the file range of the contents of the member whose header is at `offset`.
+    fn contents_range(&self, offset: usize) -> Result<core::ops::Range<usize>, ParseError> {
+        let start = offset + HEADER_SIZE;
+        let size: usize = self.size.try_into()?;
+        let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+        Ok(start..end)
+    }
+}
+
+/// A member of an archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveMember<'data> {
+    /// The member's file name. For thin archives, this is the path of the member's file
+    /// relative to the archive.
+    pub name: &'data str,
+    /// The file offset of the member's header
+    pub offset: u64,
+    /// The member's modification time, in seconds since the Unix epoch
+    pub date: u64,
+    /// The member's owner user id
+    pub uid: u32,
+    /// The member's owner group id
+    pub gid: u32,
+    /// The member's file mode
+    pub mode: u32,
+    /// The size of the member's contents
+    pub size: u64,
+    /// The member's contents, or None for members of thin archives
+    pub data: Option<&'data [u8]>,
+}
+
+impl<'data> ArchiveMember<'data> {
+    /// Parse the member's contents as an ELF object, or return None for members of thin archives.
+    pub fn elf<E: EndianParse>(&self) -> Result<Option<ElfBytes<'data, E>>, ParseError> {
+        match self.data {
+            Some(data) => ElfBytes::minimal_parse(data).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// An iterator over the members of an archive
+#[derive(Debug)]
+pub struct ArchiveMemberIterator<'data> {
+    archive: Archive<'data>,
+    offset: usize,
+}
+
+impl<'data> Iterator for ArchiveMemberIterator<'data> {
+    type Item = Result<ArchiveMember<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset < self.archive.data.len() {
+            match self.archive.parse_member_at(self.offset) {
+                Ok((member, next)) => {
+                    self.offset = next;
+                    if !is_special_member(member.name) {
+                        return Some(Ok(member));
+                    }
+                }
+                Err(err) => {
+                    // Nothing after a malformed header can be found
+                    self.offset = self.archive.data.len();
+                    return Some(Err(err));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An entry in an archive's symbol index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveSymbol<'data> {
+    /// The symbol's name
+    pub name: &'data str,
+    /// The file offset of the header of the member which defines the symbol,
+    /// which can be parsed with [Archive::member_at]
+    pub member_offset: u64,
+}
+
+/// An iterator over the entries in an archive's symbol index
+#[derive(Debug)]
+pub struct ArchiveSymbolIterator<'data> {
+    width: IndexWidth,
+    offsets: &'data [u8],
+    names: &'data [u8],
+    index: usize,
+    count: usize,
+    name_offset: usize,
+}
+
+impl<'data> Iterator for ArchiveSymbolIterator<'data> {
+    type Item = Result<ArchiveSymbol<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let result = self.parse_next();
+        match result {
+            Ok(_) => self.index += 1,
+            Err(_) => self.index = self.count,
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Iteration ends early after a parse error, so only the upper bound is known
+        (0, Some(self.count - self.index))
+    }
+}
+
+impl<'data> ArchiveSymbolIterator<'data> {
+    fn parse_next(&mut self) -> Result<ArchiveSymbol<'data>, ParseError> {
+        let member_offset = read_be(self.width, self.index, self.offsets)?;
+        let names = self.names.get_bytes(self.name_offset..self.names.len())?;
+        let len = names
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(ParseError::StringTableMissingNul(self.name_offset as u64))?;
+        self.name_offset += len + 1;
+        Ok(ArchiveSymbol {
+            name: core::str::from_utf8(&names[..len])?,
+            member_offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod archive_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    fn member_names(archive: &Archive<'_>) -> Vec<String> {
+        archive
+            .members()
+            .map(|member| member.expect("should parse").name.to_string())
+            .collect()
+    }
+
+    fn symbols(archive: &Archive<'_>) -> Vec<(String, String)> {
+        archive
+            .symbols()
+            .expect("should parse")
+            .expect("should have a symbol index")
+            .map(|symbol| {
+                let symbol = symbol.expect("should parse");
+                let member = archive
+                    .member_at(symbol.member_offset)
+                    .expect("should parse");
+                (symbol.name.to_string(), member.name.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gnu_archive() {
+        let path = std::path::PathBuf::from("sample-objects/archive.gnu.a");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let archive = Archive::parse(&file_data).expect("Open archive");
+        assert!(!archive.is_thin());
+        assert_eq!(
+            member_names(&archive),
+            ["short.o", "a_really_long_member_name.o"]
+        );
+        assert_eq!(
+            symbols(&archive),
+            [
+                ("short_fn".to_string(), "short.o".to_string()),
+                (
+                    "long_fn".to_string(),
+                    "a_really_long_member_name.o".to_string()
+                ),
+                (
+                    "shared_data".to_string(),
+                    "a_really_long_member_name.o".to_string()
+                ),
+            ]
+        );
+
+        let member = archive.members().next().unwrap().unwrap();
+        assert_eq!(member.offset, 0xcc);
+        assert_eq!(member.mode, 0o644);
+        assert_eq!(member.size, 408);
+        let file = member
+            .elf::<AnyEndian>()
+            .expect("should parse")
+            .expect("should have contents");
+        assert_eq!(file.ehdr.e_type, crate::abi::ET_REL);
+        assert!(archive.find_symbol("missing").unwrap().is_none());
+
+        // A parse error would end the iteration early, so there's no lower bound
+        let symbols = archive.symbols().unwrap().unwrap();
+        assert_eq!(symbols.size_hint(), (0, Some(3)));
+    }
+
+    #[test]
+    fn bsd_archive() {
+        let path = std::path::PathBuf::from("sample-objects/archive.bsd.a");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let archive = Archive::parse(&file_data).expect("Open archive");
+        assert_eq!(
+            member_names(&archive),
+            ["short.o", "a_really_long_member_name.o"]
+        );
+        assert!(archive.symbols().unwrap().is_none());
+
+        // The contents start after the name
+        for member in archive.members() {
+            let member = member.unwrap();
+            let data = member.data.expect("should have contents");
+            assert_eq!(member.size, data.len() as u64);
+            assert!(member.elf::<AnyEndian>().unwrap().is_some());
+        }
+    }
+
+    #[test]
+    fn thin_archive() {
+        let path = std::path::PathBuf::from("sample-objects/archive.thin.a");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let archive = Archive::parse(&file_data).expect("Open archive");
+        assert!(archive.is_thin());
+        assert_eq!(
+            member_names(&archive),
+            ["short.o", "a_really_long_member_name.o"]
+        );
+        assert_eq!(symbols(&archive).len(), 3);
+
+        let member = archive.find_symbol("long_fn").unwrap().unwrap();
+        assert_eq!(member.name, "a_really_long_member_name.o");
+        assert_eq!(member.data, None);
+        assert_eq!(member.size, 528);
+        assert!(member.elf::<AnyEndian>().unwrap().is_none());
+    }
+
+    #[test]
+    fn sym64_index() {
+        let mut data = ARMAG.to_vec();
+        // count: 1, offset: 0x58, "sym\0"
+        let index = [
+            0u8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0x58, b's', b'y', b'm', 0,
+        ];
+        data.extend_from_slice(b"/SYM64/         0           0     0     0       20        `\n");
+        data.extend_from_slice(&index);
+        data.extend_from_slice(b"foo.o/          0           0     0     644     3         `\n");
+        data.extend_from_slice(b"abc\n");
+
+        let archive = Archive::parse(&data).expect("Open archive");
+        assert_eq!(
+            symbols(&archive),
+            [("sym".to_string(), "foo.o".to_string())]
+        );
+        let member = archive.members().next().unwrap().unwrap();
+        assert_eq!(member.data, Some(b"abc".as_slice()));
+    }
+
+    #[test]
+    fn bad_headers() {
+        assert!(matches!(
+            Archive::parse(b"!<arch>"),
+            Err(ParseError::SliceReadError(_))
+        ));
+        assert!(matches!(
+            Archive::parse(b"\x7fELF\x02\x01\x01\x00"),
+            Err(ParseError::BadArchiveHeader(0))
+        ));
+
+        let mut data = ARMAG.to_vec();
+        data.extend_from_slice(b"foo.o/          0           0     0     644     0         `\n");
+        data.extend_from_slice(b"bar.o/          0           0     0     644     x         `\n");
+        let archive = Archive::parse(&data).expect("Open archive");
+        let mut members = archive.members();
+        assert!(matches!(members.next(), Some(Ok(_))));
+        assert!(matches!(
+            members.next(),
+            Some(Err(ParseError::BadArchiveHeader(68)))
+        ));
+        assert!(members.next().is_none());
+
+        // A long name with no long name table
+        let mut data = ARMAG.to_vec();
+        data.extend_from_slice(b"/0              0           0     0     644     0         `\n");
+        let archive = Archive::parse(&data).expect("Open archive");
+        assert!(matches!(
+            archive.member_at(8),
+            Err(ParseError::BadArchiveHeader(8))
+        ));
+    }
+}
@@ -0,0 +1,784 @@
+//! Parsing ARM EHABI unwind tables: `.ARM.exidx`, `.ARM.extab`, [SHT_ARM_EXIDX](crate::abi::SHT_ARM_EXIDX), [PT_ARM_EXIDX](crate::abi::PT_ARM_EXIDX)
+//!
+//! 32-bit ARM uses its own exception handling ABI instead of `.eh_frame`. The `.ARM.exidx`
+//! exception index table contains one sorted entry per function (or per group of adjacent functions)
+//! made up of two words:
+//! * A prel31 offset from the entry to the start of the function
+//! * Either [EXIDX_CANTUNWIND](crate::abi::EXIDX_CANTUNWIND), up to three inline compact
+//!   model unwind opcodes, or a prel31 offset to the function's entry in the `.ARM.extab` exception table.
+//!
+//! The compact model unwind opcodes are decoded into [UnwindInstruction]s.
+//!
+//! See: <https://github.com/ARM-software/abi-aa/blob/main/ehabi32/ehabi32.rst>
+//!
+//! Example for decoding the unwind instructions for the functions in an object file:
+//! ```
+//! use elf::arm_exidx::{ExidxUnwind, ExtabEntry, UnwindInstruction};
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/exidx.armhf.o");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let slice = file_data.as_slice();
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+//!
+//! let exidx = file
+//!     .arm_exidx()
+//!     .expect("exidx should parse")
+//!     .expect("file should have an .ARM.exidx");
+//!
+//! // push {r4, lr}
+//! let entry = exidx.get(1).expect("entry should parse");
+//! let opcodes = match entry.unwind {
+//!     ExidxUnwind::Inline(opcodes) => opcodes,
+//!     _ => panic!("Expected inline unwind opcodes"),
+//! };
+//! let instructions: Vec<UnwindInstruction> = opcodes
+//!     .iter()
+//!     .collect::<Result<_, _>>()
+//!     .expect("opcodes should decode");
+//! assert_eq!(
+//!     instructions,
+//!     vec![
+//!         UnwindInstruction::PopCoreRegisters(1 << 4 | 1 << 14),
+//!         UnwindInstruction::Finish,
+//!     ]
+//! );
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::parse::{ParseError, ReadBytesExt};
+
+/// Decode a prel31 value found at the given address: a 31-bit signed offset relative to its own location.
+fn prel31_to_address(place: u64, word: u32) -> u64 {
+    let offset = ((word << 1) as i32 >> 1) as i64;
+    (place as i64).wrapping_add(offset) as u64 & 0xffffffff
+}
+
+/// What an exception index table entry says about how to unwind its function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExidxUnwind<'data, E: EndianParse> {
+    /// The function cannot be unwound ([EXIDX_CANTUNWIND](abi::EXIDX_CANTUNWIND))
+    CantUnwind,
+    /// The unwind opcodes are stored inline in the entry, using the
+    /// [ARM_EHABI_PERSONALITY_SU16](abi::ARM_EHABI_PERSONALITY_SU16) compact model
+    Inline(UnwindOpcodes<'data, E>),
+    /// The unwind information is stored in the `.ARM.extab` entry at this address. See [ArmExtab::entry_at].
+    Extab(u64),
+}
+
+/// An entry in the `.ARM.exidx` exception index table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmExidxEntry<'data, E: EndianParse> {
+    /// The address of the start of the function that this entry describes
+    pub function: u64,
+    pub unwind: ExidxUnwind<'data, E>,
+}
+
+/// Wraps the contents of an `.ARM.exidx` section along with its virtual address,
+/// which is needed to resolve prel31 offsets.
+#[derive(Debug, Clone, Copy)]
+pub struct ArmExidxTable<'data, E: EndianParse> {
+    endian: E,
+    address: u64,
+    data: &'data [u8],
+}
+
+const EXIDX_ENTRY_SIZE: usize = 8;
+
+impl<'data, E: EndianParse> ArmExidxTable<'data, E> {
+    /// Wrap the raw `.ARM.exidx` data which is located at the given virtual address.
+    pub fn new(endian: E, address: u64, data: &'data [u8]) -> Self {
+        ArmExidxTable {
+            endian,
+            address,
+            data,
+        }
+    }
+
+    /// Returns the number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.data.len() / EXIDX_ENTRY_SIZE
+    }
+
+    /// Returns whether the table is empty (contains zero entries).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a lazy-parsing iterator over the table's entries.
+    pub fn iter(&self) -> ArmExidxIterator<'_, 'data, E> {
+        ArmExidxIterator {
+            table: self,
+            index: 0,
+        }
+    }
+
+    /// Parse the entry at `index` in the table.
+    pub fn get(&self, index: usize) -> Result<ArmExidxEntry<'data, E>, ParseError> {
+        let mut offset = index
+            .checked_mul(EXIDX_ENTRY_SIZE)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let place = self.address.wrapping_add(offset as u64);
+        let fn_word = self.endian.parse_u32_at(&mut offset, self.data)?;
+        let data_word = self.endian.parse_u32_at(&mut offset, self.data)?;
+
+        let unwind = if data_word == abi::EXIDX_CANTUNWIND {
+            ExidxUnwind::CantUnwind
+        } else if data_word & 0x80000000 != 0 {
+            let personality_index = ((data_word >> 24) & 0xf) as u8;
+            if personality_index != abi::ARM_EHABI_PERSONALITY_SU16 {
+                return Err(ParseError::UnsupportedPersonality(personality_index));
+            }
+            ExidxUnwind::Inline(UnwindOpcodes::new(self.endian, data_word, 3, &[]))
+        } else {
+            ExidxUnwind::Extab(prel31_to_address(place.wrapping_add(4), data_word))
+        };
+
+        Ok(ArmExidxEntry {
+            function: prel31_to_address(place, fn_word),
+            unwind,
+        })
+    }
+
+    /// Parse only the function address of the entry at `index` in the table.
+    fn function_at(&self, index: usize) -> Result<u64, ParseError> {
+        let mut offset = index
+            .checked_mul(EXIDX_ENTRY_SIZE)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let place = self.address.wrapping_add(offset as u64);
+        let fn_word = self.endian.parse_u32_at(&mut offset, self.data)?;
+        Ok(prel31_to_address(place, fn_word))
+    }
+
+    /// Find the entry which describes the function containing the given pc.
+    ///
+    /// The table is sorted by function address, and each entry covers the code up until the next entry's function.
+    /// This is the entry with the greatest function address that is less than or equal to the pc.
+    pub fn find(&self, pc: u64) -> Result<Option<ArmExidxEntry<'data, E>>, ParseError> {
+        // Find the number of entries with a function address <= pc
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.function_at(mid)? <= pc {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        match low {
+            0 => Ok(None),
+            n => Ok(Some(self.get(n - 1)?)),
+        }
+    }
+}
+
+/// Iterates over the [ArmExidxEntry]s in an [ArmExidxTable].
+///
+/// Iteration ends after the first [ParseError] is yielded.
+#[derive(Debug)]
+pub struct ArmExidxIterator<'a, 'data, E: EndianParse> {
+    table: &'a ArmExidxTable<'data, E>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> Iterator for ArmExidxIterator<'_, 'data, E> {
+    type Item = Result<ArmExidxEntry<'data, E>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.table.len() {
+            return None;
+        }
+
+        let result = self.table.get(self.index);
+        self.index = match result {
+            Ok(_) => self.index + 1,
+            Err(_) => self.table.len(),
+        };
+        Some(result)
+    }
+}
+
+/// An entry in the `.ARM.extab` exception table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtabEntry<'data, E: EndianParse> {
+    /// An entry for one of the ARM-defined compact model personality routines
+    /// (`__aeabi_unwind_cpp_pr0`, `__aeabi_unwind_cpp_pr1`, `__aeabi_unwind_cpp_pr2`)
+    Compact {
+        personality_index: u8,
+        opcodes: UnwindOpcodes<'data, E>,
+    },
+    /// An entry for a generic model personality routine (such as `__gxx_personality_v0`).
+    /// The format of the data following the personality routine address is defined by the routine.
+    Generic {
+        /// The address of the personality routine
+        personality: u64,
+        /// The remaining contents of the `.ARM.extab` section starting after the personality routine address.
+        data: &'data [u8],
+    },
+}
+
+/// Wraps the contents of an `.ARM.extab` section along with its virtual address,
+/// which is needed to find the entries referenced by [ExidxUnwind::Extab].
+#[derive(Debug, Clone, Copy)]
+pub struct ArmExtab<'data, E: EndianParse> {
+    endian: E,
+    address: u64,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> ArmExtab<'data, E> {
+    /// Wrap the raw `.ARM.extab` data which is located at the given virtual address.
+    pub fn new(endian: E, address: u64, data: &'data [u8]) -> Self {
+        ArmExtab {
+            endian,
+            address,
+            data,
+        }
+    }
+
+    /// The virtual address of the start of the `.ARM.extab` data.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Parse the exception table entry at the given virtual address.
+    pub fn entry_at(&self, address: u64) -> Result<ExtabEntry<'data, E>, ParseError> {
+        let start: usize = address
+            .checked_sub(self.address)
+            .ok_or(ParseError::BadOffset(address))?
+            .try_into()?;
+        let mut offset = start;
+        let word = self.endian.parse_u32_at(&mut offset, self.data)?;
+
+        if word & 0x80000000 == 0 {
+            return Ok(ExtabEntry::Generic {
+                personality: prel31_to_address(address, word),
+                data: self.data.get_bytes(offset..self.data.len())?,
+            });
+        }
+
+        let personality_index = ((word >> 24) & 0xf) as u8;
+        let opcodes = match personality_index {
+            abi::ARM_EHABI_PERSONALITY_SU16 => UnwindOpcodes::new(self.endian, word, 3, &[]),
+            abi::ARM_EHABI_PERSONALITY_LU16 | abi::ARM_EHABI_PERSONALITY_LU32 => {
+                // The number of additional words of opcodes is in bits 16-23
+                let count = ((word >> 16) & 0xff) as usize;
+                let end = offset
+                    .checked_add(count * 4)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                let words = self.data.get_bytes(offset..end)?;
+                UnwindOpcodes::new(self.endian, word, 2, words)
+            }
+            _ => return Err(ParseError::UnsupportedPersonality(personality_index)),
+        };
+
+        Ok(ExtabEntry::Compact {
+            personality_index,
+            opcodes,
+        })
+    }
+}
+
+/// The sequence of compact model unwind opcode bytes, which are packed from the most significant
+/// byte to the least significant byte of the words that contain them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnwindOpcodes<'data, E: EndianParse> {
+    endian: E,
+    /// The word containing the personality index and the first opcodes
+    first_word: u32,
+    /// The number of opcodes in the low bytes of first_word
+    first_count: usize,
+    /// Additional words of opcodes
+    words: &'data [u8],
+}
+
+impl<'data, E: EndianParse> UnwindOpcodes<'data, E> {
+    fn new(endian: E, first_word: u32, first_count: usize, words: &'data [u8]) -> Self {
+        UnwindOpcodes {
+            endian,
+            first_word,
+            first_count,
+            words,
+        }
+    }
+
+    /// Returns the number of opcode bytes, including any trailing [UnwindInstruction::Finish] padding.
+    pub fn len(&self) -> usize {
+        self.first_count + self.words.len()
+    }
+
+    /// Returns whether there are no opcode bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the raw opcode byte at the given index.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        if index < self.first_count {
+            let shift = 8 * (self.first_count - 1 - index);
+            return Some((self.first_word >> shift) as u8);
+        }
+
+        let index = index - self.first_count;
+        let mut offset = index - index % 4;
+        let word = self.endian.parse_u32_at(&mut offset, self.words).ok()?;
+        Some((word >> (8 * (3 - index % 4))) as u8)
+    }
+
+    /// Get an iterator which decodes the opcodes into [UnwindInstruction]s.
+    pub fn iter(&self) -> UnwindInstructionIterator<'data, E> {
+        UnwindInstructionIterator {
+            opcodes: *self,
+            index: 0,
+        }
+    }
+}
+
+/// A decoded compact model unwind instruction, where `vsp` is the virtual stack pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnwindInstruction {
+    /// vsp = vsp + n
+    VspAdd(u64),
+    /// vsp = vsp - n
+    VspSub(u64),
+    /// Refuse to unwind (e.g. out of a cleanup)
+    RefuseToUnwind,
+    /// Pop the core registers in the mask, where bit n is set for register rn
+    PopCoreRegisters(u16),
+    /// vsp = r\[n\]
+    SetVsp(u8),
+    /// Pop the `count` VFP double-precision registers starting at d\[first\], saved by FSTMFDX
+    PopVfpFstmfdx { first: u8, count: u8 },
+    /// Pop the `count` VFP double-precision registers starting at d\[first\], saved by VPUSH
+    PopVfp { first: u8, count: u8 },
+    /// Pop the `count` Intel Wireless MMX data registers starting at wR\[first\]
+    PopWmmxData { first: u8, count: u8 },
+    /// Pop the Intel Wireless MMX control registers in the mask, where bit n is set for wCGR\[n\]
+    PopWmmxControl(u8),
+    /// Finish unwinding
+    Finish,
+    /// A spare or reserved opcode, whose meaning is undefined
+    Spare(u8),
+}
+
+/// Decodes the [UnwindInstruction]s from [UnwindOpcodes].
+///
+/// Iteration ends after a [UnwindInstruction::Finish] (any following opcodes are padding)
+/// or after the first [ParseError] is yielded.
+#[derive(Debug)]
+pub struct UnwindInstructionIterator<'data, E: EndianParse> {
+    opcodes: UnwindOpcodes<'data, E>,
+    index: usize,
+}
+
+impl<E: EndianParse> UnwindInstructionIterator<'_, E> {
+    fn next_byte(&mut self) -> Result<u8, ParseError> {
+        let byte = self
+            .opcodes
+            .get(self.index)
+            .ok_or(ParseError::SliceReadError((self.index, self.index + 1)))?;
+        self.index += 1;
+        Ok(byte)
+    }
+
+    fn parse_uleb128(&mut self) -> Result<u64, ParseError> {
+        let mut result: u64 = 0;
+        let mut shift: u32 = 0;
+        loop {
+            let byte = self.next_byte()?;
+            let low_bits = (byte & 0x7f) as u64;
+            if shift >= 64 || (low_bits << shift) >> shift != low_bits {
+                return Err(ParseError::IntegerOverflow);
+            }
+            result |= low_bits << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn parse_next(&mut self) -> Result<UnwindInstruction, ParseError> {
+        let op = self.next_byte()?;
+        let instruction = match op {
+            0x00..=0x3f => UnwindInstruction::VspAdd((((op & 0x3f) as u64) << 2) + 4),
+            0x40..=0x7f => UnwindInstruction::VspSub((((op & 0x3f) as u64) << 2) + 4),
+            0x80..=0x8f => {
+                let op2 = self.next_byte()?;
+                if op == 0x80 && op2 == 0 {
+                    UnwindInstruction::RefuseToUnwind
+                } else {
+                    // Mask for r15-r12 in the first byte and r11-r4 in the second
+                    let mask = ((op & 0x0f) as u16) << 12 | (op2 as u16) << 4;
+                    UnwindInstruction::PopCoreRegisters(mask)
+                }
+            }
+            // Register-to-register moves into vsp from r13 and r15 are reserved
+            0x9d | 0x9f => UnwindInstruction::Spare(op),
+            0x90..=0x9f => UnwindInstruction::SetVsp(op & 0x0f),
+            0xa0..=0xaf => {
+                // Pop r4-r[4+nnn], and r14 if bit 3 is set
+                let count = (op & 0x07) + 1;
+                let mut mask = ((1u16 << count) - 1) << 4;
+                if op & 0x08 != 0 {
+                    mask |= 1 << 14;
+                }
+                UnwindInstruction::PopCoreRegisters(mask)
+            }
+            0xb0 => UnwindInstruction::Finish,
+            0xb1 => {
+                let op2 = self.next_byte()?;
+                if op2 == 0 || op2 & 0xf0 != 0 {
+                    UnwindInstruction::Spare(op)
+                } else {
+                    UnwindInstruction::PopCoreRegisters(op2 as u16)
+                }
+            }
+            0xb2 => {
+                let value = self
+                    .parse_uleb128()?
+                    .checked_mul(4)
+                    .and_then(|value| value.checked_add(0x204))
+                    .ok_or(ParseError::IntegerOverflow)?;
+                UnwindInstruction::VspAdd(value)
+            }
+            0xb3 => {
+                let op2 = self.next_byte()?;
+                UnwindInstruction::PopVfpFstmfdx {
+                    first: op2 >> 4,
+                    count: (op2 & 0x0f) + 1,
+                }
+            }
+            0xb4..=0xb7 => UnwindInstruction::Spare(op),
+            0xb8..=0xbf => UnwindInstruction::PopVfpFstmfdx {
+                first: 8,
+                count: (op & 0x07) + 1,
+            },
+            0xc0..=0xc5 => UnwindInstruction::PopWmmxData {
+                first: 10,
+                count: (op & 0x07) + 1,
+            },
+            0xc6 => {
+                let op2 = self.next_byte()?;
+                UnwindInstruction::PopWmmxData {
+                    first: op2 >> 4,
+                    count: (op2 & 0x0f) + 1,
+                }
+            }
+            0xc7 => {
+                let op2 = self.next_byte()?;
+                if op2 == 0 || op2 & 0xf0 != 0 {
+                    UnwindInstruction::Spare(op)
+                } else {
+                    UnwindInstruction::PopWmmxControl(op2)
+                }
+            }
+            0xc8 | 0xc9 => {
+                let op2 = self.next_byte()?;
+                // 0xc8 pops from d16-d31
+                let base = if op == 0xc8 { 16 } else { 0 };
+                UnwindInstruction::PopVfp {
+                    first: base + (op2 >> 4),
+                    count: (op2 & 0x0f) + 1,
+                }
+            }
+            0xd0..=0xd7 => UnwindInstruction::PopVfp {
+                first: 8,
+                count: (op & 0x07) + 1,
+            },
+            _ => UnwindInstruction::Spare(op),
+        };
+        Ok(instruction)
+    }
+}
+
+impl<E: EndianParse> Iterator for UnwindInstructionIterator<'_, E> {
+    type Item = Result<UnwindInstruction, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.opcodes.len() {
+            return None;
+        }
+
+        let result = self.parse_next();
+        if matches!(result, Err(_) | Ok(UnwindInstruction::Finish)) {
+            self.index = self.opcodes.len();
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    fn decode<E: EndianParse>(opcodes: UnwindOpcodes<'_, E>) -> Vec<UnwindInstruction> {
+        opcodes
+            .iter()
+            .collect::<Result<_, _>>()
+            .expect("opcodes should decode")
+    }
+
+    #[test]
+    fn prel31_addresses() {
+        assert_eq!(prel31_to_address(0x9000, 0x10), 0x9010);
+        assert_eq!(prel31_to_address(0x9000, 0x7ffff000), 0x8000);
+        // The top bit isn't part of the offset
+        assert_eq!(prel31_to_address(0x9000, 0xfffff000), 0x8000);
+        // Wraps around the 32-bit address space
+        assert_eq!(prel31_to_address(0x10, 0x7fffffe0), 0xfffffff0);
+    }
+
+    #[test]
+    fn exidx_entries_and_find() {
+        #[rustfmt::skip]
+        let data = [
+            // 0x8000: cantunwind
+            0x00, 0xf0, 0xff, 0x7f, 0x01, 0x00, 0x00, 0x00,
+            // 0x8100: inline: vsp += 16, finish, finish
+            0xf8, 0xf0, 0xff, 0x7f, 0xb0, 0xb0, 0x03, 0x80,
+            // 0x8200: extab entry at 0xa000
+            0xf0, 0xf1, 0xff, 0x7f, 0xec, 0x0f, 0x00, 0x00,
+        ];
+        let table = ArmExidxTable::new(LittleEndian, 0x9000, &data);
+        assert_eq!(table.len(), 3);
+
+        let entries: Vec<_> = table
+            .iter()
+            .collect::<Result<_, _>>()
+            .expect("entries should parse");
+        assert_eq!(entries[0].function, 0x8000);
+        assert_eq!(entries[0].unwind, ExidxUnwind::CantUnwind);
+        assert_eq!(entries[1].function, 0x8100);
+        match entries[1].unwind {
+            ExidxUnwind::Inline(opcodes) => {
+                assert_eq!(opcodes.len(), 3);
+                assert_eq!(
+                    decode(opcodes),
+                    vec![UnwindInstruction::VspAdd(16), UnwindInstruction::Finish]
+                );
+            }
+            unwind => panic!("Unexpected unwind: {unwind:?}"),
+        }
+        assert_eq!(entries[2].function, 0x8200);
+        assert_eq!(entries[2].unwind, ExidxUnwind::Extab(0xa000));
+
+        assert_eq!(table.find(0x7fff).expect("should parse"), None);
+        assert_eq!(table.find(0x8000).expect("should parse"), Some(entries[0]));
+        assert_eq!(table.find(0x81ff).expect("should parse"), Some(entries[1]));
+        assert_eq!(table.find(0x9000).expect("should parse"), Some(entries[2]));
+    }
+
+    #[test]
+    fn exidx_inline_bad_personality() {
+        let data = [0x00, 0x00, 0x00, 0x00, 0xb0, 0xb0, 0xb0, 0x81];
+        let table = ArmExidxTable::new(LittleEndian, 0, &data);
+        let error = table.get(0).expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::UnsupportedPersonality(1)),
+            "Unexpected Error type found: {error}"
+        );
+    }
+
+    #[test]
+    fn find_past_bad_personality() {
+        #[rustfmt::skip]
+        let data = [
+            // 0x8000: inline with an unsupported personality routine index
+            0x00, 0xf0, 0xff, 0x7f, 0xb0, 0xb0, 0xb0, 0x81,
+            // 0x8100: cantunwind
+            0xf8, 0xf0, 0xff, 0x7f, 0x01, 0x00, 0x00, 0x00,
+            // 0x8200: cantunwind
+            0xf0, 0xf1, 0xff, 0x7f, 0x01, 0x00, 0x00, 0x00,
+        ];
+        let table = ArmExidxTable::new(LittleEndian, 0x9000, &data);
+
+        // Only the matched entry's unwind data is decoded
+        let found = table.find(0x8100).expect("should parse").unwrap();
+        assert_eq!(found.function, 0x8100);
+        let found = table.find(0x8204).expect("should parse").unwrap();
+        assert_eq!(found.function, 0x8200);
+        assert!(matches!(
+            table.find(0x8004),
+            Err(ParseError::UnsupportedPersonality(1))
+        ));
+    }
+
+    #[test]
+    fn extab_entries() {
+        #[rustfmt::skip]
+        let data = [
+            // 0x0: pr0: pop {r4-r7}, finish, finish
+            0x80, 0xa3, 0xb0, 0xb0,
+            // 0x4: pr2 with one extra word: vsp = r7, pop {d8-d11}, finish, finish
+            0x82, 0x01, 0x97, 0xd3, 0xb0, 0xb0, 0xb0, 0xb0,
+            // 0xc: generic personality routine at 0x110c, followed by data
+            0x00, 0x00, 0x01, 0x00, 0xaa, 0xbb,
+        ];
+        let extab = ArmExtab::new(BigEndian, 0x1000, &data);
+
+        match extab.entry_at(0x1000).expect("should parse") {
+            ExtabEntry::Compact {
+                personality_index,
+                opcodes,
+            } => {
+                assert_eq!(personality_index, 0);
+                assert_eq!(
+                    decode(opcodes),
+                    vec![
+                        UnwindInstruction::PopCoreRegisters(0xf0),
+                        UnwindInstruction::Finish
+                    ]
+                );
+            }
+            entry => panic!("Unexpected entry: {entry:?}"),
+        }
+
+        match extab.entry_at(0x1004).expect("should parse") {
+            ExtabEntry::Compact {
+                personality_index,
+                opcodes,
+            } => {
+                assert_eq!(personality_index, 2);
+                assert_eq!(opcodes.len(), 6);
+                assert_eq!(
+                    decode(opcodes),
+                    vec![
+                        UnwindInstruction::SetVsp(7),
+                        UnwindInstruction::PopVfp { first: 8, count: 4 },
+                        UnwindInstruction::Finish
+                    ]
+                );
+            }
+            entry => panic!("Unexpected entry: {entry:?}"),
+        }
+
+        assert_eq!(
+            extab.entry_at(0x100c).expect("should parse"),
+            ExtabEntry::Generic {
+                personality: 0x110c,
+                data: &[0xaa, 0xbb],
+            }
+        );
+
+        assert!(matches!(
+            extab.entry_at(0xfff),
+            Err(ParseError::BadOffset(0xfff))
+        ));
+    }
+
+    #[test]
+    fn decode_all_opcode_forms() {
+        #[rustfmt::skip]
+        let opcode_bytes = [
+            0x3f, 0x40, 0x80, 0x00,
+            0x8f, 0xff, 0x9d, 0xa7,
+            0xb1, 0x0f, 0xb1, 0x10,
+            0xb2, 0x81, 0x01, 0xb3,
+            0x12, 0xb4, 0xbf, 0xc5,
+            0xc6, 0x21, 0xc7, 0x03,
+            0xc8, 0x01, 0xca, 0xd7,
+            0xff, 0xb0, 0xb0, 0xb0,
+        ];
+        // Words are parsed in the file's byte order and opcodes are read from their most
+        // significant byte, so lay the opcode bytes out as little endian words
+        let mut words = opcode_bytes;
+        words.chunks_mut(4).for_each(|word| word.reverse());
+        let opcodes = UnwindOpcodes::new(LittleEndian, 0x81080000, 2, &words);
+        assert_eq!(opcodes.len(), 34);
+
+        assert_eq!(
+            decode(opcodes),
+            vec![
+                // 0x00 0x00 from the first word
+                UnwindInstruction::VspAdd(4),
+                UnwindInstruction::VspAdd(4),
+                UnwindInstruction::VspAdd(256),
+                UnwindInstruction::VspSub(4),
+                UnwindInstruction::RefuseToUnwind,
+                UnwindInstruction::PopCoreRegisters(0xfff0),
+                UnwindInstruction::Spare(0x9d),
+                UnwindInstruction::PopCoreRegisters(0x0ff0),
+                UnwindInstruction::PopCoreRegisters(0x000f),
+                UnwindInstruction::Spare(0xb1),
+                UnwindInstruction::VspAdd(0x204 + (0x81 << 2)),
+                UnwindInstruction::PopVfpFstmfdx { first: 1, count: 3 },
+                UnwindInstruction::Spare(0xb4),
+                UnwindInstruction::PopVfpFstmfdx { first: 8, count: 8 },
+                UnwindInstruction::PopWmmxData {
+                    first: 10,
+                    count: 6
+                },
+                UnwindInstruction::PopWmmxData { first: 2, count: 2 },
+                UnwindInstruction::PopWmmxControl(3),
+                UnwindInstruction::PopVfp {
+                    first: 16,
+                    count: 2
+                },
+                UnwindInstruction::Spare(0xca),
+                UnwindInstruction::PopVfp { first: 8, count: 8 },
+                UnwindInstruction::Spare(0xff),
+                UnwindInstruction::Finish,
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_truncated_opcode() {
+        let opcodes = UnwindOpcodes::new(LittleEndian, 0x800000b2, 1, &[]);
+        let mut iter = opcodes.iter();
+        let error = iter.next().unwrap().expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::SliceReadError((1, 2))),
+            "Unexpected Error type found: {error}"
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn exidx_from_object_file() {
+        let path = std::path::PathBuf::from("sample-objects/exidx.armhf.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".ARM.exidx")
+            .expect("section table should be parseable")
+            .expect("file should have .ARM.exidx");
+        let exidx = file
+            .section_data_as_arm_exidx(&shdr)
+            .expect("exidx should parse");
+        let extab = file
+            .arm_extab()
+            .expect("extab should parse")
+            .expect("file should have .ARM.extab");
+        assert_eq!(exidx.len(), 3);
+
+        // This is an unlinked object, so the prel31 offsets haven't been relocated yet.
+        assert_eq!(exidx.get(0).unwrap().unwind, ExidxUnwind::CantUnwind);
+
+        // push {r4-r11, lr}; vpush {d8-d9}; sub sp, sp, #1024
+        match extab.entry_at(extab.address()).expect("should parse") {
+            ExtabEntry::Compact {
+                personality_index,
+                opcodes,
+            } => {
+                assert_eq!(personality_index, abi::ARM_EHABI_PERSONALITY_LU16);
+                assert_eq!(
+                    decode(opcodes),
+                    vec![
+                        UnwindInstruction::VspAdd(1024),
+                        UnwindInstruction::PopVfp { first: 8, count: 2 },
+                        UnwindInstruction::PopCoreRegisters(0x4ff0),
+                        UnwindInstruction::Finish,
+                    ]
+                );
+            }
+            entry => panic!("Unexpected entry: {entry:?}"),
+        }
+    }
+}
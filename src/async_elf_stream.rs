@@ -0,0 +1,497 @@
+use core::future::poll_fn;
+use core::ops::Range;
+use core::pin::Pin;
+use std::io::SeekFrom;
+
+use futures_io::{AsyncRead, AsyncSeek};
+
+use crate::abi;
+use crate::compression::CompressionHeader;
+use crate::elf_stream::{
+    ehdr_tail_range, find_section_by_name, find_symbol_table, parse_program_headers,
+    parse_section_headers, parse_shdr0, program_headers_range, section_headers_range, shdr0_range,
+    shstrtab_range, split_compression_header, symbol_table_ranges, ByteCache,
+};
+use crate::endian::EndianParse;
+use crate::file::{parse_ident, FileHeader};
+use crate::note::NoteIterator;
+use crate::parse::ParseError;
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::string_table::StringTable;
+use crate::symbol::SymbolTable;
+
+/// This type encapsulates the stream-oriented interface for parsing ELF objects from
+/// a futures-io `AsyncRead + AsyncSeek`, such as a file or an object store client.
+///
+/// It mirrors [ElfStream](crate::ElfStream): the ELF [FileHeader], section headers and program
+/// headers are parsed when the stream is opened, and all other file data is read lazily on
+/// demand. This is only available with the `async` cargo feature.
+///
+/// Example to get the ELF file's ABI-tag note:
+/// ```
+/// use elf::AsyncElfStream;
+/// use elf::endian::AnyEndian;
+/// use elf::note::{Note, NoteGnuAbiTag};
+///
+/// # futures::executor::block_on(async {
+/// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+/// let file_data = std::fs::read(path).expect("Could not read file.");
+/// let io = futures::io::Cursor::new(file_data);
+/// let mut file = AsyncElfStream::<AnyEndian, _>::open_stream(io)
+///     .await
+///     .expect("Open test1");
+///
+/// let shdr = *file
+///     .section_header_by_name(".note.ABI-tag")
+///     .await
+///     .expect("section table should be parseable")
+///     .expect("file should have a .note.ABI-tag section");
+///
+/// let notes: Vec<_> = file
+///     .section_data_as_notes(&shdr)
+///     .await
+///     .expect("Should be able to get note section data")
+///     .collect();
+/// assert_eq!(
+///     notes[0],
+///     Note::GnuAbiTag(NoteGnuAbiTag {
+///         os: 0,
+///         major: 2,
+///         minor: 6,
+///         subminor: 32
+///     }));
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncElfStream<E: EndianParse, S: AsyncRead + AsyncSeek + Unpin> {
+    pub ehdr: FileHeader<E>,
+    shdrs: Vec<SectionHeader>,
+    phdrs: Vec<ProgramHeader>,
+    reader: AsyncCachingReader<S>,
+}
+
+impl<E: EndianParse, S: AsyncRead + AsyncSeek + Unpin> AsyncElfStream<E, S> {
+    /// Do a minimal amount of parsing work to open an [AsyncElfStream] handle from an
+    /// AsyncRead+AsyncSeek containing an ELF object.
+    ///
+    /// This parses the ELF [FileHeader], [SectionHeader] table, and [ProgramHeader] (segments) table.
+    /// All other file data (section data, segment data) is left unread and unparsed.
+    pub async fn open_stream(reader: S) -> Result<AsyncElfStream<E, S>, ParseError> {
+        let mut cr = AsyncCachingReader::new(reader).await?;
+        let ident_buf = cr.read_bytes(0, abi::EI_NIDENT).await?;
+        let ident = parse_ident(ident_buf)?;
+
+        let tail = ehdr_tail_range(ident.1);
+        let tail_buf = cr.read_bytes(tail.start, tail.end).await?;
+
+        let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
+
+        let shdr0 = match shdr0_range(&ehdr)? {
+            Some(range) => Some(parse_shdr0(
+                &ehdr,
+                cr.read_bytes(range.start, range.end).await?,
+            )?),
+            None => None,
+        };
+        let shdrs = match section_headers_range(&ehdr, shdr0.as_ref())? {
+            Some(range) => {
+                parse_section_headers(&ehdr, cr.read_bytes(range.start, range.end).await?)
+            }
+            None => Vec::new(),
+        };
+        let phdrs = match program_headers_range(&ehdr, shdr0.as_ref())? {
+            Some(range) => {
+                parse_program_headers(&ehdr, cr.read_bytes(range.start, range.end).await?)
+            }
+            None => Vec::new(),
+        };
+
+        // We parsed out the ehdr and shdrs into their own allocated containers, so there's no need to keep
+        // around their backing data anymore.
+        cr.cache.clear_cache();
+
+        Ok(AsyncElfStream {
+            ehdr,
+            shdrs,
+            phdrs,
+            reader: cr,
+        })
+    }
+
+    /// Get the parsed program headers table
+    pub fn segments(&self) -> &Vec<ProgramHeader> {
+        &self.phdrs
+    }
+
+    /// Get the parsed section headers table
+    pub fn section_headers(&self) -> &Vec<SectionHeader> {
+        &self.shdrs
+    }
+
+    /// Limit the number of bytes of file data kept cached between calls.
+    ///
+    /// See [ElfStream::with_cache_limit](crate::ElfStream::with_cache_limit).
+    pub fn with_cache_limit(mut self, max_bytes: usize) -> Self {
+        self.reader.cache.set_limit(Some(max_bytes));
+        self
+    }
+
+    /// Get the number of bytes of file data currently cached.
+    pub fn cached_bytes(&self) -> usize {
+        self.reader.cache.cached_bytes()
+    }
+
+    /// Drop all the cached file data.
+    pub fn clear_cache(&mut self) {
+        self.reader.cache.clear_cache();
+    }
+
+    /// Get the parsed section headers table and its associated StringTable (if any).
+    ///
+    /// See [ElfStream::section_headers_with_strtab](crate::ElfStream::section_headers_with_strtab).
+    pub async fn section_headers_with_strtab(
+        &mut self,
+    ) -> Result<(&Vec<SectionHeader>, Option<StringTable<'_>>), ParseError> {
+        let range = match shstrtab_range(&self.ehdr, &self.shdrs)? {
+            Some(range) => range,
+            None => return Ok((&self.shdrs, None)),
+        };
+        let strtab_buf = self.reader.read_bytes(range.start, range.end).await?;
+        Ok((&self.shdrs, Some(StringTable::new(strtab_buf))))
+    }
+
+    /// Find the parsed section header with the given name (if any).
+    ///
+    /// Returns a ParseError if the section headers string table can't be read
+    pub async fn section_header_by_name(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<&SectionHeader>, ParseError> {
+        let (shdrs, strtab) = match self.section_headers_with_strtab().await? {
+            (shdr, Some(strtab)) => (shdr, strtab),
+            // We can't look up shdrs by name if there's no strtab.
+            // (hint: try looking it up by its sh_type).
+            _ => {
+                return Ok(None);
+            }
+        };
+
+        Ok(find_section_by_name(shdrs, &strtab, name))
+    }
+
+    /// Read the section data for the given [SectionHeader](SectionHeader).
+    /// Returns both the secion data and an optional CompressionHeader.
+    ///
+    /// See [ElfStream::section_data](crate::ElfStream::section_data).
+    pub async fn section_data(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<(&[u8], Option<CompressionHeader>), ParseError> {
+        if shdr.sh_type == abi::SHT_NOBITS {
+            return Ok((&[], None));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end).await?;
+        split_compression_header(&self.ehdr, shdr, buf)
+    }
+
+    async fn get_symbol_table_of_type(
+        &mut self,
+        symtab_type: u32,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        let (shdr, strtab_shdr) = match find_symbol_table(&self.shdrs, symtab_type)? {
+            Some(shdrs) => shdrs,
+            None => return Ok(None),
+        };
+        let (symtab, strtab) = symbol_table_ranges(self.ehdr.class, &shdr, &strtab_shdr)?;
+
+        // Load the section bytes for both the symtab and the strtab
+        // (we want immutable references to both the symtab and its strtab concurrently)
+        self.reader.load_bytes(symtab.clone()).await?;
+        self.reader.load_bytes(strtab.clone()).await?;
+        Ok(Some((
+            SymbolTable::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                self.reader.cache.get_bytes(symtab),
+            ),
+            StringTable::new(self.reader.cache.get_bytes(strtab)),
+        )))
+    }
+
+    /// Get the symbol table (section of type SHT_SYMTAB) and its associated string table.
+    ///
+    /// The gABI specifies that ELF object files may have zero or one sections of type SHT_SYMTAB.
+    pub async fn symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        self.get_symbol_table_of_type(abi::SHT_SYMTAB).await
+    }
+
+    /// Get the dynamic symbol table (section of type SHT_DYNSYM) and its associated string table.
+    ///
+    /// The gABI specifies that ELF object files may have zero or one sections of type SHT_DYNSYM.
+    pub async fn dynamic_symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        self.get_symbol_table_of_type(abi::SHT_DYNSYM).await
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [NoteIterator](NoteIterator).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_NOTE](abi::SHT_NOTE).
+    pub async fn section_data_as_notes(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<NoteIterator<'_, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_NOTE {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_NOTE,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end).await?;
+        Ok(NoteIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            shdr.sh_addralign as usize,
+            buf,
+        ))
+    }
+}
+
+/// Reads ranges of an `AsyncRead + AsyncSeek` into a [ByteCache], like ElfStream's CachingReader
+#[derive(Debug)]
+struct AsyncCachingReader<R: AsyncRead + AsyncSeek + Unpin> {
+    reader: R,
+    stream_len: u64,
+    cache: ByteCache,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncCachingReader<R> {
+    async fn new(mut reader: R) -> Result<Self, ParseError> {
+        // Cache the size of the stream so that we can err (rather than OOM) on invalid
+        // huge read requests.
+        let stream_len =
+            poll_fn(|cx| Pin::new(&mut reader).poll_seek(cx, SeekFrom::End(0))).await?;
+        Ok(AsyncCachingReader {
+            reader,
+            stream_len,
+            cache: ByteCache::new(),
+        })
+    }
+
+    async fn read_bytes(&mut self, start: usize, end: usize) -> Result<&[u8], ParseError> {
+        self.load_bytes(start..end).await?;
+        Ok(self.cache.get_bytes(start..end))
+    }
+
+    async fn load_bytes(&mut self, range: Range<usize>) -> Result<(), ParseError> {
+        let mut pending = match self.cache.start_load(range, self.stream_len)? {
+            Some(pending) => pending,
+            None => return Ok(()),
+        };
+        while let Some((offset, buf)) = pending.next_gap() {
+            self.read_exact_at(offset, buf).await?;
+        }
+        self.cache.finish_load(pending);
+        Ok(())
+    }
+
+    async fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<(), ParseError> {
+        let start = SeekFrom::Start(offset);
+        poll_fn(|cx| Pin::new(&mut self.reader).poll_seek(cx, start)).await?;
+
+        let mut filled = 0;
+        while filled < buf.len() {
+            let read =
+                poll_fn(|cx| Pin::new(&mut self.reader).poll_read(cx, &mut buf[filled..])).await;
+            match read {
+                Ok(0) => return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into()),
+                Ok(read) => filled += read,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod interface_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::note::{Note, NoteGnuAbiTag};
+    use crate::symbol::Symbol;
+    use crate::ElfStream;
+    use core::task::{Context, Poll};
+    use futures::executor::block_on;
+    use futures::io::Cursor;
+
+    fn open(path: &str) -> AsyncElfStream<AnyEndian, Cursor<Vec<u8>>> {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        block_on(AsyncElfStream::open_stream(Cursor::new(file_data))).expect("Open test1")
+    }
+
+    #[test]
+    fn open_stream() {
+        let file = open("sample-objects/basic.x86_64");
+        let io = std::fs::File::open("sample-objects/basic.x86_64").expect("Could not open file.");
+        let sync = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        assert_eq!(file.ehdr, sync.ehdr);
+        assert_eq!(file.section_headers(), sync.section_headers());
+        assert_eq!(file.segments(), sync.segments());
+    }
+
+    #[test]
+    fn open_stream_truncated() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let truncated = Cursor::new(file_data[..0x100].to_vec());
+        assert!(matches!(
+            block_on(AsyncElfStream::<AnyEndian, _>::open_stream(truncated)),
+            Err(ParseError::BadOffset(_))
+        ));
+    }
+
+    /// A stream which fails every other read with [std::io::ErrorKind::Interrupted]
+    struct Interrupting {
+        inner: Cursor<Vec<u8>>,
+        interrupt: bool,
+    }
+
+    impl AsyncRead for Interrupting {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Poll::Ready(Err(std::io::ErrorKind::Interrupted.into()));
+            }
+            Pin::new(&mut self.inner).poll_read(cx, buf)
+        }
+    }
+
+    impl AsyncSeek for Interrupting {
+        fn poll_seek(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            pos: SeekFrom,
+        ) -> Poll<std::io::Result<u64>> {
+            Pin::new(&mut self.inner).poll_seek(cx, pos)
+        }
+    }
+
+    #[test]
+    fn open_stream_interrupted() {
+        let file_data = std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.");
+        let io = Interrupting {
+            inner: Cursor::new(file_data),
+            interrupt: false,
+        };
+        let file = block_on(AsyncElfStream::<AnyEndian, _>::open_stream(io)).expect("Open test1");
+        assert_eq!(file.section_headers().len(), 31);
+    }
+
+    #[test]
+    fn cache_limit() {
+        let mut file = open("sample-objects/basic.x86_64").with_cache_limit(0);
+        assert_eq!(file.cached_bytes(), 0);
+
+        let shdr = file.section_headers()[7];
+        block_on(file.section_data(&shdr)).expect("Failed to get section data");
+        assert_eq!(file.cached_bytes(), 8);
+
+        // The next call evicts everything from the previous one
+        let shdr = file.section_headers()[2];
+        block_on(file.section_data(&shdr)).expect("Failed to get section data");
+        assert_eq!(file.cached_bytes(), shdr.sh_size as usize);
+
+        file.clear_cache();
+        assert_eq!(file.cached_bytes(), 0);
+    }
+
+    #[test]
+    fn section_data() {
+        let mut file = open("sample-objects/basic.x86_64");
+        let shdr = file.section_headers()[7];
+        assert_eq!(shdr.sh_type, abi::SHT_GNU_VERSYM);
+        let (data, chdr) = block_on(file.section_data(&shdr)).expect("Failed to get section data");
+        assert_eq!(chdr, None);
+        assert_eq!(data, [0, 0, 2, 0, 2, 0, 0, 0]);
+
+        let shdr = *block_on(file.section_header_by_name(".bss"))
+            .unwrap()
+            .expect("should have a .bss");
+        let (data, _) = block_on(file.section_data(&shdr)).unwrap();
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn symbol_table() {
+        let mut file = open("sample-objects/basic.x86_64");
+        let (symtab, strtab) = block_on(file.symbol_table())
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
+        let symbol = symtab.get(30).expect("Failed to get symbol");
+        assert_eq!(
+            symbol,
+            Symbol {
+                st_name: 19,
+                st_value: 6293200,
+                st_size: 0,
+                st_shndx: 21,
+                st_info: 1,
+                st_other: 0,
+            }
+        );
+        assert_eq!(
+            strtab
+                .get(symbol.st_name as usize)
+                .expect("Failed to get name from strtab"),
+            "__JCR_LIST__"
+        );
+
+        let (dynsyms, dynstrs) = block_on(file.dynamic_symbol_table())
+            .expect("Failed to read symbol table")
+            .expect("Failed to find symbol table");
+        let symbol = dynsyms.get(1).expect("Failed to get symbol");
+        assert_eq!(dynstrs.get(symbol.st_name as usize).unwrap(), "memset");
+    }
+
+    #[test]
+    fn section_data_as_notes() {
+        let mut file = open("sample-objects/basic.x86_64");
+        let shdr = file.section_headers()[2];
+        let mut notes =
+            block_on(file.section_data_as_notes(&shdr)).expect("Failed to read note section");
+        assert_eq!(
+            notes.next().expect("Failed to get first note"),
+            Note::GnuAbiTag(NoteGnuAbiTag {
+                os: 0,
+                major: 2,
+                minor: 6,
+                subminor: 32
+            })
+        );
+        assert!(notes.next().is_none());
+
+        let shdr = file.section_headers()[1];
+        assert!(matches!(
+            block_on(file.section_data_as_notes(&shdr)),
+            Err(ParseError::UnexpectedSectionType((
+                abi::SHT_PROGBITS,
+                abi::SHT_NOTE
+            )))
+        ));
+    }
+}
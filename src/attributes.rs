@@ -0,0 +1,526 @@
+//! Parsing build attributes sections: `.ARM.attributes`, `.riscv.attributes`, `.gnu.attributes`
+//!
+//! Build attributes record how an object was compiled (target ISA, float ABI, stack alignment, etc)
+//! so that linkers and loaders can check that objects are compatible with each other.
+//! They're stored in [SHT_ARM_ATTRIBUTES](crate::abi::SHT_ARM_ATTRIBUTES),
+//! [SHT_RISCV_ATTRIBUTES](crate::abi::SHT_RISCV_ATTRIBUTES), and
+//! [SHT_GNU_ATTRIBUTES](crate::abi::SHT_GNU_ATTRIBUTES) sections with the following layout:
+//!
+//! ```text
+//! 'A'                                 format-version
+//! [ u32 length, NTBS vendor-name,     vendor subsection
+//!   [ u8 tag, u32 size,               sub-subsection (Tag_File, Tag_Section, or Tag_Symbol)
+//!     [ ULEB128 index ]* 0            (section or symbol indices, for Tag_Section and Tag_Symbol)
+//!     [ ULEB128 tag, value ]*         attributes
+//!   ]*
+//! ]*
+//! ```
+//!
+//! Whether an attribute's value is a ULEB128 integer or a NUL-terminated string depends on the vendor
+//! and the tag, see [AttributeValue].
+//!
+//! Example for checking the float ABI of an ARM object:
+//! ```
+//! use elf::abi;
+//! use elf::attributes::AttributeValue;
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let slice = file_data.as_slice();
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+//!
+//! let shdr = file
+//!     .section_header_by_name(".ARM.attributes")
+//!     .expect("section table should be parseable")
+//!     .expect("file should have a .ARM.attributes section");
+//!
+//! let attrs = file
+//!     .section_data_as_attributes(&shdr)
+//!     .expect("Should be able to get attributes section data");
+//! let vfp_args = attrs
+//!     .file_attribute(abi::ELF_ATTR_VENDOR_AEABI, abi::TAG_ARM_ABI_VFP_ARGS)
+//!     .expect("attributes should parse");
+//! assert_eq!(vfp_args, Some(AttributeValue::Int(1)));
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::parse::{parse_uleb128, ParseError, ReadBytesExt};
+
+/// Wraps the contents of a build attributes section and yields its [VendorSection]s.
+#[derive(Debug, Clone, Copy)]
+pub struct AttributesSection<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> AttributesSection<'data, E> {
+    /// Wrap the raw section data, checking that it starts with the expected format-version byte.
+    ///
+    /// Returns a [ParseError::UnsupportedVersion] if the format-version isn't
+    /// [ELF_ATTR_FORMAT_VERSION](abi::ELF_ATTR_FORMAT_VERSION).
+    pub fn new(endian: E, data: &'data [u8]) -> Result<Self, ParseError> {
+        let version = data.get_bytes(0..1)?[0];
+        if version != abi::ELF_ATTR_FORMAT_VERSION {
+            return Err(ParseError::UnsupportedVersion((
+                version as u64,
+                abi::ELF_ATTR_FORMAT_VERSION as u64,
+            )));
+        }
+
+        Ok(AttributesSection {
+            endian,
+            data: &data[1..],
+        })
+    }
+
+    /// Get an iterator over the vendor subsections in this section.
+    pub fn iter(&self) -> VendorSectionIterator<'data, E> {
+        VendorSectionIterator::new(self.endian, self.data)
+    }
+
+    /// Find the value for the given attribute tag in the file-scope attributes of the given vendor.
+    ///
+    /// Returns None if there's no such vendor subsection or the file-scope attributes don't contain the tag.
+    pub fn file_attribute(
+        &self,
+        vendor: &str,
+        tag: u64,
+    ) -> Result<Option<AttributeValue<'data>>, ParseError> {
+        for section in self.iter() {
+            let section = section?;
+            if section.vendor != vendor {
+                continue;
+            }
+
+            for subsection in section.subsections() {
+                let subsection = subsection?;
+                if subsection.tag != abi::TAG_FILE {
+                    continue;
+                }
+
+                for attr in subsection.attributes() {
+                    let attr = attr?;
+                    if attr.tag == tag {
+                        return Ok(Some(attr.value));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A vendor subsection, which holds the attributes defined by a single vendor (e.g. "aeabi", "riscv", "gnu").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VendorSection<'data, E: EndianParse> {
+    endian: E,
+    /// The name of the vendor whose attributes are contained in this subsection
+    pub vendor: &'data str,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> VendorSection<'data, E> {
+    /// Get an iterator over the file, section, and symbol sub-subsections for this vendor.
+    pub fn subsections(&self) -> AttributeSubsectionIterator<'data, E> {
+        AttributeSubsectionIterator::new(self.endian, self.vendor, self.data)
+    }
+}
+
+/// Iterates over the [VendorSection]s in an [AttributesSection].
+///
+/// Iteration ends after the first [ParseError] is yielded.
+#[derive(Debug)]
+pub struct VendorSectionIterator<'data, E: EndianParse> {
+    endian: E,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> VendorSectionIterator<'data, E> {
+    pub fn new(endian: E, data: &'data [u8]) -> Self {
+        VendorSectionIterator {
+            endian,
+            data,
+            offset: 0,
+        }
+    }
+
+    fn parse_next(&mut self) -> Result<VendorSection<'data, E>, ParseError> {
+        let start = self.offset;
+        let (contents, end) = parse_sized_contents(self.endian, start, 4, self.data)?;
+
+        let mut offset = 0;
+        let vendor = parse_ntbs(&mut offset, contents)?;
+        self.offset = end;
+
+        Ok(VendorSection {
+            endian: self.endian,
+            vendor,
+            data: &contents[offset..],
+        })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for VendorSectionIterator<'data, E> {
+    type Item = Result<VendorSection<'data, E>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let result = self.parse_next();
+        if result.is_err() {
+            // Stop iterating after an error, since we don't know where the next entry starts
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// A sub-subsection of attributes which apply to either the whole file ([TAG_FILE](abi::TAG_FILE)),
+/// a list of sections ([TAG_SECTION](abi::TAG_SECTION)), or a list of symbols ([TAG_SYMBOL](abi::TAG_SYMBOL)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeSubsection<'data> {
+    /// The scope tag for this sub-subsection
+    pub tag: u8,
+    vendor: &'data str,
+    indices: &'data [u8],
+    attributes: &'data [u8],
+}
+
+impl<'data> AttributeSubsection<'data> {
+    /// Get an iterator over the section or symbol indices that these attributes apply to.
+    ///
+    /// This is empty for [TAG_FILE](abi::TAG_FILE) sub-subsections.
+    pub fn indices(&self) -> AttributeIndexIterator<'data> {
+        AttributeIndexIterator {
+            data: self.indices,
+            offset: 0,
+        }
+    }
+
+    /// Get an iterator over the tag/value pairs in this sub-subsection.
+    pub fn attributes(&self) -> AttributeIterator<'data> {
+        AttributeIterator {
+            vendor: self.vendor,
+            data: self.attributes,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterates over the [AttributeSubsection]s in a [VendorSection].
+///
+/// Iteration ends after the first [ParseError] is yielded.
+#[derive(Debug)]
+pub struct AttributeSubsectionIterator<'data, E: EndianParse> {
+    endian: E,
+    vendor: &'data str,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> AttributeSubsectionIterator<'data, E> {
+    pub fn new(endian: E, vendor: &'data str, data: &'data [u8]) -> Self {
+        AttributeSubsectionIterator {
+            endian,
+            vendor,
+            data,
+            offset: 0,
+        }
+    }
+
+    fn parse_next(&mut self) -> Result<AttributeSubsection<'data>, ParseError> {
+        let tag = self.data.get_bytes(self.offset..self.offset + 1)?[0];
+        // The size field includes the tag byte
+        let (contents, end) = parse_sized_contents(self.endian, self.offset + 1, 5, self.data)?;
+
+        let mut offset = 0;
+        if tag == abi::TAG_SECTION || tag == abi::TAG_SYMBOL {
+            // The index list is terminated by a zero index
+            while parse_uleb128(&mut offset, contents)? != 0 {}
+        }
+        self.offset = end;
+
+        // Don't include the terminating zero in the index list
+        let indices_end = offset.saturating_sub(1);
+        Ok(AttributeSubsection {
+            tag,
+            vendor: self.vendor,
+            indices: &contents[..indices_end],
+            attributes: &contents[offset..],
+        })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for AttributeSubsectionIterator<'data, E> {
+    type Item = Result<AttributeSubsection<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let result = self.parse_next();
+        if result.is_err() {
+            // Stop iterating after an error, since we don't know where the next entry starts
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+/// Iterates over the ULEB128-encoded section or symbol indices of an [AttributeSubsection].
+#[derive(Debug)]
+pub struct AttributeIndexIterator<'data> {
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl Iterator for AttributeIndexIterator<'_> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        parse_uleb128(&mut self.offset, self.data).ok()
+    }
+}
+
+/// The value of a single build attribute.
+///
+/// The ABIs define which tags have which kind of value:
+/// * For all vendors, odd-numbered tags have NUL-terminated string values and even-numbered tags
+///   have ULEB128 integer values, with the following exceptions.
+/// * [TAG_COMPATIBILITY](abi::TAG_COMPATIBILITY) for the "aeabi" and "gnu" vendors is a ULEB128 flag
+///   followed by a NUL-terminated string.
+/// * For the "aeabi" vendor, tags below 32 are all integers except for
+///   [TAG_ARM_CPU_RAW_NAME](abi::TAG_ARM_CPU_RAW_NAME) and [TAG_ARM_CPU_NAME](abi::TAG_ARM_CPU_NAME),
+///   which are strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeValue<'data> {
+    /// A ULEB128-encoded integer value
+    Int(u64),
+    /// A NUL-terminated string value
+    Str(&'data str),
+    /// A ULEB128-encoded integer followed by a NUL-terminated string
+    IntStr(u64, &'data str),
+}
+
+/// A single build attribute tag and its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attribute<'data> {
+    pub tag: u64,
+    pub value: AttributeValue<'data>,
+}
+
+/// Iterates over the [Attribute]s in an [AttributeSubsection].
+///
+/// Iteration ends after the first [ParseError] is yielded.
+#[derive(Debug)]
+pub struct AttributeIterator<'data> {
+    vendor: &'data str,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data> AttributeIterator<'data> {
+    fn parse_next(&mut self) -> Result<Attribute<'data>, ParseError> {
+        let offset = &mut self.offset;
+        let tag = parse_uleb128(offset, self.data)?;
+        let value = match value_kind(self.vendor, tag) {
+            ValueKind::Int => AttributeValue::Int(parse_uleb128(offset, self.data)?),
+            ValueKind::Str => AttributeValue::Str(parse_ntbs(offset, self.data)?),
+            ValueKind::IntStr => {
+                let flag = parse_uleb128(offset, self.data)?;
+                AttributeValue::IntStr(flag, parse_ntbs(offset, self.data)?)
+            }
+        };
+        Ok(Attribute { tag, value })
+    }
+}
+
+impl<'data> Iterator for AttributeIterator<'data> {
+    type Item = Result<Attribute<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let result = self.parse_next();
+        if result.is_err() {
+            // Stop iterating after an error, since we don't know where the next entry starts
+            self.offset = self.data.len();
+        }
+        Some(result)
+    }
+}
+
+enum ValueKind {
+    Int,
+    Str,
+    IntStr,
+}
+
+fn value_kind(vendor: &str, tag: u64) -> ValueKind {
+    match (vendor, tag) {
+        (abi::ELF_ATTR_VENDOR_AEABI | "gnu", abi::TAG_COMPATIBILITY) => ValueKind::IntStr,
+        (abi::ELF_ATTR_VENDOR_AEABI, abi::TAG_ARM_CPU_RAW_NAME | abi::TAG_ARM_CPU_NAME) => {
+            ValueKind::Str
+        }
+        (abi::ELF_ATTR_VENDOR_AEABI, tag) if tag < 32 => ValueKind::Int,
+        (_, tag) if tag % 2 == 1 => ValueKind::Str,
+        _ => ValueKind::Int,
+    }
+}
+
+/// Parse a u32 length at `start` (which counts `header_size` bytes leading up to and including
+/// itself) and return the contents following the length field along with the end offset.
+fn parse_sized_contents<E: EndianParse>(
+    endian: E,
+    start: usize,
+    header_size: usize,
+    data: &[u8],
+) -> Result<(&[u8], usize), ParseError> {
+    let mut offset = start;
+    let size: usize = endian.parse_u32_at(&mut offset, data)?.try_into()?;
+    if size < header_size {
+        return Err(ParseError::BadOffset(size as u64));
+    }
+
+    // The size counts the header bytes that precede the contents
+    let end = (start + 4 - header_size)
+        .checked_add(size)
+        .ok_or(ParseError::IntegerOverflow)?;
+    let contents = data.get_bytes(offset..end)?;
+    Ok((contents, end))
+}
+
+fn parse_ntbs<'data>(offset: &mut usize, data: &'data [u8]) -> Result<&'data str, ParseError> {
+    let start = *offset;
+    let remaining = data.get_bytes(start..data.len())?;
+    let len = remaining
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or(ParseError::StringTableMissingNul(start as u64))?;
+    *offset = start + len + 1;
+    Ok(core::str::from_utf8(&remaining[..len])?)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{BigEndian, LittleEndian};
+
+    #[test]
+    fn parse_riscv_attributes() {
+        #[rustfmt::skip]
+        let data = [
+            b'A',
+            0x19, 0x00, 0x00, 0x00, b'r', b'i', b's', b'c', b'v', 0x00,
+            // Tag_File
+            0x01, 0x0f, 0x00, 0x00, 0x00,
+            // Tag_RISCV_stack_align = 16
+            0x04, 0x10,
+            // Tag_RISCV_arch = "rv64gc"
+            0x05, b'r', b'v', b'6', b'4', b'g', b'c', 0x00,
+        ];
+        let attrs = AttributesSection::new(LittleEndian, &data).expect("should parse");
+
+        let sections: Vec<_> = attrs
+            .iter()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].vendor, "riscv");
+
+        let subsections: Vec<_> = sections[0]
+            .subsections()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(subsections.len(), 1);
+        assert_eq!(subsections[0].tag, abi::TAG_FILE);
+        assert_eq!(subsections[0].indices().count(), 0);
+
+        let values: Vec<_> = subsections[0]
+            .attributes()
+            .collect::<Result<_, _>>()
+            .expect("should parse");
+        assert_eq!(
+            values,
+            vec![
+                Attribute {
+                    tag: abi::TAG_RISCV_STACK_ALIGN,
+                    value: AttributeValue::Int(16),
+                },
+                Attribute {
+                    tag: abi::TAG_RISCV_ARCH,
+                    value: AttributeValue::Str("rv64gc"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_aeabi_section_scope_big_endian() {
+        #[rustfmt::skip]
+        let data = [
+            b'A',
+            0x00, 0x00, 0x00, 0x1a, b'a', b'e', b'a', b'b', b'i', 0x00,
+            // Tag_Section for sections 1 and 200
+            0x02, 0x00, 0x00, 0x00, 0x10, 0x01, 0xc8, 0x01, 0x00,
+            // Tag_CPU_name = "M4"
+            0x05, b'M', b'4', 0x00,
+            // Tag_compatibility = (1, "")
+            0x20, 0x01, 0x00,
+        ];
+        let attrs = AttributesSection::new(BigEndian, &data).expect("should parse");
+        let section = attrs.iter().next().unwrap().expect("should parse");
+        assert_eq!(section.vendor, "aeabi");
+
+        let subsection = section.subsections().next().unwrap().expect("should parse");
+        assert_eq!(subsection.tag, abi::TAG_SECTION);
+        assert_eq!(subsection.indices().collect::<Vec<_>>(), vec![1, 200]);
+
+        let values: Vec<_> = subsection
+            .attributes()
+            .map(|attr| attr.expect("should parse").value)
+            .collect();
+        assert_eq!(
+            values,
+            vec![AttributeValue::Str("M4"), AttributeValue::IntStr(1, "")]
+        );
+
+        // The file attribute lookup ignores the section-scoped attributes
+        assert_eq!(
+            attrs
+                .file_attribute(abi::ELF_ATTR_VENDOR_AEABI, abi::TAG_ARM_CPU_NAME)
+                .expect("should parse"),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_bad_version() {
+        let error = AttributesSection::new(LittleEndian, b"B").expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::UnsupportedVersion((0x42, 0x41))),
+            "Unexpected Error type found: {error}"
+        );
+    }
+
+    #[test]
+    fn parse_truncated_vendor_section() {
+        let data = [b'A', 0xff, 0x00, 0x00, 0x00, b'g', b'n', b'u', 0x00];
+        let attrs = AttributesSection::new(LittleEndian, &data).expect("should parse");
+        let mut iter = attrs.iter();
+        let error = iter.next().unwrap().expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::SliceReadError(_)),
+            "Unexpected Error type found: {error}"
+        );
+        assert!(iter.next().is_none());
+    }
+}
@@ -0,0 +1,190 @@
+//! Demangling symbol names: Rust (legacy and v0) and Itanium C++
+//!
+//! This module is only available with the `demangle` cargo feature, which uses the
+//! `rustc-demangle` and `cpp_demangle` crates. It only needs `alloc`, so it can be used in
+//! `no_std` environments which have an allocator.
+//!
+//! [DemangledName] keeps the mangled name from the string table alongside the demangled one,
+//! and falls back to the mangled name for names which aren't mangled (like C symbols) or
+//! which fail to demangle.
+//!
+//! Example:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::demangle::Language;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/demangle.x86_64.o");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//! let (symtab, strtab) = file
+//!     .symbol_table()
+//!     .expect("symtab should parse")
+//!     .expect("file should have a .symtab");
+//!
+//! let sym = symtab.get(3).expect("should parse");
+//! let name = sym.demangled_name(&strtab).expect("should parse");
+//! assert_eq!(name.mangled(), "_ZNSt6vectorIiSaIiEE9push_backERKi");
+//! assert_eq!(name.language(), Some(Language::Cpp));
+//! assert_eq!(
+//!     name.demangled(),
+//!     "std::vector<int, std::allocator<int> >::push_back(int const&)"
+//! );
+//! ```
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{format, string::String};
+
+/// The mangling scheme a symbol name was demangled with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// Rust's legacy (`_ZN...E` with a trailing hash) or v0 (`_R...`) mangling
+    Rust,
+    /// The Itanium C++ ABI mangling (`_Z...`)
+    Cpp,
+}
+
+/// A symbol name from a string table and its demangled form (if it was mangled)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DemangledName<'data> {
+    mangled: &'data str,
+    demangled: Option<(Language, String)>,
+}
+
+impl<'data> DemangledName<'data> {
+    /// Demangle the given name, trying Rust's manglings before C++'s since Rust's legacy
+    /// mangling is also a valid Itanium C++ mangled name.
+    pub fn new(mangled: &'data str) -> Self {
+        DemangledName {
+            mangled,
+            demangled: demangle_rust(mangled)
+                .map(|name| (Language::Rust, name))
+                .or_else(|| demangle_cpp(mangled).map(|name| (Language::Cpp, name))),
+        }
+    }
+
+    /// The name exactly as it appears in the string table.
+    pub fn mangled(&self) -> &'data str {
+        self.mangled
+    }
+
+    /// The demangled name, or the mangled name if it isn't mangled or couldn't be demangled.
+    ///
+    /// Rust names are demangled without their trailing hash, as in `core::fmt::write`.
+    pub fn demangled(&self) -> &str {
+        match self.demangled {
+            Some((_, ref name)) => name,
+            None => self.mangled,
+        }
+    }
+
+    /// The mangling scheme the name was demangled with, or None if it wasn't demangled.
+    pub fn language(&self) -> Option<Language> {
+        self.demangled.as_ref().map(|(language, _)| *language)
+    }
+
+    /// Returns whether the name was demangled.
+    pub fn is_demangled(&self) -> bool {
+        self.demangled.is_some()
+    }
+}
+
+impl core::fmt::Display for DemangledName<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.demangled())
+    }
+}
+
+fn demangle_rust(name: &str) -> Option<String> {
+    rustc_demangle::try_demangle(name)
+        .ok()
+        .map(|name| format!("{name:#}"))
+}
+
+fn demangle_cpp(name: &str) -> Option<String> {
+    if !name.starts_with("_Z") {
+        return None;
+    }
+    let symbol = cpp_demangle::Symbol::new(name).ok()?;
+    symbol
+        .demangle(&cpp_demangle::DemangleOptions::default())
+        .ok()
+}
+
+#[cfg(test)]
+mod demangle_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::ElfBytes;
+
+    #[test]
+    fn rust_legacy() {
+        let name = DemangledName::new("_ZN4core3fmt5Write9write_fmt17h0123456789abcdefE");
+        assert_eq!(name.language(), Some(Language::Rust));
+        assert_eq!(name.demangled(), "core::fmt::Write::write_fmt");
+    }
+
+    #[test]
+    fn rust_v0() {
+        let name = DemangledName::new("_RNvCs1234_7mycrate3foo");
+        assert_eq!(name.language(), Some(Language::Rust));
+        assert_eq!(name.demangled(), "mycrate::foo");
+    }
+
+    #[test]
+    fn cpp() {
+        let name = DemangledName::new("_ZNSt6vectorIiSaIiEE9push_backERKi");
+        assert_eq!(name.language(), Some(Language::Cpp));
+        assert_eq!(
+            name.to_string(),
+            "std::vector<int, std::allocator<int> >::push_back(int const&)"
+        );
+    }
+
+    #[test]
+    fn not_mangled() {
+        for mangled in ["main", "", "_Z", "_R", "_ZN3foo"] {
+            let name = DemangledName::new(mangled);
+            assert!(!name.is_demangled());
+            assert_eq!(name.language(), None);
+            assert_eq!(name.demangled(), mangled);
+            assert_eq!(name.mangled(), mangled);
+        }
+    }
+
+    #[test]
+    fn symbol_table_names() {
+        let path = std::path::PathBuf::from("sample-objects/demangle.x86_64.o");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let (symtab, strtab) = file
+            .symbol_table()
+            .expect("symtab should parse")
+            .expect("file should have a .symtab");
+
+        let names: Vec<String> = symtab
+            .iter()
+            .skip(1)
+            .map(|sym| {
+                sym.demangled_name(&strtab)
+                    .expect("should parse")
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "core::fmt::Write::write_fmt",
+                "mycrate::foo",
+                "std::vector<int, std::allocator<int> >::push_back(int const&)",
+                "main",
+            ]
+        );
+
+        let sym = symtab.get(2).expect("should parse");
+        let name = strtab
+            .get_demangled(sym.st_name as usize)
+            .expect("should parse");
+        assert_eq!(name.mangled(), "_RNvCs1234_7mycrate3foo");
+        assert_eq!(name.demangled(), "mycrate::foo");
+    }
+}
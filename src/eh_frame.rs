@@ -0,0 +1,937 @@
+//! Parsing unwind information: `.eh_frame`, `.eh_frame_hdr`, [PT_GNU_EH_FRAME](crate::abi::PT_GNU_EH_FRAME)
+//!
+//! The `.eh_frame` section contains a sequence of Common Information Entries ([Cie]s) and
+//! Frame Description Entries ([Fde]s) which describe how to unwind the stack for a range of
+//! code addresses. The `.eh_frame_hdr` section contains a sorted binary search table which
+//! maps from code addresses to the FDE which covers them.
+//!
+//! The call frame instructions are not interpreted, and are returned as the raw byte slices
+//! from [Cie::initial_instructions] and [Fde::instructions].
+//!
+//! Pointers in these sections are encoded according to the `DW_EH_PE_*` encodings, and this
+//! module resolves them into virtual addresses using the address of the section being parsed.
+//! Values with the [DW_EH_PE_INDIRECT](crate::abi::DW_EH_PE_INDIRECT) bit set (commonly used
+//! for the personality routine) are resolved to the address at which the real pointer is stored.
+//!
+//! Example for finding the FDE for a given PC:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let slice = file_data.as_slice();
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+//!
+//! let eh_frame_hdr = file
+//!     .eh_frame_hdr()
+//!     .expect("eh_frame_hdr should parse")
+//!     .expect("file should have an .eh_frame_hdr");
+//! let eh_frame = file
+//!     .eh_frame()
+//!     .expect("eh_frame should parse")
+//!     .expect("file should have an .eh_frame");
+//!
+//! let fde = eh_frame_hdr
+//!     .find_fde(0x4004f0, &eh_frame)
+//!     .expect("FDE should parse")
+//!     .expect("PC should be covered by an FDE");
+//! assert_eq!(fde.pc_begin, 0x4004e6);
+//! assert_eq!(fde.pc_range, 0x20);
+//! assert_eq!(fde.cie.return_address_register, 16);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{parse_sleb128, parse_uleb128, ParseError, ReadBytesExt};
+
+/// A Common Information Entry, which holds the information shared by the [Fde]s which refer to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cie<'data> {
+    /// The offset of this entry in the `.eh_frame` section
+    pub offset: usize,
+    pub version: u8,
+    /// The augmentation string, which describes the optional fields present in this CIE and its FDEs (e.g. "zR")
+    pub augmentation: &'data str,
+    pub code_alignment_factor: u64,
+    pub data_alignment_factor: i64,
+    pub return_address_register: u64,
+    /// The `DW_EH_PE_*` encoding used for the pc_begin and pc_range in FDEs which use this CIE
+    pub fde_pointer_encoding: u8,
+    /// The `DW_EH_PE_*` encoding used for the lsda in FDEs which use this CIE
+    pub lsda_encoding: u8,
+    /// The address of the personality routine (if any)
+    pub personality: Option<u64>,
+    /// Whether the FDEs using this CIE describe signal handler frames
+    pub is_signal_frame: bool,
+    /// The raw call frame instructions which define the initial unwind rules
+    pub initial_instructions: &'data [u8],
+}
+
+/// A Frame Description Entry, which describes how to unwind a given range of code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fde<'data> {
+    /// The offset of this entry in the `.eh_frame` section
+    pub offset: usize,
+    /// The CIE which this FDE refers to
+    pub cie: Cie<'data>,
+    /// The address of the first instruction covered by this FDE
+    pub pc_begin: u64,
+    /// The number of bytes of instructions covered by this FDE
+    pub pc_range: u64,
+    /// The address of the Language Specific Data Area (if any)
+    pub lsda: Option<u64>,
+    /// The raw call frame instructions for this FDE's code range
+    pub instructions: &'data [u8],
+}
+
+impl Fde<'_> {
+    /// Returns whether the given pc falls within the code range described by this FDE
+    pub fn contains(&self, pc: u64) -> bool {
+        pc >= self.pc_begin && pc - self.pc_begin < self.pc_range
+    }
+}
+
+/// The entries that make up an `.eh_frame` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EhFrameEntry<'data> {
+    Cie(Cie<'data>),
+    Fde(Fde<'data>),
+}
+
+/// The location of the contents of a CIE or FDE along with its CIE id/pointer field.
+struct EntryHeader {
+    /// The offset of the CIE id/pointer field
+    id_offset: usize,
+    id: u64,
+    /// The offset just after the CIE id/pointer field
+    contents_start: usize,
+    /// The offset just after the end of this entry
+    end: usize,
+}
+
+/// Wraps the contents of an `.eh_frame` section along with its virtual address,
+/// which is needed to resolve pc-relative pointers.
+#[derive(Debug, Clone, Copy)]
+pub struct EhFrame<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    address: u64,
+    data: &'data [u8],
+}
+
+impl<'data, E: EndianParse> EhFrame<'data, E> {
+    /// Wrap the raw `.eh_frame` data which is located at the given virtual address.
+    pub fn new(endian: E, class: Class, address: u64, data: &'data [u8]) -> Self {
+        EhFrame {
+            endian,
+            class,
+            address,
+            data,
+        }
+    }
+
+    /// The virtual address of the start of the `.eh_frame` data.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Get an iterator over the CIEs and FDEs in this section.
+    pub fn entries(&self) -> EhFrameEntryIterator<'_, 'data, E> {
+        EhFrameEntryIterator {
+            eh_frame: self,
+            offset: 0,
+        }
+    }
+
+    /// Parse the CIE at the given offset into the section.
+    pub fn cie_at(&self, offset: usize) -> Result<Cie<'data>, ParseError> {
+        let header = self
+            .parse_entry_header(offset)?
+            .ok_or(ParseError::BadOffset(offset as u64))?;
+        if header.id != 0 {
+            return Err(ParseError::BadOffset(offset as u64));
+        }
+        self.parse_cie(offset, &header)
+    }
+
+    /// Parse the FDE at the given offset into the section.
+    pub fn fde_at(&self, offset: usize) -> Result<Fde<'data>, ParseError> {
+        let header = self
+            .parse_entry_header(offset)?
+            .ok_or(ParseError::BadOffset(offset as u64))?;
+        if header.id == 0 {
+            return Err(ParseError::BadOffset(offset as u64));
+        }
+        self.parse_fde(offset, &header)
+    }
+
+    /// Find the FDE which covers the given pc by searching through every entry in the section.
+    ///
+    /// Prefer [EhFrameHdr::find_fde] when an `.eh_frame_hdr` is available, as it does a binary search.
+    pub fn find_fde(&self, pc: u64) -> Result<Option<Fde<'data>>, ParseError> {
+        for entry in self.entries() {
+            if let EhFrameEntry::Fde(fde) = entry? {
+                if fde.contains(pc) {
+                    return Ok(Some(fde));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Parse the length and id fields of the entry at the given offset.
+    ///
+    /// Returns None for a zero-length terminator entry.
+    fn parse_entry_header(&self, offset: usize) -> Result<Option<EntryHeader>, ParseError> {
+        let mut offset = offset;
+        let mut length = self.endian.parse_u32_at(&mut offset, self.data)? as u64;
+        if length == 0 {
+            return Ok(None);
+        }
+
+        if length == 0xffffffff {
+            length = self.endian.parse_u64_at(&mut offset, self.data)?;
+        }
+
+        let end = offset
+            .checked_add(length.try_into()?)
+            .ok_or(ParseError::IntegerOverflow)?;
+        if end > self.data.len() {
+            return Err(ParseError::SliceReadError((offset, end)));
+        }
+
+        // Unlike .debug_frame, the CIE id/pointer is 4 bytes even for 64-bit lengths
+        let id_offset = offset;
+        let id = self.endian.parse_u32_at(&mut offset, self.data)? as u64;
+        if offset > end {
+            return Err(ParseError::BadOffset(id_offset as u64));
+        }
+
+        Ok(Some(EntryHeader {
+            id_offset,
+            id,
+            contents_start: offset,
+            end,
+        }))
+    }
+
+    fn parse_cie(&self, offset: usize, header: &EntryHeader) -> Result<Cie<'data>, ParseError> {
+        let data = self.data.get_bytes(0..header.end)?;
+        let mut offset_in = header.contents_start;
+        let offset_ref = &mut offset_in;
+
+        let version = data.get_bytes(*offset_ref..*offset_ref + 1)?[0];
+        *offset_ref += 1;
+        if !matches!(version, 1 | 3 | 4) {
+            return Err(ParseError::UnsupportedVersion((version as u64, 1)));
+        }
+
+        let aug_start = *offset_ref;
+        let aug_len = data
+            .get_bytes(aug_start..data.len())?
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(ParseError::StringTableMissingNul(aug_start as u64))?;
+        let augmentation = core::str::from_utf8(&data[aug_start..aug_start + aug_len])?;
+        *offset_ref = aug_start + aug_len + 1;
+
+        // The old GCC "eh" augmentation is followed by an address-sized pointer to exception tables
+        if augmentation.starts_with("eh") {
+            *offset_ref += self.address_size();
+        }
+
+        // Version 4 CIEs carry the address and segment selector sizes
+        if version == 4 {
+            *offset_ref += 2;
+        }
+
+        let code_alignment_factor = parse_uleb128(offset_ref, data)?;
+        let data_alignment_factor = parse_sleb128(offset_ref, data)?;
+        let return_address_register = match version {
+            1 => {
+                let reg = data.get_bytes(*offset_ref..*offset_ref + 1)?[0];
+                *offset_ref += 1;
+                reg as u64
+            }
+            _ => parse_uleb128(offset_ref, data)?,
+        };
+
+        let mut fde_pointer_encoding = abi::DW_EH_PE_ABSPTR;
+        let mut lsda_encoding = abi::DW_EH_PE_OMIT;
+        let mut personality = None;
+        let mut is_signal_frame = false;
+        if let Some(aug_chars) = augmentation.strip_prefix('z') {
+            let aug_data_len: usize = parse_uleb128(offset_ref, data)?.try_into()?;
+            let aug_data_end = offset_ref
+                .checked_add(aug_data_len)
+                .ok_or(ParseError::IntegerOverflow)?;
+            // Limit parsing of the augmentation data to its specified length
+            let aug_data = data.get_bytes(0..aug_data_end)?;
+
+            for c in aug_chars.chars() {
+                match c {
+                    'L' => {
+                        lsda_encoding = aug_data.get_bytes(*offset_ref..*offset_ref + 1)?[0];
+                        *offset_ref += 1;
+                    }
+                    'P' => {
+                        let encoding = aug_data.get_bytes(*offset_ref..*offset_ref + 1)?[0];
+                        *offset_ref += 1;
+                        personality = Some(self.parse_pointer(encoding, offset_ref, aug_data)?);
+                    }
+                    'R' => {
+                        fde_pointer_encoding = aug_data.get_bytes(*offset_ref..*offset_ref + 1)?[0];
+                        *offset_ref += 1;
+                    }
+                    'S' => is_signal_frame = true,
+                    // 'B' (AArch64 BTI) and 'G' (AArch64 MTE) don't carry augmentation data
+                    'B' | 'G' => {}
+                    // We don't know how much data unknown augmentations carry, so stop here
+                    // and skip over the rest of the augmentation data
+                    _ => break,
+                }
+            }
+            *offset_ref = aug_data_end;
+        }
+
+        Ok(Cie {
+            offset,
+            version,
+            augmentation,
+            code_alignment_factor,
+            data_alignment_factor,
+            return_address_register,
+            fde_pointer_encoding,
+            lsda_encoding,
+            personality,
+            is_signal_frame,
+            initial_instructions: data.get_bytes(offset_in..header.end)?,
+        })
+    }
+
+    fn parse_fde(&self, offset: usize, header: &EntryHeader) -> Result<Fde<'data>, ParseError> {
+        // The CIE pointer is the offset from the CIE pointer field back to the CIE
+        let cie_offset: usize = (header.id_offset as u64)
+            .checked_sub(header.id)
+            .ok_or(ParseError::BadOffset(header.id))?
+            .try_into()?;
+        let cie = self.cie_at(cie_offset)?;
+
+        let data = self.data.get_bytes(0..header.end)?;
+        let mut offset_in = header.contents_start;
+        let offset_ref = &mut offset_in;
+
+        let pc_begin = self.parse_pointer(cie.fde_pointer_encoding, offset_ref, data)?;
+        // The range is encoded with the same format as pc_begin, but is never relative to anything
+        let pc_range = parse_encoded_value(
+            self.endian,
+            self.class,
+            cie.fde_pointer_encoding,
+            offset_ref,
+            data,
+        )?;
+
+        let mut lsda = None;
+        if cie.augmentation.starts_with('z') {
+            let aug_data_len: usize = parse_uleb128(offset_ref, data)?.try_into()?;
+            let aug_data_end = offset_ref
+                .checked_add(aug_data_len)
+                .ok_or(ParseError::IntegerOverflow)?;
+            let aug_data = data.get_bytes(0..aug_data_end)?;
+
+            if cie.lsda_encoding != abi::DW_EH_PE_OMIT && aug_data_len > 0 {
+                lsda = Some(self.parse_pointer(cie.lsda_encoding, offset_ref, aug_data)?);
+            }
+            *offset_ref = aug_data_end;
+        }
+
+        Ok(Fde {
+            offset,
+            cie,
+            pc_begin,
+            pc_range,
+            lsda,
+            instructions: data.get_bytes(offset_in..header.end)?,
+        })
+    }
+
+    fn parse_pointer(
+        &self,
+        encoding: u8,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<u64, ParseError> {
+        parse_encoded_pointer(
+            self.endian,
+            self.class,
+            encoding,
+            self.address,
+            None,
+            offset,
+            data,
+        )
+    }
+
+    fn address_size(&self) -> usize {
+        match self.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        }
+    }
+}
+
+/// Iterates over the [EhFrameEntry]s in an [EhFrame] until the zero terminator or the end of the section.
+///
+/// Iteration ends after the first [ParseError] is yielded.
+#[derive(Debug)]
+pub struct EhFrameEntryIterator<'a, 'data, E: EndianParse> {
+    eh_frame: &'a EhFrame<'data, E>,
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> EhFrameEntryIterator<'_, 'data, E> {
+    fn parse_next(&mut self) -> Result<Option<EhFrameEntry<'data>>, ParseError> {
+        let offset = self.offset;
+        let header = match self.eh_frame.parse_entry_header(offset)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        self.offset = header.end;
+
+        match header.id {
+            0 => Ok(Some(EhFrameEntry::Cie(
+                self.eh_frame.parse_cie(offset, &header)?,
+            ))),
+            _ => Ok(Some(EhFrameEntry::Fde(
+                self.eh_frame.parse_fde(offset, &header)?,
+            ))),
+        }
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for EhFrameEntryIterator<'_, 'data, E> {
+    type Item = Result<EhFrameEntry<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.eh_frame.data.len() {
+            return None;
+        }
+
+        match self.parse_next() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.offset = self.eh_frame.data.len();
+                None
+            }
+            Err(err) => {
+                // Stop iterating after an error, since we don't know where the next entry starts
+                self.offset = self.eh_frame.data.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Wraps the contents of an `.eh_frame_hdr` section (or [PT_GNU_EH_FRAME](abi::PT_GNU_EH_FRAME) segment)
+/// and its binary search table of (initial location, FDE address) pairs.
+#[derive(Debug, Clone, Copy)]
+pub struct EhFrameHdr<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    address: u64,
+    data: &'data [u8],
+    /// The virtual address of the `.eh_frame` section
+    pub eh_frame_ptr: u64,
+    fde_count: usize,
+    table_encoding: u8,
+    table_offset: usize,
+}
+
+impl<'data, E: EndianParse> EhFrameHdr<'data, E> {
+    /// Parse the header of the raw `.eh_frame_hdr` data which is located at the given virtual address.
+    pub fn new(
+        endian: E,
+        class: Class,
+        address: u64,
+        data: &'data [u8],
+    ) -> Result<Self, ParseError> {
+        let header = data.get_bytes(0..4)?;
+        let version = header[0];
+        if version != 1 {
+            return Err(ParseError::UnsupportedVersion((version as u64, 1)));
+        }
+        let eh_frame_ptr_encoding = header[1];
+        let fde_count_encoding = header[2];
+        let table_encoding = header[3];
+
+        let mut offset = 4;
+        let eh_frame_ptr = parse_encoded_pointer(
+            endian,
+            class,
+            eh_frame_ptr_encoding,
+            address,
+            Some(address),
+            &mut offset,
+            data,
+        )?;
+
+        // The search table is optional
+        let mut fde_count = 0;
+        if fde_count_encoding != abi::DW_EH_PE_OMIT && table_encoding != abi::DW_EH_PE_OMIT {
+            fde_count = parse_encoded_pointer(
+                endian,
+                class,
+                fde_count_encoding,
+                address,
+                Some(address),
+                &mut offset,
+                data,
+            )?
+            .try_into()?;
+            // Validate that the table entries are fixed-size so that we can binary search them
+            encoded_value_size(class, table_encoding)?;
+        }
+
+        Ok(EhFrameHdr {
+            endian,
+            class,
+            address,
+            data,
+            eh_frame_ptr,
+            fde_count,
+            table_encoding,
+            table_offset: offset,
+        })
+    }
+
+    /// Returns the number of entries in the binary search table.
+    pub fn len(&self) -> usize {
+        self.fde_count
+    }
+
+    /// Returns whether the binary search table is empty (or absent).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the (initial location, FDE address) pair at the given index in the binary search table.
+    pub fn get(&self, index: usize) -> Result<(u64, u64), ParseError> {
+        if index >= self.fde_count {
+            return Err(ParseError::BadOffset(index as u64));
+        }
+
+        let entsize = encoded_value_size(self.class, self.table_encoding)?
+            .checked_mul(2)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let mut offset = index
+            .checked_mul(entsize)
+            .and_then(|off| off.checked_add(self.table_offset))
+            .ok_or(ParseError::IntegerOverflow)?;
+        let initial_location = self.parse_table_value(&mut offset)?;
+        let fde_address = self.parse_table_value(&mut offset)?;
+        Ok((initial_location, fde_address))
+    }
+
+    /// Use the binary search table to find the address of the FDE which may cover the given pc.
+    ///
+    /// This is the FDE with the greatest initial location that is less than or equal to the pc,
+    /// so callers should still check that the pc falls within the FDE's range. See [EhFrameHdr::find_fde].
+    pub fn find(&self, pc: u64) -> Result<Option<u64>, ParseError> {
+        // Find the number of entries with an initial location <= pc
+        let mut low = 0;
+        let mut high = self.fde_count;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (initial_location, _) = self.get(mid)?;
+            if initial_location <= pc {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        match low {
+            0 => Ok(None),
+            n => Ok(Some(self.get(n - 1)?.1)),
+        }
+    }
+
+    /// Use the binary search table to find the FDE in the given `.eh_frame` which covers the given pc.
+    pub fn find_fde(
+        &self,
+        pc: u64,
+        eh_frame: &EhFrame<'data, E>,
+    ) -> Result<Option<Fde<'data>>, ParseError> {
+        let fde_address = match self.find(pc)? {
+            Some(fde_address) => fde_address,
+            None => return Ok(None),
+        };
+
+        let offset: usize = fde_address
+            .checked_sub(eh_frame.address())
+            .ok_or(ParseError::BadOffset(fde_address))?
+            .try_into()?;
+        let fde = eh_frame.fde_at(offset)?;
+        match fde.contains(pc) {
+            true => Ok(Some(fde)),
+            false => Ok(None),
+        }
+    }
+
+    fn parse_table_value(&self, offset: &mut usize) -> Result<u64, ParseError> {
+        parse_encoded_pointer(
+            self.endian,
+            self.class,
+            self.table_encoding,
+            self.address,
+            Some(self.address),
+            offset,
+            self.data,
+        )
+    }
+}
+
+/// Returns the size of a value with the given encoding, if its format is fixed-size.
+fn encoded_value_size(class: Class, encoding: u8) -> Result<usize, ParseError> {
+    match encoding & 0x0f {
+        abi::DW_EH_PE_ABSPTR => match class {
+            Class::ELF32 => Ok(4),
+            Class::ELF64 => Ok(8),
+        },
+        abi::DW_EH_PE_UDATA2 | abi::DW_EH_PE_SDATA2 => Ok(2),
+        abi::DW_EH_PE_UDATA4 | abi::DW_EH_PE_SDATA4 => Ok(4),
+        abi::DW_EH_PE_UDATA8 | abi::DW_EH_PE_SDATA8 => Ok(8),
+        _ => Err(ParseError::UnsupportedPointerEncoding(encoding)),
+    }
+}
+
+/// Parse the raw value for the format in the low nibble of the encoding, without applying its base.
+fn parse_encoded_value<E: EndianParse>(
+    endian: E,
+    class: Class,
+    encoding: u8,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<u64, ParseError> {
+    match encoding & 0x0f {
+        abi::DW_EH_PE_ABSPTR => match class {
+            Class::ELF32 => Ok(endian.parse_u32_at(offset, data)? as u64),
+            Class::ELF64 => endian.parse_u64_at(offset, data),
+        },
+        abi::DW_EH_PE_ULEB128 => parse_uleb128(offset, data),
+        abi::DW_EH_PE_UDATA2 => Ok(endian.parse_u16_at(offset, data)? as u64),
+        abi::DW_EH_PE_UDATA4 => Ok(endian.parse_u32_at(offset, data)? as u64),
+        abi::DW_EH_PE_UDATA8 => endian.parse_u64_at(offset, data),
+        abi::DW_EH_PE_SLEB128 => Ok(parse_sleb128(offset, data)? as u64),
+        abi::DW_EH_PE_SDATA2 => Ok(endian.parse_u16_at(offset, data)? as i16 as u64),
+        abi::DW_EH_PE_SDATA4 => Ok(endian.parse_i32_at(offset, data)? as u64),
+        abi::DW_EH_PE_SDATA8 => Ok(endian.parse_i64_at(offset, data)? as u64),
+        _ => Err(ParseError::UnsupportedPointerEncoding(encoding)),
+    }
+}
+
+/// Parse a pointer with the given `DW_EH_PE_*` encoding and resolve it to a virtual address.
+///
+/// `address` is the virtual address of `data[0]`, used for pc-relative values, and `datarel_base`
+/// is the base used for data-relative values (only defined for `.eh_frame_hdr`).
+fn parse_encoded_pointer<E: EndianParse>(
+    endian: E,
+    class: Class,
+    encoding: u8,
+    address: u64,
+    datarel_base: Option<u64>,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<u64, ParseError> {
+    let base = match encoding & 0x70 {
+        abi::DW_EH_PE_ABSPTR => 0,
+        abi::DW_EH_PE_PCREL => address.wrapping_add(*offset as u64),
+        abi::DW_EH_PE_DATAREL => {
+            datarel_base.ok_or(ParseError::UnsupportedPointerEncoding(encoding))?
+        }
+        _ => return Err(ParseError::UnsupportedPointerEncoding(encoding)),
+    };
+
+    let value = base.wrapping_add(parse_encoded_value(endian, class, encoding, offset, data)?);
+    match class {
+        Class::ELF32 => Ok(value & 0xffffffff),
+        Class::ELF64 => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    #[test]
+    fn eh_frame_entries() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let eh_frame = file
+            .eh_frame()
+            .expect("eh_frame should parse")
+            .expect("file should have .eh_frame");
+        assert_eq!(eh_frame.address(), 0x4005c8);
+
+        let entries: Vec<EhFrameEntry<'_>> = eh_frame
+            .entries()
+            .collect::<Result<_, _>>()
+            .expect("entries should parse");
+        assert_eq!(entries.len(), 7);
+
+        let cie = match entries[0] {
+            EhFrameEntry::Cie(cie) => cie,
+            EhFrameEntry::Fde(_) => panic!("Expected a CIE"),
+        };
+        assert_eq!(
+            cie,
+            Cie {
+                offset: 0,
+                version: 1,
+                augmentation: "zR",
+                code_alignment_factor: 1,
+                data_alignment_factor: -8,
+                return_address_register: 16,
+                fde_pointer_encoding: abi::DW_EH_PE_PCREL | abi::DW_EH_PE_SDATA4,
+                lsda_encoding: abi::DW_EH_PE_OMIT,
+                personality: None,
+                is_signal_frame: false,
+                // DW_CFA_def_cfa: r7 ofs 8, DW_CFA_offset: r16 at cfa-8, DW_CFA_undefined: r16
+                initial_instructions: &[0x0c, 0x07, 0x08, 0x90, 0x01, 0x07, 0x10],
+            }
+        );
+
+        let fde = match entries[1] {
+            EhFrameEntry::Fde(fde) => fde,
+            EhFrameEntry::Cie(_) => panic!("Expected an FDE"),
+        };
+        assert_eq!(fde.offset, 0x18);
+        assert_eq!(fde.cie, cie);
+        assert_eq!(fde.pc_begin, 0x4003f0);
+        assert_eq!(fde.pc_range, 0x2a);
+        assert_eq!(fde.lsda, None);
+        assert_eq!(fde.instructions, &[0u8; 7]);
+
+        let fde_offsets: Vec<usize> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                EhFrameEntry::Fde(fde) => Some(fde.offset),
+                EhFrameEntry::Cie(_) => None,
+            })
+            .collect();
+        assert_eq!(fde_offsets, vec![0x18, 0x48, 0x70, 0x90, 0xd8]);
+
+        // Linear search for an FDE
+        let fde = eh_frame
+            .find_fde(0x400520)
+            .expect("should parse")
+            .expect("should find FDE");
+        assert_eq!(fde.offset, 0x90);
+        assert_eq!(eh_frame.find_fde(0x400600).expect("should parse"), None);
+
+        // Asking for an FDE where there's a CIE is an error
+        assert!(matches!(eh_frame.fde_at(0), Err(ParseError::BadOffset(0))));
+        assert!(matches!(
+            eh_frame.cie_at(0x18),
+            Err(ParseError::BadOffset(0x18))
+        ));
+    }
+
+    #[test]
+    fn eh_frame_hdr_search_table() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let hdr = file
+            .eh_frame_hdr()
+            .expect("eh_frame_hdr should parse")
+            .expect("file should have .eh_frame_hdr");
+        let eh_frame = file
+            .eh_frame()
+            .expect("eh_frame should parse")
+            .expect("file should have .eh_frame");
+
+        assert_eq!(hdr.eh_frame_ptr, 0x4005c8);
+        assert_eq!(hdr.len(), 5);
+        assert_eq!(hdr.get(0).expect("should parse"), (0x4003b0, 0x400610));
+        assert_eq!(hdr.get(4).expect("should parse"), (0x400580, 0x4006a0));
+        assert!(matches!(hdr.get(5), Err(ParseError::BadOffset(5))));
+
+        // Before the first entry
+        assert_eq!(hdr.find(0x400000).expect("should parse"), None);
+        assert_eq!(hdr.find(0x4003b0).expect("should parse"), Some(0x400610));
+        assert_eq!(hdr.find(0x4003ef).expect("should parse"), Some(0x400610));
+
+        // Every FDE should be found via the search table
+        for entry in eh_frame.entries() {
+            if let EhFrameEntry::Fde(expected) = entry.expect("should parse") {
+                let last = expected.pc_begin + expected.pc_range - 1;
+                for pc in [expected.pc_begin, last] {
+                    let fde = hdr
+                        .find_fde(pc, &eh_frame)
+                        .expect("should parse")
+                        .expect("should find FDE");
+                    assert_eq!(fde, expected);
+                }
+            }
+        }
+
+        // In the gap between two FDEs
+        assert_eq!(
+            hdr.find_fde(0x4003e8, &eh_frame).expect("should parse"),
+            None
+        );
+    }
+
+    #[test]
+    fn eh_frame_located_via_eh_frame_hdr_segment() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Zero out e_shoff and e_shnum to drop the section headers
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x3e].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.section_headers().is_none());
+
+        let hdr = file
+            .eh_frame_hdr()
+            .expect("eh_frame_hdr should parse")
+            .expect("file should have PT_GNU_EH_FRAME");
+        let eh_frame = file
+            .eh_frame()
+            .expect("eh_frame should parse")
+            .expect("file should have .eh_frame");
+        assert_eq!(eh_frame.address(), 0x4005c8);
+        assert_eq!(eh_frame.entries().count(), 7);
+
+        let fde = hdr
+            .find_fde(0x400581, &eh_frame)
+            .expect("should parse")
+            .expect("should find FDE");
+        assert_eq!(fde.offset, 0xd8);
+    }
+
+    #[test]
+    fn parse_cie_with_personality_and_lsda() {
+        #[rustfmt::skip]
+        let data = [
+            // CIE: length, id, version, "zPLR\0"
+            0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, b'z', b'P', b'L', b'R', 0x00,
+            // code align 1, data align -4, ra 14
+            0x01, 0x7c, 0x0e,
+            // augmentation data len 7: P=indirect|pcrel|sdata4 -> 0x10, L=udata4, R=udata4
+            0x07, 0x9b, 0x10, 0x00, 0x00, 0x00, 0x03, 0x03,
+            // initial instructions
+            0x0c, 0x0d, 0x00,
+            // FDE: length, cie pointer (back to offset 0)
+            0x14, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            // pc_begin, pc_range
+            0x00, 0x10, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+            // augmentation data len 4: lsda
+            0x04, 0x00, 0x20, 0x00, 0x00,
+            // instructions
+            0x41, 0x0e, 0x08,
+            // terminator
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF32, 0x8000, &data);
+        let entries: Vec<EhFrameEntry<'_>> = eh_frame
+            .entries()
+            .collect::<Result<_, _>>()
+            .expect("entries should parse");
+        assert_eq!(entries.len(), 2);
+
+        let fde = match entries[1] {
+            EhFrameEntry::Fde(fde) => fde,
+            EhFrameEntry::Cie(_) => panic!("Expected an FDE"),
+        };
+        assert_eq!(fde.cie.augmentation, "zPLR");
+        assert_eq!(fde.cie.data_alignment_factor, -4);
+        assert_eq!(fde.cie.return_address_register, 14);
+        // pc-relative to the personality field at offset 0x13
+        assert_eq!(fde.cie.personality, Some(0x8000 + 0x13 + 0x10));
+        assert_eq!(fde.cie.initial_instructions, &[0x0c, 0x0d, 0x00]);
+        assert_eq!(fde.pc_begin, 0x1000);
+        assert_eq!(fde.pc_range, 0x40);
+        assert_eq!(fde.lsda, Some(0x2000));
+        assert_eq!(fde.instructions, &[0x41, 0x0e, 0x08]);
+    }
+
+    #[test]
+    fn parse_entries_with_64_bit_lengths() {
+        #[rustfmt::skip]
+        let data = [
+            // CIE: 64-bit length, 4-byte id, version, "zR\0"
+            0xff, 0xff, 0xff, 0xff, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, b'z', b'R', 0x00,
+            // code align 1, data align -8, ra 16, augmentation data len 1: R=pcrel|sdata4
+            0x01, 0x78, 0x10, 0x01, 0x1b,
+            // initial instructions
+            0x0c, 0x07, 0x08,
+            // FDE: 64-bit length, 4-byte cie pointer (back to offset 0)
+            0xff, 0xff, 0xff, 0xff, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x28, 0x00, 0x00, 0x00,
+            // pc_begin, pc_range, augmentation data len 0
+            0x00, 0x01, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00,
+            // instructions
+            0x41, 0x0e, 0x10,
+            // terminator
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF64, 0x1000, &data);
+        let entries: Vec<EhFrameEntry<'_>> = eh_frame
+            .entries()
+            .collect::<Result<_, _>>()
+            .expect("entries should parse");
+        assert_eq!(entries.len(), 2);
+
+        let fde = match entries[1] {
+            EhFrameEntry::Fde(fde) => fde,
+            EhFrameEntry::Cie(_) => panic!("Expected an FDE"),
+        };
+        assert_eq!(fde.offset, 0x1c);
+        assert_eq!(fde.cie.offset, 0);
+        assert_eq!(fde.cie.augmentation, "zR");
+        assert_eq!(fde.cie.initial_instructions, &[0x0c, 0x07, 0x08]);
+        // pc-relative to the pc_begin field at offset 0x2c
+        assert_eq!(fde.pc_begin, 0x1000 + 0x2c + 0x100);
+        assert_eq!(fde.pc_range, 0x40);
+        assert_eq!(fde.instructions, &[0x41, 0x0e, 0x10]);
+    }
+
+    #[test]
+    fn parse_unsupported_encoding() {
+        #[rustfmt::skip]
+        let data = [
+            // version 1, eh_frame_ptr funcrel|udata4
+            0x01, 0x43, 0x03, 0x3b, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let error =
+            EhFrameHdr::new(BigEndian, Class::ELF64, 0x1000, &data).expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::UnsupportedPointerEncoding(0x43)),
+            "Unexpected Error type found: {error}"
+        );
+    }
+
+    #[test]
+    fn parse_truncated_entry() {
+        let data = [0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let eh_frame = EhFrame::new(LittleEndian, Class::ELF64, 0, &data);
+        let mut entries = eh_frame.entries();
+        let error = entries.next().unwrap().expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::SliceReadError((4, 20))),
+            "Unexpected Error type found: {error}"
+        );
+        assert!(entries.next().is_none());
+    }
+}
@@ -1,19 +1,31 @@
 use crate::abi;
+use crate::arm_exidx::{ArmExidxTable, ArmExtab};
+use crate::attributes::AttributesSection;
 use crate::compression::CompressionHeader;
 use crate::dynamic::{Dyn, DynamicTable};
+use crate::eh_frame::{EhFrame, EhFrameHdr};
 use crate::endian::EndianParse;
 use crate::file::{parse_ident, Class, FileHeader};
 use crate::gnu_symver::{
     SymbolVersionTable, VerDefIterator, VerNeedIterator, VersionIndex, VersionIndexTable,
 };
-use crate::hash::{GnuHashTable, SysVHashTable};
+use crate::hash::{GnuHashTable, SysVHashHeader, SysVHashTable};
+use crate::init_array::{InitArray, PointerTable};
+#[cfg(feature = "minidebuginfo")]
+use crate::minidebuginfo::MiniDebugInfo;
 use crate::note::NoteIterator;
 use crate::parse::{ParseAt, ParseError, ReadBytesExt};
-use crate::relocation::{RelIterator, RelaIterator};
+#[cfg(feature = "alloc")]
+use crate::plt::{PltRelocations, PltStubIterator};
+use crate::relocation::{
+    relative_type, MipsRelIterator, MipsRelaIterator, RelIterator, RelaIterator,
+};
 use crate::section::{SectionHeader, SectionHeaderTable};
 use crate::segment::{ProgramHeader, SegmentTable};
 use crate::string_table::StringTable;
-use crate::symbol::{Symbol, SymbolTable};
+use crate::symbol::{DynamicSymbolIterator, Symbol, SymbolTable};
+#[cfg(feature = "alloc")]
+use crate::symbolizer::Symbolizer;
 
 //  _____ _     _____ ____        _
 // | ____| |   |  ___| __ ) _   _| |_ ___  ___
@@ -67,6 +79,10 @@ pub struct ElfBytes<'data, E: EndianParse> {
     data: &'data [u8],
     shdrs: Option<SectionHeaderTable<'data, E>>,
     phdrs: Option<SegmentTable<'data, E>>,
+    /// For loaded images, the virtual address that the first byte of `data` was linked at
+    image_base: Option<u64>,
+    /// For loaded images, the runtime address that the first byte of `data` was mapped at
+    load_base: u64,
 }
 
 /// Find the location (if any) of the section headers in the given data buffer and take a
@@ -142,6 +158,21 @@ fn find_phdrs<'data, E: EndianParse>(
     Ok(Some(SegmentTable::new(ehdr.endianness, ehdr.class, buf)))
 }
 
+/// Parse the [FileHeader] at the start of the given data buffer.
+fn parse_ehdr<E: EndianParse>(data: &[u8]) -> Result<FileHeader<E>, ParseError> {
+    let ident_buf = data.get_bytes(0..abi::EI_NIDENT)?;
+    let ident = parse_ident(ident_buf)?;
+
+    let tail_start = abi::EI_NIDENT;
+    let tail_end = match ident.1 {
+        Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
+        Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
+    };
+    let tail_buf = data.get_bytes(tail_start..tail_end)?;
+
+    FileHeader::parse_tail(ident, tail_buf)
+}
+
 /// This struct collects the common sections found in ELF objects
 #[derive(Debug, Default)]
 pub struct CommonElfData<'data, E: EndianParse> {
@@ -173,18 +204,7 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     ///
     // N.B. I thought about calling this "sparse_parse", but it felt too silly for a serious lib like this
     pub fn minimal_parse(data: &'data [u8]) -> Result<Self, ParseError> {
-        let ident_buf = data.get_bytes(0..abi::EI_NIDENT)?;
-        let ident = parse_ident(ident_buf)?;
-
-        let tail_start = abi::EI_NIDENT;
-        let tail_end = match ident.1 {
-            Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
-            Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
-        };
-        let tail_buf = data.get_bytes(tail_start..tail_end)?;
-
-        let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
-
+        let ehdr = parse_ehdr(data)?;
         let shdrs = find_shdrs(&ehdr, data)?;
         let phdrs = find_phdrs(&ehdr, data)?;
         Ok(ElfBytes {
@@ -192,9 +212,66 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             data,
             shdrs,
             phdrs,
+            image_base: None,
+            load_base: 0,
         })
     }
 
+    /// Do the minimal parsing work to get an [ElfBytes] handle from a byte slice containing an
+    /// image that's already been mapped into memory by a loader, such as the vDSO found by
+    /// `AT_SYSINFO_EHDR` or a dump of a process's mapped text.
+    ///
+    /// The data is expected to be laid out by virtual address, with its first byte being the ELF
+    /// File Header at the start of the lowest [abi::PT_LOAD] segment. Segment data is then found
+    /// at its `p_vaddr` relative to that segment's, rather than at its `p_offset`.
+    ///
+    /// Section headers usually aren't mapped, so they're ignored, and everything is found
+    /// through the program headers and the dynamic table instead. This still provides
+    /// [ElfBytes::dynamic_symbol_table], [ElfBytes::dynamic_symbols], the hash tables and
+    /// [ElfBytes::symbol_version_table] for symbol lookups.
+    ///
+    /// `load_base` is the runtime address that `data` was mapped at in the process it came from
+    /// (such as the `AT_SYSINFO_EHDR` value for the vDSO). The dynamic loader rewrites pointers
+    /// like `DT_SYMTAB` and `DT_STRTAB` in place to runtime addresses, so dynamic table pointers
+    /// which fall within `load_base..load_base + data.len()` are translated back to link-time
+    /// addresses before being looked up. All other pointers are taken to be link-time addresses.
+    pub fn minimal_parse_loaded(data: &'data [u8], load_base: u64) -> Result<Self, ParseError> {
+        let ehdr = parse_ehdr(data)?;
+        // The program headers are in the first segment, where offsets and addresses line up
+        let phdrs = find_phdrs(&ehdr, data)?;
+
+        let first_load = phdrs
+            .iter()
+            .flat_map(|phdrs| phdrs.iter())
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .min_by_key(|phdr| phdr.p_vaddr);
+        let image_base = match first_load {
+            Some(phdr) => phdr
+                .p_vaddr
+                .checked_sub(phdr.p_offset)
+                .ok_or(ParseError::BadOffset(phdr.p_offset))?,
+            None => 0,
+        };
+        Ok(ElfBytes {
+            ehdr,
+            data,
+            shdrs: None,
+            phdrs,
+            image_base: Some(image_base),
+            load_base,
+        })
+    }
+
+    /// Returns whether this was parsed from a loaded image by [ElfBytes::minimal_parse_loaded].
+    pub fn is_loaded_image(&self) -> bool {
+        self.image_base.is_some()
+    }
+
+    /// The length of the ELF data being parsed
+    pub(crate) fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
     /// Get this Elf object's zero-alloc lazy-parsing [SegmentTable] (if any).
     ///
     /// This table parses [ProgramHeader]s on demand and does not make any internal heap allocations
@@ -413,8 +490,7 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         if result.dynamic.is_none() {
             if let Some(phdrs) = self.phdrs {
                 if let Some(dyn_phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
-                    let (start, end) = dyn_phdr.get_file_data_range()?;
-                    let buf = self.data.get_bytes(start..end)?;
+                    let buf = self.segment_data(&dyn_phdr)?;
                     result.dynamic = Some(DynamicTable::new(
                         self.ehdr.endianness,
                         self.ehdr.class,
@@ -487,6 +563,9 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     /// iterator over no-addend relocations [Rel](crate::relocation::Rel)
     ///
     /// Returns a ParseError if the section is not of type [abi::SHT_REL]
+    ///
+    /// MIPS64 uses its own `r_info` layout, so use [ElfBytes::section_data_as_mips_rels] for
+    /// [abi::EM_MIPS] files instead.
     pub fn section_data_as_rels(
         &self,
         shdr: &SectionHeader,
@@ -506,6 +585,9 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     /// iterator over relocations with addends [Rela](crate::relocation::Rela)
     ///
     /// Returns a ParseError if the section is not of type [abi::SHT_RELA]
+    ///
+    /// MIPS64 uses its own `r_info` layout, so use [ElfBytes::section_data_as_mips_relas] for
+    /// [abi::EM_MIPS] files instead.
     pub fn section_data_as_relas(
         &self,
         shdr: &SectionHeader,
@@ -525,6 +607,100 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         ))
     }
 
+    /// Get the section data for a given [SectionHeader], and interpret it as an
+    /// iterator over MIPS no-addend relocations [MipsRel](crate::relocation::MipsRel)
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_REL] in an [abi::EM_MIPS] file.
+    pub fn section_data_as_mips_rels(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<MipsRelIterator<'data, E>, ParseError> {
+        if self.ehdr.e_machine != abi::EM_MIPS || shdr.sh_type != abi::SHT_REL {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_REL,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        Ok(MipsRelIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as an
+    /// iterator over MIPS relocations with addends [MipsRela](crate::relocation::MipsRela)
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_RELA] in an [abi::EM_MIPS] file.
+    pub fn section_data_as_mips_relas(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<MipsRelaIterator<'data, E>, ParseError> {
+        if self.ehdr.e_machine != abi::EM_MIPS || shdr.sh_type != abi::SHT_RELA {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_RELA,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        Ok(MipsRelaIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as a
+    /// table of class-sized pointers.
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_INIT_ARRAY],
+    /// [abi::SHT_FINI_ARRAY] or [abi::SHT_PREINIT_ARRAY]
+    pub fn section_data_as_pointers(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<PointerTable<'data, E>, ParseError> {
+        if !matches!(
+            shdr.sh_type,
+            abi::SHT_INIT_ARRAY | abi::SHT_FINI_ARRAY | abi::SHT_PREINIT_ARRAY
+        ) {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_INIT_ARRAY,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        Ok(PointerTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as a
+    /// build [AttributesSection](crate::attributes::AttributesSection)
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_GNU_ATTRIBUTES]
+    /// or one of the processor-specific attributes types ([abi::SHT_ARM_ATTRIBUTES],
+    /// [abi::SHT_RISCV_ATTRIBUTES], [abi::SHT_AARCH64_ATTRIBUTES]), which all share the same value.
+    pub fn section_data_as_attributes(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<AttributesSection<'data, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_GNU_ATTRIBUTES && shdr.sh_type != abi::SHT_ARM_ATTRIBUTES {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_GNU_ATTRIBUTES,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        AttributesSection::new(self.ehdr.endianness, buf)
+    }
+
     /// Get the section data for a given [SectionHeader], and interpret it as an
     /// iterator over [Note](crate::note::Note)s
     ///
@@ -574,9 +750,22 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
 
     /// Get the segment's file data for a given segment/[ProgramHeader].
     ///
-    /// This is the segment's data as found in the file.
+    /// This is the segment's data as found in the file, or at the segment's virtual address for
+    /// loaded images (see [ElfBytes::minimal_parse_loaded]).
     pub fn segment_data(&self, phdr: &ProgramHeader) -> Result<&'data [u8], ParseError> {
-        let (start, end) = phdr.get_file_data_range()?;
+        let (start, end) = match self.image_base {
+            Some(image_base) => {
+                let start: usize = phdr
+                    .p_vaddr
+                    .checked_sub(image_base)
+                    .ok_or(ParseError::BadOffset(phdr.p_vaddr))?
+                    .try_into()?;
+                let size: usize = phdr.p_filesz.try_into()?;
+                let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+                (start, end)
+            }
+            None => phdr.get_file_data_range()?,
+        };
         self.data.get_bytes(start..end)
     }
 
@@ -614,8 +803,7 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         // Otherwise, look up the PT_DYNAMIC segment (if any)
         } else if let Some(phdrs) = self.segments() {
             if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_DYNAMIC) {
-                let (start, end) = phdr.get_file_data_range()?;
-                let buf = self.data.get_bytes(start..end)?;
+                let buf = self.segment_data(&phdr)?;
                 return Ok(Some(DynamicTable::new(
                     self.ehdr.endianness,
                     self.ehdr.class,
@@ -627,13 +815,23 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         Ok(None)
     }
 
-    /// Helper method to get the section data for a given pair of [SectionHeader] for the symbol
-    /// table and its linked strtab, and interpret them as [SymbolTable] and [StringTable].
-    fn section_data_as_symbol_table(
+    /// Get the section data for a given pair of [SectionHeader] for a symbol table and its
+    /// strtab, and interpret them as [SymbolTable] and [StringTable].
+    ///
+    /// Returns a ParseError if the symbol table section is not of type [abi::SHT_SYMTAB] or
+    /// [abi::SHT_DYNSYM].
+    pub fn section_data_as_symbol_table(
         &self,
         shdr: &SectionHeader,
         strtab_shdr: &SectionHeader,
     ) -> Result<(SymbolTable<'data, E>, StringTable<'data>), ParseError> {
+        if shdr.sh_type != abi::SHT_SYMTAB && shdr.sh_type != abi::SHT_DYNSYM {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_SYMTAB,
+            )));
+        }
+
         // Validate entsize before trying to read the table so that we can error early for corrupted files
         Symbol::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
 
@@ -679,13 +877,17 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     }
 
     /// Get the ELF file's `.dynsym` and associated strtab (if any)
+    ///
+    /// If the file has no section headers, these are located by the [abi::DT_SYMTAB] and
+    /// [abi::DT_STRTAB] entries in the dynamic table instead, and the number of symbols is taken
+    /// from the [abi::DT_GNU_HASH] or [abi::DT_HASH] hash table.
     pub fn dynamic_symbol_table(
         &self,
     ) -> Result<Option<(SymbolTable<'data, E>, StringTable<'data>)>, ParseError> {
         let shdrs = match self.section_headers() {
             Some(shdrs) => shdrs,
             None => {
-                return Ok(None);
+                return self.dynamic_symbol_table_from_dynamic();
             }
         };
 
@@ -704,6 +906,136 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
         )?))
     }
 
+    /// Internal helper to locate the `.dynsym` and its strtab through the dynamic table.
+    fn dynamic_symbol_table_from_dynamic(
+        &self,
+    ) -> Result<Option<(SymbolTable<'data, E>, StringTable<'data>)>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(None),
+        };
+        let symtab_addr = match dynamic.iter().find(|d| d.d_tag == abi::DT_SYMTAB) {
+            Some(symtab) => symtab.d_ptr(),
+            None => return Ok(None),
+        };
+        let strtab = match self.dynamic_table_data(abi::DT_STRTAB, abi::DT_STRSZ)? {
+            Some(strtab) => strtab,
+            None => return Ok(None),
+        };
+
+        // Nothing records the size of the symbol table itself, so size it by the hash tables
+        let count = if let Some(gnu_hash) = self.gnu_hash_table()? {
+            gnu_hash.symbol_count()?
+        } else if let Some(sysv_hash) = dynamic.iter().find(|d| d.d_tag == abi::DT_HASH) {
+            match self.loaded_data_at(sysv_hash.d_ptr())? {
+                Some(buf) => {
+                    let mut offset = 0;
+                    let hdr = SysVHashHeader::parse_at(
+                        self.ehdr.endianness,
+                        self.ehdr.class,
+                        &mut offset,
+                        buf,
+                    )?;
+                    hdr.nchain as usize
+                }
+                None => return Ok(None),
+            }
+        } else {
+            return Ok(None);
+        };
+
+        let size = count
+            .checked_mul(Symbol::size_for(self.ehdr.class))
+            .ok_or(ParseError::IntegerOverflow)?;
+        let symtab = match self.loaded_data_at(symtab_addr)? {
+            Some(symtab) => symtab.get_bytes(0..size)?,
+            None => return Ok(None),
+        };
+        Ok(Some((
+            SymbolTable::new(self.ehdr.endianness, self.ehdr.class, symtab),
+            StringTable::new(strtab),
+        )))
+    }
+
+    /// Get the `.gnu.hash` GNU extension hash table (if any).
+    ///
+    /// This is located via the [abi::SHT_GNU_HASH] section if there are section headers, or the
+    /// [abi::DT_GNU_HASH] entry in the dynamic table otherwise.
+    pub fn gnu_hash_table(&self) -> Result<Option<GnuHashTable<'data, E>>, ParseError> {
+        let buf = if let Some(shdrs) = self.section_headers() {
+            match shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_GNU_HASH) {
+                Some(shdr) => {
+                    let (start, end) = shdr.get_data_range()?;
+                    self.data.get_bytes(start..end)?
+                }
+                None => return Ok(None),
+            }
+        } else {
+            let dynamic = match self.dynamic()? {
+                Some(dynamic) => dynamic,
+                None => return Ok(None),
+            };
+            // The table's size isn't recorded, so its chains run to the end of the segment
+            let addr = match dynamic.iter().find(|d| d.d_tag == abi::DT_GNU_HASH) {
+                Some(gnu_hash) => gnu_hash.d_ptr(),
+                None => return Ok(None),
+            };
+            match self.loaded_data_at(addr)? {
+                Some(buf) => buf,
+                None => return Ok(None),
+            }
+        };
+        Ok(Some(GnuHashTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        )?))
+    }
+
+    /// Get an iterator over the `.dynsym` entries joined up with their names and GNU symbol
+    /// versions (if any), which display as `memset@GLIBC_2.2.5`. See
+    /// [DynamicSymbol](crate::symbol::DynamicSymbol).
+    pub fn dynamic_symbols(&self) -> Result<Option<DynamicSymbolIterator<'data, E>>, ParseError> {
+        let (symbols, strtab) = match self.dynamic_symbol_table()? {
+            Some(tables) => tables,
+            None => return Ok(None),
+        };
+        Ok(Some(DynamicSymbolIterator::new(
+            symbols,
+            strtab,
+            self.symbol_version_table()?,
+        )))
+    }
+
+    /// Build a [Symbolizer] index over the function and object symbols in the `.symtab` and
+    /// `.dynsym` (if any) for finding the symbol which contains a given address.
+    ///
+    /// For PPC64 ELFv1 objects, the `.opd` section is used to index function symbols by their
+    /// entry points rather than their function descriptors. For relocatable objects, symbols
+    /// are indexed by section and found with [Symbolizer::lookup_in_section].
+    #[cfg(feature = "alloc")]
+    pub fn symbolizer(&self) -> Result<Symbolizer<'data, E>, ParseError> {
+        let mut symbolizer =
+            Symbolizer::new(self.ehdr.endianness, self.ehdr.class, self.ehdr.e_machine);
+        if self.ehdr.e_type == abi::ET_REL {
+            symbolizer = symbolizer.with_section_relative_values();
+        }
+        if self.ehdr.e_machine == abi::EM_PPC64 {
+            if let Some(shdr) = self.section_header_by_name(".opd")? {
+                let (data, _) = self.section_data(&shdr)?;
+                symbolizer = symbolizer.with_function_descriptors(shdr.sh_addr, data);
+            }
+        }
+
+        if let Some((symtab, strtab)) = self.symbol_table()? {
+            symbolizer.add_symbols(&symtab, &strtab)?;
+        }
+        if let Some((symtab, strtab)) = self.dynamic_symbol_table()? {
+            symbolizer.add_symbols(&symtab, &strtab)?;
+        }
+        Ok(symbolizer)
+    }
+
     /// Locate the section data for the various GNU Symbol Versioning sections (if any)
     /// and return them in a [SymbolVersionTable] that which can interpret them in-place to
     /// yield [SymbolRequirement](crate::gnu_symver::SymbolRequirement)s
@@ -712,11 +1044,11 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
     /// This is a GNU extension and not all objects use symbol versioning.
     /// Returns an empty Option if the object does not use symbol versioning.
     pub fn symbol_version_table(&self) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
-        // No sections means no GNU symbol versioning sections, which is ok
+        // Without sections, the versioning tables can still be found through the dynamic table
         let shdrs = match self.section_headers() {
             Some(shdrs) => shdrs,
             None => {
-                return Ok(None);
+                return self.symbol_version_table_from_dynamic();
             }
         };
 
@@ -816,6 +1148,431 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
             verdefs,
         )))
     }
+
+    /// Internal helper to locate the GNU Symbol Versioning tables through the [abi::DT_VERSYM],
+    /// [abi::DT_VERNEED] and [abi::DT_VERDEF] entries in the dynamic table.
+    fn symbol_version_table_from_dynamic(
+        &self,
+    ) -> Result<Option<SymbolVersionTable<'data, E>>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(None),
+        };
+        let versym_addr = match dynamic.iter().find(|d| d.d_tag == abi::DT_VERSYM) {
+            Some(versym) => versym.d_ptr(),
+            // No VERSYM table means the object doesn't use symbol versioning, which is ok.
+            None => return Ok(None),
+        };
+        let (symtab, strtab) = match self.dynamic_symbol_table()? {
+            Some(tables) => tables,
+            None => return Ok(None),
+        };
+
+        // There's one version index per dynamic symbol
+        let versym_size = symtab
+            .len()
+            .checked_mul(2)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let version_ids = match self.loaded_data_at(versym_addr)? {
+            Some(buf) => VersionIndexTable::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                buf.get_bytes(0..versym_size)?,
+            ),
+            None => return Ok(None),
+        };
+
+        // The NEED and DEF tables' sizes aren't recorded, so they run to the end of the segment
+        let table_at =
+            |addr_tag: i64, num_tag: i64| -> Result<Option<(u64, &'data [u8])>, ParseError> {
+                let addr = dynamic.iter().find(|d| d.d_tag == addr_tag);
+                let num = dynamic.iter().find(|d| d.d_tag == num_tag);
+                match (addr, num) {
+                    (Some(addr), Some(num)) => Ok(self
+                        .loaded_data_at(addr.d_ptr())?
+                        .map(|buf| (num.d_val(), buf))),
+                    _ => Ok(None),
+                }
+            };
+
+        let verneeds = table_at(abi::DT_VERNEED, abi::DT_VERNEEDNUM)?.map(|(count, buf)| {
+            (
+                VerNeedIterator::new(self.ehdr.endianness, self.ehdr.class, count, 0, buf),
+                strtab,
+            )
+        });
+        let verdefs = table_at(abi::DT_VERDEF, abi::DT_VERDEFNUM)?.map(|(count, buf)| {
+            (
+                VerDefIterator::new(self.ehdr.endianness, self.ehdr.class, count, 0, buf),
+                strtab,
+            )
+        });
+
+        Ok(Some(SymbolVersionTable::new(
+            version_ids,
+            verneeds,
+            verdefs,
+        )))
+    }
+
+    /// Get the `.eh_frame_hdr` unwind info search table (if any).
+    ///
+    /// This is located via the [abi::PT_GNU_EH_FRAME] segment if there are program headers,
+    /// or the `.eh_frame_hdr` section otherwise.
+    pub fn eh_frame_hdr(&self) -> Result<Option<EhFrameHdr<'data, E>>, ParseError> {
+        if let Some(phdrs) = self.segments() {
+            if let Some(phdr) = phdrs
+                .iter()
+                .find(|phdr| phdr.p_type == abi::PT_GNU_EH_FRAME)
+            {
+                let buf = self.segment_data(&phdr)?;
+                return Ok(Some(EhFrameHdr::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    phdr.p_vaddr,
+                    buf,
+                )?));
+            }
+        }
+
+        match self.section_header_by_name(".eh_frame_hdr")? {
+            Some(shdr) => {
+                let (buf, _) = self.section_data(&shdr)?;
+                Ok(Some(EhFrameHdr::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    shdr.sh_addr,
+                    buf,
+                )?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Get the `.eh_frame` unwind info (if any).
+    ///
+    /// This is located via the `.eh_frame` section if there are section headers. Otherwise,
+    /// this follows the `eh_frame_ptr` in the [EhFrameHdr] to the [abi::PT_LOAD] segment which contains it.
+    pub fn eh_frame(&self) -> Result<Option<EhFrame<'data, E>>, ParseError> {
+        if self.shdrs.is_some() {
+            return match self.section_header_by_name(".eh_frame")? {
+                Some(shdr) => {
+                    let (buf, _) = self.section_data(&shdr)?;
+                    Ok(Some(EhFrame::new(
+                        self.ehdr.endianness,
+                        self.ehdr.class,
+                        shdr.sh_addr,
+                        buf,
+                    )))
+                }
+                None => Ok(None),
+            };
+        }
+
+        let eh_frame_ptr = match self.eh_frame_hdr()? {
+            Some(hdr) => hdr.eh_frame_ptr,
+            None => return Ok(None),
+        };
+
+        // We don't know how long the .eh_frame is without section headers, so give it the rest
+        // of the containing segment's data and rely on its zero terminator.
+        match self.loaded_data_at(eh_frame_ptr)? {
+            Some(buf) => Ok(Some(EhFrame::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                eh_frame_ptr,
+                buf,
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the section data for a given [SectionHeader], and interpret it as an
+    /// ARM EHABI exception index table.
+    ///
+    /// Returns a ParseError if the section is not of type [abi::SHT_ARM_EXIDX] in an [abi::EM_ARM] file.
+    pub fn section_data_as_arm_exidx(
+        &self,
+        shdr: &SectionHeader,
+    ) -> Result<ArmExidxTable<'data, E>, ParseError> {
+        if self.ehdr.e_machine != abi::EM_ARM || shdr.sh_type != abi::SHT_ARM_EXIDX {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_ARM_EXIDX,
+            )));
+        }
+
+        let (buf, _) = self.section_data(shdr)?;
+        Ok(ArmExidxTable::new(self.ehdr.endianness, shdr.sh_addr, buf))
+    }
+
+    /// Get the ARM EHABI exception index table (if any).
+    ///
+    /// This is located via the [abi::PT_ARM_EXIDX] segment if there are program headers,
+    /// or the first [abi::SHT_ARM_EXIDX] section otherwise.
+    pub fn arm_exidx(&self) -> Result<Option<ArmExidxTable<'data, E>>, ParseError> {
+        if self.ehdr.e_machine != abi::EM_ARM {
+            return Ok(None);
+        }
+
+        if let Some(phdrs) = self.segments() {
+            if let Some(phdr) = phdrs.iter().find(|phdr| phdr.p_type == abi::PT_ARM_EXIDX) {
+                let buf = self.segment_data(&phdr)?;
+                return Ok(Some(ArmExidxTable::new(
+                    self.ehdr.endianness,
+                    phdr.p_vaddr,
+                    buf,
+                )));
+            }
+        }
+
+        let shdrs = match self.section_headers() {
+            Some(shdrs) => shdrs,
+            None => return Ok(None),
+        };
+        match shdrs.iter().find(|shdr| shdr.sh_type == abi::SHT_ARM_EXIDX) {
+            Some(shdr) => Ok(Some(self.section_data_as_arm_exidx(&shdr)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the ARM EHABI `.ARM.extab` exception table section (if any).
+    ///
+    /// The exception table has no segment of its own, so this requires section headers.
+    pub fn arm_extab(&self) -> Result<Option<ArmExtab<'data, E>>, ParseError> {
+        match self.section_header_by_name(".ARM.extab")? {
+            Some(shdr) => {
+                let (buf, _) = self.section_data(&shdr)?;
+                Ok(Some(ArmExtab::new(self.ehdr.endianness, shdr.sh_addr, buf)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Get the `.init_array` constructors (if any).
+    ///
+    /// This is located via the [abi::SHT_INIT_ARRAY] section if there are section headers, or the
+    /// [abi::DT_INIT_ARRAY] entry in the dynamic table otherwise. Slots which are zeroed in the
+    /// file are resolved using the object's `R_*_RELATIVE` relocations. See [InitArray].
+    pub fn init_array(&self) -> Result<Option<InitArray<'data, E>>, ParseError> {
+        self.pointer_array(
+            abi::SHT_INIT_ARRAY,
+            abi::DT_INIT_ARRAY,
+            abi::DT_INIT_ARRAYSZ,
+        )
+    }
+
+    /// Get the `.fini_array` destructors (if any).
+    ///
+    /// This is located via the [abi::SHT_FINI_ARRAY] section if there are section headers, or the
+    /// [abi::DT_FINI_ARRAY] entry in the dynamic table otherwise. See [ElfBytes::init_array].
+    pub fn fini_array(&self) -> Result<Option<InitArray<'data, E>>, ParseError> {
+        self.pointer_array(
+            abi::SHT_FINI_ARRAY,
+            abi::DT_FINI_ARRAY,
+            abi::DT_FINI_ARRAYSZ,
+        )
+    }
+
+    /// Get the `.preinit_array` pre-initialization functions (if any).
+    ///
+    /// This is located via the [abi::SHT_PREINIT_ARRAY] section if there are section headers, or
+    /// the [abi::DT_PREINIT_ARRAY] entry in the dynamic table otherwise. See [ElfBytes::init_array].
+    pub fn preinit_array(&self) -> Result<Option<InitArray<'data, E>>, ParseError> {
+        self.pointer_array(
+            abi::SHT_PREINIT_ARRAY,
+            abi::DT_PREINIT_ARRAY,
+            abi::DT_PREINIT_ARRAYSZ,
+        )
+    }
+
+    /// Internal helper to locate one of the pointer arrays by section type or dynamic tags.
+    fn pointer_array(
+        &self,
+        sh_type: u32,
+        addr_tag: i64,
+        size_tag: i64,
+    ) -> Result<Option<InitArray<'data, E>>, ParseError> {
+        let mut array = None;
+        if let Some(shdrs) = self.section_headers() {
+            if let Some(shdr) = shdrs.iter().find(|shdr| shdr.sh_type == sh_type) {
+                let (buf, _) = self.section_data(&shdr)?;
+                array = Some(InitArray::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    shdr.sh_addr,
+                    buf,
+                ));
+            }
+        } else if let Some(dynamic) = self.dynamic()? {
+            let addr = dynamic.iter().find(|d| d.d_tag == addr_tag);
+            let size = dynamic.iter().find(|d| d.d_tag == size_tag);
+            if let (Some(addr), Some(size)) = (addr, size) {
+                let buf = match self.loaded_data_at(addr.d_ptr())? {
+                    Some(buf) => buf,
+                    None => return Ok(None),
+                };
+                let size: usize = size.d_val().try_into()?;
+                array = Some(InitArray::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    addr.d_ptr(),
+                    buf.get_bytes(0..size)?,
+                ));
+            }
+        }
+
+        let array = match array {
+            Some(array) => array,
+            None => return Ok(None),
+        };
+        match self.relative_relocations()? {
+            Some((r_type, relas)) => Ok(Some(array.with_relative_relocations(r_type, relas))),
+            None => Ok(Some(array)),
+        }
+    }
+
+    /// Get an iterator over the PLT stubs in this object, each named by the imported symbol that
+    /// it calls, as in `memset@plt` (if any).
+    ///
+    /// This requires the section headers, the dynamic table and the `.dynsym`, and returns None
+    /// if any of these are missing or the machine's PLT layout isn't supported. Objects without
+    /// [abi::DT_JMPREL] relocations can still have x86_64 `.plt.got` stubs.
+    /// See [PltStubIterator] for the supported layouts.
+    #[cfg(feature = "alloc")]
+    pub fn plt_stubs(&self) -> Result<Option<PltStubIterator<'data, E>>, ParseError> {
+        if !PltStubIterator::<E>::supports(self.ehdr.e_machine) {
+            return Ok(None);
+        }
+        let sections = match self.section_headers_with_strtab()? {
+            (Some(shdrs), Some(strtab)) => (shdrs, strtab),
+            _ => return Ok(None),
+        };
+        let symbols = match self.dynamic_symbol_table()? {
+            Some(symbols) => symbols,
+            None => return Ok(None),
+        };
+        let jmprel = self
+            .dynamic_table_data(abi::DT_JMPREL, abi::DT_PLTRELSZ)?
+            .unwrap_or(&[]);
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(None),
+        };
+        let relocations = match dynamic.iter().find(|d| d.d_tag == abi::DT_PLTREL) {
+            Some(d) if d.d_val() == abi::DT_REL as u64 => PltRelocations::Rel(jmprel),
+            _ => PltRelocations::Rela(jmprel),
+        };
+        // x86_64's .plt.got stubs jump through slots filled in by GLOB_DAT relocations instead
+        let glob_dats = match self.ehdr.e_machine {
+            abi::EM_X86_64 => self
+                .dynamic_table_data(abi::DT_RELA, abi::DT_RELASZ)?
+                .unwrap_or(&[]),
+            _ => &[],
+        };
+
+        Ok(Some(PltStubIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            self.ehdr.e_machine,
+            self.data,
+            sections,
+            relocations,
+            glob_dats,
+            symbols,
+            self.symbol_version_table()?,
+        )))
+    }
+
+    /// Internal helper to get the machine's relative relocation type and the bytes of the
+    /// [abi::DT_RELA] relocation table that contains them (if any).
+    fn relative_relocations(&self) -> Result<Option<(u32, &'data [u8])>, ParseError> {
+        let r_type = match relative_type(self.ehdr.e_machine, self.ehdr.class) {
+            Some(r_type) => r_type,
+            None => return Ok(None),
+        };
+        let relas = self.dynamic_table_data(abi::DT_RELA, abi::DT_RELASZ)?;
+        Ok(relas.map(|relas| (r_type, relas)))
+    }
+
+    /// Internal helper to get the file data for a table located by the given address and size
+    /// tags in the dynamic table (if any).
+    pub(crate) fn dynamic_table_data(
+        &self,
+        addr_tag: i64,
+        size_tag: i64,
+    ) -> Result<Option<&'data [u8]>, ParseError> {
+        let dynamic = match self.dynamic()? {
+            Some(dynamic) => dynamic,
+            None => return Ok(None),
+        };
+
+        let addr = dynamic.iter().find(|d| d.d_tag == addr_tag);
+        let size = dynamic.iter().find(|d| d.d_tag == size_tag);
+        let (addr, size) = match (addr, size) {
+            (Some(addr), Some(size)) => (addr, size),
+            _ => return Ok(None),
+        };
+        let size: usize = size.d_val().try_into()?;
+        if size == 0 {
+            return Ok(Some(&[]));
+        }
+        let buf = match self.loaded_data_at(addr.d_ptr())? {
+            Some(buf) => buf,
+            None => return Ok(None),
+        };
+        Ok(Some(buf.get_bytes(0..size)?))
+    }
+
+    /// Internal helper to get the file data from the given virtual address to the end of the file
+    /// data for the [abi::PT_LOAD] segment which contains it (if any).
+    ///
+    /// For loaded images, runtime addresses within the image are translated back to link-time
+    /// ones first, since the dynamic loader may have relocated the dynamic table in place.
+    fn loaded_data_at(&self, vaddr: u64) -> Result<Option<&'data [u8]>, ParseError> {
+        let vaddr = match self.image_base {
+            Some(image_base) if vaddr.wrapping_sub(self.load_base) < self.data.len() as u64 => {
+                image_base.wrapping_add(vaddr - self.load_base)
+            }
+            _ => vaddr,
+        };
+        let phdrs = match self.segments() {
+            Some(phdrs) => phdrs,
+            None => return Ok(None),
+        };
+
+        for phdr in phdrs.iter() {
+            if phdr.p_type != abi::PT_LOAD
+                || vaddr < phdr.p_vaddr
+                || vaddr - phdr.p_vaddr >= phdr.p_filesz
+            {
+                continue;
+            }
+
+            let buf = self.segment_data(&phdr)?;
+            let start: usize = (vaddr - phdr.p_vaddr).try_into()?;
+            return Ok(Some(buf.get_bytes(start..buf.len())?));
+        }
+        Ok(None)
+    }
+
+    /// Decompress the MiniDebugInfo object embedded in the `.gnu_debugdata` section (if any).
+    ///
+    /// The returned [MiniDebugInfo] owns the decompressed ELF object, whose `.symtab` can be
+    /// used alongside this file's `.dynsym` to symbolize stripped binaries.
+    #[cfg(feature = "minidebuginfo")]
+    pub fn minidebuginfo(&self) -> Result<Option<MiniDebugInfo>, ParseError> {
+        let shdr = match self.section_header_by_name(".gnu_debugdata")? {
+            Some(shdr) => shdr,
+            None => {
+                return Ok(None);
+            }
+        };
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.data.get_bytes(start..end)?;
+        Ok(Some(MiniDebugInfo::decompress(buf)?))
+    }
 }
 
 //  _            _
@@ -829,10 +1586,13 @@ impl<'data, E: EndianParse> ElfBytes<'data, E> {
 mod interface_tests {
     use super::*;
     use crate::abi::{SHT_GNU_HASH, SHT_NOBITS, SHT_NOTE, SHT_NULL, SHT_REL, SHT_RELA, SHT_STRTAB};
+    use crate::attributes::AttributeValue;
     use crate::endian::AnyEndian;
+    use crate::gnu_symver::VersionQuery;
     use crate::hash::sysv_hash;
     use crate::note::{Note, NoteGnuAbiTag, NoteGnuBuildId};
-    use crate::relocation::Rela;
+    use crate::relocation::{MipsRela, Rela};
+    use crate::symbol::DynamicSymbol;
 
     #[test]
     fn simultaenous_segments_parsing() {
@@ -1172,6 +1932,112 @@ mod interface_tests {
         assert!(relas.next().is_none());
     }
 
+    #[test]
+    fn section_data_as_pointers() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".init_array")
+            .expect("section table should be parseable")
+            .expect("file should have .init_array");
+        let pointers = file
+            .section_data_as_pointers(&shdr)
+            .expect("Failed to read .init_array");
+        assert_eq!(
+            pointers.iter().map(u64::from).collect::<Vec<u64>>(),
+            [0x4d9]
+        );
+
+        let shdr = file
+            .section_header_by_name(".dynamic")
+            .expect("section table should be parseable")
+            .expect("file should have .dynamic");
+        assert!(matches!(
+            file.section_data_as_pointers(&shdr),
+            Err(ParseError::UnexpectedSectionType((
+                abi::SHT_DYNAMIC,
+                abi::SHT_INIT_ARRAY
+            )))
+        ));
+    }
+
+    #[test]
+    fn section_data_as_mips_relas() {
+        for path in ["sample-objects/mips64el.o", "sample-objects/mips64.o"] {
+            let file_data = std::fs::read(path).expect("Could not read file.");
+            let slice = file_data.as_slice();
+            let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+            let shdr = file
+                .section_header_by_name(".rela.text")
+                .expect("section table should be parseable")
+                .expect("file should have .rela.text");
+            let relas: Vec<MipsRela> = file
+                .section_data_as_mips_relas(&shdr)
+                .expect("Failed to read relas section")
+                .collect();
+            assert_eq!(
+                relas,
+                [
+                    MipsRela {
+                        r_offset: 4,
+                        r_sym: 1,
+                        r_ssym: abi::RSS_UNDEF,
+                        r_type: abi::R_MIPS_GPREL16 as u8,
+                        r_type2: abi::R_MIPS_SUB as u8,
+                        r_type3: abi::R_MIPS_HI16 as u8,
+                        r_addend: 0,
+                    },
+                    MipsRela {
+                        r_offset: 8,
+                        r_sym: 1,
+                        r_ssym: abi::RSS_UNDEF,
+                        r_type: abi::R_MIPS_GPREL16 as u8,
+                        r_type2: abi::R_MIPS_SUB as u8,
+                        r_type3: abi::R_MIPS_LO16 as u8,
+                        r_addend: 0,
+                    },
+                    MipsRela {
+                        r_offset: 0x10,
+                        r_sym: 2,
+                        r_ssym: abi::RSS_UNDEF,
+                        r_type: abi::R_MIPS_CALL16 as u8,
+                        r_type2: abi::R_MIPS_NONE as u8,
+                        r_type3: abi::R_MIPS_NONE as u8,
+                        r_addend: 0,
+                    },
+                ]
+            );
+
+            // .rela.text holds relocations with addends
+            assert!(matches!(
+                file.section_data_as_mips_rels(&shdr),
+                Err(ParseError::UnexpectedSectionType((
+                    abi::SHT_RELA,
+                    abi::SHT_REL
+                )))
+            ));
+        }
+
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let shdr = file
+            .section_header_by_name(".rela.dyn")
+            .expect("section table should be parseable")
+            .expect("file should have .rela.dyn");
+        assert!(matches!(
+            file.section_data_as_mips_relas(&shdr),
+            Err(ParseError::UnexpectedSectionType((
+                abi::SHT_RELA,
+                abi::SHT_RELA
+            )))
+        ));
+    }
+
     #[test]
     fn section_data_as_notes() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1200,6 +2066,42 @@ mod interface_tests {
         assert!(notes.next().is_none());
     }
 
+    #[test]
+    fn section_data_as_attributes() {
+        let path = std::path::PathBuf::from("sample-objects/symver.riscv64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(abi::SHT_RISCV_ATTRIBUTES_SECTION_NAME)
+            .expect("section table should be parseable")
+            .expect("file should have a .riscv.attributes section");
+
+        let attrs = file
+            .section_data_as_attributes(&shdr)
+            .expect("Failed to read attributes section");
+        assert_eq!(
+            attrs
+                .file_attribute(abi::ELF_ATTR_VENDOR_RISCV, abi::TAG_RISCV_ARCH)
+                .expect("Failed to parse attributes"),
+            Some(AttributeValue::Str("rv64i2p0_m2p0_a2p0_f2p0_d2p0_c2p0"))
+        );
+
+        // A non-attributes section should be rejected
+        let shdr = file
+            .section_header_by_name(".dynsym")
+            .expect("section table should be parseable")
+            .expect("file should have a .dynsym section");
+        assert!(matches!(
+            file.section_data_as_attributes(&shdr),
+            Err(ParseError::UnexpectedSectionType((
+                abi::SHT_DYNSYM,
+                abi::SHT_GNU_ATTRIBUTES
+            )))
+        ));
+    }
+
     #[test]
     fn segment_data_as_notes() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1380,6 +2282,79 @@ mod interface_tests {
         assert_eq!(def_names, &["HELLO_1.42"]);
     }
 
+    #[test]
+    fn symbol_version_names() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let vst = file
+            .symbol_version_table()
+            .expect("Failed to parse GNU symbol versions")
+            .expect("Failed to find GNU symbol versions");
+
+        let names: Vec<Option<&str>> = (0..8)
+            .map(|idx| vst.get_version_name(idx).expect("should parse"))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                None,
+                None,
+                Some("GLIBC_2.2.5"),
+                None,
+                None,
+                Some("GLIBC_2.2.5"),
+                Some("HELLO_1.0"),
+                Some("HELLO_1.42"),
+            ]
+        );
+    }
+
+    #[test]
+    fn dynamic_symbols() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+
+        let syms: Vec<DynamicSymbol<'_>> = file
+            .dynamic_symbols()
+            .expect("should parse")
+            .expect("should have .dynsym")
+            .collect();
+        let names: Vec<String> = syms.iter().map(|sym| sym.to_string()).collect();
+        assert_eq!(
+            names,
+            [
+                "_ITM_deregisterTMCloneTable",
+                "memset@GLIBC_2.2.5",
+                "__gmon_start__",
+                "_ITM_registerTMCloneTable",
+                "__cxa_finalize@GLIBC_2.2.5",
+                "HELLO_1.0@@HELLO_1.0",
+                "use_memset_v2@HELLO_1.42",
+                "HELLO_1.42@@HELLO_1.42",
+                "use_memset@HELLO_1.0",
+            ]
+        );
+
+        let memset = &syms[1];
+        assert_eq!(memset.index, 2);
+        assert_eq!(memset.name, "memset");
+        assert_eq!(memset.version, Some("GLIBC_2.2.5"));
+        assert_eq!(memset.library, Some("libc.so.6"));
+        assert!(memset.symbol.is_undefined());
+        assert!(!memset.is_default());
+
+        let use_memset = &syms[8];
+        assert_eq!(use_memset.symbol.st_value, 0x1119);
+        assert_eq!(use_memset.library, None);
+        assert!(use_memset.hidden);
+        assert!(syms[7].is_default());
+    }
+
     #[test]
     fn sysv_hash_table() {
         let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
@@ -1448,6 +2423,437 @@ mod interface_tests {
             symtab.get(sym_idx).expect("Failed to get expected sym")
         );
     }
+
+    #[test]
+    fn hash_lookups_find_each_match_once() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).unwrap();
+
+        let common = file.find_common_data().unwrap();
+        let (symtab, strtab) = (common.dynsyms.unwrap(), common.dynsyms_strs.unwrap());
+        let sysv = common.sysv_hash.expect("should have .hash section");
+        let gnu = common.gnu_hash.expect("should have .gnu.hash section");
+
+        // use_memset is the last entry of its .gnu.hash chain
+        let defined = symtab.iter().filter(|sym| !sym.is_undefined());
+        for sym in defined {
+            let name = strtab.get_raw(sym.st_name as usize).expect("should parse");
+            let sysv_matches: Vec<usize> = sysv
+                .find_all(name, &symtab, &strtab)
+                .map(|found| found.expect("should parse").0)
+                .collect();
+            let gnu_matches: Vec<usize> = gnu
+                .find_all(name, &symtab, &strtab)
+                .expect("should parse")
+                .map(|found| found.expect("should parse").0)
+                .collect();
+            assert_eq!(gnu_matches, sysv_matches, "{name:?}");
+        }
+        assert_eq!(
+            gnu.find_all(b"use_memset", &symtab, &strtab)
+                .expect("should parse")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn versioned_hash_lookups() {
+        let path = std::path::PathBuf::from("sample-objects/multiver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).unwrap();
+
+        let common = file.find_common_data().unwrap();
+        let (symtab, strtab) = (common.dynsyms.unwrap(), common.dynsyms_strs.unwrap());
+        let sysv = common.sysv_hash.expect("should have .hash section");
+        let gnu = common.gnu_hash.expect("should have .gnu.hash section");
+        let versions = file
+            .symbol_version_table()
+            .expect("should parse")
+            .expect("should have symbol versions");
+
+        // lookup@MULTI_1.0, lookup@@MULTI_3.0 and lookup@MULTI_2.0
+        let mut all: Vec<usize> = sysv
+            .find_all(b"lookup", &symtab, &strtab)
+            .map(|found| found.expect("should parse").0)
+            .collect();
+        all.sort();
+        assert_eq!(all, [10, 11, 12]);
+        let mut all: Vec<usize> = gnu
+            .find_all(b"lookup", &symtab, &strtab)
+            .expect("should parse")
+            .map(|found| found.expect("should parse").0)
+            .collect();
+        all.sort();
+        assert_eq!(all, [10, 11, 12]);
+        assert_eq!(sysv.find_all(b"missing", &symtab, &strtab).count(), 0);
+
+        let queries = [
+            (VersionQuery::Default, Some(0x1115)),
+            (VersionQuery::Name(b"MULTI_1.0"), Some(0x1109)),
+            (VersionQuery::Name(b"MULTI_2.0"), Some(0x110f)),
+            (VersionQuery::Name(b"MULTI_3.0"), Some(0x1115)),
+            (VersionQuery::Name(b"MULTI_4.0"), None),
+            (VersionQuery::Name(b"GLIBC_2.14"), None),
+            (VersionQuery::Index(3), Some(0x110f)),
+        ];
+        for (query, value) in queries {
+            let found = sysv
+                .find_versioned(b"lookup", &query, &symtab, &strtab, &versions)
+                .expect("should parse");
+            assert_eq!(found.map(|(_, sym)| sym.st_value), value, "{query:?}");
+            let found = gnu
+                .find_versioned(b"lookup", &query, &symtab, &strtab, &versions)
+                .expect("should parse");
+            assert_eq!(found.map(|(_, sym)| sym.st_value), value, "{query:?}");
+        }
+
+        // Required versions are matched too
+        let (sym_idx, _) = sysv
+            .find_versioned(
+                b"memcpy",
+                &VersionQuery::Name(b"GLIBC_2.14"),
+                &symtab,
+                &strtab,
+                &versions,
+            )
+            .expect("should parse")
+            .expect("should find memcpy@GLIBC_2.14");
+        assert_eq!(sym_idx, 3);
+
+        // The default version is only found among the definitions
+        let found = sysv
+            .find_versioned(
+                b"memcpy",
+                &VersionQuery::Default,
+                &symtab,
+                &strtab,
+                &versions,
+            )
+            .expect("should parse");
+        assert!(found.is_none());
+        assert!(!versions
+            .matches(3, &VersionQuery::Default)
+            .expect("should parse"));
+    }
+
+    #[test]
+    fn gnu_hash_table_enumeration() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).unwrap();
+
+        let hash_table = file
+            .gnu_hash_table()
+            .expect("should parse")
+            .expect("should have .gnu.hash");
+        let (symtab, strtab) = file.dynamic_symbol_table().unwrap().unwrap();
+        assert_eq!(
+            hash_table.symbol_count().expect("should parse"),
+            symtab.len()
+        );
+
+        // Only the defined symbols are hashed
+        let mut names: Vec<&str> = hash_table
+            .iter()
+            .map(|sym_idx| {
+                let sym = symtab.get(sym_idx).expect("should parse");
+                strtab.get(sym.st_name as usize).expect("should parse")
+            })
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            ["HELLO_1.0", "HELLO_1.42", "use_memset", "use_memset_v2"]
+        );
+
+        for name in names {
+            assert!(hash_table
+                .may_contain(name.as_bytes())
+                .expect("should parse"));
+        }
+    }
+
+    #[test]
+    fn dynamic_symbols_without_section_headers() {
+        let path = std::path::PathBuf::from("sample-objects/multiver.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Zero out e_shoff and e_shnum to drop the section headers
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x3e].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.section_headers().is_none());
+
+        let hash_table = file
+            .gnu_hash_table()
+            .expect("should parse")
+            .expect("should have DT_GNU_HASH");
+        assert_eq!(hash_table.symbol_count().expect("should parse"), 13);
+
+        let (symtab, strtab) = file
+            .dynamic_symbol_table()
+            .expect("should parse")
+            .expect("should have DT_SYMTAB");
+        assert_eq!(symtab.len(), 13);
+        let mut exported: Vec<&str> = hash_table
+            .iter()
+            .map(|sym_idx| {
+                let sym = symtab.get(sym_idx).expect("should parse");
+                strtab.get(sym.st_name as usize).expect("should parse")
+            })
+            .collect();
+        exported.sort();
+        assert_eq!(
+            exported,
+            [
+                "MULTI_1.0",
+                "MULTI_2.0",
+                "MULTI_3.0",
+                "copy",
+                "lookup",
+                "lookup",
+                "lookup"
+            ]
+        );
+
+        // Without DT_GNU_HASH, the symbol table is sized by the DT_HASH table instead
+        let dyn_offset = file
+            .segments()
+            .unwrap()
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_DYNAMIC)
+            .unwrap()
+            .p_offset as usize;
+        let gnu_hash_idx = file
+            .dynamic()
+            .unwrap()
+            .unwrap()
+            .iter()
+            .position(|d| d.d_tag == abi::DT_GNU_HASH)
+            .unwrap();
+        let tag = dyn_offset + gnu_hash_idx * 16;
+        file_data[tag..tag + 8].copy_from_slice(&abi::DT_DEBUG.to_le_bytes());
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.gnu_hash_table().expect("should parse").is_none());
+        let (symtab, _) = file
+            .dynamic_symbol_table()
+            .expect("should parse")
+            .expect("should have DT_SYMTAB");
+        assert_eq!(symtab.len(), 13);
+    }
+
+    /// Lay out the given file's PT_LOAD segments by virtual address, the way a loader maps them.
+    fn load_image(file_data: &[u8]) -> Vec<u8> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data).expect("Open test1");
+        let loads: Vec<ProgramHeader> = file
+            .segments()
+            .unwrap()
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .collect();
+        let size = loads
+            .iter()
+            .map(|phdr| phdr.p_vaddr + phdr.p_memsz)
+            .max()
+            .unwrap();
+        let mut image = vec![0u8; size as usize];
+        for phdr in loads {
+            let vaddr = phdr.p_vaddr as usize;
+            let data = file.segment_data(&phdr).unwrap();
+            image[vaddr..vaddr + data.len()].copy_from_slice(data);
+        }
+        image
+    }
+
+    #[test]
+    fn loaded_image() {
+        let path = std::path::PathBuf::from("sample-objects/multiver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let image = load_image(&file_data);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let loaded = ElfBytes::<AnyEndian>::minimal_parse_loaded(&image, 0).expect("Open image");
+        assert!(loaded.is_loaded_image());
+        assert!(!file.is_loaded_image());
+        assert!(loaded.section_headers().is_none());
+
+        // The .data segment's file offset and virtual address differ
+        let data_phdr = loaded
+            .segments()
+            .unwrap()
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_LOAD && phdr.p_offset != phdr.p_vaddr)
+            .expect("should have a segment whose offset differs from its address");
+        assert_eq!(
+            loaded.segment_data(&data_phdr).unwrap(),
+            file.segment_data(&data_phdr).unwrap()
+        );
+
+        let symbols = |file: &ElfBytes<'_, AnyEndian>| -> Vec<String> {
+            file.dynamic_symbols()
+                .expect("should parse")
+                .expect("should have dynamic symbols")
+                .map(|sym| sym.to_string())
+                .collect()
+        };
+        let names = symbols(&loaded);
+        assert_eq!(names, symbols(&file));
+        assert!(names.contains(&"lookup@@MULTI_3.0".to_string()));
+        assert!(names.contains(&"memcpy@GLIBC_2.14".to_string()));
+
+        let (symtab, strtab) = loaded.dynamic_symbol_table().unwrap().unwrap();
+        let versions = loaded.symbol_version_table().unwrap().unwrap();
+        let (_, sym) = loaded
+            .gnu_hash_table()
+            .unwrap()
+            .unwrap()
+            .find_versioned(
+                b"lookup",
+                &VersionQuery::Name(b"MULTI_2.0"),
+                &symtab,
+                &strtab,
+                &versions,
+            )
+            .expect("should parse")
+            .expect("should find lookup@MULTI_2.0");
+        assert_eq!(sym.st_value, 0x110f);
+    }
+
+    #[test]
+    fn loaded_image_with_relocated_dynamic() {
+        const LOAD_BASE: u64 = 0x7f12_3456_0000;
+
+        let path = std::path::PathBuf::from("sample-objects/multiver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let mut image = load_image(&file_data);
+
+        // Relocate the dynamic table's pointers in place the way ld.so does
+        let dynamic_phdr = file
+            .segments()
+            .unwrap()
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_DYNAMIC)
+            .unwrap();
+        let mut relocated = 0;
+        for (index, d) in file.dynamic().unwrap().unwrap().iter().enumerate() {
+            if matches!(
+                d.d_tag,
+                abi::DT_SYMTAB | abi::DT_STRTAB | abi::DT_GNU_HASH | abi::DT_VERSYM
+            ) {
+                let value = dynamic_phdr.p_vaddr as usize + index * 16 + 8;
+                image[value..value + 8].copy_from_slice(&(d.d_ptr() + LOAD_BASE).to_le_bytes());
+                relocated += 1;
+            }
+        }
+        assert_eq!(relocated, 4);
+
+        let loaded =
+            ElfBytes::<AnyEndian>::minimal_parse_loaded(&image, LOAD_BASE).expect("Open image");
+        let symbols = |file: &ElfBytes<'_, AnyEndian>| -> Vec<String> {
+            file.dynamic_symbols()
+                .expect("should parse")
+                .expect("should have dynamic symbols")
+                .map(|sym| sym.to_string())
+                .collect()
+        };
+        assert_eq!(symbols(&loaded), symbols(&file));
+
+        let (symtab, strtab) = loaded.dynamic_symbol_table().unwrap().unwrap();
+        let (_, sym) = loaded
+            .gnu_hash_table()
+            .unwrap()
+            .expect("should find the relocated DT_GNU_HASH")
+            .find(b"lookup", &symtab, &strtab)
+            .expect("should parse")
+            .expect("should find lookup");
+        assert_eq!(strtab.get(sym.st_name as usize).unwrap(), "lookup");
+
+        // Without the load base, the relocated pointers are outside of the image
+        let unrelocated = ElfBytes::<AnyEndian>::minimal_parse_loaded(&image, 0).unwrap();
+        assert!(unrelocated.dynamic_symbol_table().unwrap().is_none());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn loaded_vdso() {
+        use std::io::{Read, Seek, SeekFrom};
+        const AT_SYSINFO_EHDR: u64 = 33;
+
+        let word = core::mem::size_of::<usize>();
+        let auxv = std::fs::read("/proc/self/auxv").expect("Could not read auxv.");
+        let vdso = auxv
+            .chunks_exact(word * 2)
+            .map(|entry| {
+                let (key, value) = entry.split_at(word);
+                let read = |bytes: &[u8]| usize::from_ne_bytes(bytes.try_into().unwrap()) as u64;
+                (read(key), read(value))
+            })
+            .find(|(key, _)| *key == AT_SYSINFO_EHDR);
+        let vdso = match vdso {
+            Some((_, vdso)) => vdso,
+            // Not every kernel maps a vDSO
+            None => return,
+        };
+
+        // Read the first page to find out how big the image is, then read the whole image
+        let mut mem = std::fs::File::open("/proc/self/mem").expect("Could not open mem.");
+        let read_image = |mem: &mut std::fs::File, size: usize| -> Vec<u8> {
+            let mut image = vec![0u8; size];
+            mem.seek(SeekFrom::Start(vdso)).unwrap();
+            mem.read_exact(&mut image).expect("Could not read vDSO.");
+            image
+        };
+        let page = read_image(&mut mem, 4096);
+        let file = ElfBytes::<AnyEndian>::minimal_parse_loaded(&page, vdso).expect("Open vDSO");
+        let size = file
+            .segments()
+            .unwrap()
+            .iter()
+            .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+            .map(|phdr| phdr.p_vaddr + phdr.p_filesz)
+            .max()
+            .unwrap();
+        let image = read_image(&mut mem, size as usize);
+        let file = ElfBytes::<AnyEndian>::minimal_parse_loaded(&image, vdso).expect("Open vDSO");
+        assert_eq!(file.ehdr.e_type, abi::ET_DYN);
+
+        // Every exported function can be found through the hash table and symbolized
+        let (symtab, strtab) = file.dynamic_symbol_table().unwrap().unwrap();
+        let symbolizer = file.symbolizer().unwrap();
+        let mut functions = 0;
+        for sym in file.dynamic_symbols().unwrap().unwrap() {
+            if sym.symbol.st_symtype() != abi::STT_FUNC {
+                continue;
+            }
+            functions += 1;
+            assert!(sym.version.is_some(), "{sym} should be versioned");
+
+            let found = match file.gnu_hash_table().unwrap() {
+                Some(gnu) => gnu.find(sym.name.as_bytes(), &symtab, &strtab).unwrap(),
+                None => {
+                    let dynamic = file.dynamic().unwrap().unwrap();
+                    let hash = dynamic.iter().find(|d| d.d_tag == abi::DT_HASH).unwrap();
+                    let buf = file.loaded_data_at(hash.d_ptr()).unwrap().unwrap();
+                    SysVHashTable::new(file.ehdr.endianness, file.ehdr.class, buf)
+                        .unwrap()
+                        .find(sym.name.as_bytes(), &symtab, &strtab)
+                        .unwrap()
+                }
+            };
+            let (_, found) = found.expect("exported symbol should be in the hash table");
+            assert_eq!(found.st_value, sym.symbol.st_value);
+
+            let lookup = symbolizer
+                .lookup(sym.symbol.st_value)
+                .expect("function should symbolize");
+            assert_eq!(lookup.address, sym.symbol.st_value);
+        }
+        assert!(functions > 0);
+    }
 }
 
 #[cfg(test)]
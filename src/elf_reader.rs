@@ -0,0 +1,471 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::compression::CompressionHeader;
+use crate::dynamic::DynamicTable;
+use crate::elf_bytes::{CommonElfData, ElfBytes};
+#[cfg(feature = "alloc")]
+use crate::elf_stream::ElfStream;
+use crate::endian::EndianParse;
+use crate::file::FileHeader;
+use crate::gnu_symver::SymbolVersionTable;
+use crate::note::NoteIterator;
+use crate::parse::ParseError;
+#[cfg(feature = "alloc")]
+use crate::reader::ReadAt;
+use crate::section::{SectionHeader, SectionHeaderTable};
+use crate::segment::{ProgramHeader, SegmentTable};
+use crate::string_table::StringTable;
+use crate::symbol::{DynamicSymbolIterator, SymbolTable};
+#[cfg(feature = "alloc")]
+use crate::validate::Finding;
+
+/// The parsing interface shared by [ElfBytes] and [ElfStream], so analyses can be written
+/// once and run on either one.
+///
+/// Methods take `&mut self` since [ElfStream] reads (and caches) file data on demand, and the
+/// returned lazy-parsing types borrow from the parser until they're dropped. The data
+/// accessors all yield slices borrowed from the parser: [ElfBytes] hands out subslices of the
+/// ELF bytes, and [ElfStream] hands out its cached copies of what it read from the stream.
+///
+/// Example usage:
+/// ```
+/// use elf::abi::SHT_NOTE;
+/// use elf::endian::AnyEndian;
+/// use elf::{ElfBytes, ElfReader, ElfStream, ParseError};
+///
+/// // Count the notes in all of the SHT_NOTE sections
+/// fn count_notes<R: ElfReader>(reader: &mut R) -> Result<usize, ParseError> {
+///     let shdrs: Vec<_> = match reader.section_header_table()? {
+///         Some(shdrs) => shdrs.iter().filter(|shdr| shdr.sh_type == SHT_NOTE).collect(),
+///         None => return Ok(0),
+///     };
+///     let mut count = 0;
+///     for shdr in shdrs {
+///         count += reader.section_data_as_notes(&shdr)?.count();
+///     }
+///     Ok(count)
+/// }
+///
+/// let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+/// let file_data = std::fs::read(&path).expect("Could not read file.");
+/// let mut bytes = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+/// assert_eq!(count_notes(&mut bytes).unwrap(), 2);
+///
+/// let io = std::fs::File::open(path).expect("Could not open file.");
+/// let mut stream = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+/// assert_eq!(count_notes(&mut stream).unwrap(), 2);
+/// ```
+pub trait ElfReader {
+    /// The endianness specification used to parse the ELF structures
+    type Endian: EndianParse;
+
+    /// Get the ELF [FileHeader]
+    fn ehdr(&self) -> &FileHeader<Self::Endian>;
+
+    /// Get the total size of the ELF file data in bytes
+    fn file_size(&self) -> u64;
+
+    /// Get a lazy-parsing [SegmentTable] view of the program headers (if any)
+    fn segment_table(&mut self) -> Result<Option<SegmentTable<'_, Self::Endian>>, ParseError>;
+
+    /// Get a lazy-parsing [SectionHeaderTable] view of the section headers (if any)
+    fn section_header_table(
+        &mut self,
+    ) -> Result<Option<SectionHeaderTable<'_, Self::Endian>>, ParseError>;
+
+    /// Get the [SectionHeader] for the section with the given name (if any)
+    fn section_header_by_name(&mut self, name: &str) -> Result<Option<SectionHeader>, ParseError>;
+
+    /// Get the section data for a given [SectionHeader], alongside an optional compression
+    /// context. Compressed section data is returned as found in the file.
+    fn section_data(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<(&[u8], Option<CompressionHeader>), ParseError>;
+
+    /// Get the segment's file data for a given [ProgramHeader]
+    fn segment_data(&mut self, phdr: &ProgramHeader) -> Result<&[u8], ParseError>;
+
+    /// Get the section data for a given [SectionHeader], and interpret it as a [StringTable]
+    fn section_data_as_strtab(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<StringTable<'_>, ParseError>;
+
+    /// Get the section data for a given [SectionHeader], and interpret it as notes
+    fn section_data_as_notes(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<NoteIterator<'_, Self::Endian>, ParseError>;
+
+    /// Get the segment data for a given [ProgramHeader], and interpret it as notes
+    fn segment_data_as_notes(
+        &mut self,
+        phdr: &ProgramHeader,
+    ) -> Result<NoteIterator<'_, Self::Endian>, ParseError>;
+
+    /// Get the section data for a given pair of [SectionHeader] for a symbol table and its
+    /// strtab, and interpret them as [SymbolTable] and [StringTable].
+    fn section_data_as_symbol_table(
+        &mut self,
+        shdr: &SectionHeader,
+        strtab_shdr: &SectionHeader,
+    ) -> Result<(SymbolTable<'_, Self::Endian>, StringTable<'_>), ParseError>;
+
+    /// Get the ELF file's `.symtab` and associated strtab (if any)
+    #[allow(clippy::type_complexity)]
+    fn symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, Self::Endian>, StringTable<'_>)>, ParseError>;
+
+    /// Get the ELF file's `.dynsym` and associated strtab (if any)
+    #[allow(clippy::type_complexity)]
+    fn dynamic_symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, Self::Endian>, StringTable<'_>)>, ParseError>;
+
+    /// Get an iterator over the `.dynsym` entries joined up with their names and GNU symbol
+    /// versions (if any)
+    fn dynamic_symbols(
+        &mut self,
+    ) -> Result<Option<DynamicSymbolIterator<'_, Self::Endian>>, ParseError>;
+
+    /// Get the .dynamic section/segment contents (if any)
+    fn dynamic(&mut self) -> Result<Option<DynamicTable<'_, Self::Endian>>, ParseError>;
+
+    /// Get the GNU Symbol Versioning sections (if any) as a [SymbolVersionTable]
+    fn symbol_version_table(
+        &mut self,
+    ) -> Result<Option<SymbolVersionTable<'_, Self::Endian>>, ParseError>;
+
+    /// Locate the set of common sections found in ELF files in a single pass over the section
+    /// headers
+    fn find_common_data(&mut self) -> Result<CommonElfData<'_, Self::Endian>, ParseError>;
+
+    /// Walk the object and collect all of the structural problems found in it. See
+    /// [validate](crate::validate::validate).
+    #[cfg(feature = "alloc")]
+    fn validate(&mut self) -> Vec<Finding>
+    where
+        Self: Sized,
+    {
+        crate::validate::validate(self)
+    }
+}
+
+impl<E: EndianParse> ElfReader for ElfBytes<'_, E> {
+    type Endian = E;
+
+    fn ehdr(&self) -> &FileHeader<E> {
+        &self.ehdr
+    }
+
+    fn file_size(&self) -> u64 {
+        self.data_len() as u64
+    }
+
+    fn segment_table(&mut self) -> Result<Option<SegmentTable<'_, E>>, ParseError> {
+        Ok(self.segments())
+    }
+
+    fn section_header_table(&mut self) -> Result<Option<SectionHeaderTable<'_, E>>, ParseError> {
+        Ok(self.section_headers())
+    }
+
+    fn section_header_by_name(&mut self, name: &str) -> Result<Option<SectionHeader>, ParseError> {
+        ElfBytes::section_header_by_name(self, name)
+    }
+
+    fn section_data(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<(&[u8], Option<CompressionHeader>), ParseError> {
+        ElfBytes::section_data(self, shdr)
+    }
+
+    fn segment_data(&mut self, phdr: &ProgramHeader) -> Result<&[u8], ParseError> {
+        ElfBytes::segment_data(self, phdr)
+    }
+
+    fn section_data_as_strtab(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<StringTable<'_>, ParseError> {
+        ElfBytes::section_data_as_strtab(self, shdr)
+    }
+
+    fn section_data_as_notes(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<NoteIterator<'_, E>, ParseError> {
+        ElfBytes::section_data_as_notes(self, shdr)
+    }
+
+    fn segment_data_as_notes(
+        &mut self,
+        phdr: &ProgramHeader,
+    ) -> Result<NoteIterator<'_, E>, ParseError> {
+        ElfBytes::segment_data_as_notes(self, phdr)
+    }
+
+    fn section_data_as_symbol_table(
+        &mut self,
+        shdr: &SectionHeader,
+        strtab_shdr: &SectionHeader,
+    ) -> Result<(SymbolTable<'_, E>, StringTable<'_>), ParseError> {
+        ElfBytes::section_data_as_symbol_table(self, shdr, strtab_shdr)
+    }
+
+    fn symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        ElfBytes::symbol_table(self)
+    }
+
+    fn dynamic_symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        ElfBytes::dynamic_symbol_table(self)
+    }
+
+    fn dynamic_symbols(&mut self) -> Result<Option<DynamicSymbolIterator<'_, E>>, ParseError> {
+        ElfBytes::dynamic_symbols(self)
+    }
+
+    fn dynamic(&mut self) -> Result<Option<DynamicTable<'_, E>>, ParseError> {
+        ElfBytes::dynamic(self)
+    }
+
+    fn symbol_version_table(&mut self) -> Result<Option<SymbolVersionTable<'_, E>>, ParseError> {
+        ElfBytes::symbol_version_table(self)
+    }
+
+    fn find_common_data(&mut self) -> Result<CommonElfData<'_, E>, ParseError> {
+        ElfBytes::find_common_data(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: EndianParse, S: ReadAt> ElfReader for ElfStream<E, S> {
+    type Endian = E;
+
+    fn ehdr(&self) -> &FileHeader<E> {
+        &self.ehdr
+    }
+
+    fn file_size(&self) -> u64 {
+        self.stream_len()
+    }
+
+    fn segment_table(&mut self) -> Result<Option<SegmentTable<'_, E>>, ParseError> {
+        ElfStream::segment_table(self)
+    }
+
+    fn section_header_table(&mut self) -> Result<Option<SectionHeaderTable<'_, E>>, ParseError> {
+        ElfStream::section_header_table(self)
+    }
+
+    fn section_header_by_name(&mut self, name: &str) -> Result<Option<SectionHeader>, ParseError> {
+        ElfStream::section_header_by_name(self, name).map(|shdr| shdr.copied())
+    }
+
+    fn section_data(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<(&[u8], Option<CompressionHeader>), ParseError> {
+        ElfStream::section_data(self, shdr)
+    }
+
+    fn segment_data(&mut self, phdr: &ProgramHeader) -> Result<&[u8], ParseError> {
+        ElfStream::segment_data(self, phdr)
+    }
+
+    fn section_data_as_strtab(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<StringTable<'_>, ParseError> {
+        ElfStream::section_data_as_strtab(self, shdr)
+    }
+
+    fn section_data_as_notes(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<NoteIterator<'_, E>, ParseError> {
+        ElfStream::section_data_as_notes(self, shdr)
+    }
+
+    fn segment_data_as_notes(
+        &mut self,
+        phdr: &ProgramHeader,
+    ) -> Result<NoteIterator<'_, E>, ParseError> {
+        ElfStream::segment_data_as_notes(self, phdr)
+    }
+
+    fn section_data_as_symbol_table(
+        &mut self,
+        shdr: &SectionHeader,
+        strtab_shdr: &SectionHeader,
+    ) -> Result<(SymbolTable<'_, E>, StringTable<'_>), ParseError> {
+        ElfStream::section_data_as_symbol_table(self, shdr, strtab_shdr)
+    }
+
+    fn symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        ElfStream::symbol_table(self)
+    }
+
+    fn dynamic_symbol_table(
+        &mut self,
+    ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
+        ElfStream::dynamic_symbol_table(self)
+    }
+
+    fn dynamic_symbols(&mut self) -> Result<Option<DynamicSymbolIterator<'_, E>>, ParseError> {
+        ElfStream::dynamic_symbols(self)
+    }
+
+    fn dynamic(&mut self) -> Result<Option<DynamicTable<'_, E>>, ParseError> {
+        ElfStream::dynamic(self)
+    }
+
+    fn symbol_version_table(&mut self) -> Result<Option<SymbolVersionTable<'_, E>>, ParseError> {
+        ElfStream::symbol_version_table(self)
+    }
+
+    fn find_common_data(&mut self) -> Result<CommonElfData<'_, E>, ParseError> {
+        ElfStream::find_common_data(self)
+    }
+}
+
+#[cfg(test)]
+mod elf_reader_tests {
+    use super::*;
+    use crate::abi;
+    use crate::endian::AnyEndian;
+
+    /// Summarize a bunch of things through the shared interface so the parsers can be compared
+    fn summarize<R: ElfReader>(reader: &mut R) -> Vec<String> {
+        let ehdr = reader.ehdr();
+        let mut summary = vec![format!(
+            "{:?} {:?} {} {}",
+            ehdr.class, ehdr.e_type, ehdr.e_entry, ehdr.e_shnum
+        )];
+
+        let phdrs: Vec<ProgramHeader> = reader
+            .segment_table()
+            .expect("phdrs should parse")
+            .expect("should have phdrs")
+            .iter()
+            .collect();
+        for phdr in phdrs.iter() {
+            let data = reader.segment_data(phdr).expect("segment data should read");
+            summary.push(format!("{phdr:?} {}", data.len()));
+            if phdr.p_type == abi::PT_NOTE {
+                for note in reader
+                    .segment_data_as_notes(phdr)
+                    .expect("notes should parse")
+                {
+                    summary.push(format!("{note:?}"));
+                }
+            }
+        }
+
+        let shdrs: Vec<SectionHeader> = reader
+            .section_header_table()
+            .expect("shdrs should parse")
+            .expect("should have shdrs")
+            .iter()
+            .collect();
+        for shdr in shdrs.iter() {
+            let (data, chdr) = reader.section_data(shdr).expect("section data should read");
+            summary.push(format!("{shdr:?} {} {chdr:?}", data.len()));
+            if shdr.sh_type == abi::SHT_NOTE {
+                for note in reader
+                    .section_data_as_notes(shdr)
+                    .expect("notes should parse")
+                {
+                    summary.push(format!("{note:?}"));
+                }
+            }
+        }
+
+        let shstrtab = shdrs[reader.ehdr().e_shstrndx as usize];
+        let strtab = reader
+            .section_data_as_strtab(&shstrtab)
+            .expect("shstrtab should parse");
+        let name = strtab
+            .get(shdrs[1].sh_name as usize)
+            .expect("name should parse");
+        summary.push(name.into());
+
+        let (symtab, strtab) = reader
+            .symbol_table()
+            .expect("symtab should parse")
+            .expect("should have symtab");
+        for sym in symtab.iter() {
+            summary.push(format!("{sym:?} {:?}", strtab.get(sym.st_name as usize)));
+        }
+        let (dynsyms, _) = reader
+            .dynamic_symbol_table()
+            .expect("dynsym should parse")
+            .expect("should have dynsym");
+        summary.push(format!("{}", dynsyms.len()));
+        for sym in reader
+            .dynamic_symbols()
+            .expect("dynsym should parse")
+            .expect("should have dynsym")
+        {
+            summary.push(format!("{sym}"));
+        }
+        for dyn_entry in reader
+            .dynamic()
+            .expect("dynamic should parse")
+            .expect("should have dynamic")
+            .iter()
+        {
+            summary.push(format!("{dyn_entry:?}"));
+        }
+        let versions = reader
+            .symbol_version_table()
+            .expect("versions should parse")
+            .expect("should have versions");
+        summary.push(format!(
+            "{:?}",
+            versions.get_requirement(2).expect("should parse")
+        ));
+
+        let common = reader.find_common_data().expect("common data should parse");
+        summary.push(format!(
+            "{} {}",
+            common.symtab.is_some(),
+            common.sysv_hash.is_some()
+        ));
+
+        let dynsym = reader
+            .section_header_by_name(".dynsym")
+            .expect("shdrs should parse")
+            .expect("should have .dynsym");
+        let dynstr = shdrs[dynsym.sh_link as usize];
+        let (dynsyms, _) = reader
+            .section_data_as_symbol_table(&dynsym, &dynstr)
+            .expect("dynsym should parse");
+        summary.push(format!("{}", dynsyms.len()));
+        summary
+    }
+
+    #[test]
+    fn bytes_and_stream_agree() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+
+        let mut bytes = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let mut stream = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+
+        let expected = summarize(&mut bytes);
+        assert!(expected.len() > 50);
+        assert_eq!(summarize(&mut stream), expected);
+    }
+}
@@ -1,48 +1,92 @@
+use core::cell::Cell;
 use core::ops::Range;
-use std::collections::HashMap;
-use std::io::{Read, Seek, SeekFrom};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, collections::BTreeMap, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
 
 use crate::abi;
+use crate::attributes::AttributesSection;
 use crate::compression::CompressionHeader;
 use crate::dynamic::DynamicTable;
+use crate::elf_bytes::CommonElfData;
 use crate::endian::EndianParse;
 use crate::file::{parse_ident, Class};
 use crate::gnu_symver::{
     SymbolVersionTable, VerDefIterator, VerNeedIterator, VersionIndex, VersionIndexTable,
 };
+use crate::hash::{GnuHashTable, SysVHashTable};
+use crate::init_array::PointerTable;
 use crate::note::NoteIterator;
 use crate::parse::{ParseAt, ParseError};
-use crate::relocation::{RelIterator, RelaIterator};
+use crate::reader::ReadAt;
+use crate::relocation::{MipsRelIterator, MipsRelaIterator, RelIterator, RelaIterator};
 use crate::section::{SectionHeader, SectionHeaderTable};
 use crate::segment::ProgramHeader;
 use crate::segment::SegmentTable;
 use crate::string_table::StringTable;
-use crate::symbol::{Symbol, SymbolTable};
+use crate::symbol::{DynamicSymbolIterator, Symbol, SymbolTable};
 
 use crate::file::FileHeader;
 
 /// This type encapsulates the stream-oriented interface for parsing ELF objects from
-/// a `Read + Seek`.
+/// a [ReadAt], such as any `std::io::Read + std::io::Seek`.
 #[derive(Debug)]
-pub struct ElfStream<E: EndianParse, S: std::io::Read + std::io::Seek> {
+pub struct ElfStream<E: EndianParse, S: ReadAt> {
     pub ehdr: FileHeader<E>,
     shdrs: Vec<SectionHeader>,
     phdrs: Vec<ProgramHeader>,
     reader: CachingReader<S>,
 }
 
-/// Read the stream bytes backing the section headers table and parse them all into their Rust native type.
+// The helpers below do the parsing shared by ElfStream and AsyncElfStream on bytes which have
+// already been read, leaving each of them to read the ranges from its own kind of stream.
+
+/// Get the file range of the ELF [FileHeader] that follows the ident, for the given [Class].
+pub(crate) fn ehdr_tail_range(class: Class) -> Range<usize> {
+    let tail_start = abi::EI_NIDENT;
+    let tail_end = match class {
+        Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
+        Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
+    };
+    tail_start..tail_end
+}
+
+/// Get the file range of the section header at index 0 if it's needed to find the size of the
+/// section or program header tables, which is when their counts don't fit in the ELF [FileHeader].
+pub(crate) fn shdr0_range<E: EndianParse>(
+    ehdr: &FileHeader<E>,
+) -> Result<Option<Range<usize>>, ParseError> {
+    let shnum_in_shdr0 = ehdr.e_shoff != 0 && ehdr.e_shnum == 0;
+    let phnum_in_shdr0 = ehdr.e_phoff != 0 && ehdr.e_phnum == abi::PN_XNUM;
+    if !shnum_in_shdr0 && !phnum_in_shdr0 {
+        return Ok(None);
+    }
+
+    // Validate shentsize before trying to read the table so that we can error early for corrupted files
+    if shnum_in_shdr0 {
+        SectionHeader::validate_entsize(ehdr.class, ehdr.e_shentsize as usize)?;
+    }
+
+    let shoff: usize = ehdr.e_shoff.try_into()?;
+    let end = shoff
+        .checked_add(SectionHeader::size_for(ehdr.class))
+        .ok_or(ParseError::IntegerOverflow)?;
+    Ok(Some(shoff..end))
+}
+
+/// Get the file range of the section headers table (if any), given the section header at index 0
+/// when [shdr0_range] says it's needed.
 ///
-/// Returns a [ParseError] if the data bytes for the section table cannot be read.
-/// i.e. if the ELF [FileHeader]'s e_shnum, e_shoff, e_shentsize are invalid and point
-/// to a range in the file data that does not actually exist, or if any of the headers failed to parse.
-fn parse_section_headers<E: EndianParse, S: Read + Seek>(
+/// Returns a [ParseError] if the ELF [FileHeader]'s e_shoff or e_shentsize are invalid.
+pub(crate) fn section_headers_range<E: EndianParse>(
     ehdr: &FileHeader<E>,
-    reader: &mut CachingReader<S>,
-) -> Result<Vec<SectionHeader>, ParseError> {
+    shdr0: Option<&SectionHeader>,
+) -> Result<Option<Range<usize>>, ParseError> {
     // It's Ok to have no section headers
     if ehdr.e_shoff == 0 {
-        return Ok(Vec::default());
+        return Ok(None);
     }
 
     // Validate shentsize before trying to read the table so that we can error early for corrupted files
@@ -51,52 +95,39 @@ fn parse_section_headers<E: EndianParse, S: Read + Seek>(
     // If the number of sections is greater than or equal to SHN_LORESERVE (0xff00),
     // e_shnum is zero and the actual number of section header table entries
     // is contained in the sh_size field of the section header at index 0.
-    let shoff: usize = ehdr.e_shoff.try_into()?;
-    let mut shnum = ehdr.e_shnum as usize;
-    if shnum == 0 {
-        let end = shoff
-            .checked_add(entsize)
-            .ok_or(ParseError::IntegerOverflow)?;
-        let mut offset = 0;
-        let data = reader.read_bytes(shoff, end)?;
-        let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        shnum = shdr0.sh_size.try_into()?;
-    }
+    let shnum: usize = match (ehdr.e_shnum, shdr0) {
+        (0, Some(shdr0)) => shdr0.sh_size.try_into()?,
+        (shnum, _) => shnum as usize,
+    };
 
+    let shoff: usize = ehdr.e_shoff.try_into()?;
     let size = entsize
         .checked_mul(shnum)
         .ok_or(ParseError::IntegerOverflow)?;
     let end = shoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = reader.read_bytes(shoff, end)?;
-    let shdr_vec = SectionHeaderTable::new(ehdr.endianness, ehdr.class, buf)
-        .iter()
-        .collect();
-    Ok(shdr_vec)
+    Ok(Some(shoff..end))
 }
 
-fn parse_program_headers<E: EndianParse, S: Read + Seek>(
+/// Get the file range of the program headers table (if any), given the section header at index 0
+/// when [shdr0_range] says it's needed.
+///
+/// Returns a [ParseError] if the ELF [FileHeader]'s e_phoff or e_phentsize are invalid.
+pub(crate) fn program_headers_range<E: EndianParse>(
     ehdr: &FileHeader<E>,
-    reader: &mut CachingReader<S>,
-) -> Result<Vec<ProgramHeader>, ParseError> {
+    shdr0: Option<&SectionHeader>,
+) -> Result<Option<Range<usize>>, ParseError> {
     // It's Ok to have no program headers
     if ehdr.e_phoff == 0 {
-        return Ok(Vec::default());
+        return Ok(None);
     }
 
     // If the number of segments is greater than or equal to PN_XNUM (0xffff),
     // e_phnum is set to PN_XNUM, and the actual number of program header table
     // entries is contained in the sh_info field of the section header at index 0.
-    let mut phnum = ehdr.e_phnum as usize;
-    if phnum == abi::PN_XNUM as usize {
-        let shoff: usize = ehdr.e_shoff.try_into()?;
-        let end = shoff
-            .checked_add(SectionHeader::size_for(ehdr.class))
-            .ok_or(ParseError::IntegerOverflow)?;
-        let data = reader.read_bytes(shoff, end)?;
-        let mut offset = 0;
-        let shdr0 = SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
-        phnum = shdr0.sh_info.try_into()?;
-    }
+    let phnum: usize = match shdr0 {
+        Some(shdr0) if ehdr.e_phnum == abi::PN_XNUM => shdr0.sh_info.try_into()?,
+        _ => ehdr.e_phnum as usize,
+    };
 
     // Validate phentsize before trying to read the table so that we can error early for corrupted files
     let entsize = ProgramHeader::validate_entsize(ehdr.class, ehdr.e_phentsize as usize)?;
@@ -106,15 +137,147 @@ fn parse_program_headers<E: EndianParse, S: Read + Seek>(
         .checked_mul(phnum)
         .ok_or(ParseError::IntegerOverflow)?;
     let end = phoff.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
-    let buf = reader.read_bytes(phoff, end)?;
-    let phdrs_vec = SegmentTable::new(ehdr.endianness, ehdr.class, buf)
+    Ok(Some(phoff..end))
+}
+
+/// Parse the section header at index 0 from the bytes at [shdr0_range].
+pub(crate) fn parse_shdr0<E: EndianParse>(
+    ehdr: &FileHeader<E>,
+    data: &[u8],
+) -> Result<SectionHeader, ParseError> {
+    let mut offset = 0;
+    SectionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)
+}
+
+/// Parse all the section headers from the bytes at [section_headers_range] into their Rust native type.
+pub(crate) fn parse_section_headers<E: EndianParse>(
+    ehdr: &FileHeader<E>,
+    data: &[u8],
+) -> Vec<SectionHeader> {
+    SectionHeaderTable::new(ehdr.endianness, ehdr.class, data)
         .iter()
-        .collect();
-    Ok(phdrs_vec)
+        .collect()
 }
 
-impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
-    /// Do a minimal amount of parsing work to open an [ElfStream] handle from a Read+Seek containing an ELF object.
+/// Parse all the program headers from the bytes at [program_headers_range] into their Rust native type.
+pub(crate) fn parse_program_headers<E: EndianParse>(
+    ehdr: &FileHeader<E>,
+    data: &[u8],
+) -> Vec<ProgramHeader> {
+    SegmentTable::new(ehdr.endianness, ehdr.class, data)
+        .iter()
+        .collect()
+}
+
+/// Get the file range of the section name string table (if any).
+pub(crate) fn shstrtab_range<E: EndianParse>(
+    ehdr: &FileHeader<E>,
+    shdrs: &[SectionHeader],
+) -> Result<Option<Range<usize>>, ParseError> {
+    // It's Ok to have no section headers
+    if shdrs.is_empty() {
+        return Ok(None);
+    }
+
+    // It's Ok to not have a string table
+    if ehdr.e_shstrndx == abi::SHN_UNDEF {
+        return Ok(None);
+    }
+
+    // If the section name string table section index is greater than or
+    // equal to SHN_LORESERVE (0xff00), e_shstrndx has the value SHN_XINDEX
+    // (0xffff) and the actual index of the section name string table section
+    // is contained in the sh_link field of the section header at index 0.
+    let mut shstrndx = ehdr.e_shstrndx as usize;
+    if ehdr.e_shstrndx == abi::SHN_XINDEX {
+        shstrndx = shdrs[0].sh_link as usize;
+    }
+
+    let strtab = shdrs
+        .get(shstrndx)
+        .ok_or(ParseError::BadOffset(shstrndx as u64))?;
+    let (start, end) = strtab.get_data_range()?;
+    Ok(Some(start..end))
+}
+
+/// Find the section header with the given name in the section name string table (if any).
+pub(crate) fn find_section_by_name<'a>(
+    shdrs: &'a [SectionHeader],
+    strtab: &StringTable<'_>,
+    name: &str,
+) -> Option<&'a SectionHeader> {
+    shdrs.iter().find(|shdr| {
+        let sh_name = match strtab.get(shdr.sh_name as usize) {
+            Ok(name) => name,
+            _ => {
+                return false;
+            }
+        };
+        name == sh_name
+    })
+}
+
+/// Split the [CompressionHeader] (if any) off of the bytes of the given section's data.
+pub(crate) fn split_compression_header<'data, E: EndianParse>(
+    ehdr: &FileHeader<E>,
+    shdr: &SectionHeader,
+    data: &'data [u8],
+) -> Result<(&'data [u8], Option<CompressionHeader>), ParseError> {
+    if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
+        Ok((data, None))
+    } else {
+        let mut offset = 0;
+        let chdr = CompressionHeader::parse_at(ehdr.endianness, ehdr.class, &mut offset, data)?;
+        let compressed_buf = data.get(offset..).ok_or(ParseError::SliceReadError((
+            offset,
+            shdr.sh_size.try_into()?,
+        )))?;
+        Ok((compressed_buf, Some(chdr)))
+    }
+}
+
+/// Find the section header for the symtab of the given type and that of its strtab (if any).
+pub(crate) fn find_symbol_table(
+    shdrs: &[SectionHeader],
+    symtab_type: u32,
+) -> Result<Option<(SectionHeader, SectionHeader)>, ParseError> {
+    // Get the symtab header for the symtab. The gABI states there can be zero or one per ELF file.
+    let shdr = match shdrs.iter().find(|shdr| shdr.sh_type == symtab_type) {
+        Some(shdr) => *shdr,
+        None => return Ok(None),
+    };
+    let strtab_shdr = *shdrs
+        .get(shdr.sh_link as usize)
+        .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
+    Ok(Some((shdr, strtab_shdr)))
+}
+
+/// Get the file ranges of the given symtab and strtab.
+///
+/// Returns a [ParseError] if the symbol table section is not of type
+/// [SHT_SYMTAB](abi::SHT_SYMTAB) or [SHT_DYNSYM](abi::SHT_DYNSYM), or has an invalid entsize.
+pub(crate) fn symbol_table_ranges(
+    class: Class,
+    shdr: &SectionHeader,
+    strtab_shdr: &SectionHeader,
+) -> Result<SymbolTableRanges, ParseError> {
+    if shdr.sh_type != abi::SHT_SYMTAB && shdr.sh_type != abi::SHT_DYNSYM {
+        return Err(ParseError::UnexpectedSectionType((
+            shdr.sh_type,
+            abi::SHT_SYMTAB,
+        )));
+    }
+
+    // Validate entsize before trying to read the table so that we can error early for corrupted files
+    Symbol::validate_entsize(class, shdr.sh_entsize.try_into()?)?;
+
+    let (symtab_start, symtab_end) = shdr.get_data_range()?;
+    let (strtab_start, strtab_end) = strtab_shdr.get_data_range()?;
+    Ok((symtab_start..symtab_end, strtab_start..strtab_end))
+}
+
+impl<E: EndianParse, S: ReadAt> ElfStream<E, S> {
+    /// Do a minimal amount of parsing work to open an [ElfStream] handle from a [ReadAt] containing an ELF object.
     ///
     /// This parses the ELF [FileHeader], [SectionHeader] table, and [ProgramHeader] (segments) table.
     /// All other file data (section data, segment data) is left unread and unparsed.
@@ -123,21 +286,27 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         let ident_buf = cr.read_bytes(0, abi::EI_NIDENT)?;
         let ident = parse_ident(ident_buf)?;
 
-        let tail_start = abi::EI_NIDENT;
-        let tail_end = match ident.1 {
-            Class::ELF32 => tail_start + crate::file::ELF32_EHDR_TAILSIZE,
-            Class::ELF64 => tail_start + crate::file::ELF64_EHDR_TAILSIZE,
-        };
-        let tail_buf = cr.read_bytes(tail_start, tail_end)?;
+        let tail = ehdr_tail_range(ident.1);
+        let tail_buf = cr.read_bytes(tail.start, tail.end)?;
 
         let ehdr = FileHeader::parse_tail(ident, tail_buf)?;
 
-        let shdrs = parse_section_headers(&ehdr, &mut cr)?;
-        let phdrs = parse_program_headers(&ehdr, &mut cr)?;
+        let shdr0 = match shdr0_range(&ehdr)? {
+            Some(range) => Some(parse_shdr0(&ehdr, cr.read_bytes(range.start, range.end)?)?),
+            None => None,
+        };
+        let shdrs = match section_headers_range(&ehdr, shdr0.as_ref())? {
+            Some(range) => parse_section_headers(&ehdr, cr.read_bytes(range.start, range.end)?),
+            None => Vec::new(),
+        };
+        let phdrs = match program_headers_range(&ehdr, shdr0.as_ref())? {
+            Some(range) => parse_program_headers(&ehdr, cr.read_bytes(range.start, range.end)?),
+            None => Vec::new(),
+        };
 
         // We parsed out the ehdr and shdrs into their own allocated containers, so there's no need to keep
         // around their backing data anymore.
-        cr.clear_cache();
+        cr.cache.clear_cache();
 
         Ok(ElfStream {
             ehdr,
@@ -157,6 +326,96 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         &self.shdrs
     }
 
+    /// Get a lazy-parsing [SegmentTable] view of the program headers (if any), like
+    /// [ElfBytes::segments](crate::ElfBytes::segments).
+    ///
+    /// The table's bytes are read from the stream when the view is requested.
+    pub fn segment_table(&mut self) -> Result<Option<SegmentTable<'_, E>>, ParseError> {
+        if self.phdrs.is_empty() {
+            return Ok(None);
+        }
+        let start: usize = self.ehdr.e_phoff.try_into()?;
+        let size = ProgramHeader::size_for(self.ehdr.class)
+            .checked_mul(self.phdrs.len())
+            .ok_or(ParseError::IntegerOverflow)?;
+        let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+        let buf = self.reader.read_bytes(start, end)?;
+        Ok(Some(SegmentTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        )))
+    }
+
+    /// Get a lazy-parsing [SectionHeaderTable] view of the section headers (if any), like
+    /// [ElfBytes::section_headers](crate::ElfBytes::section_headers).
+    ///
+    /// The table's bytes are read from the stream when the view is requested.
+    pub fn section_header_table(
+        &mut self,
+    ) -> Result<Option<SectionHeaderTable<'_, E>>, ParseError> {
+        if self.shdrs.is_empty() {
+            return Ok(None);
+        }
+        let start: usize = self.ehdr.e_shoff.try_into()?;
+        let size = SectionHeader::size_for(self.ehdr.class)
+            .checked_mul(self.shdrs.len())
+            .ok_or(ParseError::IntegerOverflow)?;
+        let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+        let buf = self.reader.read_bytes(start, end)?;
+        Ok(Some(SectionHeaderTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        )))
+    }
+
+    /// Read the segment's file data for a given segment/[ProgramHeader].
+    pub fn segment_data(&mut self, phdr: &ProgramHeader) -> Result<&[u8], ParseError> {
+        let (start, end) = phdr.get_file_data_range()?;
+        self.reader.read_bytes(start, end)
+    }
+
+    /// Limit the number of bytes of file data kept cached between calls, evicting the least
+    /// recently used data once it's exceeded.
+    ///
+    /// By default, everything that's read stays cached until [ElfStream::clear_cache]. The
+    /// data needed by a single call is always kept until the next call, even if it exceeds
+    /// the limit on its own.
+    pub fn with_cache_limit(mut self, max_bytes: usize) -> Self {
+        self.reader.cache.set_limit(Some(max_bytes));
+        self
+    }
+
+    /// The total length of the underlying stream
+    pub(crate) fn stream_len(&self) -> u64 {
+        self.reader.stream_len
+    }
+
+    /// Get the number of bytes of file data currently cached.
+    pub fn cached_bytes(&self) -> usize {
+        self.reader.cache.cached_bytes()
+    }
+
+    /// Drop the cached file data for the given section, for callers which are done with it.
+    pub fn evict_section_data(&mut self, shdr: &SectionHeader) -> Result<(), ParseError> {
+        let (start, end) = shdr.get_data_range()?;
+        self.reader.cache.evict(start..end);
+        Ok(())
+    }
+
+    /// Drop the cached file data for the given segment, for callers which are done with it.
+    pub fn evict_segment_data(&mut self, phdr: &ProgramHeader) -> Result<(), ParseError> {
+        let (start, end) = phdr.get_file_data_range()?;
+        self.reader.cache.evict(start..end);
+        Ok(())
+    }
+
+    /// Drop all the cached file data.
+    pub fn clear_cache(&mut self) {
+        self.reader.cache.clear_cache();
+    }
+
     /// Get an lazy-parsing table for the Section Headers in the file and its associated StringTable.
     ///
     /// The underlying ELF bytes backing the section headers table and string
@@ -174,32 +433,13 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     pub fn section_headers_with_strtab(
         &mut self,
     ) -> Result<(&Vec<SectionHeader>, Option<StringTable<'_>>), ParseError> {
-        // It's Ok to have no section headers
-        if self.shdrs.is_empty() {
-            return Ok((&self.shdrs, None));
-        }
-
-        // It's Ok to not have a string table
-        if self.ehdr.e_shstrndx == abi::SHN_UNDEF {
-            return Ok((&self.shdrs, None));
-        }
-
-        // If the section name string table section index is greater than or
-        // equal to SHN_LORESERVE (0xff00), e_shstrndx has the value SHN_XINDEX
-        // (0xffff) and the actual index of the section name string table section
-        // is contained in the sh_link field of the section header at index 0.
-        let mut shstrndx = self.ehdr.e_shstrndx as usize;
-        if self.ehdr.e_shstrndx == abi::SHN_XINDEX {
-            shstrndx = self.shdrs[0].sh_link as usize;
-        }
+        let range = match shstrtab_range(&self.ehdr, &self.shdrs)? {
+            Some(range) => range,
+            None => return Ok((&self.shdrs, None)),
+        };
 
         // We have a strtab, so wrap it in a zero-copy StringTable
-        let strtab = self
-            .shdrs
-            .get(shstrndx)
-            .ok_or(ParseError::BadOffset(shstrndx as u64))?;
-        let (strtab_start, strtab_end) = strtab.get_data_range()?;
-        let strtab_buf = self.reader.read_bytes(strtab_start, strtab_end)?;
+        let strtab_buf = self.reader.read_bytes(range.start, range.end)?;
         let strtab = StringTable::new(strtab_buf);
         Ok((&self.shdrs, Some(strtab)))
     }
@@ -251,15 +491,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
             }
         };
 
-        Ok(shdrs.iter().find(|shdr| {
-            let sh_name = match strtab.get(shdr.sh_name as usize) {
-                Ok(name) => name,
-                _ => {
-                    return false;
-                }
-            };
-            name == sh_name
-        }))
+        Ok(find_section_by_name(shdrs, &strtab, name))
     }
 
     /// Read the section data for the given [SectionHeader](SectionHeader).
@@ -286,23 +518,7 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
 
         let (start, end) = shdr.get_data_range()?;
         let buf = self.reader.read_bytes(start, end)?;
-
-        if shdr.sh_flags & abi::SHF_COMPRESSED as u64 == 0 {
-            Ok((buf, None))
-        } else {
-            let mut offset = 0;
-            let chdr = CompressionHeader::parse_at(
-                self.ehdr.endianness,
-                self.ehdr.class,
-                &mut offset,
-                buf,
-            )?;
-            let compressed_buf = buf.get(offset..).ok_or(ParseError::SliceReadError((
-                offset,
-                shdr.sh_size.try_into()?,
-            )))?;
-            Ok((compressed_buf, Some(chdr)))
-        }
+        split_compression_header(&self.ehdr, shdr, buf)
     }
 
     /// Read the section data for the given
@@ -332,41 +548,39 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         &mut self,
         symtab_type: u32,
     ) -> Result<Option<(SymbolTable<'_, E>, StringTable<'_>)>, ParseError> {
-        if self.shdrs.is_empty() {
-            return Ok(None);
+        match self.load_symbol_table_of_type(symtab_type)? {
+            Some(ranges) => Ok(Some(self.cached_symbol_table(ranges))),
+            None => Ok(None),
         }
+    }
 
-        // Get the symtab header for the symtab. The gABI states there can be zero or one per ELF file.
-        match self.shdrs.iter().find(|shdr| shdr.sh_type == symtab_type) {
-            Some(shdr) => {
-                // Load the section bytes for the symtab
-                // (we want immutable references to both the symtab and its strtab concurrently)
-                let (symtab_start, symtab_end) = shdr.get_data_range()?;
-                self.reader.load_bytes(symtab_start..symtab_end)?;
-
-                // Load the section bytes for the strtab
-                // (we want immutable references to both the symtab and its strtab concurrently)
-                let strtab = self
-                    .shdrs
-                    .get(shdr.sh_link as usize)
-                    .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
-                let (strtab_start, strtab_end) = strtab.get_data_range()?;
-                self.reader.load_bytes(strtab_start..strtab_end)?;
-
-                // Validate entsize before trying to read the table so that we can error early for corrupted files
-                Symbol::validate_entsize(self.ehdr.class, shdr.sh_entsize.try_into()?)?;
-                let symtab = SymbolTable::new(
-                    self.ehdr.endianness,
-                    self.ehdr.class,
-                    self.reader.get_bytes(symtab_start..symtab_end),
-                );
-                let strtab = StringTable::new(self.reader.get_bytes(strtab_start..strtab_end));
-                Ok(Some((symtab, strtab)))
-            }
+    /// Load the section bytes for the symtab of the given type and its strtab (if any),
+    /// returning their file ranges.
+    fn load_symbol_table_of_type(
+        &mut self,
+        symtab_type: u32,
+    ) -> Result<Option<SymbolTableRanges>, ParseError> {
+        match find_symbol_table(&self.shdrs, symtab_type)? {
+            Some((shdr, strtab_shdr)) => Ok(Some(self.load_symbol_table(&shdr, &strtab_shdr)?)),
             None => Ok(None),
         }
     }
 
+    /// Wrap the symtab and strtab bytes loaded by [ElfStream::load_symbol_table_of_type].
+    fn cached_symbol_table(
+        &self,
+        (symtab, strtab): SymbolTableRanges,
+    ) -> (SymbolTable<'_, E>, StringTable<'_>) {
+        (
+            SymbolTable::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                self.reader.get_bytes(symtab),
+            ),
+            StringTable::new(self.reader.get_bytes(strtab)),
+        )
+    }
+
     /// Get the symbol table (section of type SHT_SYMTAB) and its associated string table.
     ///
     /// The gABI specifies that ELF object files may have zero or one sections of type SHT_SYMTAB.
@@ -385,6 +599,125 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         self.get_symbol_table_of_type(abi::SHT_DYNSYM)
     }
 
+    /// Read the section data for a given pair of [SectionHeader] for a symbol table and its
+    /// strtab, and interpret them as [SymbolTable] and [StringTable].
+    ///
+    /// Returns a [ParseError] if the symbol table section is not of type
+    /// [SHT_SYMTAB](abi::SHT_SYMTAB) or [SHT_DYNSYM](abi::SHT_DYNSYM).
+    pub fn section_data_as_symbol_table(
+        &mut self,
+        shdr: &SectionHeader,
+        strtab_shdr: &SectionHeader,
+    ) -> Result<(SymbolTable<'_, E>, StringTable<'_>), ParseError> {
+        let ranges = self.load_symbol_table(shdr, strtab_shdr)?;
+        Ok(self.cached_symbol_table(ranges))
+    }
+
+    /// Load the section bytes for the given symtab and strtab, returning their file ranges.
+    fn load_symbol_table(
+        &mut self,
+        shdr: &SectionHeader,
+        strtab_shdr: &SectionHeader,
+    ) -> Result<SymbolTableRanges, ParseError> {
+        let (symtab, strtab) = symbol_table_ranges(self.ehdr.class, shdr, strtab_shdr)?;
+
+        // Load the section bytes for both the symtab and the strtab
+        // (we want immutable references to both the symtab and its strtab concurrently)
+        self.reader.load_bytes(symtab.clone())?;
+        self.reader.load_bytes(strtab.clone())?;
+        Ok((symtab, strtab))
+    }
+
+    /// Efficiently locate the set of common sections found in ELF files by doing a single iteration
+    /// over the SectionHeaders table, like [ElfBytes::find_common_data](crate::ElfBytes::find_common_data).
+    ///
+    /// The data for all of them is read from the stream up front.
+    pub fn find_common_data(&mut self) -> Result<CommonElfData<'_, E>, ParseError> {
+        let mut symtab = None;
+        let mut dynsyms = None;
+        let mut dynamic = None;
+        let mut sysv_hash = None;
+        let mut gnu_hash = None;
+
+        // Iterate once over the shdrs to load up any known sections
+        for index in 0..self.shdrs.len() {
+            let shdr = self.shdrs[index];
+            match shdr.sh_type {
+                abi::SHT_SYMTAB | abi::SHT_DYNSYM => {
+                    let strtab_shdr = *self
+                        .shdrs
+                        .get(shdr.sh_link as usize)
+                        .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
+                    let ranges = self.load_symbol_table(&shdr, &strtab_shdr)?;
+                    if shdr.sh_type == abi::SHT_SYMTAB {
+                        symtab = Some(ranges);
+                    } else {
+                        dynsyms = Some(ranges);
+                    }
+                }
+                abi::SHT_DYNAMIC | abi::SHT_HASH | abi::SHT_GNU_HASH => {
+                    let (start, end) = shdr.get_data_range()?;
+                    self.reader.load_bytes(start..end)?;
+                    match shdr.sh_type {
+                        abi::SHT_DYNAMIC => dynamic = Some(start..end),
+                        abi::SHT_HASH => sysv_hash = Some(start..end),
+                        _ => gnu_hash = Some(start..end),
+                    }
+                }
+                _ => {
+                    continue;
+                }
+            }
+        }
+
+        // If we didn't find SHT_DYNAMIC from the section headers, try the program headers
+        if dynamic.is_none() {
+            if let Some(phdr) = self
+                .phdrs
+                .iter()
+                .find(|phdr| phdr.p_type == abi::PT_DYNAMIC)
+            {
+                let (start, end) = phdr.get_file_data_range()?;
+                self.reader.load_bytes(start..end)?;
+                dynamic = Some(start..end);
+            }
+        }
+
+        let mut result: CommonElfData<'_, E> = CommonElfData::default();
+        if let Some(ranges) = symtab {
+            let (symtab, strtab) = self.cached_symbol_table(ranges);
+            result.symtab = Some(symtab);
+            result.symtab_strs = Some(strtab);
+        }
+        if let Some(ranges) = dynsyms {
+            let (symtab, strtab) = self.cached_symbol_table(ranges);
+            result.dynsyms = Some(symtab);
+            result.dynsyms_strs = Some(strtab);
+        }
+        result.dynamic = dynamic.map(|range| {
+            DynamicTable::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                self.reader.get_bytes(range),
+            )
+        });
+        if let Some(range) = sysv_hash {
+            result.sysv_hash = Some(SysVHashTable::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                self.reader.get_bytes(range),
+            )?);
+        }
+        if let Some(range) = gnu_hash {
+            result.gnu_hash = Some(GnuHashTable::new(
+                self.ehdr.endianness,
+                self.ehdr.class,
+                self.reader.get_bytes(range),
+            )?);
+        }
+        Ok(result)
+    }
+
     /// Get the .dynamic section/segment contents.
     pub fn dynamic(&mut self) -> Result<Option<DynamicTable<'_, E>>, ParseError> {
         // If we have section headers, then look it up there
@@ -431,6 +764,30 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     pub fn symbol_version_table(
         &mut self,
     ) -> Result<Option<SymbolVersionTable<'_, E>>, ParseError> {
+        match self.load_symbol_version_table()? {
+            Some(ranges) => Ok(Some(self.cached_symbol_version_table(ranges))),
+            None => Ok(None),
+        }
+    }
+
+    /// Get an iterator over the `.dynsym` entries joined up with their names and GNU symbol
+    /// versions (if any), which display as `memset@GLIBC_2.2.5`. See
+    /// [DynamicSymbol](crate::symbol::DynamicSymbol).
+    pub fn dynamic_symbols(&mut self) -> Result<Option<DynamicSymbolIterator<'_, E>>, ParseError> {
+        let symtab = match self.load_symbol_table_of_type(abi::SHT_DYNSYM)? {
+            Some(ranges) => ranges,
+            None => return Ok(None),
+        };
+        let versions = self.load_symbol_version_table()?;
+
+        let (symbols, strtab) = self.cached_symbol_table(symtab);
+        let versions = versions.map(|ranges| self.cached_symbol_version_table(ranges));
+        Ok(Some(DynamicSymbolIterator::new(symbols, strtab, versions)))
+    }
+
+    /// Load the section bytes for the GNU Symbol Versioning sections and their strtabs (if any),
+    /// returning their file ranges.
+    fn load_symbol_version_table(&mut self) -> Result<Option<SymbolVersionRanges>, ParseError> {
         // No sections means no GNU symbol versioning sections, which is ok
         if self.shdrs.is_empty() {
             return Ok(None);
@@ -467,105 +824,89 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         let (versym_start, versym_end) = versym_shdr.get_data_range()?;
         self.reader.load_bytes(versym_start..versym_end)?;
 
-        // Get the VERNEED string shdr and load the VERNEED section data (if any)
-        let needs_shdrs = match needs_opt {
-            Some(shdr) => {
-                let (start, end) = shdr.get_data_range()?;
-                self.reader.load_bytes(start..end)?;
-
-                let strs_shdr = self
-                    .shdrs
-                    .get(shdr.sh_link as usize)
-                    .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                self.reader.load_bytes(strs_start..strs_end)?;
+        // Load the VERNEED section data and its strings (if any).
+        // It's possible to have symbol versioning with no NEEDs if we're an object that only
+        // exports defined symbols.
+        let verneeds = match needs_opt {
+            Some(shdr) => Some(self.load_symbol_version_section(&shdr)?),
+            None => None,
+        };
 
-                Some((shdr, strs_shdr))
-            }
-            // It's possible to have symbol versioning with no NEEDs if we're an object that only
-            // exports defined symbols.
+        // Load the VERDEF section data and its strings (if any).
+        // It's possible to have symbol versioning with no DEFs if we're an object that doesn't
+        // export any symbols but does use dynamic symbols from other objects.
+        let verdefs = match defs_opt {
+            Some(shdr) => Some(self.load_symbol_version_section(&shdr)?),
             None => None,
         };
 
-        // Get the VERDEF string shdr and load the VERDEF section data (if any)
-        let defs_shdrs = match defs_opt {
-            Some(shdr) => {
-                let (start, end) = shdr.get_data_range()?;
-                self.reader.load_bytes(start..end)?;
+        Ok(Some(SymbolVersionRanges {
+            versym: versym_start..versym_end,
+            verneeds,
+            verdefs,
+        }))
+    }
 
-                let strs_shdr = self
-                    .shdrs
-                    .get(shdr.sh_link as usize)
-                    .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                self.reader.load_bytes(strs_start..strs_end)?;
+    /// Load the section bytes for a VERNEED or VERDEF section and its linked strtab, returning
+    /// the section's entry count and the file ranges of the two.
+    fn load_symbol_version_section(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<(u64, Range<usize>, Range<usize>), ParseError> {
+        let (start, end) = shdr.get_data_range()?;
+        self.reader.load_bytes(start..end)?;
 
-                Some((shdr, strs_shdr))
-            }
-            // It's possible to have symbol versioning with no DEFs if we're an object that doesn't
-            // export any symbols but does use dynamic symbols from other objects.
-            None => None,
-        };
+        let strs_shdr = self
+            .shdrs
+            .get(shdr.sh_link as usize)
+            .ok_or(ParseError::BadOffset(shdr.sh_link as u64))?;
+        let (strs_start, strs_end) = strs_shdr.get_data_range()?;
+        self.reader.load_bytes(strs_start..strs_end)?;
 
-        // Wrap the VERNEED section and strings data in an iterator and string table
-        let verneeds = match needs_shdrs {
-            Some((shdr, strs_shdr)) => {
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                let strs_buf = self.reader.get_bytes(strs_start..strs_end);
+        Ok((shdr.sh_info as u64, start..end, strs_start..strs_end))
+    }
 
-                let (start, end) = shdr.get_data_range()?;
-                let buf = self.reader.get_bytes(start..end);
-                Some((
-                    VerNeedIterator::new(
-                        self.ehdr.endianness,
-                        self.ehdr.class,
-                        shdr.sh_info as u64,
-                        0,
-                        buf,
-                    ),
-                    StringTable::new(strs_buf),
-                ))
-            }
-            // If there's no NEEDs, then construct empty wrappers for them
-            None => None,
-        };
+    /// Wrap the section bytes loaded by [ElfStream::load_symbol_version_table].
+    fn cached_symbol_version_table(
+        &self,
+        ranges: SymbolVersionRanges,
+    ) -> SymbolVersionTable<'_, E> {
+        // Wrap the VERNEED section and strings data in an iterator and string table
+        let verneeds = ranges.verneeds.map(|(count, range, strs_range)| {
+            (
+                VerNeedIterator::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    count,
+                    0,
+                    self.reader.get_bytes(range),
+                ),
+                StringTable::new(self.reader.get_bytes(strs_range)),
+            )
+        });
 
         // Wrap the VERDEF section and strings data in an iterator and string table
-        let verdefs = match defs_shdrs {
-            Some((shdr, strs_shdr)) => {
-                let (strs_start, strs_end) = strs_shdr.get_data_range()?;
-                let strs_buf = self.reader.get_bytes(strs_start..strs_end);
-
-                let (start, end) = shdr.get_data_range()?;
-                let buf = self.reader.get_bytes(start..end);
-                Some((
-                    VerDefIterator::new(
-                        self.ehdr.endianness,
-                        self.ehdr.class,
-                        shdr.sh_info as u64,
-                        0,
-                        buf,
-                    ),
-                    StringTable::new(strs_buf),
-                ))
-            }
-            // If there's no DEFs, then construct empty wrappers for them
-            None => None,
-        };
+        let verdefs = ranges.verdefs.map(|(count, range, strs_range)| {
+            (
+                VerDefIterator::new(
+                    self.ehdr.endianness,
+                    self.ehdr.class,
+                    count,
+                    0,
+                    self.reader.get_bytes(range),
+                ),
+                StringTable::new(self.reader.get_bytes(strs_range)),
+            )
+        });
 
         // Wrap the versym section data in a parsing table
         let version_ids = VersionIndexTable::new(
             self.ehdr.endianness,
             self.ehdr.class,
-            self.reader.get_bytes(versym_start..versym_end),
+            self.reader.get_bytes(ranges.versym),
         );
 
-        // whew, we're done here!
-        Ok(Some(SymbolVersionTable::new(
-            version_ids,
-            verneeds,
-            verdefs,
-        )))
+        SymbolVersionTable::new(version_ids, verneeds, verdefs)
     }
 
     /// Read the section data for the given
@@ -618,6 +959,113 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
         ))
     }
 
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [MipsRelIterator](MipsRelIterator).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_REL](abi::SHT_REL) or the file is not [EM_MIPS](abi::EM_MIPS).
+    pub fn section_data_as_mips_rels(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<MipsRelIterator<'_, E>, ParseError> {
+        if self.ehdr.e_machine != abi::EM_MIPS || shdr.sh_type != abi::SHT_REL {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_REL,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end)?;
+        Ok(MipsRelIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [MipsRelaIterator](MipsRelaIterator).
+    ///
+    /// Returns a [ParseError] if the
+    /// [sh_type](SectionHeader#structfield.sh_type) is not
+    /// [SHT_RELA](abi::SHT_RELA) or the file is not [EM_MIPS](abi::EM_MIPS).
+    pub fn section_data_as_mips_relas(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<MipsRelaIterator<'_, E>, ParseError> {
+        if self.ehdr.e_machine != abi::EM_MIPS || shdr.sh_type != abi::SHT_RELA {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_RELA,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end)?;
+        Ok(MipsRelaIterator::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// [PointerTable](PointerTable) of class-sized pointers.
+    ///
+    /// Returns a [ParseError] if the [sh_type](SectionHeader#structfield.sh_type)
+    /// is not [SHT_INIT_ARRAY](abi::SHT_INIT_ARRAY), [SHT_FINI_ARRAY](abi::SHT_FINI_ARRAY)
+    /// or [SHT_PREINIT_ARRAY](abi::SHT_PREINIT_ARRAY).
+    pub fn section_data_as_pointers(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<PointerTable<'_, E>, ParseError> {
+        if !matches!(
+            shdr.sh_type,
+            abi::SHT_INIT_ARRAY | abi::SHT_FINI_ARRAY | abi::SHT_PREINIT_ARRAY
+        ) {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_INIT_ARRAY,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end)?;
+        Ok(PointerTable::new(
+            self.ehdr.endianness,
+            self.ehdr.class,
+            buf,
+        ))
+    }
+
+    /// Read the section data for the given
+    /// [SectionHeader](SectionHeader) and interpret it in-place as a
+    /// build [AttributesSection](crate::attributes::AttributesSection).
+    ///
+    /// Returns a [ParseError] if the [sh_type](SectionHeader#structfield.sh_type)
+    /// is not [SHT_GNU_ATTRIBUTES](abi::SHT_GNU_ATTRIBUTES) or one of the
+    /// processor-specific attributes types such as [SHT_ARM_ATTRIBUTES](abi::SHT_ARM_ATTRIBUTES).
+    pub fn section_data_as_attributes(
+        &mut self,
+        shdr: &SectionHeader,
+    ) -> Result<AttributesSection<'_, E>, ParseError> {
+        if shdr.sh_type != abi::SHT_GNU_ATTRIBUTES && shdr.sh_type != abi::SHT_ARM_ATTRIBUTES {
+            return Err(ParseError::UnexpectedSectionType((
+                shdr.sh_type,
+                abi::SHT_GNU_ATTRIBUTES,
+            )));
+        }
+
+        let (start, end) = shdr.get_data_range()?;
+        let buf = self.reader.read_bytes(start, end)?;
+        AttributesSection::new(self.ehdr.endianness, buf)
+    }
+
     /// Read the section data for the given
     /// [SectionHeader](SectionHeader) and interpret it in-place as a
     /// [NoteIterator](NoteIterator).
@@ -675,22 +1123,257 @@ impl<E: EndianParse, S: std::io::Read + std::io::Seek> ElfStream<E, S> {
     }
 }
 
+/// The file ranges of a symtab and its strtab
+pub(crate) type SymbolTableRanges = (Range<usize>, Range<usize>);
+
+/// The file ranges of the GNU Symbol Versioning sections and their strtabs, along with the
+/// VERNEED and VERDEF entry counts
+#[derive(Debug)]
+struct SymbolVersionRanges {
+    versym: Range<usize>,
+    verneeds: Option<(u64, Range<usize>, Range<usize>)>,
+    verdefs: Option<(u64, Range<usize>, Range<usize>)>,
+}
+
+/// A cached range of the stream's bytes
 #[derive(Debug)]
-struct CachingReader<R: Read + Seek> {
+struct CacheEntry {
+    bytes: Box<[u8]>,
+    /// The request which last used these bytes, for least-recently-used eviction
+    last_used: u64,
+}
+
+/// Tracks the ranges of a stream which have been read, so that the parsing types can borrow
+/// them. This is the bookkeeping shared by the sync and async stream readers, which do the
+/// actual reading.
+///
+/// The cached ranges never overlap: loading a range which overlaps cached ones merges them all
+/// into one entry (reading only the missing bytes), and loading a range inside a cached one is
+/// served from the cache.
+///
+/// Each stream method loads all the ranges it needs before getting any of them, so a new
+/// "request" starts with the first load after a get. Entries used by the current request are
+/// never evicted, which means the cache can temporarily exceed its limit when a single request
+/// needs more than the limit.
+#[derive(Debug)]
+pub(crate) struct ByteCache {
+    /// Cached ranges, keyed by their start offset
+    bufs: BTreeMap<usize, CacheEntry>,
+    cached_bytes: usize,
+    limit: Option<usize>,
+    request: u64,
+    /// Whether any bytes have been handed out by get_bytes during the current request
+    handed_out: Cell<bool>,
+}
+
+/// A range being loaded into a [ByteCache], with the bytes that were already cached copied in.
+/// The reader fills in the missing bytes from [PendingLoad::next_gap] before handing it back to
+/// [ByteCache::finish_load].
+#[derive(Debug)]
+pub(crate) struct PendingLoad {
+    start: usize,
+    bytes: Box<[u8]>,
+    /// The ranges of `bytes` which still need to be read
+    gaps: Vec<Range<usize>>,
+    next: usize,
+}
+
+impl PendingLoad {
+    /// Get the stream offset and buffer of the next range which needs to be read (if any).
+    pub(crate) fn next_gap(&mut self) -> Option<(u64, &mut [u8])> {
+        let gap = self.gaps.get(self.next)?.clone();
+        self.next += 1;
+        Some(((self.start + gap.start) as u64, &mut self.bytes[gap]))
+    }
+}
+
+impl ByteCache {
+    pub(crate) fn new() -> Self {
+        ByteCache {
+            bufs: BTreeMap::new(),
+            cached_bytes: 0,
+            limit: None,
+            request: 0,
+            handed_out: Cell::new(false),
+        }
+    }
+
+    /// Get the number of bytes currently cached.
+    pub(crate) fn cached_bytes(&self) -> usize {
+        self.cached_bytes
+    }
+
+    /// Find the start offset of the cached entry which contains the given range (if any).
+    fn containing(&self, range: &Range<usize>) -> Option<usize> {
+        let (&start, entry) = self.bufs.range(..=range.start).next_back()?;
+        if start + entry.bytes.len() >= range.end {
+            Some(start)
+        } else {
+            None
+        }
+    }
+
+    /// Get the start offsets of the cached entries which overlap the given range, in ascending order.
+    fn overlapping(&self, range: &Range<usize>) -> Vec<usize> {
+        let mut starts: Vec<usize> = self
+            .bufs
+            .range(..range.end)
+            .rev()
+            .take_while(|(&start, entry)| start + entry.bytes.len() > range.start)
+            .map(|(&start, _)| start)
+            .collect();
+        starts.reverse();
+        starts
+    }
+
+    pub(crate) fn get_bytes(&self, range: Range<usize>) -> &[u8] {
+        self.handed_out.set(true);
+        // It's a programmer error to call get_bytes without first calling load_bytes, so
+        // we want to panic here.
+        let start = self
+            .containing(&range)
+            .expect("load_bytes must be called before get_bytes for every range");
+        &self.bufs[&start].bytes[range.start - start..range.end - start]
+    }
+
+    /// Start loading the given range of a stream of `stream_len` bytes, returning None if it's
+    /// already cached.
+    ///
+    /// The range is merged with any cached ranges it overlaps, so only the bytes in between
+    /// need to be read.
+    pub(crate) fn start_load(
+        &mut self,
+        range: Range<usize>,
+        stream_len: u64,
+    ) -> Result<Option<PendingLoad>, ParseError> {
+        if self.handed_out.replace(false) {
+            self.request += 1;
+        }
+
+        if let Some(start) = self.containing(&range) {
+            if let Some(entry) = self.bufs.get_mut(&start) {
+                entry.last_used = self.request;
+            }
+            return Ok(None);
+        }
+
+        // Verify that the read range doesn't go past the end of the stream (corrupted files)
+        let end = range.end as u64;
+        if end > stream_len {
+            return Err(ParseError::BadOffset(end));
+        }
+
+        let overlapping = self.overlapping(&range);
+        let merged_start = overlapping
+            .first()
+            .map_or(range.start, |&start| start.min(range.start));
+        let merged_end = overlapping.last().map_or(range.end, |&start| {
+            (start + self.bufs[&start].bytes.len()).max(range.end)
+        });
+
+        let mut bytes = vec![0; merged_end - merged_start].into_boxed_slice();
+        let mut gaps = Vec::new();
+        let mut offset = merged_start;
+        for start in overlapping {
+            if offset < start {
+                gaps.push(offset - merged_start..start - merged_start);
+            }
+            let entry = self
+                .bufs
+                .remove(&start)
+                .expect("overlapping entry should exist");
+            let entry_end = start + entry.bytes.len();
+            bytes[start - merged_start..entry_end - merged_start].copy_from_slice(&entry.bytes);
+            self.cached_bytes -= entry.bytes.len();
+            offset = entry_end;
+        }
+        if offset < merged_end {
+            gaps.push(offset - merged_start..merged_end - merged_start);
+        }
+
+        Ok(Some(PendingLoad {
+            start: merged_start,
+            bytes,
+            gaps,
+            next: 0,
+        }))
+    }
+
+    /// Cache the bytes of a load started by [ByteCache::start_load] once they've all been read.
+    pub(crate) fn finish_load(&mut self, pending: PendingLoad) {
+        self.cached_bytes += pending.bytes.len();
+        self.bufs.insert(
+            pending.start,
+            CacheEntry {
+                bytes: pending.bytes,
+                last_used: self.request,
+            },
+        );
+        self.evict_to_limit();
+    }
+
+    pub(crate) fn set_limit(&mut self, limit: Option<usize>) {
+        self.limit = limit;
+        self.evict_to_limit();
+    }
+
+    /// Evict the least recently used entries until the cache fits in its limit, or only the
+    /// entries used by the current request are left.
+    fn evict_to_limit(&mut self) {
+        let limit = match self.limit {
+            Some(limit) => limit,
+            None => return,
+        };
+        while self.cached_bytes > limit {
+            let lru = self
+                .bufs
+                .iter()
+                .filter(|(_, entry)| entry.last_used != self.request)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(&start, _)| start);
+            match lru {
+                Some(start) => {
+                    if let Some(entry) = self.bufs.remove(&start) {
+                        self.cached_bytes -= entry.bytes.len();
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Drop the cached bytes which overlap the given range.
+    pub(crate) fn evict(&mut self, range: Range<usize>) {
+        for start in self.overlapping(&range) {
+            if let Some(entry) = self.bufs.remove(&start) {
+                self.cached_bytes -= entry.bytes.len();
+            }
+        }
+    }
+
+    pub(crate) fn clear_cache(&mut self) {
+        self.bufs.clear();
+        self.cached_bytes = 0;
+    }
+}
+
+/// Reads ranges of a [ReadAt] into a [ByteCache]
+#[derive(Debug)]
+struct CachingReader<R: ReadAt> {
     reader: R,
     stream_len: u64,
-    bufs: HashMap<(usize, usize), Box<[u8]>>,
+    cache: ByteCache,
 }
 
-impl<R: Read + Seek> CachingReader<R> {
+impl<R: ReadAt> CachingReader<R> {
     fn new(mut reader: R) -> Result<Self, ParseError> {
         // Cache the size of the stream so that we can err (rather than OOM) on invalid
         // huge read requests.
-        let stream_len = reader.seek(SeekFrom::End(0))?;
+        let stream_len = reader.size()?;
         Ok(CachingReader {
             reader,
             stream_len,
-            bufs: HashMap::<(usize, usize), Box<[u8]>>::default(),
+            cache: ByteCache::new(),
         })
     }
 
@@ -700,39 +1383,26 @@ impl<R: Read + Seek> CachingReader<R> {
     }
 
     fn get_bytes(&self, range: Range<usize>) -> &[u8] {
-        // It's a programmer error to call get_bytes without first calling load_bytes, so
-        // we want to panic here.
-        self.bufs
-            .get(&(range.start, range.end))
-            .expect("load_bytes must be called before get_bytes for every range")
+        self.cache.get_bytes(range)
     }
 
     fn load_bytes(&mut self, range: Range<usize>) -> Result<(), ParseError> {
-        if self.bufs.contains_key(&(range.start, range.end)) {
-            return Ok(());
-        }
-
-        // Verify that the read range doesn't go past the end of the stream (corrupted files)
-        let end = range.end as u64;
-        if end > self.stream_len {
-            return Err(ParseError::BadOffset(end));
+        let mut pending = match self.cache.start_load(range, self.stream_len)? {
+            Some(pending) => pending,
+            None => return Ok(()),
+        };
+        while let Some((offset, buf)) = pending.next_gap() {
+            self.reader.read_exact_at(offset, buf)?;
         }
-
-        self.reader.seek(SeekFrom::Start(range.start as u64))?;
-        let mut bytes = vec![0; range.len()].into_boxed_slice();
-        self.reader.read_exact(&mut bytes)?;
-        self.bufs.insert((range.start, range.end), bytes);
+        self.cache.finish_load(pending);
         Ok(())
     }
-
-    fn clear_cache(&mut self) {
-        self.bufs.clear()
-    }
 }
 
 #[cfg(test)]
 mod interface_tests {
     use super::*;
+    use crate::attributes::{Attribute, AttributeValue};
     use crate::dynamic::Dyn;
     use crate::endian::AnyEndian;
     use crate::hash::SysVHashTable;
@@ -959,6 +1629,127 @@ mod interface_tests {
         );
     }
 
+    #[test]
+    fn section_data_as_symbol_table() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let bytes = crate::ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+
+        let shdr = *file
+            .section_headers()
+            .iter()
+            .find(|shdr| shdr.sh_type == abi::SHT_DYNSYM)
+            .expect("Failed to find .dynsym");
+        let strtab_shdr = file.section_headers()[shdr.sh_link as usize];
+        let (expected_symtab, expected_strtab) = bytes
+            .section_data_as_symbol_table(&shdr, &strtab_shdr)
+            .expect("Failed to read symbol table");
+        let (symtab, strtab) = file
+            .section_data_as_symbol_table(&shdr, &strtab_shdr)
+            .expect("Failed to read symbol table");
+        assert!(symtab.iter().eq(expected_symtab.iter()));
+        assert_eq!(strtab.get(11).expect("Failed to get name"), "memset");
+        assert_eq!(
+            expected_strtab.get(11).expect("Failed to get name"),
+            "memset"
+        );
+
+        // Only symbol tables can be interpreted as symbol tables
+        assert!(matches!(
+            file.section_data_as_symbol_table(&strtab_shdr, &strtab_shdr),
+            Err(ParseError::UnexpectedSectionType((
+                abi::SHT_STRTAB,
+                abi::SHT_SYMTAB
+            )))
+        ));
+    }
+
+    #[test]
+    fn find_common_data() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let bytes = crate::ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let expected = bytes.find_common_data().expect("should parse");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+        let common = file.find_common_data().expect("should parse");
+
+        assert!(common
+            .symtab
+            .expect("should have .symtab")
+            .iter()
+            .eq(expected.symtab.expect("should have .symtab").iter()));
+        assert!(common.symtab_strs.is_some());
+        let dynsyms = common.dynsyms.expect("should have .dynsym");
+        let dynsyms_strs = common.dynsyms_strs.expect("should have .dynstr");
+        assert!(dynsyms
+            .iter()
+            .eq(expected.dynsyms.expect("should have .dynsym").iter()));
+        assert!(common
+            .dynamic
+            .expect("should have .dynamic")
+            .iter()
+            .eq(expected.dynamic.expect("should have .dynamic").iter()));
+        let (sym_idx, sym) = common
+            .sysv_hash
+            .expect("should have .hash")
+            .find(b"memset", &dynsyms, &dynsyms_strs)
+            .expect("hash table and symbols should parse")
+            .expect("should find memset");
+        assert_eq!(sym_idx, 2);
+        assert_eq!(dynsyms_strs.get(sym.st_name as usize).unwrap(), "memset");
+        assert_eq!(common.gnu_hash.is_some(), expected.gnu_hash.is_some());
+    }
+
+    #[test]
+    fn segment_table() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let bytes = crate::ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+
+        let phdrs: Vec<ProgramHeader> = file
+            .segment_table()
+            .expect("should read phdrs")
+            .expect("should have phdrs")
+            .iter()
+            .collect();
+        assert_eq!(phdrs.as_slice(), file.segments().as_slice());
+        assert!(phdrs
+            .iter()
+            .copied()
+            .eq(bytes.segments().expect("should have phdrs").iter()));
+
+        let interp = phdrs
+            .iter()
+            .find(|phdr| phdr.p_type == abi::PT_INTERP)
+            .expect("should have PT_INTERP");
+        assert_eq!(
+            file.segment_data(interp).expect("should read segment data"),
+            bytes
+                .segment_data(interp)
+                .expect("should read segment data")
+        );
+    }
+
+    #[test]
+    fn section_header_table() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdrs: Vec<SectionHeader> = file
+            .section_header_table()
+            .expect("should read shdrs")
+            .expect("should have shdrs")
+            .iter()
+            .collect();
+        assert_eq!(shdrs.as_slice(), file.section_headers().as_slice());
+    }
+
     #[test]
     fn dynamic() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1050,6 +1841,99 @@ mod interface_tests {
         assert!(notes.next().is_none());
     }
 
+    #[test]
+    fn section_data_as_mips_relas() {
+        let path = std::path::PathBuf::from("sample-objects/mips64el.o");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr = *file
+            .section_header_by_name(".rela.data")
+            .expect("section table should be parseable")
+            .expect("file should have a .rela.data section");
+        let relas: Vec<_> = file
+            .section_data_as_mips_relas(&shdr)
+            .expect("Failed to read relas section")
+            .collect();
+        assert_eq!(relas.len(), 1);
+        assert_eq!(relas[0].r_sym, 1);
+        assert_eq!(
+            relas[0].types().collect::<Vec<u8>>(),
+            [abi::R_MIPS_64 as u8]
+        );
+    }
+
+    #[test]
+    fn section_data_as_pointers() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr = *file
+            .section_header_by_name(".fini_array")
+            .expect("section table should be parseable")
+            .expect("file should have a .fini_array section");
+        let pointers = file
+            .section_data_as_pointers(&shdr)
+            .expect("Failed to read .fini_array");
+        assert_eq!(
+            pointers.iter().map(u64::from).collect::<Vec<u64>>(),
+            [0x10d0]
+        );
+    }
+
+    #[test]
+    fn section_data_as_attributes() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdr = *file
+            .section_header_by_name(".ARM.attributes")
+            .expect("section table should be parseable")
+            .expect("file should have a .ARM.attributes section");
+        let attrs = file
+            .section_data_as_attributes(&shdr)
+            .expect("Failed to read attributes section");
+
+        let section = attrs
+            .iter()
+            .next()
+            .expect("Failed to get vendor section")
+            .expect("Failed to parse vendor section");
+        assert_eq!(section.vendor, abi::ELF_ATTR_VENDOR_AEABI);
+
+        let subsection = section
+            .subsections()
+            .next()
+            .expect("Failed to get subsection")
+            .expect("Failed to parse subsection");
+        assert_eq!(subsection.tag, abi::TAG_FILE);
+
+        let values: Vec<Attribute<'_>> = subsection
+            .attributes()
+            .take(3)
+            .map(|attr| attr.expect("Failed to parse attribute"))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Attribute {
+                    tag: abi::TAG_ARM_CPU_NAME,
+                    value: AttributeValue::Str("7-A")
+                },
+                Attribute {
+                    tag: abi::TAG_ARM_CPU_ARCH,
+                    value: AttributeValue::Int(10)
+                },
+                Attribute {
+                    tag: abi::TAG_ARM_CPU_ARCH_PROFILE,
+                    value: AttributeValue::Int(b'A' as u64)
+                },
+            ]
+        );
+    }
+
     #[test]
     fn segment_data_as_notes() {
         let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
@@ -1134,6 +2018,40 @@ mod interface_tests {
         assert_eq!(def_names, &["HELLO_1.42"]);
     }
 
+    #[test]
+    fn dynamic_symbols() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let names: Vec<String> = file
+            .dynamic_symbols()
+            .expect("should parse")
+            .expect("should have .dynsym")
+            .map(|sym| sym.to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "_ITM_deregisterTMCloneTable",
+                "memset@GLIBC_2.2.5",
+                "__gmon_start__",
+                "_ITM_registerTMCloneTable",
+                "__cxa_finalize@GLIBC_2.2.5",
+                "HELLO_1.0@@HELLO_1.0",
+                "use_memset_v2@HELLO_1.42",
+                "HELLO_1.42@@HELLO_1.42",
+                "use_memset@HELLO_1.0",
+            ]
+        );
+
+        // Relocatable objects don't have a .dynsym
+        let path = std::path::PathBuf::from("sample-objects/demangle.x86_64.o");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+        assert!(file.dynamic_symbols().expect("should parse").is_none());
+    }
+
     #[test]
     fn sysv_hash_table() {
         let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
@@ -1186,6 +2104,163 @@ mod interface_tests {
     }
 }
 
+#[cfg(test)]
+mod caching_reader_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use std::rc::Rc;
+
+    /// A ReadAt which counts the bytes read through it
+    struct CountingReader {
+        data: Vec<u8>,
+        bytes_read: Rc<Cell<usize>>,
+    }
+
+    impl ReadAt for CountingReader {
+        fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<(), ParseError> {
+            let start = offset as usize;
+            buf.copy_from_slice(&self.data[start..start + buf.len()]);
+            self.bytes_read.set(self.bytes_read.get() + buf.len());
+            Ok(())
+        }
+
+        fn size(&mut self) -> Result<u64, ParseError> {
+            Ok(self.data.len() as u64)
+        }
+    }
+
+    fn counting_reader() -> (CachingReader<CountingReader>, Rc<Cell<usize>>) {
+        let bytes_read = Rc::new(Cell::new(0));
+        let reader = CountingReader {
+            data: (0..=255).collect(),
+            bytes_read: bytes_read.clone(),
+        };
+        (CachingReader::new(reader).unwrap(), bytes_read)
+    }
+
+    #[test]
+    fn coalesces_overlapping_reads() {
+        let (mut cr, bytes_read) = counting_reader();
+        assert_eq!(
+            cr.read_bytes(10, 20).unwrap(),
+            (10..20).collect::<Vec<u8>>()
+        );
+        assert_eq!(bytes_read.get(), 10);
+
+        // Contained in a cached range
+        assert_eq!(
+            cr.read_bytes(12, 18).unwrap(),
+            (12..18).collect::<Vec<u8>>()
+        );
+        assert_eq!(bytes_read.get(), 10);
+
+        // Overlapping a cached range only reads the rest
+        assert_eq!(
+            cr.read_bytes(15, 30).unwrap(),
+            (15..30).collect::<Vec<u8>>()
+        );
+        assert_eq!(bytes_read.get(), 20);
+        assert_eq!(cr.cache.bufs.len(), 1);
+
+        // Spanning several cached ranges only reads the gaps between them
+        cr.read_bytes(40, 50).unwrap();
+        assert_eq!(bytes_read.get(), 30);
+        assert_eq!(cr.read_bytes(5, 45).unwrap(), (5..45).collect::<Vec<u8>>());
+        assert_eq!(bytes_read.get(), 45);
+        assert_eq!(cr.cache.bufs.len(), 1);
+        assert_eq!(cr.cache.cached_bytes, 45);
+
+        assert!(matches!(
+            cr.read_bytes(250, 257),
+            Err(ParseError::BadOffset(257))
+        ));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let (mut cr, bytes_read) = counting_reader();
+        cr.cache.set_limit(Some(20));
+        cr.read_bytes(0, 10).unwrap();
+        cr.read_bytes(20, 30).unwrap();
+        // Reusing 0..10 makes 20..30 the least recently used
+        cr.read_bytes(0, 10).unwrap();
+        cr.read_bytes(40, 50).unwrap();
+        assert_eq!(cr.cache.cached_bytes, 20);
+        assert!(cr.cache.containing(&(0..10)).is_some());
+        assert!(cr.cache.containing(&(20..30)).is_none());
+        assert!(cr.cache.containing(&(40..50)).is_some());
+
+        // The ranges loaded for one request are kept together, even over the limit
+        cr.load_bytes(100..120).unwrap();
+        cr.load_bytes(140..160).unwrap();
+        assert_eq!(cr.get_bytes(100..120), (100..120).collect::<Vec<u8>>());
+        assert_eq!(cr.get_bytes(140..160), (140..160).collect::<Vec<u8>>());
+        assert_eq!(cr.cache.cached_bytes, 40);
+
+        // Until the next request
+        cr.read_bytes(0, 10).unwrap();
+        assert_eq!(cr.cache.cached_bytes, 10);
+
+        let before = bytes_read.get();
+        cr.cache.evict(5..6);
+        assert_eq!(cr.cache.cached_bytes, 0);
+        cr.read_bytes(0, 10).unwrap();
+        assert_eq!(bytes_read.get(), before + 10);
+    }
+
+    #[test]
+    fn stream_cache_limit() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io)
+            .expect("Open test1")
+            .with_cache_limit(0);
+        assert_eq!(file.cached_bytes(), 0);
+
+        let (symtab, strtab) = file.symbol_table().unwrap().unwrap();
+        let symbol = symtab.get(30).unwrap();
+        assert_eq!(strtab.get(symbol.st_name as usize).unwrap(), "__JCR_LIST__");
+        let symtab_shdr = *file
+            .section_headers()
+            .iter()
+            .find(|shdr| shdr.sh_type == abi::SHT_SYMTAB)
+            .unwrap();
+        let strtab_shdr = file.section_headers()[symtab_shdr.sh_link as usize];
+        assert_eq!(
+            file.cached_bytes(),
+            (symtab_shdr.sh_size + strtab_shdr.sh_size) as usize
+        );
+
+        // The next call evicts everything from the previous one
+        let shdr = file.section_headers()[7];
+        let (data, _) = file.section_data(&shdr).unwrap();
+        assert_eq!(data, [0, 0, 2, 0, 2, 0, 0, 0]);
+        assert_eq!(file.cached_bytes(), 8);
+
+        file.evict_section_data(&shdr).unwrap();
+        assert_eq!(file.cached_bytes(), 0);
+    }
+
+    #[test]
+    fn stream_evict_section_data() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let io = std::fs::File::open(path).expect("Could not open file.");
+        let mut file = ElfStream::<AnyEndian, _>::open_stream(io).expect("Open test1");
+
+        let shdrs = file.section_headers().clone();
+        for shdr in &shdrs {
+            file.section_data(shdr).unwrap();
+        }
+        let cached = file.cached_bytes();
+        assert!(cached > 0);
+
+        file.evict_section_data(&shdrs[7]).unwrap();
+        assert_eq!(file.cached_bytes(), cached - 8);
+        file.clear_cache();
+        assert_eq!(file.cached_bytes(), 0);
+    }
+}
+
 #[cfg(test)]
 mod arch_tests {
     use super::*;
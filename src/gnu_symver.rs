@@ -2,6 +2,7 @@
 use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
+use crate::hash::sysv_hash;
 use crate::parse::{ParseAt, ParseError, ParsingTable};
 use crate::string_table::StringTable;
 
@@ -22,6 +23,31 @@ pub struct SymbolDefinition<'data, E: EndianParse> {
     pub hidden: bool,
 }
 
+/// The version required by or defined for a dynamic symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolVersion<'data> {
+    /// The version name, as in `GLIBC_2.2.5`
+    pub name: &'data str,
+    /// The library the version is required from, for undefined symbols
+    pub file: Option<&'data str>,
+    /// Whether the symbol is hidden, i.e. not the default version of its name
+    pub hidden: bool,
+}
+
+/// The version to look for in versioned symbol lookups, like
+/// [GnuHashTable::find_versioned](crate::hash::GnuHashTable::find_versioned)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionQuery<'a> {
+    /// The default version, which is what unversioned references bind to: a defined symbol
+    /// whose version is one of the object's own definitions and isn't hidden (`name@@VERSION`)
+    Default,
+    /// The version with the given name, as in `GLIBC_2.2.5`
+    Name(&'a [u8]),
+    /// The version with the given index into `.gnu.version_d` or `.gnu.version_r`,
+    /// as in [VersionIndex::index]
+    Index(u16),
+}
+
 #[derive(Debug)]
 pub struct SymbolNamesIterator<'data, E: EndianParse> {
     vda_iter: VerDefAuxIterator<'data, E>,
@@ -69,7 +95,7 @@ impl<'data, E: EndianParse> SymbolVersionTable<'data, E> {
     pub fn get_requirement(
         &self,
         sym_idx: usize,
-    ) -> Result<Option<SymbolRequirement<'_>>, ParseError> {
+    ) -> Result<Option<SymbolRequirement<'data>>, ParseError> {
         let (verneeds, verneed_strs) = match self.verneeds {
             Some(verneeds) => verneeds,
             None => {
@@ -106,6 +132,112 @@ impl<'data, E: EndianParse> SymbolVersionTable<'data, E> {
         Ok(None)
     }
 
+    /// Get the version required by or defined for the symbol at `sym_idx`, or None for symbols
+    /// which are local or global (unversioned).
+    ///
+    /// For a definition, this is the defined version itself, not its parents.
+    pub fn get_version(&self, sym_idx: usize) -> Result<Option<SymbolVersion<'data>>, ParseError> {
+        let ver_ndx = self.version_ids.get(sym_idx)?;
+        if ver_ndx.is_local() || ver_ndx.is_global() {
+            return Ok(None);
+        }
+        let hidden = ver_ndx.is_hidden();
+
+        if let Some((verneeds, verneed_strs)) = self.verneeds {
+            for (vn, vna_iter) in verneeds {
+                for vna in vna_iter {
+                    if vna.vna_other == ver_ndx.index() {
+                        return Ok(Some(SymbolVersion {
+                            name: verneed_strs.get(vna.vna_name as usize)?,
+                            file: Some(verneed_strs.get(vn.vn_file as usize)?),
+                            hidden,
+                        }));
+                    }
+                }
+            }
+        }
+
+        if let Some((verdefs, verdef_strs)) = self.verdefs {
+            for (vd, mut vda_iter) in verdefs {
+                if vd.vd_ndx != ver_ndx.index() {
+                    continue;
+                }
+                return match vda_iter.next() {
+                    Some(vda) => Ok(Some(SymbolVersion {
+                        name: verdef_strs.get(vda.vda_name as usize)?,
+                        file: None,
+                        hidden,
+                    })),
+                    None => Ok(None),
+                };
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get the name of the version required by or defined for the symbol at `sym_idx`, or None
+    /// for symbols which are local or global (unversioned). See [SymbolVersionTable::get_version].
+    pub fn get_version_name(&self, sym_idx: usize) -> Result<Option<&'data str>, ParseError> {
+        Ok(self.get_version(sym_idx)?.map(|version| version.name))
+    }
+
+    /// Returns whether the symbol at `sym_idx` has the version described by `query`.
+    ///
+    /// Version names are matched by comparing the `vd_hash`/`vna_hash` of the symbol's version
+    /// against the SysV hash of the name before comparing the names themselves.
+    ///
+    /// [VersionQuery::Default] only matches symbols whose version index refers to one of the
+    /// `.gnu.version_d` definitions, so local, global and required versions don't match it. The
+    /// symbol itself must also be defined, which the hash tables' `find_versioned` check.
+    pub fn matches(&self, sym_idx: usize, query: &VersionQuery<'_>) -> Result<bool, ParseError> {
+        let ver_ndx = self.version_ids.get(sym_idx)?;
+        let name = match *query {
+            VersionQuery::Default => {
+                if ver_ndx.is_hidden() || ver_ndx.is_local() || ver_ndx.is_global() {
+                    return Ok(false);
+                }
+                return Ok(self.verdefs.is_some_and(|(verdefs, _)| {
+                    verdefs
+                        .into_iter()
+                        .any(|(vd, _)| vd.vd_ndx == ver_ndx.index())
+                }));
+            }
+            VersionQuery::Index(index) => return Ok(ver_ndx.index() == index),
+            VersionQuery::Name(name) => name,
+        };
+        if ver_ndx.is_local() || ver_ndx.is_global() {
+            return Ok(false);
+        }
+        let hash = sysv_hash(name);
+
+        if let Some((verneeds, verneed_strs)) = self.verneeds {
+            for (_, vna_iter) in verneeds {
+                for vna in vna_iter {
+                    if vna.vna_other == ver_ndx.index() {
+                        return Ok(vna.vna_hash == hash
+                            && verneed_strs.get_raw(vna.vna_name as usize)? == name);
+                    }
+                }
+            }
+        }
+
+        if let Some((verdefs, verdef_strs)) = self.verdefs {
+            for (vd, mut vda_iter) in verdefs {
+                if vd.vd_ndx != ver_ndx.index() {
+                    continue;
+                }
+                return match vda_iter.next() {
+                    Some(vda) => {
+                        Ok(vd.vd_hash == hash
+                            && verdef_strs.get_raw(vda.vda_name as usize)? == name)
+                    }
+                    None => Ok(false),
+                };
+            }
+        }
+        Ok(false)
+    }
+
     pub fn get_definition(
         &self,
         sym_idx: usize,
@@ -0,0 +1,346 @@
+//! Reporting the exploit mitigations an ELF binary was built with, like `checksec`
+//!
+//! [hardening_report] inspects the program headers, the dynamic table, the imported symbols and
+//! the GNU program properties to produce a [HardeningReport]. With the `serde` feature, the
+//! report can be serialized for storing alongside build artifacts.
+//!
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::hardening::{hardening_report, Relro};
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/hardening.hardened.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let mut file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let report = hardening_report(&mut file).expect("Should be able to inspect the binary");
+//! assert!(report.pie);
+//! assert!(report.nx_stack);
+//! assert_eq!(report.relro, Relro::Full);
+//! assert!(report.stack_protector);
+//! ```
+use crate::abi;
+use crate::elf_reader::ElfReader;
+use crate::endian::EndianParse;
+use crate::note::{GnuProperty, GnuPropertyIterator, Note, NoteIterator};
+use crate::parse::ParseError;
+use crate::segment::ProgramHeader;
+
+/// How much of the relocated data is made read-only after relocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Relro {
+    /// There's no [PT_GNU_RELRO](abi::PT_GNU_RELRO) segment
+    None,
+    /// There's a [PT_GNU_RELRO](abi::PT_GNU_RELRO) segment, but symbols are bound lazily so
+    /// the PLT's GOT entries stay writable
+    Partial,
+    /// There's a [PT_GNU_RELRO](abi::PT_GNU_RELRO) segment and all symbols are bound at load
+    /// time, so the whole GOT is read-only
+    Full,
+}
+
+/// The exploit mitigations found in an ELF binary by [hardening_report]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HardeningReport {
+    /// The object is an [ET_DYN](abi::ET_DYN) flagged with [DF_1_PIE](abi::DF_1_PIE)
+    pub pie: bool,
+    /// There's a [PT_GNU_STACK](abi::PT_GNU_STACK) segment without [PF_X](abi::PF_X). Without
+    /// the segment, most platforms default to an executable stack.
+    pub nx_stack: bool,
+    /// See [Relro]
+    pub relro: Relro,
+    /// `__stack_chk_fail` is imported, or defined in a static binary
+    pub stack_protector: bool,
+    /// The number of FORTIFY_SOURCE checked functions (`__*_chk`) imported, or defined in a
+    /// static binary
+    pub fortified_functions: u64,
+    /// The dynamic table has [DT_TEXTREL](abi::DT_TEXTREL) or [DF_TEXTREL](abi::DF_TEXTREL), so
+    /// text pages are made writable at load time
+    pub textrel: bool,
+    /// The dynamic table has a [DT_RPATH](abi::DT_RPATH)
+    pub rpath: bool,
+    /// The dynamic table has a [DT_RUNPATH](abi::DT_RUNPATH)
+    pub runpath: bool,
+    /// x86 CET Indirect Branch Tracking is marked in the GNU program properties
+    pub ibt: bool,
+    /// x86 CET Shadow Stack is marked in the GNU program properties
+    pub shstk: bool,
+    /// AArch64 Branch Target Identification is marked in the GNU program properties
+    pub bti: bool,
+    /// AArch64 Pointer Authentication is marked in the GNU program properties
+    pub pac: bool,
+}
+
+impl HardeningReport {
+    /// Returns whether FORTIFY_SOURCE checked functions are used
+    pub fn fortified(&self) -> bool {
+        self.fortified_functions > 0
+    }
+}
+
+/// Inspect the given ELF binary for the exploit mitigations it was built with.
+///
+/// The stack protector and FORTIFY_SOURCE functions are looked for among the `.dynsym` imports,
+/// or among the `.symtab` definitions for static binaries which have no `.dynsym`. A stripped
+/// static binary has neither table, so those mitigations can't be detected in it.
+pub fn hardening_report<R: ElfReader>(reader: &mut R) -> Result<HardeningReport, ParseError> {
+    let ehdr = reader.ehdr();
+    let (e_type, e_machine) = (ehdr.e_type, ehdr.e_machine);
+    let (endian, class) = (ehdr.endianness, ehdr.class);
+
+    let mut report = HardeningReport {
+        pie: false,
+        nx_stack: false,
+        relro: Relro::None,
+        stack_protector: false,
+        fortified_functions: 0,
+        textrel: false,
+        rpath: false,
+        runpath: false,
+        ibt: false,
+        shstk: false,
+        bti: false,
+        pac: false,
+    };
+
+    let mut property_phdr: Option<ProgramHeader> = None;
+    if let Some(phdrs) = reader.segment_table()? {
+        for phdr in phdrs.iter() {
+            match phdr.p_type {
+                abi::PT_GNU_STACK => report.nx_stack = phdr.p_flags & abi::PF_X == 0,
+                abi::PT_GNU_RELRO => report.relro = Relro::Partial,
+                abi::PT_GNU_PROPERTY => property_phdr = Some(phdr),
+                _ => {}
+            }
+        }
+    }
+
+    let mut bind_now = false;
+    if let Some(dynamic) = reader.dynamic()? {
+        for d in dynamic.iter() {
+            match d.d_tag {
+                abi::DT_BIND_NOW => bind_now = true,
+                abi::DT_TEXTREL => report.textrel = true,
+                abi::DT_RPATH => report.rpath = true,
+                abi::DT_RUNPATH => report.runpath = true,
+                abi::DT_FLAGS => {
+                    bind_now |= d.d_val() & abi::DF_BIND_NOW as u64 != 0;
+                    report.textrel |= d.d_val() & abi::DF_TEXTREL as u64 != 0;
+                }
+                abi::DT_FLAGS_1 => {
+                    bind_now |= d.d_val() & abi::DF_1_NOW as u64 != 0;
+                    report.pie = e_type == abi::ET_DYN && d.d_val() & abi::DF_1_PIE as u64 != 0;
+                }
+                _ => {}
+            }
+        }
+    }
+    if bind_now && report.relro == Relro::Partial {
+        report.relro = Relro::Full;
+    }
+
+    // Dynamically linked objects import the checked functions, while static binaries have them
+    // linked in as definitions
+    let (symbols, imported) = match reader.dynamic_symbol_table()? {
+        Some(tables) => (Some(tables), true),
+        None => (reader.symbol_table()?, false),
+    };
+    if let Some((symtab, strtab)) = symbols {
+        for sym in symtab.iter().filter(|sym| sym.is_undefined() == imported) {
+            let name = strtab.get_raw(sym.st_name as usize)?;
+            if name == b"__stack_chk_fail" {
+                report.stack_protector = true;
+            } else if name.starts_with(b"__") && name.ends_with(b"_chk") {
+                report.fortified_functions += 1;
+            }
+        }
+    }
+
+    // The properties are found by the PT_GNU_PROPERTY segment in linked objects, or the
+    // .note.gnu.property section otherwise
+    let notes = match property_phdr {
+        Some(phdr) => {
+            let align = phdr.p_align as usize;
+            Some((align, reader.segment_data(&phdr)?))
+        }
+        None => match reader.section_header_by_name(".note.gnu.property")? {
+            Some(shdr) if shdr.sh_type == abi::SHT_NOTE => {
+                let align = shdr.sh_addralign as usize;
+                Some((align, reader.section_data(&shdr)?.0))
+            }
+            _ => None,
+        },
+    };
+    if let Some((align, data)) = notes {
+        for note in NoteIterator::new(endian, class, align, data) {
+            let note = match note {
+                Note::Unknown(note)
+                    if note.name == abi::ELF_NOTE_GNU
+                        && note.n_type == abi::NT_GNU_PROPERTY_TYPE_0 =>
+                {
+                    note
+                }
+                _ => continue,
+            };
+            for property in GnuPropertyIterator::new(endian, class, note.desc) {
+                apply_property(&mut report, e_machine, endian, &property?)?;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+fn apply_property<E: EndianParse>(
+    report: &mut HardeningReport,
+    e_machine: u16,
+    endian: E,
+    property: &GnuProperty<'_>,
+) -> Result<(), ParseError> {
+    match (e_machine, property.pr_type) {
+        (abi::EM_X86_64 | abi::EM_386, abi::GNU_PROPERTY_X86_FEATURE_1_AND) => {
+            let features = property.bitmask(endian)?;
+            report.ibt = features & abi::GNU_PROPERTY_X86_FEATURE_1_IBT != 0;
+            report.shstk = features & abi::GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0;
+        }
+        (abi::EM_AARCH64, abi::GNU_PROPERTY_AARCH64_FEATURE_1_AND) => {
+            let features = property.bitmask(endian)?;
+            report.bti = features & abi::GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0;
+            report.pac = features & abi::GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod hardening_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::{ElfBytes, ElfStream};
+
+    fn report_for(name: &str) -> HardeningReport {
+        let path = std::path::PathBuf::from("sample-objects").join(name);
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let mut file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let report = hardening_report(&mut file).expect("Failed to inspect");
+
+        let mut stream = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&file_data))
+            .expect("Open test1");
+        assert_eq!(
+            hardening_report(&mut stream).expect("Failed to inspect"),
+            report
+        );
+        report
+    }
+
+    #[test]
+    fn hardened() {
+        let report = report_for("hardening.hardened.x86_64");
+        assert_eq!(
+            report,
+            HardeningReport {
+                pie: true,
+                nx_stack: true,
+                relro: Relro::Full,
+                stack_protector: true,
+                fortified_functions: 1,
+                textrel: false,
+                rpath: false,
+                runpath: false,
+                ibt: true,
+                shstk: true,
+                bti: false,
+                pac: false,
+            }
+        );
+        assert!(report.fortified());
+    }
+
+    #[test]
+    fn unhardened() {
+        let report = report_for("hardening.unhardened.x86_64");
+        assert_eq!(
+            report,
+            HardeningReport {
+                pie: false,
+                nx_stack: false,
+                relro: Relro::None,
+                stack_protector: false,
+                fortified_functions: 0,
+                textrel: false,
+                rpath: true,
+                runpath: false,
+                ibt: false,
+                shstk: false,
+                bti: false,
+                pac: false,
+            }
+        );
+        assert!(!report.fortified());
+    }
+
+    #[test]
+    fn static_binary() {
+        // Without a .dynsym, the checked functions are found among the .symtab definitions
+        let report = report_for("hardening.static.x86_64");
+        assert!(report.stack_protector);
+        assert_eq!(report.fortified_functions, 1);
+        assert!(!report.pie);
+    }
+
+    #[test]
+    fn shared_object() {
+        // Shared objects are ET_DYN, but not PIE executables
+        let report = report_for("symver.x86_64.so");
+        assert!(!report.pie);
+        assert_eq!(report.relro, Relro::Partial);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        use serde_test::{assert_tokens, Token};
+
+        let report = report_for("hardening.unhardened.x86_64");
+        assert_tokens(
+            &report,
+            &[
+                Token::Struct {
+                    name: "HardeningReport",
+                    len: 12,
+                },
+                Token::Str("pie"),
+                Token::Bool(false),
+                Token::Str("nx_stack"),
+                Token::Bool(false),
+                Token::Str("relro"),
+                Token::UnitVariant {
+                    name: "Relro",
+                    variant: "None",
+                },
+                Token::Str("stack_protector"),
+                Token::Bool(false),
+                Token::Str("fortified_functions"),
+                Token::U64(0),
+                Token::Str("textrel"),
+                Token::Bool(false),
+                Token::Str("rpath"),
+                Token::Bool(true),
+                Token::Str("runpath"),
+                Token::Bool(false),
+                Token::Str("ibt"),
+                Token::Bool(false),
+                Token::Str("shstk"),
+                Token::Bool(false),
+                Token::Str("bti"),
+                Token::Bool(false),
+                Token::Str("pac"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
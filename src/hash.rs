@@ -3,7 +3,8 @@ use core::mem::size_of;
 
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingTable, ReadBytesExt};
+use crate::gnu_symver::{SymbolVersionTable, VersionQuery};
+use crate::parse::{ClassWord, ParseAt, ParseError, ParsingTable, ReadBytesExt};
 use crate::string_table::StringTable;
 use crate::symbol::{Symbol, SymbolTable};
 
@@ -104,31 +105,98 @@ impl<'data, E: EndianParse> SysVHashTable<'data, E> {
         symtab: &SymbolTable<'data, E>,
         strtab: &StringTable<'data>,
     ) -> Result<Option<(usize, Symbol)>, ParseError> {
+        self.find_all(name, symtab, strtab).next().transpose()
+    }
+
+    /// Use the hash table to find every symbol table entry with the given name, such as each
+    /// of the versions of a versioned symbol.
+    pub fn find_all<'a>(
+        &'a self,
+        name: &'a [u8],
+        symtab: &'a SymbolTable<'data, E>,
+        strtab: &'a StringTable<'data>,
+    ) -> SysVHashMatches<'a, 'data, E> {
         // empty hash tables don't have any entries. This avoids a divde by zero in the modulus calculation
-        if self.buckets.is_empty() {
-            return Ok(None);
+        let index = match self.buckets.len() {
+            0 => Ok(0),
+            len => self.buckets.get((sysv_hash(name) as usize) % len),
+        };
+        SysVHashMatches {
+            table: self,
+            name,
+            symtab,
+            strtab,
+            index,
+            i: 0,
+        }
+    }
+
+    /// Use the hash table to find the symbol table entry with the given name and version.
+    ///
+    /// With [VersionQuery::Default], this finds the version that unversioned references bind to.
+    pub fn find_versioned(
+        &self,
+        name: &[u8],
+        version: &VersionQuery<'_>,
+        symtab: &SymbolTable<'data, E>,
+        strtab: &StringTable<'data>,
+        versions: &SymbolVersionTable<'data, E>,
+    ) -> Result<Option<(usize, Symbol)>, ParseError> {
+        for found in self.find_all(name, symtab, strtab) {
+            let (sym_idx, symbol) = found?;
+            // Unversioned references only bind to definitions
+            if *version == VersionQuery::Default && symbol.is_undefined() {
+                continue;
+            }
+            if versions.matches(sym_idx, version)? {
+                return Ok(Some((sym_idx, symbol)));
+            }
         }
+        Ok(None)
+    }
+}
 
-        let hash = sysv_hash(name);
+/// Iterator over the symbol table entries with a given name in a [SysVHashTable]
+#[derive(Debug)]
+pub struct SysVHashMatches<'a, 'data, E: EndianParse> {
+    table: &'a SysVHashTable<'data, E>,
+    name: &'a [u8],
+    symtab: &'a SymbolTable<'data, E>,
+    strtab: &'a StringTable<'data>,
+    index: Result<u32, ParseError>,
+    i: usize,
+}
 
-        let start = (hash as usize) % self.buckets.len();
-        let mut index = self.buckets.get(start)? as usize;
+impl<E: EndianParse> SysVHashMatches<'_, '_, E> {
+    fn next_match(&mut self, index: usize) -> Result<Option<(usize, Symbol)>, ParseError> {
+        let mut index = index;
 
         // Bound the number of chain lookups by the chain size so we don't loop forever
-        let mut i = 0;
-        while index != 0 && i < self.chains.len() {
-            let symbol = symtab.get(index)?;
-            if strtab.get_raw(symbol.st_name as usize)? == name {
+        while index != 0 && self.i < self.table.chains.len() {
+            let symbol = self.symtab.get(index)?;
+            let next = self.table.chains.get(index)?;
+            self.i += 1;
+            if self.strtab.get_raw(symbol.st_name as usize)? == self.name {
+                self.index = Ok(next);
                 return Ok(Some((index, symbol)));
             }
-
-            index = self.chains.get(index)? as usize;
-            i += 1;
+            index = next as usize;
         }
         Ok(None)
     }
 }
 
+impl<E: EndianParse> Iterator for SysVHashMatches<'_, '_, E> {
+    type Item = Result<(usize, Symbol), ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = match core::mem::replace(&mut self.index, Ok(0)) {
+            Ok(index) => index as usize,
+            Err(e) => return Some(Err(e)),
+        };
+        self.next_match(index).transpose()
+    }
+}
+
 /// Calculate the GNU hash for a given symbol name.
 pub fn gnu_hash(name: &[u8]) -> u32 {
     let mut hash = 5381u32;
@@ -172,7 +240,7 @@ impl ParseAt for GnuHashHeader {
     }
 }
 
-type U64Table<'data, E> = ParsingTable<'data, E, u64>;
+type ClassWordTable<'data, E> = ParsingTable<'data, E, ClassWord>;
 
 impl ParseAt for u64 {
     fn parse_at<E: EndianParse>(
@@ -256,14 +324,99 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
         symtab: &SymbolTable<'data, E>,
         strtab: &StringTable<'data>,
     ) -> Result<Option<(usize, Symbol)>, ParseError> {
-        // empty hash tables don't have any entries. This avoids a divde by zero in the modulus calculation,
-        // and also avoids a potential division by zero panic in the bloom filter index calculation.
-        if self.buckets.is_empty() || self.hdr.nbloom == 0 {
-            return Ok(None);
-        }
+        self.find_all(name, symtab, strtab)?.next().transpose()
+    }
 
+    /// Use the hash table to find every symbol table entry with the given name, such as each
+    /// of the versions of a versioned symbol.
+    pub fn find_all<'a>(
+        &'a self,
+        name: &'a [u8],
+        symtab: &'a SymbolTable<'data, E>,
+        strtab: &'a StringTable<'data>,
+    ) -> Result<GnuHashMatches<'a, 'data, E>, ParseError> {
         let hash = gnu_hash(name);
+        let chain_idx = self.chain_start(hash)?;
+        Ok(GnuHashMatches {
+            table: self,
+            name,
+            hash,
+            symtab,
+            strtab,
+            chain_idx,
+        })
+    }
+
+    /// Use the hash table to find the symbol table entry with the given name and version.
+    ///
+    /// With [VersionQuery::Default], this finds the version that unversioned references bind to.
+    pub fn find_versioned(
+        &self,
+        name: &[u8],
+        version: &VersionQuery<'_>,
+        symtab: &SymbolTable<'data, E>,
+        strtab: &StringTable<'data>,
+        versions: &SymbolVersionTable<'data, E>,
+    ) -> Result<Option<(usize, Symbol)>, ParseError> {
+        for found in self.find_all(name, symtab, strtab)? {
+            let (sym_idx, symbol) = found?;
+            // Unversioned references only bind to definitions
+            if *version == VersionQuery::Default && symbol.is_undefined() {
+                continue;
+            }
+            if versions.matches(sym_idx, version)? {
+                return Ok(Some((sym_idx, symbol)));
+            }
+        }
+        Ok(None)
+    }
 
+    /// Returns the number of entries in the symbol table that this hash table indexes, which is
+    /// the end of the last hash chain.
+    ///
+    /// This is the only way to size the `.dynsym` of objects which don't have section headers.
+    pub fn symbol_count(&self) -> Result<usize, ParseError> {
+        let table_start_idx = self.hdr.table_start_idx as usize;
+        let mut last_start = 0;
+        for bucket in self.buckets.iter() {
+            last_start = last_start.max(bucket as usize);
+        }
+        if last_start < table_start_idx {
+            // No symbols are in the hash table
+            return Ok(table_start_idx);
+        }
+
+        // Walk the last chain until the 1's bit signals its end
+        let mut chain_idx = last_start - table_start_idx;
+        while self.chains.get(chain_idx)? & 1 == 0 {
+            chain_idx += 1;
+        }
+        chain_idx
+            .checked_add(table_start_idx + 1)
+            .ok_or(ParseError::IntegerOverflow)
+    }
+
+    /// Get an iterator over the symbol table indexes of every symbol in the hash table, in
+    /// bucket and chain order.
+    pub fn iter(&self) -> GnuHashIterator<'_, 'data, E> {
+        GnuHashIterator {
+            table: self,
+            bucket: 0,
+            chain_idx: None,
+        }
+    }
+
+    /// Test the given name against the bloom filter only. A false result means the name is
+    /// definitely not in the table, while a true result means it might be.
+    pub fn may_contain(&self, name: &[u8]) -> Result<bool, ParseError> {
+        if self.hdr.nbloom == 0 {
+            return Ok(false);
+        }
+        self.bloom_check(gnu_hash(name))
+    }
+
+    /// Internal helper to check the bloom filter for the given hash.
+    fn bloom_check(&self, hash: u32) -> Result<bool, ParseError> {
         // Test against bloom filter.
         let (bloom_width, filter) = match self.class {
             Class::ELF32 => {
@@ -275,19 +428,31 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
             Class::ELF64 => {
                 let bloom_width: u32 = 8 * size_of::<u64>() as u32; // 64
                 let bloom_idx = (hash / (bloom_width)) % self.hdr.nbloom;
-                let bloom_table = U64Table::new(self.endian, self.class, self.bloom);
-                (bloom_width, bloom_table.get(bloom_idx as usize)?)
+                let bloom_table = ClassWordTable::new(self.endian, self.class, self.bloom);
+                (bloom_width, bloom_table.get(bloom_idx as usize)?.0)
             }
         };
 
         // Check bloom filter for both hashes - symbol is present in the hash table IFF both bits are set.
         if filter & (1 << (hash % bloom_width)) == 0 {
-            return Ok(None);
+            return Ok(false);
         }
         let hash2 = hash
             .checked_shr(self.hdr.nshift)
             .ok_or(ParseError::IntegerOverflow)?;
-        if filter & (1 << (hash2 % bloom_width)) == 0 {
+        Ok(filter & (1 << (hash2 % bloom_width)) != 0)
+    }
+
+    /// Internal helper to check the bloom filter for the given hash and find the chain index
+    /// at which its bucket starts (if it might be in the table).
+    fn chain_start(&self, hash: u32) -> Result<Option<usize>, ParseError> {
+        // empty hash tables don't have any entries. This avoids a divde by zero in the modulus calculation,
+        // and also avoids a potential division by zero panic in the bloom filter index calculation.
+        if self.buckets.is_empty() || self.hdr.nbloom == 0 {
+            return Ok(None);
+        }
+
+        if !self.bloom_check(hash)? {
             return Ok(None);
         }
 
@@ -297,27 +462,85 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
             // All symbols before table_start_idx don't exist in the hash table
             return Ok(None);
         }
+        Ok(Some(chain_start_idx - table_start_idx))
+    }
+}
+
+/// Iterator over the symbol table indexes of every symbol in a [GnuHashTable]
+#[derive(Debug)]
+pub struct GnuHashIterator<'a, 'data, E: EndianParse> {
+    table: &'a GnuHashTable<'data, E>,
+    bucket: usize,
+    chain_idx: Option<usize>,
+}
+
+impl<E: EndianParse> Iterator for GnuHashIterator<'_, '_, E> {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        let table_start_idx = self.table.hdr.table_start_idx as usize;
+        loop {
+            if let Some(chain_idx) = self.chain_idx {
+                let chain_hash = self.table.chains.get(chain_idx).ok()?;
+                // the chain uses the 1's bit to signal its end
+                self.chain_idx = match chain_hash & 1 {
+                    0 => Some(chain_idx + 1),
+                    _ => None,
+                };
+                return chain_idx.checked_add(table_start_idx);
+            }
 
-        let chain_len = self.chains.len();
-        for chain_idx in (chain_start_idx - table_start_idx)..chain_len {
-            let chain_hash = self.chains.get(chain_idx)?;
+            if self.bucket >= self.table.buckets.len() {
+                return None;
+            }
+            let start = self.table.buckets.get(self.bucket).ok()? as usize;
+            self.bucket += 1;
+            // Empty buckets are 0, and all symbols before table_start_idx aren't in the hash table
+            if start != 0 && start >= table_start_idx {
+                self.chain_idx = Some(start - table_start_idx);
+            }
+        }
+    }
+}
+
+/// Iterator over the symbol table entries with a given name in a [GnuHashTable]
+#[derive(Debug)]
+pub struct GnuHashMatches<'a, 'data, E: EndianParse> {
+    table: &'a GnuHashTable<'data, E>,
+    name: &'a [u8],
+    hash: u32,
+    symtab: &'a SymbolTable<'data, E>,
+    strtab: &'a StringTable<'data>,
+    chain_idx: Option<usize>,
+}
+
+impl<E: EndianParse> GnuHashMatches<'_, '_, E> {
+    fn next_match(&mut self, start: usize) -> Result<Option<(usize, Symbol)>, ParseError> {
+        let table_start_idx = self.table.hdr.table_start_idx as usize;
+        let chain_len = self.table.chains.len();
+        for chain_idx in start..chain_len {
+            let chain_hash = self.table.chains.get(chain_idx)?;
+
+            // the chain uses the 1's bit to signal chain comparison stoppage
+            self.chain_idx = match chain_hash & 1 {
+                0 => Some(chain_idx + 1),
+                _ => None,
+            };
 
             // compare the hashes by or'ing the 1's bit back on
-            if hash | 1 == chain_hash | 1 {
+            if self.hash | 1 == chain_hash | 1 {
                 // we have a hash match!
                 // let's see if this symtab[sym_idx].name is what we're looking for
                 let sym_idx = chain_idx
                     .checked_add(table_start_idx)
                     .ok_or(ParseError::IntegerOverflow)?;
-                let symbol = symtab.get(sym_idx)?;
-                let r_sym_name = strtab.get_raw(symbol.st_name as usize)?;
+                let symbol = self.symtab.get(sym_idx)?;
+                let r_sym_name = self.strtab.get_raw(symbol.st_name as usize)?;
 
-                if r_sym_name == name {
+                if r_sym_name == self.name {
                     return Ok(Some((sym_idx, symbol)));
                 }
             }
 
-            // the chain uses the 1's bit to signal chain comparison stoppage
             if chain_hash & 1 != 0 {
                 break;
             }
@@ -327,6 +550,14 @@ impl<'data, E: EndianParse> GnuHashTable<'data, E> {
     }
 }
 
+impl<E: EndianParse> Iterator for GnuHashMatches<'_, '_, E> {
+    type Item = Result<(usize, Symbol), ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.chain_idx.take()?;
+        self.next_match(start).transpose()
+    }
+}
+
 #[cfg(test)]
 mod sysv_parse_tests {
     use super::*;
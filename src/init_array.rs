@@ -0,0 +1,262 @@
+//! Parsing the constructor/destructor pointer arrays: `.init_array`, `.fini_array`, `.preinit_array`,
+//! [SHT_INIT_ARRAY](crate::abi::SHT_INIT_ARRAY), [SHT_FINI_ARRAY](crate::abi::SHT_FINI_ARRAY),
+//! [SHT_PREINIT_ARRAY](crate::abi::SHT_PREINIT_ARRAY)
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::{ClassWord, ParseAt, ParseError, ParsingTable};
+use crate::relocation::RelaIterator;
+
+/// A table of class-sized addresses (`Elf32_Addr` or `Elf64_Addr`).
+pub type PointerTable<'data, E> = ParsingTable<'data, E, ClassWord>;
+
+/// An array of function pointers as found in `.init_array`, `.fini_array` or `.preinit_array`.
+///
+/// Linkers which emit relocations with explicit addends ([Rela](crate::relocation::Rela)) may
+/// leave the array's slots zeroed in the file, relying on the dynamic loader to fill them in
+/// by applying `R_*_RELATIVE` relocations. If this array was given the object's relative
+/// relocations via [InitArray::with_relative_relocations], zeroed slots are resolved
+/// to the relocation's addend, which is the function's address relative to the load base.
+#[derive(Debug, Clone, Copy)]
+pub struct InitArray<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    address: u64,
+    table: PointerTable<'data, E>,
+    relative_type: Option<u32>,
+    relas: &'data [u8],
+}
+
+impl<'data, E: EndianParse> InitArray<'data, E> {
+    /// Construct an InitArray from the array's bytes, which are loaded at virtual address `address`.
+    pub fn new(endian: E, class: Class, address: u64, data: &'data [u8]) -> Self {
+        InitArray {
+            endian,
+            class,
+            address,
+            table: PointerTable::new(endian, class, data),
+            relative_type: None,
+            relas: &[],
+        }
+    }
+
+    /// Resolve zeroed slots using the relocations of type `relative_type` found in the given
+    /// [Rela](crate::relocation::Rela) table bytes (i.e. the `DT_RELA` table).
+    pub fn with_relative_relocations(self, relative_type: u32, relas: &'data [u8]) -> Self {
+        InitArray {
+            relative_type: Some(relative_type),
+            relas,
+            ..self
+        }
+    }
+
+    /// The virtual address of the first slot in the array.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Returns the number of function pointers in the array.
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Get the function pointer at `index` in the array.
+    pub fn get(&self, index: usize) -> Result<u64, ParseError> {
+        let value = self.table.get(index)?.0;
+        if value != 0 {
+            return Ok(value);
+        }
+
+        let relative_type = match self.relative_type {
+            Some(relative_type) => relative_type,
+            None => return Ok(value),
+        };
+        let slot = (index as u64)
+            .checked_mul(ClassWord::size_for(self.class) as u64)
+            .and_then(|offset| self.address.checked_add(offset))
+            .ok_or(ParseError::IntegerOverflow)?;
+        let rela = RelaIterator::new(self.endian, self.class, self.relas)
+            .find(|rela| rela.r_offset == slot && rela.r_type == relative_type);
+        match rela {
+            Some(rela) => Ok(rela.r_addend as u64),
+            None => Ok(value),
+        }
+    }
+
+    /// Get an iterator over the function pointers in the array.
+    pub fn iter(&self) -> InitArrayIterator<'data, E> {
+        InitArrayIterator {
+            array: *self,
+            index: 0,
+        }
+    }
+}
+
+impl<'data, E: EndianParse> IntoIterator for InitArray<'data, E> {
+    type IntoIter = InitArrayIterator<'data, E>;
+    type Item = u64;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the function pointers in an [InitArray]
+#[derive(Debug)]
+pub struct InitArrayIterator<'data, E: EndianParse> {
+    array: InitArray<'data, E>,
+    index: usize,
+}
+
+impl<E: EndianParse> Iterator for InitArrayIterator<'_, E> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            return None;
+        }
+
+        let value = self.array.get(self.index).ok()?;
+        self.index += 1;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    #[test]
+    fn parse_pointer32_lsb() {
+        let data = [0x10u8, 0x32, 0x54, 0x76, 0x98, 0xBA, 0xDC, 0xFE];
+        let table = PointerTable::new(LittleEndian, Class::ELF32, &data);
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(0).unwrap(), ClassWord(0x76543210));
+        assert_eq!(table.get(1).unwrap(), ClassWord(0xFEDCBA98));
+        assert!(table.get(2).is_err());
+    }
+
+    #[test]
+    fn parse_pointer64_msb() {
+        let data = [0x01u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFF];
+        let table = PointerTable::new(BigEndian, Class::ELF64, &data);
+        assert_eq!(table.len(), 1);
+        assert_eq!(
+            table.iter().collect::<Vec<ClassWord>>(),
+            [ClassWord(0x0123456789ABCDEF)]
+        );
+    }
+
+    #[test]
+    fn resolve_zeroed_slots() {
+        // Two slots at 0x1000: the first is zeroed and has a relative relocation, the second is filled in
+        let data = [0u8, 0, 0, 0, 0, 0, 0, 0, 0x40, 0x20, 0, 0, 0, 0, 0, 0];
+        #[rustfmt::skip]
+        let relas = [
+            // r_offset: 0x1000, r_info: R_X86_64_64 against symbol 1, r_addend: 0xbad
+            0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x01, 0, 0, 0, 0x01, 0, 0, 0, 0xad, 0x0b, 0, 0, 0, 0, 0, 0,
+            // r_offset: 0x1000, r_info: R_X86_64_RELATIVE, r_addend: 0x2010
+            0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x08, 0, 0, 0, 0, 0, 0, 0, 0x10, 0x20, 0, 0, 0, 0, 0, 0,
+        ];
+
+        let array = InitArray::new(LittleEndian, Class::ELF64, 0x1000, &data);
+        assert_eq!(array.address(), 0x1000);
+        assert_eq!(array.iter().collect::<Vec<u64>>(), [0, 0x2040]);
+
+        let array = array.with_relative_relocations(crate::abi::R_X86_64_RELATIVE, &relas);
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.get(0).unwrap(), 0x2010);
+        assert_eq!(array.into_iter().collect::<Vec<u64>>(), [0x2010, 0x2040]);
+    }
+
+    #[test]
+    fn init_and_fini_arrays() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let init = file
+            .init_array()
+            .expect("should parse")
+            .expect("should have .init_array");
+        assert_eq!(init.address(), 0x3de0);
+        assert_eq!(init.iter().collect::<Vec<u64>>(), [0x1110]);
+
+        let fini = file
+            .fini_array()
+            .expect("should parse")
+            .expect("should have .fini_array");
+        assert_eq!(fini.address(), 0x3de8);
+        assert_eq!(fini.iter().collect::<Vec<u64>>(), [0x10d0]);
+
+        assert!(file.preinit_array().expect("should parse").is_none());
+    }
+
+    #[test]
+    fn init_array_elf32() {
+        let path = std::path::PathBuf::from("sample-objects/symver.armhf.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let init = file
+            .init_array()
+            .expect("should parse")
+            .expect("should have .init_array");
+        assert_eq!(init.address(), 0x10f00);
+        assert_eq!(init.iter().collect::<Vec<u64>>(), [0x4d9]);
+    }
+
+    #[test]
+    fn init_array_with_zeroed_slots() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Zero out the .init_array and .fini_array slots like a linker that relies on RELA addends would
+        file_data[0x2de0..0x2df0].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let shdr = file
+            .section_header_by_name(".init_array")
+            .expect("section table should be parseable")
+            .expect("file should have .init_array");
+        let raw = file.section_data_as_pointers(&shdr).expect("should parse");
+        assert_eq!(raw.iter().collect::<Vec<ClassWord>>(), [ClassWord(0)]);
+
+        let init = file.init_array().expect("should parse").unwrap();
+        assert_eq!(init.iter().collect::<Vec<u64>>(), [0x1110]);
+        let fini = file.fini_array().expect("should parse").unwrap();
+        assert_eq!(fini.iter().collect::<Vec<u64>>(), [0x10d0]);
+    }
+
+    #[test]
+    fn init_array_located_via_dynamic() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+
+        // Zero out e_shoff and e_shnum to drop the section headers, and zero the slot as well
+        file_data[0x28..0x30].fill(0);
+        file_data[0x3c..0x3e].fill(0);
+        file_data[0x2de0..0x2de8].fill(0);
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.section_headers().is_none());
+
+        let init = file
+            .init_array()
+            .expect("should parse")
+            .expect("should have DT_INIT_ARRAY");
+        assert_eq!(init.address(), 0x3de0);
+        assert_eq!(init.len(), 1);
+        assert_eq!(init.get(0).expect("should parse"), 0x1110);
+
+        let fini = file
+            .fini_array()
+            .expect("should parse")
+            .expect("should have DT_FINI_ARRAY");
+        assert_eq!(fini.iter().collect::<Vec<u64>>(), [0x10d0]);
+    }
+}
@@ -66,7 +66,9 @@
 //!
 //! ### ✨ Stream-based lazy i/o interface ✨
 //! The [ElfStream] parser type takes a `std:: Read + Seek` (such as `std::fs::File`) where ranges of
-//! file contents are read lazily on-demand based on what the user wants to parse.
+//! file contents are read lazily on-demand based on what the user wants to parse. It only needs
+//! the `alloc` feature, and can read from anything which implements the [ReadAt] trait (such as a
+//! bootloader's block device driver) when `std` isn't available.
 //!
 //! This, alongside the bytes-oriented interface, allow you to decide which tradeoffs
 //! you want to make. If you're going to be working with the whole file contents,
@@ -76,9 +78,25 @@
 //! overhead of reading a bunch of unused file data just to parse out a few things, (like
 //! grabbing the `.gnu.note.build-id`)
 //!
-//! ### ✨ Tiny library with no dependencies and fast compilation times ✨
+//! Both parsers implement the [ElfReader] trait, so analyses can be written once against it and
+//! run with whichever parser suits the use-case.
+//!
+//! With the `async` feature, [AsyncElfStream] offers the same lazy i/o on top of a futures-io
+//! `AsyncRead + AsyncSeek` (such as an object storage client's range reads), without blocking
+//! a thread on each read.
+//!
+//! ### ✨ Tiny library with no default dependencies and fast compilation times ✨
 //! Release-target compilation times on this developer's 2021 m1 macbook are sub-second.
 //!
+//! The default features pull in no dependencies. A few opt-in features do, for functionality
+//! that's out of scope to implement here:
+//!
+//! * `minidebuginfo`: [lzma-rs](https://crates.io/crates/lzma-rs) to decompress `.gnu_debugdata`
+//! * `demangle`: [rustc-demangle](https://crates.io/crates/rustc-demangle) and
+//!   [cpp_demangle](https://crates.io/crates/cpp_demangle) for Rust and C++ symbol names
+//! * `async`: [futures-io](https://crates.io/crates/futures-io) for the `AsyncRead + AsyncSeek` traits
+//! * `serde`: [serde](https://crates.io/crates/serde) to (de)serialize a [HardeningReport](hardening::HardeningReport)
+//!
 //! Example using [ElfBytes]:
 //! ```
 //! use elf::ElfBytes;
@@ -135,21 +153,41 @@ extern crate alloc;
 
 pub mod abi;
 
+pub mod archive;
+pub mod arm_exidx;
+pub mod attributes;
 pub mod compression;
+#[cfg(feature = "demangle")]
+pub mod demangle;
 pub mod dynamic;
+pub mod eh_frame;
 pub mod file;
 pub mod gnu_symver;
+pub mod hardening;
 pub mod hash;
+pub mod init_array;
+pub mod loader;
 pub mod note;
+#[cfg(feature = "alloc")]
+pub mod plt;
+pub mod reader;
+pub mod relocate;
 pub mod relocation;
 pub mod section;
 pub mod segment;
 pub mod string_table;
 pub mod symbol;
+#[cfg(feature = "alloc")]
+pub mod symbolizer;
+#[cfg(feature = "alloc")]
+pub mod validate;
 
 #[cfg(feature = "to_str")]
 pub mod to_str;
 
+#[cfg(feature = "minidebuginfo")]
+pub mod minidebuginfo;
+
 pub mod endian;
 pub mod parse;
 
@@ -157,9 +195,18 @@ mod elf_bytes;
 pub use elf_bytes::CommonElfData;
 pub use elf_bytes::ElfBytes;
 
-#[cfg(feature = "std")]
+mod elf_reader;
+pub use elf_reader::ElfReader;
+
+#[cfg(feature = "alloc")]
 mod elf_stream;
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub use elf_stream::ElfStream;
+pub use reader::ReadAt;
+
+#[cfg(feature = "async")]
+mod async_elf_stream;
+#[cfg(feature = "async")]
+pub use async_elf_stream::AsyncElfStream;
 
 pub use parse::ParseError;
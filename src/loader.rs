@@ -0,0 +1,509 @@
+//! Loading an executable image's [PT_LOAD](abi::PT_LOAD) segments into memory
+//!
+//! A [Loader] does the part of program loading that bootloaders and kernels otherwise each
+//! write for themselves:
+//!
+//! * Copy each `PT_LOAD` segment to `p_vaddr - min_vaddr` in a caller-provided buffer and zero
+//!   the rest of its `p_memsz` (the `.bss`).
+//! * Apply the relative relocations ([DT_RELA](abi::DT_RELA), [DT_REL](abi::DT_REL),
+//!   [DT_JMPREL](abi::DT_JMPREL) and [DT_RELR](abi::DT_RELR)) needed by static position
+//!   independent executables, for the address the buffer will run at.
+//! * Report the entry point, the TLS initialization template and each segment's permissions.
+//!
+//! Any other relocation needs a dynamic linker to resolve symbols, so loading an image which
+//! has them fails with [ParseError::UnsupportedRelocation].
+//!
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//! use elf::loader::Loader;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/loader.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let loader = Loader::new(&file).expect("segments should parse");
+//! let mut memory = vec![0u8; loader.size() as usize];
+//! let image = loader
+//!     .load(&mut memory, 0x200000)
+//!     .expect("image should load");
+//!
+//! assert_eq!(image.entry(), 0x201000);
+//! for segment in image.segments() {
+//!     println!(
+//!         "{:#x}..{:#x} writable: {}",
+//!         segment.address,
+//!         segment.address + segment.size,
+//!         segment.is_writable()
+//!     );
+//! }
+//! ```
+use crate::abi;
+use crate::elf_bytes::ElfBytes;
+use crate::endian::EndianParse;
+use crate::parse::{ClassWord, ParseAt, ParseError, ParsingIterator};
+use crate::relocation::{relative_type, RelIterator, RelaIterator, RelrIterator};
+use crate::segment::{ProgramHeader, SegmentTable};
+
+/// Loads an [ElfBytes] image's [PT_LOAD](abi::PT_LOAD) segments into a caller-provided buffer.
+#[derive(Debug)]
+pub struct Loader<'a, 'data, E: EndianParse> {
+    file: &'a ElfBytes<'data, E>,
+    min_vaddr: u64,
+    max_vaddr: u64,
+    align: u64,
+}
+
+impl<'a, 'data, E: EndianParse> Loader<'a, 'data, E> {
+    /// Construct a Loader for the given file, computing the span of memory its segments cover.
+    pub fn new(file: &'a ElfBytes<'data, E>) -> Result<Self, ParseError> {
+        let mut min_vaddr = u64::MAX;
+        let mut max_vaddr = 0;
+        let mut align = 1;
+        for phdr in load_segments(file.segments()) {
+            let end = phdr
+                .p_vaddr
+                .checked_add(phdr.p_memsz)
+                .ok_or(ParseError::IntegerOverflow)?;
+            min_vaddr = min_vaddr.min(phdr.p_vaddr);
+            max_vaddr = max_vaddr.max(end);
+            align = align.max(phdr.p_align);
+        }
+        if min_vaddr > max_vaddr {
+            min_vaddr = 0;
+        }
+
+        Ok(Loader {
+            file,
+            min_vaddr,
+            max_vaddr,
+            align,
+        })
+    }
+
+    /// The lowest virtual address of the image's segments, which is loaded at the start of the buffer.
+    pub fn min_vaddr(&self) -> u64 {
+        self.min_vaddr
+    }
+
+    /// The number of bytes of memory needed to load the image.
+    pub fn size(&self) -> u64 {
+        self.max_vaddr - self.min_vaddr
+    }
+
+    /// The largest alignment required by the image's segments.
+    ///
+    /// The address an image is loaded at must have the same alignment relative to this as its
+    /// [Loader::min_vaddr] does, so that each segment's pages keep their alignment.
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// Load the image into `memory`, whose first byte will be at virtual address `base` when
+    /// the image runs, and apply its relative relocations for that address.
+    ///
+    /// Only the bytes covered by the image's segments are written, so the gaps between
+    /// segments retain whatever `memory` held before.
+    ///
+    /// [ET_EXEC](abi::ET_EXEC) images have no relocations to move them, so they can only be
+    /// loaded at their [Loader::min_vaddr].
+    pub fn load(&self, memory: &mut [u8], base: u64) -> Result<LoadedImage<'data, E>, ParseError> {
+        if self.file.ehdr.e_type == abi::ET_EXEC && base != self.min_vaddr {
+            return Err(ParseError::UnexpectedLoadAddress((base, self.min_vaddr)));
+        }
+
+        for phdr in load_segments(self.file.segments()) {
+            let data = self.file.segment_data(&phdr)?;
+            let data = data.get(..phdr.p_memsz.try_into()?).unwrap_or(data);
+
+            let start: usize = (phdr.p_vaddr - self.min_vaddr).try_into()?;
+            let end = start
+                .checked_add(phdr.p_memsz.try_into()?)
+                .ok_or(ParseError::IntegerOverflow)?;
+            let dest = memory
+                .get_mut(start..end)
+                .ok_or(ParseError::SliceReadError((start, end)))?;
+            let (init, bss) = dest.split_at_mut(data.len());
+            init.copy_from_slice(data);
+            bss.fill(0);
+        }
+
+        let bias = base.wrapping_sub(self.min_vaddr);
+        self.relocate(memory, bias)?;
+
+        Ok(LoadedImage {
+            base,
+            bias,
+            entry: self.file.ehdr.e_entry.wrapping_add(bias),
+            phdrs: self.file.segments(),
+        })
+    }
+
+    /// Apply the image's relative relocations to the loaded `memory`, adjusting by `bias`.
+    fn relocate(&self, memory: &mut [u8], bias: u64) -> Result<(), ParseError> {
+        let endian = self.file.ehdr.endianness;
+        let class = self.file.ehdr.class;
+        let e_machine = self.file.ehdr.e_machine;
+        let relative = relative_type(e_machine, class);
+        let check = |r_type: u32| match r_type {
+            0 => Ok(false),
+            r_type if Some(r_type) == relative => Ok(true),
+            r_type => Err(ParseError::UnsupportedRelocation((e_machine, r_type))),
+        };
+
+        let mut rela_tables = [
+            self.file.dynamic_table_data(abi::DT_RELA, abi::DT_RELASZ)?,
+            None,
+        ];
+        let mut rel_tables = [
+            self.file.dynamic_table_data(abi::DT_REL, abi::DT_RELSZ)?,
+            None,
+        ];
+        if let Some(dynamic) = self.file.dynamic()? {
+            let pltrel = dynamic.iter().find(|d| d.d_tag == abi::DT_PLTREL);
+            let jmprel = self
+                .file
+                .dynamic_table_data(abi::DT_JMPREL, abi::DT_PLTRELSZ)?;
+            match pltrel.map(|d| d.d_val() as i64) {
+                Some(abi::DT_RELA) => rela_tables[1] = jmprel,
+                Some(abi::DT_REL) => rel_tables[1] = jmprel,
+                _ => (),
+            }
+        }
+
+        for relas in rela_tables.into_iter().flatten() {
+            for rela in RelaIterator::new(endian, class, relas) {
+                if check(rela.r_type)? {
+                    self.update_word(memory, rela.r_offset, |_| {
+                        bias.wrapping_add(rela.r_addend as u64)
+                    })?;
+                }
+            }
+        }
+        for rels in rel_tables.into_iter().flatten() {
+            for rel in RelIterator::new(endian, class, rels) {
+                if check(rel.r_type)? {
+                    self.update_word(memory, rel.r_offset, |addend| bias.wrapping_add(addend))?;
+                }
+            }
+        }
+        if let Some(relrs) = self.file.dynamic_table_data(abi::DT_RELR, abi::DT_RELRSZ)? {
+            for address in RelrIterator::new(endian, class, relrs) {
+                self.update_word(memory, address, |addend| bias.wrapping_add(addend))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the class-sized word at virtual address `vaddr` in the loaded `memory`.
+    fn update_word<F>(&self, memory: &mut [u8], vaddr: u64, update: F) -> Result<(), ParseError>
+    where
+        F: FnOnce(u64) -> u64,
+    {
+        let endian = self.file.ehdr.endianness;
+        let start: usize = vaddr
+            .checked_sub(self.min_vaddr)
+            .ok_or(ParseError::BadOffset(vaddr))?
+            .try_into()?;
+        let mut end = start;
+        let ClassWord(value) = ClassWord::parse_at(endian, self.file.ehdr.class, &mut end, memory)?;
+        let value = update(value);
+
+        let size = end - start;
+        let bytes = if endian.is_little() {
+            &value.to_le_bytes()[..size]
+        } else {
+            &value.to_be_bytes()[8 - size..]
+        };
+        memory[start..end].copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// An image that was loaded into memory by a [Loader].
+#[derive(Debug, Clone, Copy)]
+pub struct LoadedImage<'data, E: EndianParse> {
+    base: u64,
+    bias: u64,
+    entry: u64,
+    phdrs: Option<SegmentTable<'data, E>>,
+}
+
+impl<'data, E: EndianParse> LoadedImage<'data, E> {
+    /// The virtual address of the start of the loaded image.
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// The difference between the image's loaded addresses and its linked virtual addresses.
+    pub fn bias(&self) -> u64 {
+        self.bias
+    }
+
+    /// The loaded address of the image's entry point.
+    pub fn entry(&self) -> u64 {
+        self.entry
+    }
+
+    /// Get an iterator over the loaded [PT_LOAD](abi::PT_LOAD) segments.
+    pub fn segments(&self) -> LoadedSegmentIterator<'data, E> {
+        LoadedSegmentIterator {
+            phdrs: self.phdrs.map(|phdrs| phdrs.iter()),
+            bias: self.bias,
+        }
+    }
+
+    /// The [PT_GNU_RELRO](abi::PT_GNU_RELRO) region (if any), which can be made read-only
+    /// now that the image's relocations have been applied.
+    pub fn relro(&self) -> Option<LoadedSegment> {
+        self.find_segment(abi::PT_GNU_RELRO)
+            .map(|phdr| LoadedSegment::new(&phdr, self.bias))
+    }
+
+    /// The image's thread-local storage template from [PT_TLS](abi::PT_TLS) (if any).
+    pub fn tls(&self) -> Option<TlsTemplate> {
+        self.find_segment(abi::PT_TLS).map(|phdr| TlsTemplate {
+            address: phdr.p_vaddr.wrapping_add(self.bias),
+            file_size: phdr.p_filesz,
+            mem_size: phdr.p_memsz,
+            align: phdr.p_align,
+        })
+    }
+
+    fn find_segment(&self, p_type: u32) -> Option<ProgramHeader> {
+        self.phdrs?.iter().find(|phdr| phdr.p_type == p_type)
+    }
+}
+
+/// Iterator over the loaded [PT_LOAD](abi::PT_LOAD) segments of a [LoadedImage]
+#[derive(Debug)]
+pub struct LoadedSegmentIterator<'data, E: EndianParse> {
+    phdrs: Option<ParsingIterator<'data, E, ProgramHeader>>,
+    bias: u64,
+}
+
+impl<E: EndianParse> Iterator for LoadedSegmentIterator<'_, E> {
+    type Item = LoadedSegment;
+    fn next(&mut self) -> Option<Self::Item> {
+        let phdrs = self.phdrs.as_mut()?;
+        phdrs
+            .find(|phdr| phdr.p_type == abi::PT_LOAD)
+            .map(|phdr| LoadedSegment::new(&phdr, self.bias))
+    }
+}
+
+/// A region of a loaded image and the permissions it should be mapped with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadedSegment {
+    /// The loaded virtual address of the start of the region
+    pub address: u64,
+    /// The size of the region in memory
+    pub size: u64,
+    /// The region's `PF_*` permission flags
+    pub flags: u32,
+}
+
+impl LoadedSegment {
+    fn new(phdr: &ProgramHeader, bias: u64) -> Self {
+        LoadedSegment {
+            address: phdr.p_vaddr.wrapping_add(bias),
+            size: phdr.p_memsz,
+            flags: phdr.p_flags,
+        }
+    }
+
+    /// Whether the region should be mapped readable ([PF_R](abi::PF_R)).
+    pub fn is_readable(&self) -> bool {
+        self.flags & abi::PF_R != 0
+    }
+
+    /// Whether the region should be mapped writable ([PF_W](abi::PF_W)).
+    pub fn is_writable(&self) -> bool {
+        self.flags & abi::PF_W != 0
+    }
+
+    /// Whether the region should be mapped executable ([PF_X](abi::PF_X)).
+    pub fn is_executable(&self) -> bool {
+        self.flags & abi::PF_X != 0
+    }
+}
+
+/// The initialization image for each thread's copy of an image's thread-local storage.
+///
+/// Each thread's block is `mem_size` bytes aligned to `align`, where the first `file_size`
+/// bytes are copied from the loaded image at `address` (`.tdata`) and the rest are zeroed (`.tbss`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlsTemplate {
+    pub address: u64,
+    pub file_size: u64,
+    pub mem_size: u64,
+    pub align: u64,
+}
+
+fn load_segments<'data, E: EndianParse + 'data>(
+    phdrs: Option<SegmentTable<'data, E>>,
+) -> impl Iterator<Item = ProgramHeader> + 'data {
+    phdrs
+        .into_iter()
+        .flat_map(|phdrs| phdrs.iter())
+        .filter(|phdr| phdr.p_type == abi::PT_LOAD)
+}
+
+#[cfg(test)]
+mod loader_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+
+    const BASE: u64 = 0x7f12_3400_0000;
+
+    fn read(path: &str) -> Vec<u8> {
+        std::fs::read(path).expect("Could not read file.")
+    }
+
+    fn load(
+        file_data: &[u8],
+        fill: u8,
+    ) -> Result<(Vec<u8>, LoadedImage<'_, AnyEndian>), ParseError> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data).expect("Open test1");
+        let loader = Loader::new(&file)?;
+        let mut memory = vec![fill; loader.size() as usize];
+        let image = loader.load(&mut memory, BASE)?;
+        Ok((memory, image))
+    }
+
+    fn word(memory: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(memory[offset..offset + 8].try_into().unwrap())
+    }
+
+    #[test]
+    fn layout() {
+        let path = std::path::PathBuf::from("sample-objects/loader.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let loader = Loader::new(&file).expect("should parse");
+        assert_eq!(loader.min_vaddr(), 0);
+        assert_eq!(loader.size(), 0x4160);
+        assert_eq!(loader.align(), 0x1000);
+
+        let mut memory = [0u8; 0x4000];
+        assert!(matches!(
+            loader.load(&mut memory, BASE),
+            Err(ParseError::SliceReadError((0x3efc, 0x4160)))
+        ));
+    }
+
+    #[test]
+    fn load_static_pie() {
+        let file_data = read("sample-objects/loader.x86_64");
+        let (memory, image) = load(&file_data, 0xaa).expect("should load");
+        assert_eq!(image.base(), BASE);
+        assert_eq!(image.bias(), BASE);
+        assert_eq!(image.entry(), BASE + 0x1000);
+
+        // The R_X86_64_RELATIVE relocations for `pointer` and `pointers` point at `counter`
+        assert_eq!(word(&memory, 0x4020), BASE + 0x4000);
+        assert_eq!(word(&memory, 0x4028), BASE + 0x4000);
+        assert_eq!(word(&memory, 0x4030), 0);
+        assert_eq!(word(&memory, 0x4038), BASE + 0x4000);
+        assert_eq!(word(&memory, 0x4040), BASE + 0x4000);
+        assert_eq!(memory[0x4000..0x4004], 5u32.to_le_bytes());
+
+        // .bss is zeroed, while the gaps between segments are left alone
+        assert!(memory[0x4048..0x4160].iter().all(|b| *b == 0));
+        assert!(memory[0x2d8..0x1000].iter().all(|b| *b == 0xaa));
+        assert_eq!(memory[0x1000..0x102d], file_data[0x1000..0x102d]);
+
+        let segments: Vec<LoadedSegment> = image.segments().collect();
+        assert_eq!(
+            segments,
+            [
+                LoadedSegment {
+                    address: BASE,
+                    size: 0x2d8,
+                    flags: abi::PF_R
+                },
+                LoadedSegment {
+                    address: BASE + 0x1000,
+                    size: 0x2d,
+                    flags: abi::PF_R | abi::PF_X
+                },
+                LoadedSegment {
+                    address: BASE + 0x2000,
+                    size: 0x44,
+                    flags: abi::PF_R
+                },
+                LoadedSegment {
+                    address: BASE + 0x3efc,
+                    size: 0x264,
+                    flags: abi::PF_R | abi::PF_W
+                },
+            ]
+        );
+        assert!(segments[1].is_executable() && !segments[1].is_writable());
+        assert!(segments[3].is_readable() && segments[3].is_writable());
+
+        assert_eq!(
+            image.relro(),
+            Some(LoadedSegment {
+                address: BASE + 0x3efc,
+                size: 0x104,
+                flags: abi::PF_R
+            })
+        );
+        assert_eq!(
+            image.tls(),
+            Some(TlsTemplate {
+                address: BASE + 0x3efc,
+                file_size: 4,
+                mem_size: 8,
+                align: 4,
+            })
+        );
+        assert_eq!(memory[0x3efc..0x3f00], 42u32.to_le_bytes());
+    }
+
+    #[test]
+    fn load_static_pie_with_relr() {
+        let file_data = read("sample-objects/loader.relr.x86_64");
+        let (memory, image) = load(&file_data, 0).expect("should load");
+        assert_eq!(image.entry(), BASE + 0x1000);
+        assert_eq!(word(&memory, 0x4020), BASE + 0x4000);
+        assert_eq!(word(&memory, 0x4028), BASE + 0x4000);
+        assert_eq!(word(&memory, 0x4030), 0);
+        assert_eq!(word(&memory, 0x4038), BASE + 0x4000);
+        assert_eq!(word(&memory, 0x4040), BASE + 0x4000);
+    }
+
+    #[test]
+    fn load_fixed_position_executable() {
+        let file_data = read("sample-objects/hardening.static.x86_64");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert_eq!(file.ehdr.e_type, abi::ET_EXEC);
+        let loader = Loader::new(&file).expect("should parse");
+        let min_vaddr = loader.min_vaddr();
+        let mut memory = vec![0u8; loader.size() as usize];
+
+        // Without relocations, the image only works at the address it was linked at
+        assert!(matches!(
+            loader.load(&mut memory, BASE),
+            Err(ParseError::UnexpectedLoadAddress((BASE, vaddr))) if vaddr == min_vaddr
+        ));
+        let image = loader
+            .load(&mut memory, min_vaddr)
+            .expect("should load at its link address");
+        assert_eq!(image.bias(), 0);
+        assert_eq!(image.entry(), file.ehdr.e_entry);
+    }
+
+    #[test]
+    fn load_needs_dynamic_linker() {
+        // Shared objects have symbolic relocations which need a dynamic linker to resolve
+        let file_data = read("sample-objects/symver.x86_64.so");
+        let err = load(&file_data, 0).expect_err("should fail");
+        assert!(matches!(
+            err,
+            ParseError::UnsupportedRelocation((abi::EM_X86_64, abi::R_X86_64_GLOB_DAT))
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+//! Decompressing MiniDebugInfo: `.gnu_debugdata`
+//!
+//! Distributions such as Fedora strip their binaries down to just `.dynsym`, but embed
+//! a small xz-compressed ELF object in the `.gnu_debugdata` section. That embedded object
+//! contains a `.symtab` with the function symbols that aren't already exported through
+//! `.dynsym`, which is enough to symbolize crashes in otherwise stripped binaries.
+//!
+//! This module is only available with the `minidebuginfo` cargo feature, which uses the
+//! pure-rust `lzma-rs` crate to do the xz decompression.
+//!
+//! Example:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! // The outer object only has the exported symbols
+//! let (dynsyms, dynstrs) = file
+//!     .dynamic_symbol_table()
+//!     .expect("dynsym should parse")
+//!     .expect("file should have a .dynsym");
+//! assert!(file.symbol_table().expect("should parse").is_none());
+//!
+//! // The MiniDebugInfo has the rest of the function symbols
+//! let mdi = file
+//!     .minidebuginfo()
+//!     .expect("should decompress")
+//!     .expect("file should have a .gnu_debugdata section");
+//! let inner = mdi.elf::<AnyEndian>().expect("inner object should parse");
+//! let (symtab, strtab) = inner
+//!     .symbol_table()
+//!     .expect("symtab should parse")
+//!     .expect("inner object should have a .symtab");
+//!
+//! let names: Vec<&str> = dynsyms
+//!     .iter()
+//!     .map(|sym| dynstrs.get(sym.st_name as usize).expect("should parse"))
+//!     .chain(
+//!         symtab
+//!             .iter()
+//!             .map(|sym| strtab.get(sym.st_name as usize).expect("should parse")),
+//!     )
+//!     .collect();
+//! assert!(names.contains(&"exported_fn"));
+//! assert!(names.contains(&"helper"));
+//! ```
+use crate::endian::EndianParse;
+use crate::parse::ParseError;
+use crate::ElfBytes;
+
+/// Owns the decompressed contents of a `.gnu_debugdata` section, which is itself a
+/// complete ELF object. Use [MiniDebugInfo::elf] to get an [ElfBytes] parser over it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiniDebugInfo {
+    data: Vec<u8>,
+}
+
+impl MiniDebugInfo {
+    /// Decompress the raw xz-compressed contents of a `.gnu_debugdata` section.
+    ///
+    /// Returns a [ParseError::LzmaError] if the data isn't a valid xz stream.
+    pub fn decompress(compressed: &[u8]) -> Result<Self, ParseError> {
+        let mut input = compressed;
+        let mut data = Vec::new();
+        lzma_rs::xz_decompress(&mut input, &mut data)?;
+        Ok(MiniDebugInfo { data })
+    }
+
+    /// Get the decompressed ELF object bytes.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get a zero-copy [ElfBytes] parser for the decompressed ELF object.
+    pub fn elf<E: EndianParse>(&self) -> Result<ElfBytes<'_, E>, ParseError> {
+        ElfBytes::minimal_parse(&self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi;
+    use crate::endian::AnyEndian;
+
+    #[test]
+    fn decompress_gnu_debugdata() {
+        let path = std::path::PathBuf::from("sample-objects/minidebuginfo.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        let mdi = file
+            .minidebuginfo()
+            .expect("should decompress")
+            .expect("file should have .gnu_debugdata");
+        let inner = mdi.elf::<AnyEndian>().expect("should parse");
+        assert_eq!(inner.ehdr.e_machine, abi::EM_X86_64);
+
+        let (symtab, strtab) = inner
+            .symbol_table()
+            .expect("should parse")
+            .expect("should have .symtab");
+        let helper = symtab
+            .iter()
+            .find(|sym| strtab.get(sym.st_name as usize).unwrap() == "helper")
+            .expect("should have helper symbol");
+        assert_eq!(helper.st_symtype(), abi::STT_FUNC);
+        assert_eq!(helper.st_value, 0x1139);
+        assert_eq!(helper.st_size, 5);
+    }
+
+    #[test]
+    fn no_gnu_debugdata() {
+        let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+
+        assert_eq!(file.minidebuginfo().expect("should parse"), None);
+    }
+
+    #[test]
+    fn decompress_bad_data() {
+        let err = MiniDebugInfo::decompress(&[0x7f, b'E', b'L', b'F'])
+            .expect_err("should fail to decompress");
+        assert!(
+            matches!(err, ParseError::LzmaError(_)),
+            "Unexpected Error type found: {err}"
+        );
+    }
+}
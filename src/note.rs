@@ -184,6 +184,90 @@ impl NoteAny<'_> {
     }
 }
 
+/// A single program property found in the desc of a
+/// [NT_GNU_PROPERTY_TYPE_0](abi::NT_GNU_PROPERTY_TYPE_0) note, such as
+/// [GNU_PROPERTY_X86_FEATURE_1_AND](abi::GNU_PROPERTY_X86_FEATURE_1_AND).
+///
+/// (see: <https://raw.githubusercontent.com/wiki/hjl-tools/linux-abi/linux-abi-draft.pdf>)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GnuProperty<'data> {
+    pub pr_type: u32,
+    pub data: &'data [u8],
+}
+
+impl GnuProperty<'_> {
+    /// Interpret the property data as the 4-byte bitmask used by the `*_FEATURE_1_AND` properties
+    pub fn bitmask<E: EndianParse>(&self, endian: E) -> Result<u32, ParseError> {
+        let mut offset = 0;
+        endian.parse_u32_at(&mut offset, self.data)
+    }
+}
+
+/// An iterator over the [GnuProperty]s in the desc of a
+/// [NT_GNU_PROPERTY_TYPE_0](abi::NT_GNU_PROPERTY_TYPE_0) note.
+///
+/// Each property's data is padded to 8 bytes in 64-bit objects and 4 bytes in 32-bit objects.
+/// Iteration stops after yielding the error for a malformed property.
+#[derive(Debug)]
+pub struct GnuPropertyIterator<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    data: &'data [u8],
+    offset: usize,
+}
+
+impl<'data, E: EndianParse> GnuPropertyIterator<'data, E> {
+    pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        GnuPropertyIterator {
+            endian,
+            class,
+            data,
+            offset: 0,
+        }
+    }
+
+    fn parse_next(&mut self) -> Result<GnuProperty<'data>, ParseError> {
+        let pr_type = self.endian.parse_u32_at(&mut self.offset, self.data)?;
+        let pr_datasz: usize = self
+            .endian
+            .parse_u32_at(&mut self.offset, self.data)?
+            .try_into()?;
+        let start = self.offset;
+        let end = start
+            .checked_add(pr_datasz)
+            .ok_or(ParseError::IntegerOverflow)?;
+        let data = self.data.get_bytes(start..end)?;
+
+        let align = match self.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        self.offset = end;
+        if self.offset % align > 0 {
+            self.offset = self
+                .offset
+                .checked_add(align - self.offset % align)
+                .ok_or(ParseError::IntegerOverflow)?;
+        }
+        Ok(GnuProperty { pr_type, data })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for GnuPropertyIterator<'data, E> {
+    type Item = Result<GnuProperty<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        let property = self.parse_next();
+        if property.is_err() {
+            self.offset = self.data.len();
+        }
+        Some(property)
+    }
+}
+
 #[derive(Debug)]
 pub struct NoteIterator<'data, E: EndianParse> {
     endian: E,
@@ -389,6 +473,60 @@ mod parse_tests {
         assert_eq!(offset, 32);
     }
 
+    #[test]
+    fn parse_gnu_properties() {
+        let desc = [
+            0x2, 0x0, 0x0, 0xc0, 0x4, 0x0, 0x0, 0x0, // x86 feature_1_and, pr_datasz 4
+            0x3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // IBT | SHSTK + 4 pad for alignment
+            0x2, 0x80, 0x0, 0xc0, 0x4, 0x0, 0x0, 0x0, // x86 isa_1_needed, pr_datasz 4
+            0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // baseline + 4 pad for alignment
+        ];
+
+        let mut props = GnuPropertyIterator::new(LittleEndian, Class::ELF64, &desc);
+        let prop = props
+            .next()
+            .expect("Should have a property")
+            .expect("Failed to parse");
+        assert_eq!(prop.pr_type, abi::GNU_PROPERTY_X86_FEATURE_1_AND);
+        assert_eq!(
+            prop.bitmask(LittleEndian).expect("Failed to parse"),
+            abi::GNU_PROPERTY_X86_FEATURE_1_IBT | abi::GNU_PROPERTY_X86_FEATURE_1_SHSTK
+        );
+        assert_eq!(
+            props.next().transpose().expect("Failed to parse"),
+            Some(GnuProperty {
+                pr_type: 0xc0008002,
+                data: &[0x1, 0x0, 0x0, 0x0],
+            })
+        );
+        assert!(props.next().is_none());
+
+        // 32-bit objects only pad to 4 bytes
+        let desc = [
+            0x2, 0x0, 0x0, 0xc0, 0x4, 0x0, 0x0, 0x0, 0x3, 0x0, 0x0, 0x0, // feature_1_and
+            0x2, 0x80, 0x0, 0xc0, 0x4, 0x0, 0x0, 0x0, 0x1, 0x0, 0x0, 0x0, // isa_1_needed
+        ];
+        let mut props = GnuPropertyIterator::new(LittleEndian, Class::ELF32, &desc);
+        assert_eq!(
+            props.next().map(|prop| prop.unwrap().pr_type),
+            Some(abi::GNU_PROPERTY_X86_FEATURE_1_AND)
+        );
+        assert_eq!(
+            props.next().map(|prop| prop.unwrap().pr_type),
+            Some(0xc0008002)
+        );
+        assert!(props.next().is_none());
+
+        // A pr_datasz past the end of the desc is an error, which ends the iteration
+        let desc = [0x2, 0x0, 0x0, 0xc0, 0x8, 0x0, 0x0, 0x0, 0x3, 0x0, 0x0, 0x0];
+        let mut props = GnuPropertyIterator::new(LittleEndian, Class::ELF32, &desc);
+        assert!(matches!(
+            props.next(),
+            Some(Err(ParseError::SliceReadError((8, 16))))
+        ));
+        assert!(props.next().is_none());
+    }
+
     #[test]
     fn parse_note_for_elf64_expects_nhdr32() {
         let data = [
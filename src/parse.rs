@@ -53,10 +53,35 @@ pub enum ParseError {
     /// to represent in the native machine's usize type for in-memory processing.
     /// This could be the case when processessing large 64-bit files on a 32-bit machine.
     TryFromIntError(core::num::TryFromIntError),
+    /// Returned when parsing an encoded pointer (e.g. in `.eh_frame`) whose
+    /// `DW_EH_PE_*` encoding we don't know how to interpret.
+    UnsupportedPointerEncoding(u8),
+    /// Returned when parsing an ARM EHABI compact model unwind entry whose
+    /// personality routine index isn't one of the ARM-defined routines.
+    UnsupportedPersonality(u8),
+    /// Returned when applying a relocation whose `(e_machine, r_type)` we don't
+    /// know how to apply.
+    UnsupportedRelocation((u16, u32)),
+    /// Returned when applying a relocation whose computed value doesn't fit in
+    /// (or isn't suitably aligned for) the field being relocated.
+    /// Contains the relocation's `(r_type, r_offset)`.
+    RelocationOverflow((u32, u64)),
+    /// Returned when a relocation refers to a symbol whose address couldn't be resolved.
+    UnresolvedSymbol(u32),
+    /// Returned when loading a fixed-position ([ET_EXEC](crate::abi::ET_EXEC)) image anywhere
+    /// other than the address it was linked at. Contains the `(base, min_vaddr)`.
+    UnexpectedLoadAddress((u64, u64)),
+    /// Returned when parsing an `ar` archive whose magic or member header at the
+    /// contained file offset was malformed.
+    BadArchiveHeader(u64),
     #[cfg(feature = "std")]
     /// Returned when parsing an ELF structure out of an io stream encountered
     /// an io error.
     IOError(std::io::Error),
+    #[cfg(feature = "minidebuginfo")]
+    /// Returned when decompressing the xz-compressed contents of a `.gnu_debugdata`
+    /// section failed.
+    LzmaError(lzma_rs::error::Error),
 }
 
 #[cfg(feature = "std")]
@@ -78,7 +103,16 @@ impl std::error::Error for ParseError {
             ParseError::Utf8Error(ref err) => Some(err),
             ParseError::TryFromSliceError(ref err) => Some(err),
             ParseError::TryFromIntError(ref err) => Some(err),
+            ParseError::UnsupportedPointerEncoding(_) => None,
+            ParseError::UnsupportedPersonality(_) => None,
+            ParseError::UnsupportedRelocation(_) => None,
+            ParseError::RelocationOverflow(_) => None,
+            ParseError::UnresolvedSymbol(_) => None,
+            ParseError::UnexpectedLoadAddress(_) => None,
+            ParseError::BadArchiveHeader(_) => None,
             ParseError::IOError(ref err) => Some(err),
+            #[cfg(feature = "minidebuginfo")]
+            ParseError::LzmaError(ref err) => Some(err),
         }
     }
 }
@@ -102,6 +136,13 @@ impl core::error::Error for ParseError {
             ParseError::Utf8Error(ref err) => Some(err),
             ParseError::TryFromSliceError(ref err) => Some(err),
             ParseError::TryFromIntError(ref err) => Some(err),
+            ParseError::UnsupportedPointerEncoding(_) => None,
+            ParseError::UnsupportedPersonality(_) => None,
+            ParseError::UnsupportedRelocation(_) => None,
+            ParseError::RelocationOverflow(_) => None,
+            ParseError::UnresolvedSymbol(_) => None,
+            ParseError::UnexpectedLoadAddress(_) => None,
+            ParseError::BadArchiveHeader(_) => None,
         }
     }
 }
@@ -166,8 +207,43 @@ impl core::fmt::Display for ParseError {
             ParseError::Utf8Error(ref err) => err.fmt(f),
             ParseError::TryFromSliceError(ref err) => err.fmt(f),
             ParseError::TryFromIntError(ref err) => err.fmt(f),
+            ParseError::UnsupportedPointerEncoding(encoding) => {
+                write!(f, "Unsupported pointer encoding: {encoding:#X}")
+            }
+            ParseError::UnsupportedPersonality(index) => {
+                write!(
+                    f,
+                    "Unsupported compact model personality routine index: {index}"
+                )
+            }
+            ParseError::UnsupportedRelocation((machine, r_type)) => {
+                write!(
+                    f,
+                    "Unsupported relocation type {r_type} for machine {machine}"
+                )
+            }
+            ParseError::RelocationOverflow((r_type, offset)) => {
+                write!(
+                    f,
+                    "Relocation of type {r_type} at offset {offset:#X} does not fit its field"
+                )
+            }
+            ParseError::UnresolvedSymbol(index) => {
+                write!(f, "Could not resolve the address of symbol {index}")
+            }
+            ParseError::UnexpectedLoadAddress((base, min_vaddr)) => {
+                write!(
+                    f,
+                    "Fixed-position image linked at {min_vaddr:#X} cannot be loaded at {base:#X}"
+                )
+            }
+            ParseError::BadArchiveHeader(offset) => {
+                write!(f, "Invalid ar archive header at offset {offset:#X}")
+            }
             #[cfg(feature = "std")]
             ParseError::IOError(ref err) => err.fmt(f),
+            #[cfg(feature = "minidebuginfo")]
+            ParseError::LzmaError(ref err) => err.fmt(f),
         }
     }
 }
@@ -197,6 +273,13 @@ impl From<std::io::Error> for ParseError {
     }
 }
 
+#[cfg(feature = "minidebuginfo")]
+impl From<lzma_rs::error::Error> for ParseError {
+    fn from(err: lzma_rs::error::Error) -> ParseError {
+        ParseError::LzmaError(err)
+    }
+}
+
 /// Trait for safely parsing an ELF structure of a given class (32/64 bit) with
 /// an given endian-awareness at the given offset into the data buffer.
 ///
@@ -230,7 +313,7 @@ pub trait ParseAt: Sized {
 }
 
 /// Lazy-parsing iterator which wraps bytes and parses out a `P: ParseAt` on each `next()`
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ParsingIterator<'data, E: EndianParse, P: ParseAt> {
     endian: E,
     class: Class,
@@ -327,6 +410,38 @@ impl<'data, E: EndianParse, P: ParseAt> IntoIterator for ParsingTable<'data, E,
     }
 }
 
+/// A class-sized word, i.e. a u32 for [Class::ELF32] and a u64 for [Class::ELF64], widened to u64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClassWord(pub u64);
+
+impl From<ClassWord> for u64 {
+    fn from(word: ClassWord) -> Self {
+        word.0
+    }
+}
+
+impl ParseAt for ClassWord {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        match class {
+            Class::ELF32 => Ok(ClassWord(endian.parse_u32_at(offset, data)? as u64)),
+            Class::ELF64 => Ok(ClassWord(endian.parse_u64_at(offset, data)?)),
+        }
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        }
+    }
+}
+
 // Simple convenience extension trait to wrap get() with .ok_or(SliceReadError)
 pub(crate) trait ReadBytesExt<'data> {
     fn get_bytes(self, range: Range<usize>) -> Result<&'data [u8], ParseError>;
@@ -341,6 +456,66 @@ impl<'data> ReadBytesExt<'data> for &'data [u8] {
     }
 }
 
+/// Parse an unsigned LEB128-encoded integer at the given offset, advancing the offset past it.
+///
+/// Returns [ParseError::IntegerOverflow] if the encoded value doesn't fit in a u64.
+pub(crate) fn parse_uleb128(offset: &mut usize, data: &[u8]) -> Result<u64, ParseError> {
+    let mut result: u64 = 0;
+    let mut shift: u32 = 0;
+    loop {
+        let byte = *data.get(*offset).ok_or(ParseError::SliceReadError((
+            *offset,
+            offset.saturating_add(1),
+        )))?;
+        *offset += 1;
+
+        let low_bits = (byte & 0x7f) as u64;
+        if shift >= 64 || (low_bits << shift) >> shift != low_bits {
+            return Err(ParseError::IntegerOverflow);
+        }
+        result |= low_bits << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+}
+
+/// Parse a signed LEB128-encoded integer at the given offset, advancing the offset past it.
+///
+/// Returns [ParseError::IntegerOverflow] if the encoded value doesn't fit in an i64.
+pub(crate) fn parse_sleb128(offset: &mut usize, data: &[u8]) -> Result<i64, ParseError> {
+    let mut result: i64 = 0;
+    let mut shift: u32 = 0;
+    loop {
+        let byte = *data.get(*offset).ok_or(ParseError::SliceReadError((
+            *offset,
+            offset.saturating_add(1),
+        )))?;
+        *offset += 1;
+
+        if shift >= 64 {
+            return Err(ParseError::IntegerOverflow);
+        }
+        let low_bits = (byte & 0x7f) as i64;
+        // The final byte at shift 63 can only carry the sign bit
+        if shift == 63 && low_bits != 0 && low_bits != 0x7f {
+            return Err(ParseError::IntegerOverflow);
+        }
+        result |= low_bits << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            // Sign-extend from the last byte's sign bit
+            if shift < 64 && byte & 0x40 != 0 {
+                result |= -1i64 << shift;
+            }
+            return Ok(result);
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) fn test_parse_for<E: EndianParse, P: ParseAt + core::fmt::Debug + PartialEq>(
     endian: E,
@@ -378,6 +553,60 @@ pub(crate) fn test_parse_fuzz_too_short<E: EndianParse, P: ParseAt + core::fmt::
     }
 }
 
+#[cfg(test)]
+mod leb128_tests {
+    use super::*;
+
+    #[test]
+    fn parse_uleb128_values() {
+        let data = [0x02, 0x7f, 0x80, 0x01, 0xe5, 0x8e, 0x26];
+        let mut offset = 0;
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 2);
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 127);
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 128);
+        assert_eq!(parse_uleb128(&mut offset, &data).unwrap(), 624485);
+        assert_eq!(offset, data.len());
+    }
+
+    #[test]
+    fn parse_sleb128_values() {
+        let data = [0x02, 0x7e, 0xff, 0x00, 0x80, 0x7f, 0xc0, 0xbb, 0x78];
+        let mut offset = 0;
+        assert_eq!(parse_sleb128(&mut offset, &data).unwrap(), 2);
+        assert_eq!(parse_sleb128(&mut offset, &data).unwrap(), -2);
+        assert_eq!(parse_sleb128(&mut offset, &data).unwrap(), 127);
+        assert_eq!(parse_sleb128(&mut offset, &data).unwrap(), -128);
+        assert_eq!(parse_sleb128(&mut offset, &data).unwrap(), -123456);
+        assert_eq!(offset, data.len());
+
+        let min = [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f];
+        let mut offset = 0;
+        assert_eq!(parse_sleb128(&mut offset, &min).unwrap(), i64::MIN);
+    }
+
+    #[test]
+    fn parse_uleb128_truncated() {
+        let data = [0x80, 0x80];
+        let mut offset = 0;
+        let error = parse_uleb128(&mut offset, &data).expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::SliceReadError((2, 3))),
+            "Unexpected Error type found: {error}"
+        );
+    }
+
+    #[test]
+    fn parse_uleb128_overflow() {
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f];
+        let mut offset = 0;
+        let error = parse_uleb128(&mut offset, &data).expect_err("Expected an error");
+        assert!(
+            matches!(error, ParseError::IntegerOverflow),
+            "Unexpected Error type found: {error}"
+        );
+    }
+}
+
 #[cfg(test)]
 mod read_bytes_tests {
     use super::ParseError;
@@ -427,6 +656,26 @@ mod parsing_table_tests {
         assert_eq!(result, 0x05040302);
     }
 
+    #[test]
+    fn test_u64_and_class_word_parse_at() {
+        // A u64 is always 8 bytes, while a ClassWord follows the object's class
+        let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7];
+        let mut offset = 0;
+        let result = u64::parse_at(LittleEndian, Class::ELF32, &mut offset, data.as_ref())
+            .expect("Expected to parse but:");
+        assert_eq!((result, offset), (0x0706050403020100, 8));
+
+        let mut offset = 0;
+        let result = ClassWord::parse_at(LittleEndian, Class::ELF32, &mut offset, data.as_ref())
+            .expect("Expected to parse but:");
+        assert_eq!((result, offset), (ClassWord(0x03020100), 4));
+
+        let mut offset = 0;
+        let result = ClassWord::parse_at(LittleEndian, Class::ELF64, &mut offset, data.as_ref())
+            .expect("Expected to parse but:");
+        assert_eq!((result, offset), (ClassWord(0x0706050403020100), 8));
+    }
+
     #[test]
     fn test_u32_table_len() {
         let data = vec![0u8, 1, 2, 3, 4, 5, 6, 7];
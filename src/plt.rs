@@ -0,0 +1,623 @@
+//! Mapping Procedure Linkage Table stubs to the imported symbols they call, as in `foo@plt`
+//!
+//! Calls to imported functions go through a small PLT stub which jumps to the address stored in
+//! the function's GOT slot, and the dynamic linker fills in that slot by applying the `JUMP_SLOT`
+//! relocation which targets it. [PltEntryIterator] decodes the stubs of a single section to find
+//! the GOT slot each one jumps through, and [PltStubIterator] matches those up with the
+//! relocations in [DT_JMPREL](abi::DT_JMPREL) to name each stub.
+//!
+//! Supported layouts:
+//!
+//! * x86_64: `.plt`, the IBT `.plt.sec` and `.plt.got`
+//! * AArch64, including BTI landing pads: `.plt`
+//! * RISC-V: `.plt`
+//! * ARM: `.plt`
+//! * PPC64: the `plt_call` stubs the linker places in `.text`, `.init` and `.fini`, and the
+//!   global entry stubs in `.glink`, for both ELFv1 and ELFv2
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::gnu_symver::SymbolVersionTable;
+use crate::parse::{ParseError, ReadBytesExt};
+use crate::relocation::{RelIterator, RelaIterator};
+use crate::section::{SectionHeader, SectionHeaderTable};
+use crate::string_table::StringTable;
+use crate::symbol::SymbolTable;
+
+/// A decoded PLT stub and the GOT slot it jumps through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PltEntry {
+    /// The virtual address of the first instruction of the stub
+    pub address: u64,
+    /// The virtual address of the GOT slot holding the stub's target
+    pub got_address: u64,
+}
+
+/// Iterator over the PLT stubs found in one section's instructions for a given `e_machine`
+#[derive(Debug, Clone)]
+pub struct PltEntryIterator<'data, E: EndianParse> {
+    endian: E,
+    e_machine: u16,
+    address: u64,
+    data: &'data [u8],
+    offset: usize,
+    toc: u64,
+}
+
+impl<'data, E: EndianParse> PltEntryIterator<'data, E> {
+    /// Construct an iterator over the stubs in `data`, the contents of a section which is
+    /// loaded at virtual address `address`.
+    pub fn new(endian: E, e_machine: u16, address: u64, data: &'data [u8]) -> Self {
+        PltEntryIterator {
+            endian,
+            e_machine,
+            address,
+            data,
+            offset: 0,
+            toc: 0,
+        }
+    }
+
+    /// Set the TOC pointer (`r2`) value which PPC64 stubs address their GOT slots relative to.
+    /// This is conventionally the address of the `.got` section plus 0x8000.
+    pub fn with_toc_base(self, toc: u64) -> Self {
+        PltEntryIterator { toc, ..self }
+    }
+
+    fn word(&self, offset: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self
+            .data
+            .get(offset..offset.checked_add(4)?)?
+            .try_into()
+            .ok()?;
+        match self.e_machine == abi::EM_PPC64 && self.endian.is_big() {
+            true => Some(u32::from_be_bytes(bytes)),
+            false => Some(u32::from_le_bytes(bytes)),
+        }
+    }
+
+    fn pc(&self, offset: usize) -> u64 {
+        self.address.wrapping_add(offset as u64)
+    }
+
+    /// Decode the stub at `offset`, returning its GOT slot address and length if there is one.
+    fn decode(&self, offset: usize) -> Option<(u64, usize)> {
+        match self.e_machine {
+            abi::EM_X86_64 => self.decode_x86_64(offset),
+            abi::EM_AARCH64 => self.decode_aarch64(offset),
+            abi::EM_RISCV => self.decode_riscv(offset),
+            abi::EM_ARM => self.decode_arm(offset),
+            abi::EM_PPC64 => self.decode_ppc64(offset),
+            _ => None,
+        }
+    }
+
+    fn decode_x86_64(&self, offset: usize) -> Option<(u64, usize)> {
+        // [endbr64] [bnd] jmp *disp32(%rip)
+        let mut insn = offset;
+        if self.data.get(insn..insn + 4)? == [0xf3, 0x0f, 0x1e, 0xfa] {
+            insn += 4;
+        }
+        if *self.data.get(insn)? == 0xf2 {
+            insn += 1;
+        }
+        if self.data.get(insn..insn + 2)? != [0xff, 0x25] {
+            return None;
+        }
+        let disp: [u8; 4] = self.data.get(insn + 2..insn + 6)?.try_into().ok()?;
+        let mut end = insn + 6;
+        let got = self
+            .pc(end)
+            .wrapping_add(i32::from_le_bytes(disp) as i64 as u64);
+        // Lazy .plt entries go on to push $n; jmp .plt
+        if self.data.get(end) == Some(&0x68) {
+            end += 10;
+        }
+        // The rest is nop padding: IBT entries are 16 bytes, while .plt.got entries are 8
+        let align = match insn - offset {
+            0 | 1 => 8,
+            _ => 16,
+        };
+        Some((got, (end - offset).next_multiple_of(align)))
+    }
+
+    fn decode_aarch64(&self, offset: usize) -> Option<(u64, usize)> {
+        // [bti c] adrp x16, page; ldr x17, [x16, lo12]
+        let mut insn = offset;
+        if self.word(insn)? == 0xd503245f {
+            insn += 4;
+        }
+        let adrp = self.word(insn)?;
+        let ldr = self.word(insn + 4)?;
+        if adrp & 0x9f00001f != 0x90000010 || ldr & 0xffc003ff != 0xf9400211 {
+            return None;
+        }
+        let immlo = ((adrp >> 29) & 0x3) as u64;
+        let immhi = ((adrp >> 5) & 0x7ffff) as u64;
+        let pages = sign_extend((immhi << 2) | immlo, 21) << 12;
+        let lo12 = (((ldr >> 10) & 0xfff) as u64) * 8;
+        let got = (self.pc(insn) & !0xfff)
+            .wrapping_add(pages)
+            .wrapping_add(lo12);
+        Some((got, insn - offset + 16))
+    }
+
+    fn decode_riscv(&self, offset: usize) -> Option<(u64, usize)> {
+        // auipc t3, hi; ld/lw t3, lo(t3); jalr t1, t3; [nop]
+        const T3: u32 = 28;
+        let auipc = self.word(offset)?;
+        let load = self.word(offset + 4)?;
+        if auipc & 0xfff != 0x17 | (T3 << 7)
+            || load & 0x7f != 0x03
+            || (load >> 7) & 0x1f != T3
+            || (load >> 15) & 0x1f != T3
+            || self.word(offset + 8)? != 0x000e0367
+        {
+            return None;
+        }
+        let hi = sign_extend((auipc & 0xfffff000) as u64, 32);
+        let lo = sign_extend((load >> 20) as u64, 12);
+        let got = self.pc(offset).wrapping_add(hi).wrapping_add(lo);
+        match self.word(offset + 12) {
+            Some(0x00000013) => Some((got, 16)),
+            _ => Some((got, 12)),
+        }
+    }
+
+    fn decode_arm(&self, offset: usize) -> Option<(u64, usize)> {
+        // add ip, pc, #imm; [add ip, ip, #imm]...; ldr pc, [ip, #imm]!
+        let first = self.word(offset)?;
+        if first & 0xfffff000 != 0xe28fc000 {
+            return None;
+        }
+        let mut got = (self.pc(offset) as u32)
+            .wrapping_add(8)
+            .wrapping_add(arm_immediate(first));
+        let mut insn = offset + 4;
+        loop {
+            let word = self.word(insn)?;
+            insn += 4;
+            match word & 0xfffff000 {
+                0xe28cc000 => got = got.wrapping_add(arm_immediate(word)),
+                0xe5bcf000 => {
+                    let got = got.wrapping_add(word & 0xfff);
+                    return Some((got as u64, insn - offset));
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn decode_ppc64(&self, offset: usize) -> Option<(u64, usize)> {
+        // [std r2, 24|40(r1)] [addis rX, r2, hi] ld r12, lo(r2|rX); mtctr r12
+        let mut insn = offset;
+        if matches!(self.word(insn)?, 0xf8410018 | 0xf8410028) {
+            insn += 4;
+        }
+        let mut base = 2;
+        let mut got = self.toc;
+        let word = self.word(insn)?;
+        if word & 0xfc1f0000 == 0x3c020000 {
+            base = (word >> 21) & 0x1f;
+            got = got.wrapping_add(sign_extend((word & 0xffff) as u64, 16) << 16);
+            insn += 4;
+        }
+        let ld = self.word(insn)?;
+        if ld & 0xffff0003 != 0xe9800000 | (base << 16) || self.word(insn + 4)? != 0x7d8903a6 {
+            return None;
+        }
+        let got = got.wrapping_add(sign_extend((ld & 0xfffc) as u64, 16));
+        Some((got, insn + 8 - offset))
+    }
+}
+
+impl<E: EndianParse> Iterator for PltEntryIterator<'_, E> {
+    type Item = PltEntry;
+    fn next(&mut self) -> Option<Self::Item> {
+        // x86_64 stubs are aligned 16-byte entries, while the others are found at any instruction
+        let step = match self.e_machine {
+            abi::EM_X86_64 => 16,
+            _ => 4,
+        };
+        while self.offset < self.data.len() {
+            let offset = self.offset;
+            match self.decode(offset) {
+                Some((got_address, len)) => {
+                    self.offset += len;
+                    return Some(PltEntry {
+                        address: self.pc(offset),
+                        got_address,
+                    });
+                }
+                None => self.offset += step,
+            }
+        }
+        None
+    }
+}
+
+fn sign_extend(value: u64, bits: u32) -> u64 {
+    let shift = 64 - bits;
+    (((value << shift) as i64) >> shift) as u64
+}
+
+/// Decode an ARM data-processing instruction's rotated 8-bit immediate.
+fn arm_immediate(insn: u32) -> u32 {
+    let rotate = ((insn >> 8) & 0xf) * 2;
+    (insn & 0xff).rotate_right(rotate)
+}
+
+/// A PLT stub and the imported symbol that it calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PltStub<'data> {
+    /// The virtual address of the stub
+    pub address: u64,
+    /// The virtual address of the GOT slot the stub jumps through
+    pub got_address: u64,
+    /// The index of the called symbol in the dynamic symbol table
+    pub sym_idx: usize,
+    /// The name of the called symbol
+    pub name: &'data str,
+    /// The name of the symbol's GNU symbol version (if any)
+    pub version: Option<&'data str>,
+}
+
+/// The relocations which fill in the GOT slots that PLT stubs jump through
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PltRelocations<'data> {
+    Rel(&'data [u8]),
+    Rela(&'data [u8]),
+}
+
+/// Iterator over the PLT stubs of an object, named by the symbol that each stub calls
+///
+/// Stubs whose GOT slot isn't targeted by a `JUMP_SLOT` relocation (or, for x86_64's
+/// `.plt.got`, a `GLOB_DAT` relocation) are skipped. A section header, symbol or version which
+/// can't be parsed is yielded as an error, and iteration carries on with the next section or
+/// stub.
+#[derive(Debug)]
+pub struct PltStubIterator<'data, E: EndianParse> {
+    endian: E,
+    e_machine: u16,
+    file_data: &'data [u8],
+    shdrs: SectionHeaderTable<'data, E>,
+    shstrtab: StringTable<'data>,
+    shdr_index: usize,
+    entries: Option<PltEntryIterator<'data, E>>,
+    toc: u64,
+    /// The symbol index of the relocation filling in each GOT slot, by the slot's address
+    symbols: BTreeMap<u64, u32>,
+    dynsyms: SymbolTable<'data, E>,
+    dynstrs: StringTable<'data>,
+    versions: Option<SymbolVersionTable<'data, E>>,
+}
+
+impl<'data, E: EndianParse> PltStubIterator<'data, E> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        endian: E,
+        class: Class,
+        e_machine: u16,
+        file_data: &'data [u8],
+        (shdrs, shstrtab): (SectionHeaderTable<'data, E>, StringTable<'data>),
+        relocations: PltRelocations<'data>,
+        glob_dats: &'data [u8],
+        (dynsyms, dynstrs): (SymbolTable<'data, E>, StringTable<'data>),
+        versions: Option<SymbolVersionTable<'data, E>>,
+    ) -> Self {
+        // PPC64 stubs address the GOT relative to the TOC pointer
+        let toc = shdrs
+            .iter()
+            .find(|shdr| matches!(shstrtab.get(shdr.sh_name as usize), Ok(".got")))
+            .map_or(0, |shdr| shdr.sh_addr.wrapping_add(0x8000));
+
+        let mut symbols = BTreeMap::new();
+        match relocations {
+            PltRelocations::Rela(data) => {
+                for rela in RelaIterator::new(endian, class, data) {
+                    symbols.entry(rela.r_offset).or_insert(rela.r_sym);
+                }
+            }
+            PltRelocations::Rel(data) => {
+                for rel in RelIterator::new(endian, class, data) {
+                    symbols.entry(rel.r_offset).or_insert(rel.r_sym);
+                }
+            }
+        }
+        for rela in RelaIterator::new(endian, class, glob_dats) {
+            if rela.r_type == abi::R_X86_64_GLOB_DAT {
+                symbols.entry(rela.r_offset).or_insert(rela.r_sym);
+            }
+        }
+
+        PltStubIterator {
+            endian,
+            e_machine,
+            file_data,
+            shdrs,
+            shstrtab,
+            shdr_index: 0,
+            entries: None,
+            toc,
+            symbols,
+            dynsyms,
+            dynstrs,
+            versions,
+        }
+    }
+
+    /// Whether PLT stubs can be decoded for the given `e_machine`
+    pub fn supports(e_machine: u16) -> bool {
+        matches!(
+            e_machine,
+            abi::EM_X86_64 | abi::EM_AARCH64 | abi::EM_RISCV | abi::EM_ARM | abi::EM_PPC64
+        )
+    }
+
+    /// Whether the given section holds PLT stubs for this machine
+    fn is_plt_section(&self, shdr: &SectionHeader) -> bool {
+        if shdr.sh_type != abi::SHT_PROGBITS || shdr.sh_flags & abi::SHF_EXECINSTR as u64 == 0 {
+            return false;
+        }
+        let name = self.shstrtab.get(shdr.sh_name as usize).unwrap_or("");
+        match self.e_machine {
+            abi::EM_X86_64 => matches!(name, ".plt" | ".plt.sec" | ".plt.got"),
+            abi::EM_AARCH64 | abi::EM_RISCV | abi::EM_ARM => name == ".plt",
+            // The linker places plt_call stubs alongside the code calling them
+            abi::EM_PPC64 => matches!(name, ".text" | ".init" | ".fini" | ".glink"),
+            _ => false,
+        }
+    }
+
+    /// Advance to the next section holding PLT stubs (if any).
+    fn next_section(&mut self) -> Option<Result<PltEntryIterator<'data, E>, ParseError>> {
+        while self.shdr_index < self.shdrs.len() {
+            let index = self.shdr_index;
+            self.shdr_index += 1;
+            let shdr = match self.shdrs.get(index) {
+                Ok(shdr) => shdr,
+                Err(err) => return Some(Err(err)),
+            };
+            if !self.is_plt_section(&shdr) {
+                continue;
+            }
+            let data = shdr
+                .get_data_range()
+                .and_then(|(start, end)| self.file_data.get_bytes(start..end));
+            return Some(data.map(|data| {
+                PltEntryIterator::new(self.endian, self.e_machine, shdr.sh_addr, data)
+                    .with_toc_base(self.toc)
+            }));
+        }
+        None
+    }
+
+    fn stub(&self, entry: PltEntry, sym_idx: u32) -> Result<PltStub<'data>, ParseError> {
+        let sym_idx = sym_idx as usize;
+        let sym = self.dynsyms.get(sym_idx)?;
+        let name = self.dynstrs.get(sym.st_name as usize)?;
+        let version = match self.versions {
+            Some(ref versions) => versions.get_version_name(sym_idx)?,
+            None => None,
+        };
+        Ok(PltStub {
+            address: entry.address,
+            got_address: entry.got_address,
+            sym_idx,
+            name,
+            version,
+        })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for PltStubIterator<'data, E> {
+    type Item = Result<PltStub<'data>, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entries = match self.entries {
+                Some(ref mut entries) => entries,
+                None => {
+                    self.entries = Some(match self.next_section()? {
+                        Ok(entries) => entries,
+                        Err(err) => return Some(Err(err)),
+                    });
+                    continue;
+                }
+            };
+            let entry = match entries.next() {
+                Some(entry) => entry,
+                None => {
+                    self.entries = None;
+                    continue;
+                }
+            };
+            if let Some(sym_idx) = self.symbols.get(&entry.got_address) {
+                return Some(self.stub(entry, *sym_idx));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod plt_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    fn stubs(path: &str) -> Vec<(u64, String, Option<String>)> {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        file.plt_stubs()
+            .expect("should parse")
+            .expect("should have PLT stubs")
+            .map(|stub| {
+                let stub = stub.expect("stub should parse");
+                (
+                    stub.address,
+                    stub.name.to_string(),
+                    stub.version.map(str::to_string),
+                )
+            })
+            .collect()
+    }
+
+    fn stub(address: u64, name: &str, version: Option<&str>) -> (u64, String, Option<String>) {
+        (address, name.to_string(), version.map(str::to_string))
+    }
+
+    #[test]
+    fn x86_64_ibt() {
+        assert_eq!(
+            stubs("sample-objects/symver.x86_64.so"),
+            [
+                stub(0x1040, "__cxa_finalize", Some("GLIBC_2.2.5")),
+                stub(0x1050, "memset", Some("GLIBC_2.2.5")),
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_section_header() {
+        let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+        let mut file_data = std::fs::read(path).expect("Could not read file.");
+        // Point .plt.got's sh_offset past the end of the file
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let sh_offset = file.ehdr.e_shoff as usize + 14 * 64 + 24;
+        file_data[sh_offset..sh_offset + 8].copy_from_slice(&0x100000u64.to_le_bytes());
+
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let mut stubs = file.plt_stubs().unwrap().unwrap();
+        assert!(matches!(
+            stubs.next(),
+            Some(Err(ParseError::SliceReadError((0x100000, 0x100010))))
+        ));
+        // The bad section is skipped, and .plt.sec's stub is still found
+        let stub = stubs.next().unwrap().expect("stub should parse");
+        assert_eq!((stub.address, stub.name), (0x1050, "memset"));
+        assert!(stubs.next().is_none());
+    }
+
+    #[test]
+    fn x86_64_plt_got() {
+        // Built without -fcf-protection, so the .plt.got entries are 8 bytes: jmp *GOT(%rip); xchg %ax,%ax
+        assert_eq!(
+            stubs("sample-objects/plt_got.x86_64.so"),
+            [
+                stub(0x1030, "memset", Some("GLIBC_2.2.5")),
+                stub(0x1038, "memcpy", Some("GLIBC_2.14")),
+                stub(0x1040, "__cxa_finalize", Some("GLIBC_2.2.5")),
+            ]
+        );
+    }
+
+    #[test]
+    fn x86_64_lazy() {
+        // A classic .plt: a 16-byte header which isn't a stub, then jmp *GOT(%rip); push $n; jmp .plt
+        #[rustfmt::skip]
+        let data = [
+            0xff, 0x35, 0xe2, 0x2f, 0x00, 0x00, 0xff, 0x25, 0xe4, 0x2f, 0x00, 0x00, 0x0f, 0x1f, 0x40, 0x00,
+            0xff, 0x25, 0xe2, 0x2f, 0x00, 0x00, 0x68, 0x00, 0x00, 0x00, 0x00, 0xe9, 0xe0, 0xff, 0xff, 0xff,
+            0xff, 0x25, 0xda, 0x2f, 0x00, 0x00, 0x68, 0x01, 0x00, 0x00, 0x00, 0xe9, 0xd0, 0xff, 0xff, 0xff,
+        ];
+        let entries: Vec<PltEntry> =
+            PltEntryIterator::new(LittleEndian, abi::EM_X86_64, 0x1020, &data).collect();
+        assert_eq!(
+            entries,
+            [
+                PltEntry {
+                    address: 0x1030,
+                    got_address: 0x4018
+                },
+                PltEntry {
+                    address: 0x1040,
+                    got_address: 0x4020
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn aarch64() {
+        assert_eq!(
+            stubs("sample-objects/symver.aarch64.so"),
+            [
+                stub(0x600, "__cxa_finalize", Some("GLIBC_2.17")),
+                stub(0x610, "memset", Some("GLIBC_2.17")),
+                stub(0x620, "__gmon_start__", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn aarch64_bti() {
+        // bti c; adrp x16, 0x11000; ldr x17, [x16, #8]; add x16, x16, #8; br x17
+        let words: [u32; 5] = [0xd503245f, 0xb0000090, 0xf9400611, 0x91002210, 0xd61f0220];
+        let data: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+        let entries: Vec<PltEntry> =
+            PltEntryIterator::new(LittleEndian, abi::EM_AARCH64, 0x600, &data).collect();
+        assert_eq!(
+            entries,
+            [PltEntry {
+                address: 0x600,
+                got_address: 0x11008
+            }]
+        );
+    }
+
+    #[test]
+    fn riscv64() {
+        assert_eq!(
+            stubs("sample-objects/symver.riscv64.so"),
+            [stub(0x550, "memset", Some("GLIBC_2.27"))]
+        );
+    }
+
+    #[test]
+    fn arm() {
+        assert_eq!(
+            stubs("sample-objects/symver.armhf.so"),
+            [
+                stub(0x3f0, "__cxa_finalize", Some("GLIBC_2.4")),
+                stub(0x3fc, "__gmon_start__", None),
+                stub(0x408, "memset", Some("GLIBC_2.4")),
+            ]
+        );
+    }
+
+    #[test]
+    fn ppc64_elfv2() {
+        assert_eq!(
+            stubs("sample-objects/symver.powerpc64le.so"),
+            [
+                stub(0x5a0, "__gmon_start__", None),
+                stub(0x620, "__cxa_finalize", Some("GLIBC_2.17")),
+                stub(0x640, "memset", Some("GLIBC_2.17")),
+            ]
+        );
+    }
+
+    #[test]
+    fn ppc64_elfv1() {
+        assert_eq!(
+            stubs("sample-objects/symver.powerpc64.so"),
+            [
+                stub(0x720, "__gmon_start__", None),
+                stub(0x7a0, "memset", Some("GLIBC_2.3")),
+                stub(0x7c0, "__cxa_finalize", Some("GLIBC_2.3")),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_machine() {
+        let path = std::path::PathBuf::from("sample-objects/symver.m68k.so");
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        assert!(file.plt_stubs().expect("should parse").is_none());
+    }
+}
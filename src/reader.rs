@@ -0,0 +1,93 @@
+//! A minimal random-access reader interface for the stream-based [ElfStream](crate::ElfStream) parser
+//!
+//! [ReadAt] is all that [ElfStream](crate::ElfStream) needs from its underlying storage: the
+//! ability to fill a buffer with the bytes at a given offset, and the total length. It doesn't
+//! depend on `std`, so firmware and bootloaders can implement it for a block device or a
+//! flash controller and lazily parse ELF objects which are too big to load into RAM.
+//!
+//! With the `std` feature, every `std::io::Read + std::io::Seek` (such as `std::fs::File` or
+//! `std::io::Cursor`) implements [ReadAt].
+//!
+//! Example implementation for a memory-mapped flash region:
+//! ```
+//! use elf::ElfStream;
+//! use elf::ParseError;
+//! use elf::endian::AnyEndian;
+//! use elf::reader::ReadAt;
+//!
+//! struct Flash<'a> {
+//!     region: &'a [u8],
+//! }
+//!
+//! impl ReadAt for Flash<'_> {
+//!     fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<(), ParseError> {
+//!         let start: usize = offset.try_into()?;
+//!         let end = start + buf.len();
+//!         let data = self
+//!             .region
+//!             .get(start..end)
+//!             .ok_or(ParseError::SliceReadError((start, end)))?;
+//!         buf.copy_from_slice(data);
+//!         Ok(())
+//!     }
+//!
+//!     fn size(&mut self) -> Result<u64, ParseError> {
+//!         Ok(self.region.len() as u64)
+//!     }
+//! }
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let flash = Flash { region: &file_data };
+//! let file = ElfStream::<AnyEndian, _>::open_stream(flash).expect("Open test1");
+//! assert_eq!(file.ehdr.e_type, elf::abi::ET_EXEC);
+//! ```
+use crate::parse::ParseError;
+
+/// A source of bytes which can be read at arbitrary offsets
+pub trait ReadAt {
+    /// Fill `buf` with the bytes starting at `offset`.
+    ///
+    /// Returns an error if the whole buffer couldn't be filled, such as when reading past
+    /// the end of the data.
+    fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<(), ParseError>;
+
+    /// Get the total length of the data in bytes.
+    fn size(&mut self) -> Result<u64, ParseError>;
+}
+
+#[cfg(feature = "std")]
+impl<T: std::io::Read + std::io::Seek> ReadAt for T {
+    fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<(), ParseError> {
+        self.seek(std::io::SeekFrom::Start(offset))?;
+        self.read_exact(buf)?;
+        Ok(())
+    }
+
+    fn size(&mut self) -> Result<u64, ParseError> {
+        Ok(self.seek(std::io::SeekFrom::End(0))?)
+    }
+}
+
+#[cfg(test)]
+mod reader_tests {
+    use super::*;
+
+    #[test]
+    fn std_io() {
+        let mut cursor = std::io::Cursor::new(b"\x7fELF\x02\x01".to_vec());
+        assert_eq!(cursor.size().unwrap(), 6);
+
+        let mut buf = [0u8; 3];
+        cursor.read_exact_at(1, &mut buf).unwrap();
+        assert_eq!(&buf, b"ELF");
+        // Reads don't depend on the current position
+        cursor.read_exact_at(0, &mut buf[..1]).unwrap();
+        assert_eq!(buf[0], 0x7f);
+
+        assert!(matches!(
+            cursor.read_exact_at(4, &mut buf),
+            Err(ParseError::IOError(_))
+        ));
+    }
+}
@@ -0,0 +1,945 @@
+//! Applying relocations to the sections of relocatable ([ET_REL](crate::abi::ET_REL)) objects
+//!
+//! A [Relocator] patches a section's bytes in place once the caller has decided where each
+//! section of the object will live, which is what is needed to load a relocatable object
+//! (a plugin, JIT output, kernel module, ...) without running it through a linker.
+//!
+//! Only relocations that can be resolved without synthesizing linker-generated data (GOT
+//! entries, PLT stubs, TLS blocks) are supported. For x86_64, `R_X86_64_GOTPCRELX` and
+//! `R_X86_64_REX_GOTPCRELX` are relaxed to direct PC-relative forms, since every symbol
+//! is known at relocation time.
+//!
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//! use elf::parse::ParseError;
+//! use elf::relocate::{symbol_address, Relocator};
+//!
+//! let path = std::path::PathBuf::from("sample-objects/relocate.x86_64.o");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! // Place .text at 0x1000 and .data at 0x2000, and give the undefined symbols an address
+//! let (shdrs, strtab) = file.section_headers_with_strtab().expect("shdrs should parse");
+//! let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+//! let bases: Vec<u64> = shdrs
+//!     .iter()
+//!     .map(|shdr| match strtab.get(shdr.sh_name as usize).unwrap() {
+//!         ".text" => 0x1000,
+//!         ".data" => 0x2000,
+//!         _ => 0,
+//!     })
+//!     .collect();
+//! let (symtab, symstrs) = file.symbol_table().expect("symtab should parse").unwrap();
+//! let resolve = |index: u32| {
+//!     let sym = symtab.get(index as usize)?;
+//!     symbol_address(&sym, &bases)
+//!         .or_else(|| match symstrs.get(sym.st_name as usize).ok()? {
+//!             "ext_func" => Some(0x3000),
+//!             "ext_var" => Some(0x4000),
+//!             _ => None,
+//!         })
+//!         .ok_or(ParseError::UnresolvedSymbol(index))
+//! };
+//!
+//! let text_shdr = file.section_header_by_name(".text").unwrap().unwrap();
+//! let rela_shdr = file.section_header_by_name(".rela.text").unwrap().unwrap();
+//! let (text, _) = file.section_data(&text_shdr).unwrap();
+//! let mut text = text.to_vec();
+//!
+//! let relocator = Relocator::new(file.ehdr.endianness, file.ehdr.class, file.ehdr.e_machine);
+//! let relas = file.section_data_as_relas(&rela_shdr).unwrap();
+//! relocator
+//!     .apply_relas(&mut text, 0x1000, relas, resolve)
+//!     .expect("relocations should apply");
+//!
+//! // call func: e8 + (0x103a - 0x1005)
+//! assert_eq!(text[0..5], [0xe8, 0x35, 0x00, 0x00, 0x00]);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::parse::ParseError;
+use crate::relocation::{Rel, Rela};
+use crate::symbol::Symbol;
+
+/// Compute the address of a symbol from a relocatable object, given the base address that
+/// each of the object's sections was placed at (indexed by section header index).
+///
+/// Returns `None` for undefined and common symbols, symbols with extended section indexes,
+/// and symbols whose section isn't in `section_bases`. These must be resolved by other means.
+pub fn symbol_address(symbol: &Symbol, section_bases: &[u64]) -> Option<u64> {
+    match symbol.st_shndx {
+        abi::SHN_UNDEF | abi::SHN_COMMON | abi::SHN_XINDEX => None,
+        abi::SHN_ABS => Some(symbol.st_value),
+        shndx => section_bases
+            .get(shndx as usize)
+            .map(|base| base.wrapping_add(symbol.st_value)),
+    }
+}
+
+/// Applies relocations to a section's bytes for a given target machine.
+///
+/// Supported machines are [EM_X86_64](abi::EM_X86_64), [EM_AARCH64](abi::EM_AARCH64) and
+/// [EM_RISCV](abi::EM_RISCV).
+#[derive(Debug, Clone, Copy)]
+pub struct Relocator<E: EndianParse> {
+    endian: E,
+    class: Class,
+    e_machine: u16,
+}
+
+impl<E: EndianParse> Relocator<E> {
+    /// Construct a Relocator for objects of the given endianness, class and `e_machine`.
+    pub fn new(endian: E, class: Class, e_machine: u16) -> Self {
+        Relocator {
+            endian,
+            class,
+            e_machine,
+        }
+    }
+
+    /// Apply the given [Rela] entries to `data`, the contents of the section they relocate,
+    /// which will be loaded at virtual address `address`.
+    ///
+    /// `resolve` is called with each relocation's `r_sym` and returns the symbol's final
+    /// address. It isn't called for `r_sym` 0, which means "no symbol" and has a value of 0.
+    ///
+    /// `relas` is cloned to re-scan the section for the `R_RISCV_PCREL_HI20` that each
+    /// `R_RISCV_PCREL_LO12_*` pairs with, so that no allocation is needed.
+    pub fn apply_relas<I, F>(
+        &self,
+        data: &mut [u8],
+        address: u64,
+        relas: I,
+        mut resolve: F,
+    ) -> Result<(), ParseError>
+    where
+        I: Iterator<Item = Rela> + Clone,
+        F: FnMut(u32) -> Result<u64, ParseError>,
+    {
+        for rela in relas.clone() {
+            if self.e_machine == abi::EM_RISCV
+                && matches!(
+                    rela.r_type,
+                    abi::R_RISCV_PCREL_LO12_I | abi::R_RISCV_PCREL_LO12_S
+                )
+            {
+                // These point at the auipc carrying the matching R_RISCV_PCREL_HI20, and
+                // use the low bits of that relocation's value.
+                let label =
+                    resolve_symbol(&mut resolve, rela.r_sym)?.wrapping_add(rela.r_addend as u64);
+                let hi = relas
+                    .clone()
+                    .find(|hi| {
+                        hi.r_type == abi::R_RISCV_PCREL_HI20
+                            && address.wrapping_add(hi.r_offset) == label
+                    })
+                    .ok_or(ParseError::BadOffset(label))?;
+                let symbol = resolve_symbol(&mut resolve, hi.r_sym)?;
+                let value = symbol.wrapping_add(hi.r_addend as u64).wrapping_sub(label);
+                self.apply(data, address, rela.r_type, rela.r_offset, value, 0)?;
+                continue;
+            }
+
+            let symbol = resolve_symbol(&mut resolve, rela.r_sym)?;
+            self.apply(
+                data,
+                address,
+                rela.r_type,
+                rela.r_offset,
+                symbol,
+                rela.r_addend,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Apply the given [Rel] entries to `data`, the contents of the section they relocate,
+    /// which will be loaded at virtual address `address`.
+    ///
+    /// The addends are read from the relocated fields, so only relocations of plain data
+    /// words are supported. See [Relocator::apply_relas] for `resolve`.
+    pub fn apply_rels<I, F>(
+        &self,
+        data: &mut [u8],
+        address: u64,
+        rels: I,
+        mut resolve: F,
+    ) -> Result<(), ParseError>
+    where
+        I: Iterator<Item = Rel>,
+        F: FnMut(u32) -> Result<u64, ParseError>,
+    {
+        for rel in rels {
+            let symbol = resolve_symbol(&mut resolve, rel.r_sym)?;
+            let addend = self.implicit_addend(data, rel.r_type, rel.r_offset)?;
+            self.apply(data, address, rel.r_type, rel.r_offset, symbol, addend)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a single relocation of type `r_type` at `r_offset` into `data`, which will be
+    /// loaded at virtual address `address`, against a symbol at address `symbol`.
+    ///
+    /// `R_RISCV_PCREL_LO12_*` relocations refer to the `R_RISCV_PCREL_HI20` they pair with
+    /// rather than to a symbol. For these, `symbol` must be the paired relocation's
+    /// `S + A - P` value, with an `addend` of 0. [Relocator::apply_relas] does this for you.
+    pub fn apply(
+        &self,
+        data: &mut [u8],
+        address: u64,
+        r_type: u32,
+        r_offset: u64,
+        symbol: u64,
+        addend: i64,
+    ) -> Result<(), ParseError> {
+        let mut fixup = Fixup {
+            endian: self.endian,
+            class: self.class,
+            data,
+            r_type,
+            r_offset,
+        };
+        let place = address.wrapping_add(r_offset) as i128;
+        let value = symbol as i128 + addend as i128;
+        match self.e_machine {
+            abi::EM_X86_64 => fixup.x86_64(value, value - place),
+            abi::EM_AARCH64 => fixup.aarch64(value, place),
+            abi::EM_RISCV => fixup.riscv(value, value - place),
+            _ => Err(ParseError::UnsupportedRelocation((self.e_machine, r_type))),
+        }
+        .map_err(|err| match err {
+            ParseError::UnsupportedRelocation(_) => {
+                ParseError::UnsupportedRelocation((self.e_machine, r_type))
+            }
+            err => err,
+        })
+    }
+
+    fn implicit_addend(&self, data: &[u8], r_type: u32, r_offset: u64) -> Result<i64, ParseError> {
+        let bits = match (self.e_machine, r_type) {
+            (abi::EM_X86_64, abi::R_X86_64_NONE)
+            | (abi::EM_AARCH64, abi::R_AARCH64_NONE)
+            | (abi::EM_RISCV, abi::R_RISCV_NONE | abi::R_RISCV_RELAX | abi::R_RISCV_ALIGN) => {
+                return Ok(0)
+            }
+            (abi::EM_X86_64, abi::R_X86_64_64 | abi::R_X86_64_PC64)
+            | (abi::EM_AARCH64, abi::R_AARCH64_ABS64 | abi::R_AARCH64_PREL64)
+            | (abi::EM_RISCV, abi::R_RISCV_64) => 64,
+            (
+                abi::EM_X86_64,
+                abi::R_X86_64_32 | abi::R_X86_64_32S | abi::R_X86_64_PC32 | abi::R_X86_64_PLT32,
+            )
+            | (abi::EM_AARCH64, abi::R_AARCH64_ABS32 | abi::R_AARCH64_PREL32)
+            | (abi::EM_RISCV, abi::R_RISCV_32 | abi::R_RISCV_32_PCREL) => 32,
+            (abi::EM_X86_64, abi::R_X86_64_16 | abi::R_X86_64_PC16)
+            | (abi::EM_AARCH64, abi::R_AARCH64_ABS16 | abi::R_AARCH64_PREL16) => 16,
+            (abi::EM_X86_64, abi::R_X86_64_8 | abi::R_X86_64_PC8) => 8,
+            _ => return Err(ParseError::UnsupportedRelocation((self.e_machine, r_type))),
+        };
+        let start: usize = r_offset.try_into()?;
+        let mut offset = start;
+        let value = match bits {
+            8 => self.endian.parse_u8_at(&mut offset, data)? as i8 as i64,
+            16 => self.endian.parse_u16_at(&mut offset, data)? as i16 as i64,
+            32 => self.endian.parse_i32_at(&mut offset, data)? as i64,
+            _ => self.endian.parse_i64_at(&mut offset, data)?,
+        };
+        Ok(value)
+    }
+}
+
+fn resolve_symbol<F>(resolve: &mut F, r_sym: u32) -> Result<u64, ParseError>
+where
+    F: FnMut(u32) -> Result<u64, ParseError>,
+{
+    match r_sym {
+        0 => Ok(0),
+        r_sym => resolve(r_sym),
+    }
+}
+
+/// How a relocated value must be range checked before being truncated into its field
+#[derive(Debug, Clone, Copy)]
+enum Check {
+    /// The value is truncated without checking, e.g. for `_NC` relocations
+    None,
+    Signed,
+    Unsigned,
+    /// The value must be representable as either a signed or an unsigned integer
+    Either,
+}
+
+/// A single relocation being applied to a section's bytes
+struct Fixup<'a, E: EndianParse> {
+    endian: E,
+    class: Class,
+    data: &'a mut [u8],
+    r_type: u32,
+    r_offset: u64,
+}
+
+impl<E: EndianParse> Fixup<'_, E> {
+    fn x86_64(&mut self, value: i128, pcrel: i128) -> Result<(), ParseError> {
+        match self.r_type {
+            abi::R_X86_64_NONE => Ok(()),
+            abi::R_X86_64_64 => self.write(64, value, Check::None),
+            abi::R_X86_64_PC64 => self.write(64, pcrel, Check::None),
+            abi::R_X86_64_32 => self.write(32, value, Check::Unsigned),
+            abi::R_X86_64_32S => self.write(32, value, Check::Signed),
+            abi::R_X86_64_PC32 | abi::R_X86_64_PLT32 => self.write(32, pcrel, Check::Signed),
+            abi::R_X86_64_16 => self.write(16, value, Check::Either),
+            abi::R_X86_64_PC16 => self.write(16, pcrel, Check::Signed),
+            abi::R_X86_64_8 => self.write(8, value, Check::Either),
+            abi::R_X86_64_PC8 => self.write(8, pcrel, Check::Signed),
+            abi::R_X86_64_GOTPCRELX | abi::R_X86_64_REX_GOTPCRELX => self.relax_gotpcrelx(pcrel),
+            _ => Err(ParseError::UnsupportedRelocation((0, self.r_type))),
+        }
+    }
+
+    /// Rewrite an indirect access through a GOT slot into a direct PC-relative one.
+    fn relax_gotpcrelx(&mut self, pcrel: i128) -> Result<(), ParseError> {
+        self.check(32, pcrel, Check::Signed)?;
+        // The opcode and ModRM bytes precede the 32-bit displacement being relocated
+        let start = self
+            .r_offset
+            .checked_sub(2)
+            .ok_or(ParseError::UnsupportedRelocation((0, self.r_type)))?;
+        let insn = field(self.data, start, 6)?;
+        match (insn[0], insn[1]) {
+            // mov foo@GOTPCREL(%rip), %reg => lea foo(%rip), %reg
+            (0x8b, _) => insn[0] = 0x8d,
+            // call *foo@GOTPCREL(%rip) => addr32 call foo
+            (0xff, 0x15) => {
+                insn[0] = 0x67;
+                insn[1] = 0xe8;
+            }
+            // jmp *foo@GOTPCREL(%rip) => jmp foo; nop
+            (0xff, 0x25) => {
+                insn[0] = 0xe9;
+                insn[1..5].copy_from_slice(&((pcrel + 1) as i32).to_le_bytes());
+                insn[5] = 0x90;
+                return Ok(());
+            }
+            _ => return Err(ParseError::UnsupportedRelocation((0, self.r_type))),
+        }
+        self.write(32, pcrel, Check::Signed)
+    }
+
+    fn aarch64(&mut self, value: i128, place: i128) -> Result<(), ParseError> {
+        let pcrel = value - place;
+        match self.r_type {
+            abi::R_AARCH64_NONE => Ok(()),
+            abi::R_AARCH64_ABS64 => self.write(64, value, Check::None),
+            abi::R_AARCH64_ABS32 => self.write(32, value, Check::Either),
+            abi::R_AARCH64_ABS16 => self.write(16, value, Check::Either),
+            abi::R_AARCH64_PREL64 => self.write(64, pcrel, Check::None),
+            abi::R_AARCH64_PREL32 => self.write(32, pcrel, Check::Either),
+            abi::R_AARCH64_PREL16 => self.write(16, pcrel, Check::Either),
+            abi::R_AARCH64_MOVW_UABS_G0
+            | abi::R_AARCH64_MOVW_UABS_G0_NC
+            | abi::R_AARCH64_MOVW_UABS_G1
+            | abi::R_AARCH64_MOVW_UABS_G1_NC
+            | abi::R_AARCH64_MOVW_UABS_G2
+            | abi::R_AARCH64_MOVW_UABS_G2_NC
+            | abi::R_AARCH64_MOVW_UABS_G3 => {
+                let group = (self.r_type - abi::R_AARCH64_MOVW_UABS_G0) / 2;
+                let shift = 16 * group;
+                if !matches!(
+                    self.r_type,
+                    abi::R_AARCH64_MOVW_UABS_G0_NC
+                        | abi::R_AARCH64_MOVW_UABS_G1_NC
+                        | abi::R_AARCH64_MOVW_UABS_G2_NC
+                ) {
+                    self.check(shift + 16, value, Check::Unsigned)?;
+                }
+                let imm = (value >> shift) as u32 & 0xffff;
+                self.patch(0xffff << 5, imm << 5)
+            }
+            abi::R_AARCH64_LD_PREL_LO19 | abi::R_AARCH64_CONDBR19 => {
+                self.check(21, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 4)?;
+                let imm = (pcrel >> 2) as u32 & 0x7ffff;
+                self.patch(0x7ffff << 5, imm << 5)
+            }
+            abi::R_AARCH64_ADR_PREL_LO21 => {
+                self.check(21, pcrel, Check::Signed)?;
+                self.patch_adr(pcrel as u32)
+            }
+            abi::R_AARCH64_ADR_PREL_PG_HI21 | abi::R_AARCH64_ADR_PREL_PG_HI21_NC => {
+                let pages = (value & !0xfff) - (place & !0xfff);
+                if self.r_type == abi::R_AARCH64_ADR_PREL_PG_HI21 {
+                    self.check(33, pages, Check::Signed)?;
+                }
+                self.patch_adr((pages >> 12) as u32)
+            }
+            abi::R_AARCH64_ADD_ABS_LO12_NC => self.patch_lo12(value, 0),
+            abi::R_AARCH64_LDST8_ABS_LO12_NC => self.patch_lo12(value, 0),
+            abi::R_AARCH64_LDST16_ABS_LO12_NC => self.patch_lo12(value, 1),
+            abi::R_AARCH64_LDST32_ABS_LO12_NC => self.patch_lo12(value, 2),
+            abi::R_AARCH64_LDST64_ABS_LO12_NC => self.patch_lo12(value, 3),
+            abi::R_AARCH64_LDST128_ABS_LO12_NC => self.patch_lo12(value, 4),
+            abi::R_AARCH64_TSTBR14 => {
+                self.check(16, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 4)?;
+                let imm = (pcrel >> 2) as u32 & 0x3fff;
+                self.patch(0x3fff << 5, imm << 5)
+            }
+            abi::R_AARCH64_JUMP26 | abi::R_AARCH64_CALL26 => {
+                self.check(28, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 4)?;
+                self.patch(0x3ffffff, (pcrel >> 2) as u32 & 0x3ffffff)
+            }
+            _ => Err(ParseError::UnsupportedRelocation((0, self.r_type))),
+        }
+    }
+
+    /// Encode the 21-bit immediate of an `adr` or `adrp` instruction.
+    fn patch_adr(&mut self, imm: u32) -> Result<(), ParseError> {
+        let immlo = (imm & 0x3) << 29;
+        let immhi = ((imm >> 2) & 0x7ffff) << 5;
+        self.patch((0x3 << 29) | (0x7ffff << 5), immlo | immhi)
+    }
+
+    /// Encode the 12-bit unsigned offset of an `add` or load/store, scaled by the access size.
+    fn patch_lo12(&mut self, value: i128, scale: u32) -> Result<(), ParseError> {
+        let imm = (value as u32 & 0xfff) >> scale;
+        self.patch(0xfff << 10, imm << 10)
+    }
+
+    fn riscv(&mut self, value: i128, pcrel: i128) -> Result<(), ParseError> {
+        match self.r_type {
+            // Without relaxation, R_RISCV_ALIGN's padding is left as the assembler's nops
+            abi::R_RISCV_NONE | abi::R_RISCV_RELAX | abi::R_RISCV_ALIGN => Ok(()),
+            abi::R_RISCV_32 => self.write(32, value, Check::Either),
+            abi::R_RISCV_64 => self.write(64, value, Check::None),
+            abi::R_RISCV_32_PCREL => self.write(32, pcrel, Check::Signed),
+            abi::R_RISCV_BRANCH => {
+                self.check(13, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 2)?;
+                let imm = pcrel as u32;
+                let bits = ((imm >> 12) & 0x1) << 31
+                    | ((imm >> 5) & 0x3f) << 25
+                    | ((imm >> 1) & 0xf) << 8
+                    | ((imm >> 11) & 0x1) << 7;
+                self.patch(0xfe000f80, bits)
+            }
+            abi::R_RISCV_JAL => {
+                self.check(21, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 2)?;
+                let imm = pcrel as u32;
+                let bits = ((imm >> 20) & 0x1) << 31
+                    | ((imm >> 1) & 0x3ff) << 21
+                    | ((imm >> 11) & 0x1) << 20
+                    | ((imm >> 12) & 0xff) << 12;
+                self.patch(0xfffff000, bits)
+            }
+            abi::R_RISCV_CALL | abi::R_RISCV_CALL_PLT => {
+                // An auipc + jalr pair
+                self.patch_hi20(pcrel)?;
+                self.r_offset = self
+                    .r_offset
+                    .checked_add(4)
+                    .ok_or(ParseError::IntegerOverflow)?;
+                self.patch_lo12_i(pcrel)
+            }
+            abi::R_RISCV_PCREL_HI20 => self.patch_hi20(pcrel),
+            abi::R_RISCV_HI20 => self.patch_hi20(value),
+            // For the PCREL_LO12 relocations, the value is that of the paired PCREL_HI20
+            abi::R_RISCV_LO12_I | abi::R_RISCV_PCREL_LO12_I => self.patch_lo12_i(value),
+            abi::R_RISCV_LO12_S | abi::R_RISCV_PCREL_LO12_S => {
+                let imm = value as u32;
+                let bits = ((imm >> 5) & 0x7f) << 25 | (imm & 0x1f) << 7;
+                self.patch(0xfe000f80, bits)
+            }
+            abi::R_RISCV_RVC_BRANCH => {
+                self.check(9, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 2)?;
+                let imm = pcrel as u16;
+                let bits = ((imm >> 8) & 0x1) << 12
+                    | ((imm >> 3) & 0x3) << 10
+                    | ((imm >> 6) & 0x3) << 5
+                    | ((imm >> 1) & 0x3) << 3
+                    | ((imm >> 5) & 0x1) << 2;
+                self.patch16(0x1c7c, bits)
+            }
+            abi::R_RISCV_RVC_JUMP => {
+                self.check(12, pcrel, Check::Signed)?;
+                self.check_aligned(pcrel, 2)?;
+                let imm = pcrel as u16;
+                let bits = ((imm >> 11) & 0x1) << 12
+                    | ((imm >> 4) & 0x1) << 11
+                    | ((imm >> 8) & 0x3) << 9
+                    | ((imm >> 10) & 0x1) << 8
+                    | ((imm >> 6) & 0x1) << 7
+                    | ((imm >> 7) & 0x1) << 6
+                    | ((imm >> 1) & 0x7) << 3
+                    | ((imm >> 5) & 0x1) << 2;
+                self.patch16(0x1ffc, bits)
+            }
+            abi::R_RISCV_ADD8 => self.accumulate(8, value),
+            abi::R_RISCV_ADD16 => self.accumulate(16, value),
+            abi::R_RISCV_ADD32 => self.accumulate(32, value),
+            abi::R_RISCV_ADD64 => self.accumulate(64, value),
+            abi::R_RISCV_SUB8 => self.accumulate(8, -value),
+            abi::R_RISCV_SUB16 => self.accumulate(16, -value),
+            abi::R_RISCV_SUB32 => self.accumulate(32, -value),
+            abi::R_RISCV_SUB64 => self.accumulate(64, -value),
+            abi::R_RISCV_SUB6 | abi::R_RISCV_SET6 => {
+                let byte = &mut field(self.data, self.r_offset, 1)?[0];
+                let low = match self.r_type {
+                    abi::R_RISCV_SUB6 => (*byte as i128 - value) as u8,
+                    _ => value as u8,
+                };
+                *byte = (*byte & 0xc0) | (low & 0x3f);
+                Ok(())
+            }
+            abi::R_RISCV_SET8 => self.write(8, value, Check::None),
+            abi::R_RISCV_SET16 => self.write(16, value, Check::None),
+            abi::R_RISCV_SET32 => self.write(32, value, Check::None),
+            _ => Err(ParseError::UnsupportedRelocation((0, self.r_type))),
+        }
+    }
+
+    /// Encode the upper 20 bits of a `lui` or `auipc`, rounded to pair with a signed low 12 bits.
+    fn patch_hi20(&mut self, value: i128) -> Result<(), ParseError> {
+        let value = value + 0x800;
+        // On RV32, addresses wrap at 32 bits so the whole address space is in range
+        if self.class == Class::ELF64 {
+            self.check(32, value, Check::Signed)?;
+        }
+        self.patch(0xfffff000, value as u32 & 0xfffff000)
+    }
+
+    /// Encode the low 12 bits of an I-type instruction's immediate.
+    fn patch_lo12_i(&mut self, value: i128) -> Result<(), ParseError> {
+        self.patch(0xfff00000, (value as u32 & 0xfff) << 20)
+    }
+
+    /// Add `value` to the data word being relocated, wrapping on overflow.
+    fn accumulate(&mut self, bits: u32, value: i128) -> Result<(), ParseError> {
+        let mut offset: usize = self.r_offset.try_into()?;
+        let current = match bits {
+            8 => self.endian.parse_u8_at(&mut offset, self.data)? as u64,
+            16 => self.endian.parse_u16_at(&mut offset, self.data)? as u64,
+            32 => self.endian.parse_u32_at(&mut offset, self.data)? as u64,
+            _ => self.endian.parse_u64_at(&mut offset, self.data)?,
+        };
+        self.write(bits, current as i128 + value, Check::None)
+    }
+
+    fn check(&self, bits: u32, value: i128, check: Check) -> Result<(), ParseError> {
+        let (min, max) = match check {
+            Check::None => return Ok(()),
+            Check::Signed => (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1),
+            Check::Unsigned => (0, (1i128 << bits) - 1),
+            Check::Either => (-(1i128 << (bits - 1)), (1i128 << bits) - 1),
+        };
+        match (min..=max).contains(&value) {
+            true => Ok(()),
+            false => Err(ParseError::RelocationOverflow((self.r_type, self.r_offset))),
+        }
+    }
+
+    fn check_aligned(&self, value: i128, align: i128) -> Result<(), ParseError> {
+        match value % align {
+            0 => Ok(()),
+            _ => Err(ParseError::RelocationOverflow((self.r_type, self.r_offset))),
+        }
+    }
+
+    /// Write the low `bits` of `value` as a data word in the object's byte order.
+    fn write(&mut self, bits: u32, value: i128, check: Check) -> Result<(), ParseError> {
+        self.check(bits, value, check)?;
+        let size = bits as usize / 8;
+        let place = field(self.data, self.r_offset, size)?;
+        let value = value as u64;
+        if self.endian.is_little() {
+            place.copy_from_slice(&value.to_le_bytes()[..size]);
+        } else {
+            place.copy_from_slice(&value.to_be_bytes()[8 - size..]);
+        }
+        Ok(())
+    }
+
+    /// Replace the `mask`ed bits of the 32-bit instruction being relocated with `bits`.
+    ///
+    /// Instructions are always little-endian on the supported machines.
+    fn patch(&mut self, mask: u32, bits: u32) -> Result<(), ParseError> {
+        let place = field(self.data, self.r_offset, 4)?;
+        let insn = u32::from_le_bytes([place[0], place[1], place[2], place[3]]);
+        place.copy_from_slice(&((insn & !mask) | (bits & mask)).to_le_bytes());
+        Ok(())
+    }
+
+    /// Replace the `mask`ed bits of the 16-bit compressed instruction being relocated with `bits`.
+    fn patch16(&mut self, mask: u16, bits: u16) -> Result<(), ParseError> {
+        let place = field(self.data, self.r_offset, 2)?;
+        let insn = u16::from_le_bytes([place[0], place[1]]);
+        place.copy_from_slice(&((insn & !mask) | (bits & mask)).to_le_bytes());
+        Ok(())
+    }
+}
+
+fn field(data: &mut [u8], offset: u64, size: usize) -> Result<&mut [u8], ParseError> {
+    let start: usize = offset.try_into()?;
+    let end = start.checked_add(size).ok_or(ParseError::IntegerOverflow)?;
+    data.get_mut(start..end)
+        .ok_or(ParseError::SliceReadError((start, end)))
+}
+
+#[cfg(test)]
+mod relocate_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, BigEndian, LittleEndian};
+    use crate::relocation::RelaIterator;
+    use crate::ElfBytes;
+
+    const TEXT: u64 = 0x10000;
+    const DATA: u64 = 0x20000;
+    const EXT_FUNC: u64 = 0x30000;
+    const EXT_VAR: u64 = 0x40000;
+
+    /// Relocate the given section of a sample object, with .text at TEXT and .data at `data`.
+    fn relocate(path: &str, section: &str, data: u64) -> Result<Vec<u8>, ParseError> {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+
+        let (shdrs, strtab) = file
+            .section_headers_with_strtab()
+            .expect("shdrs should parse");
+        let (shdrs, strtab) = (shdrs.unwrap(), strtab.unwrap());
+        let bases: Vec<u64> = shdrs
+            .iter()
+            .map(|shdr| match strtab.get(shdr.sh_name as usize).unwrap() {
+                ".text" => TEXT,
+                ".data" => data,
+                _ => 0,
+            })
+            .collect();
+        let (symtab, symstrs) = file.symbol_table().expect("symtab should parse").unwrap();
+        let resolve = |index: u32| {
+            let sym = symtab.get(index as usize)?;
+            symbol_address(&sym, &bases)
+                .or_else(|| match symstrs.get(sym.st_name as usize).ok()? {
+                    "ext_func" => Some(EXT_FUNC),
+                    "ext_var" => Some(EXT_VAR),
+                    _ => None,
+                })
+                .ok_or(ParseError::UnresolvedSymbol(index))
+        };
+
+        let shdr = file.section_header_by_name(section).unwrap().unwrap();
+        let rela_shdr = file
+            .section_header_by_name(&format!(".rela{section}"))
+            .unwrap()
+            .unwrap();
+        let (bytes, _) = file.section_data(&shdr).unwrap();
+        let mut bytes = bytes.to_vec();
+        let address = match section {
+            ".text" => TEXT,
+            _ => data,
+        };
+
+        let relocator = Relocator::new(file.ehdr.endianness, file.ehdr.class, file.ehdr.e_machine);
+        let relas = file.section_data_as_relas(&rela_shdr).unwrap();
+        relocator.apply_relas(&mut bytes, address, relas, resolve)?;
+        Ok(bytes)
+    }
+
+    fn insn(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn symbol_addresses() {
+        let mut sym = Symbol {
+            st_name: 0,
+            st_shndx: 2,
+            st_info: 0,
+            st_other: 0,
+            st_value: 0x10,
+            st_size: 0,
+        };
+        let bases = [0, 0, 0x1000];
+        assert_eq!(symbol_address(&sym, &bases), Some(0x1010));
+        sym.st_shndx = 3;
+        assert_eq!(symbol_address(&sym, &bases), None);
+        sym.st_shndx = abi::SHN_ABS;
+        assert_eq!(symbol_address(&sym, &bases), Some(0x10));
+        sym.st_shndx = abi::SHN_UNDEF;
+        assert_eq!(symbol_address(&sym, &bases), None);
+        sym.st_shndx = abi::SHN_COMMON;
+        assert_eq!(symbol_address(&sym, &bases), None);
+    }
+
+    #[test]
+    fn relocate_x86_64_text() {
+        let text =
+            relocate("sample-objects/relocate.x86_64.o", ".text", DATA).expect("should relocate");
+        #[rustfmt::skip]
+        let expected = [
+            // call func
+            0xe8, 0x35, 0x00, 0x00, 0x00,
+            // call ext_func
+            0xe8, 0xf6, 0xff, 0x01, 0x00,
+            // lea data_var(%rip), %rax
+            0x48, 0x8d, 0x05, 0xef, 0xff, 0x00, 0x00,
+            // mov ext_var@GOTPCREL(%rip), %rax => lea ext_var(%rip), %rax
+            0x48, 0x8d, 0x05, 0xe8, 0xff, 0x02, 0x00,
+            // call *ext_func@GOTPCREL(%rip) => addr32 call ext_func
+            0x67, 0xe8, 0xe2, 0xff, 0x01, 0x00,
+            // jmp *ext_func@GOTPCREL(%rip) => jmp ext_func; nop
+            0xe9, 0xdd, 0xff, 0x01, 0x00, 0x90,
+            // movl $data_var, %ecx
+            0xb9, 0x00, 0x00, 0x02, 0x00,
+            // movq $data_var, %rcx
+            0x48, 0xc7, 0xc1, 0x00, 0x00, 0x02, 0x00,
+            // movabsq $ext_var, %rdx
+            0x48, 0xba, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // ret
+            0xc3,
+        ];
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn relocate_x86_64_data() {
+        let data =
+            relocate("sample-objects/relocate.x86_64.o", ".data", DATA).expect("should relocate");
+        #[rustfmt::skip]
+        let expected = [
+            // .quad func
+            0x3a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // .long ext_var - .
+            0xf8, 0xff, 0x01, 0x00,
+            // .quad ext_func - .
+            0xf4, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn relocate_x86_64_overflow() {
+        // .data is now too far away from .text for a 32-bit PC-relative displacement
+        let err = relocate("sample-objects/relocate.x86_64.o", ".text", 0x1_0000_0000)
+            .expect_err("should overflow");
+        assert!(matches!(
+            err,
+            ParseError::RelocationOverflow((abi::R_X86_64_PC32, 0xd))
+        ));
+    }
+
+    #[test]
+    fn relocate_aarch64_text() {
+        let text = relocate("sample-objects/relocate.aarch64.o", ".text", DATA + 0x18)
+            .expect("should relocate");
+        let words: Vec<u32> = (0..text.len()).step_by(4).map(|i| insn(&text, i)).collect();
+        let expected = [
+            0x9400000b, // bl func (0x1002c)
+            0x14007fff, // b ext_func (0x30000)
+            0x90000080, // adrp x0, 0x20000
+            0x91006000, // add x0, x0, #0x18
+            0xf9400c01, // ldr x1, [x0, #0x18]
+            0x10080022, // adr x2, 0x20018
+            0x540000a0, // b.eq func
+            0x36180080, // tbz x0, #3, func
+            0xd2a00083, // movz x3, #0x4, lsl #16
+            0xf2800003, // movk x3, #0x0
+            0x5817fec4, // ldr x4, ext_var (0x40000)
+            0xd65f03c0, // ret
+        ];
+        assert_eq!(words, expected);
+
+        let data =
+            relocate("sample-objects/relocate.aarch64.o", ".data", DATA).expect("should relocate");
+        #[rustfmt::skip]
+        let expected = [
+            // .xword ext_var
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // .word ext_var - .
+            0xf8, 0xff, 0x01, 0x00,
+        ];
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn relocate_aarch64_overflow() {
+        // .data is now more than 4GiB away from .text for adrp
+        let err = relocate("sample-objects/relocate.aarch64.o", ".text", 0x1_0002_0000)
+            .expect_err("should overflow");
+        assert!(matches!(
+            err,
+            ParseError::RelocationOverflow((abi::R_AARCH64_ADR_PREL_PG_HI21, 0x8))
+        ));
+    }
+
+    #[test]
+    fn relocate_riscv64_text() {
+        let text =
+            relocate("sample-objects/relocate.riscv64.o", ".text", DATA).expect("should relocate");
+        let words: Vec<u32> = (0..0x34).step_by(4).map(|i| insn(&text, i)).collect();
+        let expected = [
+            0x00020097, // auipc ra, 0x20
+            0x000080e7, // jalr ra
+            0x00000317, // auipc t1, 0x0
+            0x02c30067, // jr 0x2c(t1) => func (0x10034)
+            0x00010517, // auipc a0, 0x10
+            0xff050513, // addi a0, a0, -0x10 => 0x20000
+            0x000405b7, // lui a1, 0x40
+            0x00058593, // addi a1, a1, 0
+            0x00c5a023, // sw a2, 0(a1)
+            0x00b50863, // beq a0, a1, func
+            0x00c000ef, // jal func
+            0x00050463, // beqz a0, func
+            0x0040006f, // j func
+        ];
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn relocate_riscv64_data() {
+        let data =
+            relocate("sample-objects/relocate.riscv64.o", ".data", DATA).expect("should relocate");
+        #[rustfmt::skip]
+        let expected = [
+            // .dword ext_var
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // .word ext_var - .
+            0xf8, 0xff, 0x01, 0x00,
+            // .word func - _start
+            0x34, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn relocate_riscv_compressed() {
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_RISCV);
+
+        // c.beqz a0, . + 0x6e
+        let mut data = 0xc101u16.to_le_bytes();
+        relocator
+            .apply(&mut data, 0x1000, abi::R_RISCV_RVC_BRANCH, 0, 0x106e, 0)
+            .expect("should relocate");
+        assert_eq!(u16::from_le_bytes(data), 0xc53d);
+
+        // c.j . - 0x2
+        let mut data = 0xa001u16.to_le_bytes();
+        relocator
+            .apply(&mut data, 0x1000, abi::R_RISCV_RVC_JUMP, 0, 0xffe, 0)
+            .expect("should relocate");
+        assert_eq!(u16::from_le_bytes(data), 0xbffd);
+
+        let err = relocator
+            .apply(&mut data, 0x1000, abi::R_RISCV_RVC_BRANCH, 0, 0x1100, 0)
+            .expect_err("should overflow");
+        assert!(matches!(
+            err,
+            ParseError::RelocationOverflow((abi::R_RISCV_RVC_BRANCH, 0))
+        ));
+    }
+
+    #[test]
+    fn relocate_riscv32_high_addresses() {
+        // lui a0, %hi(0x80001000)
+        let mut data = 0x537u32.to_le_bytes();
+        let relocator = Relocator::new(LittleEndian, Class::ELF32, abi::EM_RISCV);
+        relocator
+            .apply(&mut data, 0x1000, abi::R_RISCV_HI20, 0, 0x80001000, 0)
+            .expect("should relocate");
+        assert_eq!(u32::from_le_bytes(data), 0x80001537);
+
+        // auipc a0, %pcrel_hi(0xf0000000) from 0x1000
+        let mut data = 0x517u32.to_le_bytes();
+        relocator
+            .apply(&mut data, 0x1000, abi::R_RISCV_PCREL_HI20, 0, 0xf0000000, 0)
+            .expect("should relocate");
+        assert_eq!(u32::from_le_bytes(data), 0xeffff517);
+
+        // lui sign-extends on RV64, so the same address is out of range there
+        let mut data = 0x537u32.to_le_bytes();
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_RISCV);
+        let err = relocator
+            .apply(&mut data, 0x1000, abi::R_RISCV_HI20, 0, 0x80001000, 0)
+            .expect_err("should overflow");
+        assert!(matches!(
+            err,
+            ParseError::RelocationOverflow((abi::R_RISCV_HI20, 0))
+        ));
+    }
+
+    #[test]
+    fn relocate_rels() {
+        // Relocations with implicit addends of 0x10 and 0x7fffffff, the second of which overflows
+        let mut data = [0x10u8, 0, 0, 0, 0xff, 0xff, 0xff, 0x7f];
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        let rels = [Rel {
+            r_offset: 0,
+            r_sym: 1,
+            r_type: abi::R_X86_64_32,
+        }];
+        relocator
+            .apply_rels(&mut data, 0, rels.into_iter(), |_| Ok(0x1000))
+            .expect("should relocate");
+        assert_eq!(data[..4], [0x10, 0x10, 0, 0]);
+
+        let rels = [Rel {
+            r_offset: 4,
+            r_sym: 1,
+            r_type: abi::R_X86_64_32S,
+        }];
+        let err = relocator
+            .apply_rels(&mut data, 0, rels.into_iter(), |_| Ok(0x1000))
+            .expect_err("should overflow");
+        assert!(matches!(
+            err,
+            ParseError::RelocationOverflow((abi::R_X86_64_32S, 4))
+        ));
+    }
+
+    #[test]
+    fn relocate_big_endian_data() {
+        let mut data = [0u8; 4];
+        let relocator = Relocator::new(BigEndian, Class::ELF64, abi::EM_AARCH64);
+        relocator
+            .apply(&mut data, 0, abi::R_AARCH64_ABS32, 0, 0x12345678, 0)
+            .expect("should relocate");
+        assert_eq!(data, [0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn relocate_errors() {
+        let mut data = [0u8; 4];
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        assert!(matches!(
+            relocator.apply(&mut data, 0, abi::R_X86_64_GOTPCREL, 0, 0, 0),
+            Err(ParseError::UnsupportedRelocation((
+                abi::EM_X86_64,
+                abi::R_X86_64_GOTPCREL
+            )))
+        ));
+        assert!(matches!(
+            relocator.apply(&mut data, 0, abi::R_X86_64_64, 0, 0, 0),
+            Err(ParseError::SliceReadError((0, 8)))
+        ));
+
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_PPC64);
+        assert!(matches!(
+            relocator.apply(&mut data, 0, abi::R_PPC64_ADDR32, 0, 0, 0),
+            Err(ParseError::UnsupportedRelocation((
+                abi::EM_PPC64,
+                abi::R_PPC64_ADDR32
+            )))
+        ));
+
+        let relocator = Relocator::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        let relas = RelaIterator::new(LittleEndian, Class::ELF64, &[]);
+        relocator
+            .apply_relas(&mut data, 0, relas, |index| {
+                Err(ParseError::UnresolvedSymbol(index))
+            })
+            .expect("no relocations to apply");
+    }
+}
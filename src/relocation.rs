@@ -1,10 +1,13 @@
 //! Parsing relocation sections: `.rel.*`, `.rela.*`, [SHT_REL](crate::abi::SHT_REL), [SHT_RELA](crate::abi::SHT_RELA)
+use crate::abi;
 use crate::endian::EndianParse;
 use crate::file::Class;
-use crate::parse::{ParseAt, ParseError, ParsingIterator};
+use crate::parse::{ClassWord, ParseAt, ParseError, ParsingIterator};
 
 pub type RelIterator<'data, E> = ParsingIterator<'data, E, Rel>;
 pub type RelaIterator<'data, E> = ParsingIterator<'data, E, Rela>;
+pub type MipsRelIterator<'data, E> = ParsingIterator<'data, E, MipsRel>;
+pub type MipsRelaIterator<'data, E> = ParsingIterator<'data, E, MipsRela>;
 
 /// C-style 32-bit ELF Relocation definition
 ///
@@ -143,12 +146,289 @@ impl ParseAt for Rela {
     }
 }
 
+/// Iterator over the addresses relocated by a table of compressed relative relocations,
+/// [SHT_RELR](abi::SHT_RELR) or [DT_RELR](abi::DT_RELR).
+///
+/// Each entry in the table is a class-sized word. An even entry is the address of the next word
+/// to relocate. An odd entry is a bitmap, where bit `n` says whether to relocate the `n - 1`th
+/// word after the last address relocated by the previous entry.
+///
+/// The words at each yielded address are to be adjusted by the object's load bias, i.e. the
+/// same adjustment as the machine's `R_*_RELATIVE` relocation.
+#[derive(Debug, Clone)]
+pub struct RelrIterator<'data, E: EndianParse> {
+    entries: ParsingIterator<'data, E, ClassWord>,
+    word_size: u64,
+    /// The address of the word following the last one covered by the previous entry
+    next: u64,
+    /// The remaining bits of the current bitmap entry, and the address of its lowest bit's word
+    bitmap: u64,
+    cursor: u64,
+}
+
+impl<'data, E: EndianParse> RelrIterator<'data, E> {
+    pub fn new(endian: E, class: Class, data: &'data [u8]) -> Self {
+        RelrIterator {
+            entries: ParsingIterator::new(endian, class, data),
+            word_size: ClassWord::size_for(class) as u64,
+            next: 0,
+            bitmap: 0,
+            cursor: 0,
+        }
+    }
+}
+
+impl<E: EndianParse> Iterator for RelrIterator<'_, E> {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.bitmap != 0 {
+                let skip = self.bitmap.trailing_zeros() as u64;
+                let address = self.cursor.wrapping_add(skip * self.word_size);
+                self.bitmap = (self.bitmap >> skip) >> 1;
+                self.cursor = address.wrapping_add(self.word_size);
+                return Some(address);
+            }
+
+            let ClassWord(entry) = self.entries.next()?;
+            if entry & 1 == 0 {
+                self.next = entry.wrapping_add(self.word_size);
+                return Some(entry);
+            }
+
+            // The bitmap's first bit is the marker, so it covers one word less than it has bits
+            let bits = self.word_size * 8 - 1;
+            self.bitmap = entry >> 1;
+            self.cursor = self.next;
+            self.next = self.next.wrapping_add(bits * self.word_size);
+        }
+    }
+}
+
+/// C-style 64-bit MIPS ELF Relocation definition
+///
+/// MIPS64 splits the generic `r_info` field into a 32-bit symbol index, a special symbol and up to
+/// three relocation types which are applied in sequence, with the final type in the last byte.
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf64_Mips_Rel {
+    pub r_offset: u64,
+    pub r_sym: u32,
+    pub r_ssym: u8,
+    pub r_type3: u8,
+    pub r_type2: u8,
+    pub r_type: u8,
+}
+
+/// A MIPS relocation without an addend.
+///
+/// For [Class::ELF64] this parses the MIPS64 `r_info` layout (see [Elf64_Mips_Rel]). For
+/// [Class::ELF32], MIPS uses the generic `r_info` layout, so `r_ssym`, `r_type2` and `r_type3`
+/// are always zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MipsRel {
+    pub r_offset: u64,
+    pub r_sym: u32,
+    /// The special symbol for the second relocation type, one of the `RSS_*` values
+    pub r_ssym: u8,
+    pub r_type: u8,
+    pub r_type2: u8,
+    pub r_type3: u8,
+}
+
+impl MipsRel {
+    /// The relocation types in the order they're applied, ending at the first [abi::R_MIPS_NONE].
+    pub fn types(&self) -> impl Iterator<Item = u8> {
+        mips_types(self.r_type, self.r_type2, self.r_type3)
+    }
+}
+
+impl ParseAt for MipsRel {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        let r_offset = match class {
+            Class::ELF32 => endian.parse_u32_at(offset, data)? as u64,
+            Class::ELF64 => endian.parse_u64_at(offset, data)?,
+        };
+        let (r_sym, r_ssym, r_type, r_type2, r_type3) =
+            parse_mips_info(endian, class, offset, data)?;
+        Ok(MipsRel {
+            r_offset,
+            r_sym,
+            r_ssym,
+            r_type,
+            r_type2,
+            r_type3,
+        })
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        Rel::size_for(class)
+    }
+}
+
+/// C-style 64-bit MIPS ELF Relocation (with addend) definition
+///
+/// These C-style definitions are for users who want to implement their own ELF manipulation logic.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Elf64_Mips_Rela {
+    pub r_offset: u64,
+    pub r_sym: u32,
+    pub r_ssym: u8,
+    pub r_type3: u8,
+    pub r_type2: u8,
+    pub r_type: u8,
+    pub r_addend: i64,
+}
+
+/// A MIPS relocation with an addend. See [MipsRel].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MipsRela {
+    pub r_offset: u64,
+    pub r_sym: u32,
+    /// The special symbol for the second relocation type, one of the `RSS_*` values
+    pub r_ssym: u8,
+    pub r_type: u8,
+    pub r_type2: u8,
+    pub r_type3: u8,
+    pub r_addend: i64,
+}
+
+impl MipsRela {
+    /// The relocation types in the order they're applied, ending at the first [abi::R_MIPS_NONE].
+    pub fn types(&self) -> impl Iterator<Item = u8> {
+        mips_types(self.r_type, self.r_type2, self.r_type3)
+    }
+}
+
+impl ParseAt for MipsRela {
+    fn parse_at<E: EndianParse>(
+        endian: E,
+        class: Class,
+        offset: &mut usize,
+        data: &[u8],
+    ) -> Result<Self, ParseError> {
+        let r_offset = match class {
+            Class::ELF32 => endian.parse_u32_at(offset, data)? as u64,
+            Class::ELF64 => endian.parse_u64_at(offset, data)?,
+        };
+        let (r_sym, r_ssym, r_type, r_type2, r_type3) =
+            parse_mips_info(endian, class, offset, data)?;
+        let r_addend = match class {
+            Class::ELF32 => endian.parse_i32_at(offset, data)? as i64,
+            Class::ELF64 => endian.parse_i64_at(offset, data)?,
+        };
+        Ok(MipsRela {
+            r_offset,
+            r_sym,
+            r_ssym,
+            r_type,
+            r_type2,
+            r_type3,
+            r_addend,
+        })
+    }
+
+    #[inline]
+    fn size_for(class: Class) -> usize {
+        Rela::size_for(class)
+    }
+}
+
+/// Parse a MIPS `r_info` into (r_sym, r_ssym, r_type, r_type2, r_type3)
+fn parse_mips_info<E: EndianParse>(
+    endian: E,
+    class: Class,
+    offset: &mut usize,
+    data: &[u8],
+) -> Result<(u32, u8, u8, u8, u8), ParseError> {
+    match class {
+        Class::ELF32 => {
+            let r_info = endian.parse_u32_at(offset, data)?;
+            Ok((r_info >> 8, 0, r_info as u8, 0, 0))
+        }
+        Class::ELF64 => {
+            // The symbol index is a word in the file's byte order, but the rest are single bytes
+            // which are laid out the same way regardless of the file's endianness.
+            let r_sym = endian.parse_u32_at(offset, data)?;
+            let r_ssym = endian.parse_u8_at(offset, data)?;
+            let r_type3 = endian.parse_u8_at(offset, data)?;
+            let r_type2 = endian.parse_u8_at(offset, data)?;
+            let r_type = endian.parse_u8_at(offset, data)?;
+            Ok((r_sym, r_ssym, r_type, r_type2, r_type3))
+        }
+    }
+}
+
+fn mips_types(r_type: u8, r_type2: u8, r_type3: u8) -> impl Iterator<Item = u8> {
+    [r_type, r_type2, r_type3]
+        .into_iter()
+        .take_while(|r_type| *r_type as u32 != abi::R_MIPS_NONE)
+}
+
+/// Returns the machine-specific `R_*_RELATIVE` relocation type for the given `e_machine` and
+/// [Class], or None if we don't know of one.
+///
+/// Relative relocations adjust a pointer by the object's load base, and are what the linker emits
+/// for pointers to the object's own code and data in position-independent objects.
+pub fn relative_type(e_machine: u16, class: Class) -> Option<u32> {
+    match (e_machine, class) {
+        (abi::EM_AARCH64, Class::ELF64) => Some(abi::R_AARCH64_RELATIVE),
+        (abi::EM_AARCH64, Class::ELF32) => Some(abi::R_AARCH64_P32_RELATIVE),
+        (abi::EM_ARM, _) => Some(abi::R_ARM_RELATIVE),
+        (abi::EM_QDSP6, _) => Some(abi::R_HEX_RELATIVE),
+        (abi::EM_386, _) => Some(abi::R_386_RELATIVE),
+        (abi::EM_LOONGARCH, _) => Some(abi::R_LARCH_RELATIVE),
+        (abi::EM_PPC, _) => Some(abi::R_PPC_RELATIVE),
+        (abi::EM_PPC64, _) => Some(abi::R_PPC64_RELATIVE),
+        (abi::EM_RISCV, _) => Some(abi::R_RISCV_RELATIVE),
+        (abi::EM_S390, _) => Some(abi::R_390_RELATIVE),
+        (abi::EM_SPARC | abi::EM_SPARC32PLUS | abi::EM_SPARCV9, _) => Some(abi::R_SPARC_RELATIVE),
+        (abi::EM_X86_64, _) => Some(abi::R_X86_64_RELATIVE),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod parse_tests {
     use super::*;
     use crate::endian::{BigEndian, LittleEndian};
     use crate::parse::{test_parse_for, test_parse_fuzz_too_short};
 
+    #[test]
+    fn relr_addresses() {
+        // 0x1000, then a bitmap of words 0x1004 and 0x100c, then a bitmap of the word at 0x1080
+        let data = [
+            0x00u8, 0x10, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        ];
+        let relrs = RelrIterator::new(LittleEndian, Class::ELF32, &data);
+        assert_eq!(
+            relrs.collect::<Vec<u64>>(),
+            [0x1000, 0x1004, 0x100c, 0x1080]
+        );
+
+        // A full bitmap, then a new address
+        let data = [
+            0x00u8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00,
+        ];
+        let relrs = RelrIterator::new(BigEndian, Class::ELF64, &data);
+        let addresses: Vec<u64> = relrs.collect();
+        assert_eq!(addresses.len(), 65);
+        assert_eq!(addresses[0], 0x2000);
+        assert_eq!(addresses[1], 0x2008);
+        assert_eq!(addresses[63], 0x2000 + 63 * 8);
+        assert_eq!(addresses[64], 0x3000);
+    }
+
     #[test]
     fn parse_rel32_lsb() {
         test_parse_for(
@@ -296,4 +576,142 @@ mod parse_tests {
     fn parse_rela64_msb_fuzz_too_short() {
         test_parse_fuzz_too_short::<_, Rela>(BigEndian, Class::ELF64);
     }
+
+    #[test]
+    fn parse_mips_rel32_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF32,
+            MipsRel {
+                r_offset: 0x03020100,
+                r_sym: 0x00070605,
+                r_ssym: 0,
+                r_type: 0x04,
+                r_type2: 0,
+                r_type3: 0,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_mips_rel64_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF64,
+            MipsRel {
+                r_offset: 0x0706050403020100,
+                r_sym: 0x0B0A0908,
+                r_ssym: 0x0C,
+                r_type: 0x0F,
+                r_type2: 0x0E,
+                r_type3: 0x0D,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_mips_rel64_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF64,
+            MipsRel {
+                r_offset: 0x0001020304050607,
+                r_sym: 0x08090A0B,
+                r_ssym: 0x0C,
+                r_type: 0x0F,
+                r_type2: 0x0E,
+                r_type3: 0x0D,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_mips_rel64_lsb_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, MipsRel>(LittleEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn parse_mips_rela32_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF32,
+            MipsRela {
+                r_offset: 0x00010203,
+                r_sym: 0x00040506,
+                r_ssym: 0,
+                r_type: 0x07,
+                r_type2: 0,
+                r_type3: 0,
+                r_addend: 0x08090A0B,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_mips_rela64_lsb() {
+        test_parse_for(
+            LittleEndian,
+            Class::ELF64,
+            MipsRela {
+                r_offset: 0x0706050403020100,
+                r_sym: 0x0B0A0908,
+                r_ssym: 0x0C,
+                r_type: 0x0F,
+                r_type2: 0x0E,
+                r_type3: 0x0D,
+                r_addend: 0x1716151413121110,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_mips_rela64_msb() {
+        test_parse_for(
+            BigEndian,
+            Class::ELF64,
+            MipsRela {
+                r_offset: 0x0001020304050607,
+                r_sym: 0x08090A0B,
+                r_ssym: 0x0C,
+                r_type: 0x0F,
+                r_type2: 0x0E,
+                r_type3: 0x0D,
+                r_addend: 0x1011121314151617,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_mips_rela64_msb_fuzz_too_short() {
+        test_parse_fuzz_too_short::<_, MipsRela>(BigEndian, Class::ELF64);
+    }
+
+    #[test]
+    fn mips_composed_types() {
+        let rel = MipsRel {
+            r_offset: 0,
+            r_sym: 1,
+            r_ssym: abi::RSS_UNDEF,
+            r_type: abi::R_MIPS_GPREL16 as u8,
+            r_type2: abi::R_MIPS_SUB as u8,
+            r_type3: abi::R_MIPS_HI16 as u8,
+        };
+        assert_eq!(
+            rel.types().collect::<Vec<u8>>(),
+            [
+                abi::R_MIPS_GPREL16 as u8,
+                abi::R_MIPS_SUB as u8,
+                abi::R_MIPS_HI16 as u8
+            ]
+        );
+
+        let rel = MipsRel {
+            r_type2: abi::R_MIPS_NONE as u8,
+            ..rel
+        };
+        assert_eq!(
+            rel.types().collect::<Vec<u8>>(),
+            [abi::R_MIPS_GPREL16 as u8]
+        );
+    }
 }
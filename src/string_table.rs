@@ -1,4 +1,6 @@
 //! Interpreting string table sections: `.strtab`, [SHT_STRTAB][crate::abi::SHT_STRTAB]
+#[cfg(feature = "demangle")]
+use crate::demangle::DemangledName;
 use crate::parse::ParseError;
 use core::str::from_utf8;
 
@@ -33,6 +35,12 @@ impl<'data> StringTable<'data> {
         let raw_data = self.get_raw(offset)?;
         Ok(from_utf8(raw_data)?)
     }
+
+    /// Get the string at `offset` along with its demangled form. See [DemangledName].
+    #[cfg(feature = "demangle")]
+    pub fn get_demangled(&self, offset: usize) -> Result<DemangledName<'data>, ParseError> {
+        Ok(DemangledName::new(self.get(offset)?))
+    }
 }
 
 #[cfg(test)]
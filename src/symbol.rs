@@ -1,8 +1,12 @@
 //! Parsing symbol table sections: `.symtab`, `.dynsym`
 use crate::abi;
+#[cfg(feature = "demangle")]
+use crate::demangle::DemangledName;
 use crate::endian::EndianParse;
 use crate::file::Class;
+use crate::gnu_symver::SymbolVersionTable;
 use crate::parse::{ParseAt, ParseError, ParsingTable};
+use crate::string_table::StringTable;
 
 pub type SymbolTable<'data, E> = ParsingTable<'data, E, Symbol>;
 
@@ -100,6 +104,16 @@ impl Symbol {
     pub fn st_vis(&self) -> u8 {
         self.st_other & 0x3
     }
+
+    /// Get this symbol's name from its string table along with its demangled form.
+    /// See [DemangledName].
+    #[cfg(feature = "demangle")]
+    pub fn demangled_name<'data>(
+        &self,
+        strtab: &StringTable<'data>,
+    ) -> Result<DemangledName<'data>, ParseError> {
+        strtab.get_demangled(self.st_name as usize)
+    }
 }
 
 impl ParseAt for Symbol {
@@ -151,6 +165,100 @@ impl ParseAt for Symbol {
     }
 }
 
+/// A `.dynsym` entry joined up with its name and GNU symbol version (if any)
+///
+/// Displays as `name@@VERSION` for the default version of a defined symbol, `name@VERSION` for
+/// hidden versions and undefined symbols, or just `name` for unversioned symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicSymbol<'data> {
+    /// The index of the symbol in the dynamic symbol table
+    pub index: usize,
+    /// The symbol itself
+    pub symbol: Symbol,
+    /// The symbol's name
+    pub name: &'data str,
+    /// The name of the version required by or defined for this symbol (if any)
+    pub version: Option<&'data str>,
+    /// The library the version is required from, for undefined (imported) symbols
+    pub library: Option<&'data str>,
+    /// Whether the version is hidden, i.e. this isn't the default version of the symbol
+    pub hidden: bool,
+}
+
+impl DynamicSymbol<'_> {
+    /// Returns whether this is the default version of a defined symbol, which is the one
+    /// that unversioned references will bind to.
+    pub fn is_default(&self) -> bool {
+        self.version.is_some() && !self.hidden && !self.symbol.is_undefined()
+    }
+}
+
+impl core::fmt::Display for DynamicSymbol<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.version {
+            Some(version) if self.is_default() => write!(f, "{}@@{version}", self.name),
+            Some(version) => write!(f, "{}@{version}", self.name),
+            None => f.write_str(self.name),
+        }
+    }
+}
+
+/// Iterator over the entries of a dynamic symbol table as [DynamicSymbol]s, skipping the
+/// null symbol at index 0
+#[derive(Debug)]
+pub struct DynamicSymbolIterator<'data, E: EndianParse> {
+    symbols: SymbolTable<'data, E>,
+    strtab: StringTable<'data>,
+    versions: Option<SymbolVersionTable<'data, E>>,
+    index: usize,
+}
+
+impl<'data, E: EndianParse> DynamicSymbolIterator<'data, E> {
+    pub fn new(
+        symbols: SymbolTable<'data, E>,
+        strtab: StringTable<'data>,
+        versions: Option<SymbolVersionTable<'data, E>>,
+    ) -> Self {
+        DynamicSymbolIterator {
+            symbols,
+            strtab,
+            versions,
+            index: 1,
+        }
+    }
+
+    /// Get the [DynamicSymbol] at `index` in the dynamic symbol table.
+    pub fn get(&self, index: usize) -> Result<DynamicSymbol<'data>, ParseError> {
+        let symbol = self.symbols.get(index)?;
+        let name = self.strtab.get(symbol.st_name as usize)?;
+        let version = match self.versions {
+            Some(ref versions) => versions.get_version(index)?,
+            None => None,
+        };
+        Ok(DynamicSymbol {
+            index,
+            symbol,
+            name,
+            version: version.map(|version| version.name),
+            library: version.and_then(|version| version.file),
+            hidden: version.is_some_and(|version| version.hidden),
+        })
+    }
+}
+
+impl<'data, E: EndianParse> Iterator for DynamicSymbolIterator<'data, E> {
+    type Item = DynamicSymbol<'data>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.symbols.len() {
+            return None;
+        }
+
+        let symbol = self.get(self.index).ok()?;
+        self.index += 1;
+        Some(symbol)
+    }
+}
+
 #[cfg(test)]
 mod symbol_tests {
     use super::*;
@@ -0,0 +1,539 @@
+//! Symbolizing addresses: finding the function or object symbol which contains an address
+//!
+//! A [Symbolizer] is an index of the function and object symbols from one or more symbol
+//! tables (typically `.symtab` and `.dynsym`), sorted by address so that lookups are a binary
+//! search. It requires the `alloc` feature.
+//!
+//! The index accounts for a few things that make symbol values differ from code addresses:
+//!
+//! * ARM: The low bit of Thumb function symbols' values is set, and is cleared in the index.
+//! * PPC64 ELFv1: Function symbols point to a function descriptor in `.opd`, and are indexed
+//!   by the entry point address read out of the descriptor.
+//! * Relocatable objects: Symbol values are offsets into their sections, so each section's
+//!   symbols are indexed separately and looked up with [Symbolizer::lookup_in_section].
+//!
+//! Example:
+//! ```
+//! use elf::ElfBytes;
+//! use elf::endian::AnyEndian;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/symver.x86_64.so");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).expect("Open test1");
+//!
+//! let symbolizer = file.symbolizer().expect("symbols should parse");
+//! let found = symbolizer.lookup(0x1120).expect("address should be in a function");
+//! assert_eq!(found.name, "use_memset");
+//! assert_eq!(found.address, 0x1119);
+//! assert_eq!(found.offset, 7);
+//! assert_eq!(found.size, 44);
+//! ```
+use crate::abi;
+use crate::endian::EndianParse;
+use crate::file::Class;
+use crate::init_array::PointerTable;
+use crate::parse::ParseError;
+use crate::string_table::StringTable;
+use crate::symbol::{Symbol, SymbolTable};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Reverse;
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+/// The symbol found for an address by [Symbolizer::lookup]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolLookup<'data> {
+    /// The symbol's name
+    pub name: &'data str,
+    /// The address the symbol starts at
+    pub address: u64,
+    /// The offset of the looked up address from the start of the symbol
+    pub offset: u64,
+    /// The symbol's size, which is 0 for symbols of unknown size
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry<'data> {
+    /// The section whose symbols this entry is indexed with, which is always 0 unless the
+    /// symbol values are section-relative
+    table: u16,
+    /// The symbol's st_shndx
+    section: u16,
+    address: u64,
+    size: u64,
+    name: &'data str,
+    /// Orders aliases at the same address: global, then weak, then local symbols
+    rank: u8,
+}
+
+impl Entry<'_> {
+    fn end(&self) -> u64 {
+        self.address.saturating_add(self.size)
+    }
+}
+
+/// A range of addresses in which `entry` is the innermost sized symbol
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    table: u16,
+    start: u64,
+    end: u64,
+    entry: usize,
+}
+
+/// An index of function and object symbols sorted by address, for finding the symbol which
+/// contains a given address.
+#[derive(Debug, Clone)]
+pub struct Symbolizer<'data, E: EndianParse> {
+    endian: E,
+    class: Class,
+    e_machine: u16,
+    descriptors: Option<(u64, &'data [u8])>,
+    section_relative: bool,
+    entries: Vec<Entry<'data>>,
+    spans: Vec<Span>,
+}
+
+impl<'data, E: EndianParse> Symbolizer<'data, E> {
+    /// Construct an empty Symbolizer for symbols from an object of the given class and machine.
+    pub fn new(endian: E, class: Class, e_machine: u16) -> Self {
+        Symbolizer {
+            endian,
+            class,
+            e_machine,
+            descriptors: None,
+            section_relative: false,
+            entries: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Index symbols by section, for relocatable ([ET_REL](abi::ET_REL)) objects whose symbol
+    /// values are offsets into their sections rather than addresses. Such symbols are found
+    /// with [Symbolizer::lookup_in_section].
+    pub fn with_section_relative_values(self) -> Self {
+        Symbolizer {
+            section_relative: true,
+            ..self
+        }
+    }
+
+    /// Use the given PPC64 ELFv1 `.opd` function descriptor section contents, which are loaded
+    /// at virtual address `address`, to translate function symbols to their entry points.
+    pub fn with_function_descriptors(self, address: u64, data: &'data [u8]) -> Self {
+        Symbolizer {
+            descriptors: Some((address, data)),
+            ..self
+        }
+    }
+
+    /// Add the function and object symbols from the given symbol table to the index.
+    ///
+    /// Undefined, absolute and unnamed symbols are skipped, as are symbols which appear in
+    /// multiple tables (such as in both `.symtab` and `.dynsym`).
+    pub fn add_symbols(
+        &mut self,
+        symtab: &SymbolTable<'data, E>,
+        strtab: &StringTable<'data>,
+    ) -> Result<(), ParseError> {
+        for sym in symtab.iter() {
+            if !matches!(
+                sym.st_symtype(),
+                abi::STT_FUNC | abi::STT_OBJECT | abi::STT_GNU_IFUNC
+            ) || sym.st_shndx == abi::SHN_UNDEF
+                || sym.st_shndx == abi::SHN_ABS
+            {
+                continue;
+            }
+            let name = strtab.get(sym.st_name as usize)?;
+            if name.is_empty() {
+                continue;
+            }
+
+            let rank = match sym.st_bind() {
+                abi::STB_GLOBAL => 0,
+                abi::STB_WEAK => 1,
+                _ => 2,
+            };
+            self.entries.push(Entry {
+                table: match self.section_relative {
+                    true => sym.st_shndx,
+                    false => 0,
+                },
+                section: sym.st_shndx,
+                address: self.code_address(&sym)?,
+                size: sym.st_size,
+                name,
+                rank,
+            });
+        }
+
+        self.entries.sort_unstable_by(|a, b| {
+            (a.table, a.address, a.rank, b.size, a.name)
+                .cmp(&(b.table, b.address, b.rank, a.size, b.name))
+        });
+        self.entries
+            .dedup_by(|a, b| a.table == b.table && a.address == b.address && a.name == b.name);
+        self.build_spans();
+        Ok(())
+    }
+
+    /// Split the addresses covered by each section's sized symbols into spans which each belong
+    /// to the innermost symbol containing them, so that lookups are a single binary search.
+    ///
+    /// The innermost symbol is the one which starts last, with ties going to the preferred
+    /// alias. This sweeps each section's entries in order, keeping the symbols which have
+    /// started in a heap ordered by that preference, and popping the ones which have ended
+    /// off the top of it as the sweep passes their end.
+    fn build_spans(&mut self) {
+        self.spans.clear();
+        let mut base = 0;
+        for table in self.entries.chunk_by(|a, b| a.table == b.table) {
+            let mut started: BinaryHeap<(u64, Reverse<usize>)> = BinaryHeap::new();
+            let mut next = 0;
+            let mut pos = 0;
+            loop {
+                if started.is_empty() {
+                    match table[next..].iter().position(|entry| entry.size > 0) {
+                        Some(skip) => next += skip,
+                        None => break,
+                    }
+                    pos = table[next].address;
+                }
+                while let Some(entry) = table.get(next) {
+                    if entry.address > pos {
+                        break;
+                    }
+                    if entry.size > 0 {
+                        started.push((entry.address, Reverse(next)));
+                    }
+                    next += 1;
+                }
+                while let Some(&(_, Reverse(index))) = started.peek() {
+                    if table[index].end() > pos {
+                        break;
+                    }
+                    started.pop();
+                }
+                let Some(&(_, Reverse(index))) = started.peek() else {
+                    continue;
+                };
+
+                let end = match table.get(next) {
+                    Some(entry) => entry.address.min(table[index].end()),
+                    None => table[index].end(),
+                };
+                match self.spans.last_mut() {
+                    Some(span) if span.entry == base + index && span.end == pos => span.end = end,
+                    _ => self.spans.push(Span {
+                        table: table[index].table,
+                        start: pos,
+                        end,
+                        entry: base + index,
+                    }),
+                }
+                pos = end;
+            }
+            base += table.len();
+        }
+    }
+
+    /// Get the address of the code or data that the given symbol refers to.
+    fn code_address(&self, sym: &Symbol) -> Result<u64, ParseError> {
+        if sym.st_symtype() != abi::STT_FUNC {
+            return Ok(sym.st_value);
+        }
+        match self.e_machine {
+            // The low bit of Thumb function addresses selects the instruction set
+            abi::EM_ARM => Ok(sym.st_value & !1),
+            abi::EM_PPC64 => {
+                let (address, data) = match self.descriptors {
+                    Some(descriptors) => descriptors,
+                    None => return Ok(sym.st_value),
+                };
+                let offset = match sym.st_value.checked_sub(address) {
+                    Some(offset) if offset < data.len() as u64 => offset as usize,
+                    _ => return Ok(sym.st_value),
+                };
+                // The entry point is the first word of the descriptor
+                let slot = offset / 8;
+                if slot * 8 != offset {
+                    return Err(ParseError::BadOffset(sym.st_value));
+                }
+                Ok(PointerTable::new(self.endian, self.class, data)
+                    .get(slot)?
+                    .0)
+            }
+            _ => Ok(sym.st_value),
+        }
+    }
+
+    /// Returns the number of symbols in the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find the symbol which contains `addr` (if any).
+    ///
+    /// Of several aliases at the same address, global symbols are preferred over weak ones,
+    /// which are preferred over local ones. Symbols of unknown (zero) size are taken to extend
+    /// up to the next symbol in the same section, but only when no sized symbol starts at the
+    /// same address. The last one in its section only contains its own address. When sized
+    /// symbols are nested, the innermost one containing `addr` is found.
+    pub fn lookup(&self, addr: u64) -> Option<SymbolLookup<'data>> {
+        self.find(0, addr)
+    }
+
+    /// Find the symbol which contains `offset` into the section with index `shndx` (if any),
+    /// for a Symbolizer built [with_section_relative_values](Symbolizer::with_section_relative_values).
+    /// See [Symbolizer::lookup] for how symbols are chosen.
+    pub fn lookup_in_section(&self, shndx: u16, offset: u64) -> Option<SymbolLookup<'data>> {
+        match self.section_relative {
+            true => self.find(shndx, offset),
+            false => None,
+        }
+    }
+
+    fn find(&self, table: u16, addr: u64) -> Option<SymbolLookup<'data>> {
+        // Find the last group of symbols starting at or before addr, which is used if they're
+        // all of unknown size
+        let end = self
+            .entries
+            .partition_point(|entry| (entry.table, entry.address) <= (table, addr));
+        if let Some(last) = end.checked_sub(1).map(|index| &self.entries[index]) {
+            let start = self.entries[..end]
+                .partition_point(|entry| (entry.table, entry.address) < (table, last.address));
+            let group = &self.entries[start..end];
+            let bounded = last.address == addr
+                || self
+                    .entries
+                    .get(end)
+                    .is_some_and(|next| next.table == table && next.section == group[0].section);
+            if last.table == table && bounded && group.iter().all(|entry| entry.size == 0) {
+                return Some(found(&group[0], addr));
+            }
+        }
+
+        let index = self
+            .spans
+            .partition_point(|span| (span.table, span.start) <= (table, addr));
+        let span = self.spans.get(index.checked_sub(1)?)?;
+        if span.table != table || addr >= span.end {
+            return None;
+        }
+        Some(found(&self.entries[span.entry], addr))
+    }
+
+    /// Get an iterator over the names of all the symbols which start at exactly `addr`.
+    pub fn aliases(&self, addr: u64) -> impl Iterator<Item = &'data str> + '_ {
+        let start = self
+            .entries
+            .partition_point(|entry| (entry.table, entry.address) < (0, addr));
+        self.entries[start..]
+            .iter()
+            .take_while(move |entry| entry.table == 0 && entry.address == addr)
+            .map(|entry| entry.name)
+    }
+}
+
+fn found<'data>(entry: &Entry<'data>, addr: u64) -> SymbolLookup<'data> {
+    SymbolLookup {
+        name: entry.name,
+        address: entry.address,
+        offset: addr - entry.address,
+        size: entry.size,
+    }
+}
+
+#[cfg(test)]
+mod symbolizer_tests {
+    use super::*;
+    use crate::endian::{AnyEndian, LittleEndian};
+    use crate::ElfBytes;
+
+    fn symbolize(path: &str, addr: u64) -> Option<(String, u64, u64)> {
+        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+        let symbolizer = file.symbolizer().expect("should parse");
+        symbolizer
+            .lookup(addr)
+            .map(|found| (found.name.to_string(), found.offset, found.size))
+    }
+
+    #[test]
+    fn sized_and_zero_sized_symbols() {
+        let path = "sample-objects/symver.x86_64.so";
+        assert_eq!(
+            symbolize(path, 0x1119),
+            Some(("use_memset".to_string(), 0, 44))
+        );
+        assert_eq!(
+            symbolize(path, 0x116d),
+            Some(("use_memset_v2".to_string(), 0x28, 41))
+        );
+        assert_eq!(symbolize(path, 0x1170), Some(("_fini".to_string(), 0, 0)));
+        // _fini is the last symbol, so it has nothing to extend up to
+        assert_eq!(symbolize(path, 0x1171), None);
+        // frame_dummy has no size, so it covers everything up to use_memset
+        assert_eq!(
+            symbolize(path, 0x1118),
+            Some(("frame_dummy".to_string(), 8, 0))
+        );
+        // Past the end of a sized symbol
+        assert_eq!(symbolize(path, 0x116e), None);
+        // Before the first symbol
+        assert_eq!(symbolize(path, 0xfff), None);
+    }
+
+    #[test]
+    fn arm_thumb() {
+        let path = "sample-objects/symver.armhf.so";
+        assert_eq!(
+            symbolize(path, 0x4dc),
+            Some(("use_memset".to_string(), 0, 30))
+        );
+        assert_eq!(
+            symbolize(path, 0x4fa),
+            Some(("use_memset_v2".to_string(), 0, 28))
+        );
+    }
+
+    #[test]
+    fn ppc64_function_descriptors() {
+        let path = "sample-objects/symver.powerpc64.so";
+        assert_eq!(
+            symbolize(path, 0x9a4),
+            Some(("use_memset".to_string(), 0, 88))
+        );
+        assert_eq!(
+            symbolize(path, 0xa00),
+            Some(("use_memset_v2".to_string(), 4, 84))
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        #[rustfmt::skip]
+        let symbols = [
+            // st_name: 1 ("weak"), st_info: STB_WEAK | STT_FUNC, st_shndx: 1, st_value: 0x1000, st_size: 0x10
+            1u8, 0, 0, 0, 0x22, 0, 1, 0, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0,
+            // st_name: 6 ("strong"), st_info: STB_GLOBAL | STT_FUNC, st_shndx: 1, st_value: 0x1000, st_size: 0x10
+            6, 0, 0, 0, 0x12, 0, 1, 0, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0,
+            // st_name: 13 ("local"), st_info: STB_LOCAL | STT_FUNC, st_shndx: 1, st_value: 0x1000, st_size: 0
+            13, 0, 0, 0, 0x02, 0, 1, 0, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let strs = b"\0weak\0strong\0local\0";
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &symbols);
+        let strtab = StringTable::new(strs);
+
+        let mut symbolizer = Symbolizer::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        symbolizer.add_symbols(&symtab, &strtab).unwrap();
+        // Adding the same table again doesn't duplicate its symbols
+        symbolizer.add_symbols(&symtab, &strtab).unwrap();
+        assert_eq!(symbolizer.len(), 3);
+
+        let found = symbolizer.lookup(0x1004).unwrap();
+        assert_eq!(found.name, "strong");
+        assert_eq!(found.offset, 4);
+        assert!(symbolizer.lookup(0x1010).is_none());
+        assert_eq!(
+            symbolizer.aliases(0x1000).collect::<Vec<&str>>(),
+            ["strong", "weak", "local"]
+        );
+    }
+
+    #[test]
+    fn nested_symbols() {
+        #[rustfmt::skip]
+        let symbols = [
+            // st_name: 1 ("outer"), st_info: STB_GLOBAL | STT_FUNC, st_shndx: 1, st_value: 0x1000, st_size: 0x100
+            1u8, 0, 0, 0, 0x12, 0, 1, 0, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x00, 0x01, 0, 0, 0, 0, 0, 0,
+            // st_name: 7 ("inner"), st_info: STB_LOCAL | STT_FUNC, st_shndx: 1, st_value: 0x1010, st_size: 0x10
+            7, 0, 0, 0, 0x02, 0, 1, 0, 0x10, 0x10, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let strs = b"\0outer\0inner\0";
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &symbols);
+        let strtab = StringTable::new(strs);
+
+        let mut symbolizer = Symbolizer::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        symbolizer.add_symbols(&symtab, &strtab).unwrap();
+
+        let found = symbolizer.lookup(0x1014).unwrap();
+        assert_eq!((found.name, found.offset), ("inner", 4));
+        // Past the end of the inner symbol, but still within the outer one
+        let found = symbolizer.lookup(0x1080).unwrap();
+        assert_eq!(
+            (found.name, found.offset, found.size),
+            ("outer", 0x80, 0x100)
+        );
+        assert!(symbolizer.lookup(0x1100).is_none());
+    }
+
+    #[test]
+    fn overlapping_symbols() {
+        #[rustfmt::skip]
+        let symbols = [
+            // st_name: 1 ("a"), st_info: STB_GLOBAL | STT_FUNC, st_shndx: 1, st_value: 0x1000, st_size: 0x4
+            1u8, 0, 0, 0, 0x12, 0, 1, 0, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0x04, 0, 0, 0, 0, 0, 0, 0,
+            // st_name: 3 ("b"), st_info: STB_GLOBAL | STT_FUNC, st_shndx: 1, st_value: 0x1004, st_size: 0x4
+            3, 0, 0, 0, 0x12, 0, 1, 0, 0x04, 0x10, 0, 0, 0, 0, 0, 0, 0x04, 0, 0, 0, 0, 0, 0, 0,
+            // st_name: 5 ("big"), st_info: STB_GLOBAL | STT_OBJECT, st_shndx: 1, st_value: 0x1006, st_size: 0x100
+            5, 0, 0, 0, 0x11, 0, 1, 0, 0x06, 0x10, 0, 0, 0, 0, 0, 0, 0x00, 0x01, 0, 0, 0, 0, 0, 0,
+            // st_name: 9 ("label"), st_info: STB_LOCAL | STT_FUNC, st_shndx: 1, st_value: 0x1200, st_size: 0
+            9, 0, 0, 0, 0x02, 0, 1, 0, 0x00, 0x12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let strs = b"\0a\0b\0big\0label\0";
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &symbols);
+        let strtab = StringTable::new(strs);
+
+        let mut symbolizer = Symbolizer::new(LittleEndian, Class::ELF64, abi::EM_X86_64);
+        symbolizer.add_symbols(&symtab, &strtab).unwrap();
+
+        let name = |addr| symbolizer.lookup(addr).map(|found| found.name);
+        assert_eq!(name(0x1003), Some("a"));
+        assert_eq!(name(0x1005), Some("b"));
+        // big starts after b, so it's preferred where they overlap
+        assert_eq!(name(0x1007), Some("big"));
+        assert_eq!(name(0x1105), Some("big"));
+        assert_eq!(name(0x1106), None);
+        assert_eq!(name(0x1200), Some("label"));
+        assert_eq!(name(0x1201), None);
+    }
+
+    #[test]
+    fn section_relative_values() {
+        #[rustfmt::skip]
+        let symbols = [
+            // st_name: 1 ("text"), st_info: STB_GLOBAL | STT_FUNC, st_shndx: 1, st_value: 0, st_size: 0x10
+            1u8, 0, 0, 0, 0x12, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0,
+            // st_name: 6 ("data"), st_info: STB_GLOBAL | STT_OBJECT, st_shndx: 2, st_value: 0, st_size: 0x8
+            6, 0, 0, 0, 0x11, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x08, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let strs = b"\0text\0data\0";
+        let symtab = SymbolTable::new(LittleEndian, Class::ELF64, &symbols);
+        let strtab = StringTable::new(strs);
+
+        let mut symbolizer = Symbolizer::new(LittleEndian, Class::ELF64, abi::EM_X86_64)
+            .with_section_relative_values();
+        symbolizer.add_symbols(&symtab, &strtab).unwrap();
+
+        let name = |shndx, offset| {
+            symbolizer
+                .lookup_in_section(shndx, offset)
+                .map(|found| found.name)
+        };
+        assert_eq!(name(1, 0x4), Some("text"));
+        assert_eq!(name(2, 0x4), Some("data"));
+        assert_eq!(name(2, 0x8), None);
+        assert_eq!(name(3, 0x0), None);
+        assert!(symbolizer.lookup(0x4).is_none());
+    }
+}
@@ -479,6 +479,7 @@ pub fn sh_type_to_str(sh_type: u32) -> Option<&'static str> {
         abi::SHT_PREINIT_ARRAY => Some("SHT_PREINIT_ARRAY"),
         abi::SHT_GROUP => Some("SHT_GROUP"),
         abi::SHT_SYMTAB_SHNDX => Some("SHT_SYMTAB_SHNDX"),
+        abi::SHT_RELR => Some("SHT_RELR"),
         abi::SHT_GNU_ATTRIBUTES => Some("SHT_GNU_ATTRIBUTES"),
         abi::SHT_GNU_HASH => Some("SHT_GNU_HASH"),
         abi::SHT_GNU_LIBLIST => Some("SHT_GNU_LIBLIST"),
@@ -497,6 +498,72 @@ pub fn sh_type_to_string(sh_type: u32) -> String {
     }
 }
 
+/// Like [sh_type_to_str], but also names the processor-specific values for the given `e_machine`.
+pub fn machine_sh_type_to_str(e_machine: u16, sh_type: u32) -> Option<&'static str> {
+    let name = match (e_machine, sh_type) {
+        (abi::EM_ARM, abi::SHT_ARM_EXIDX) => Some("SHT_ARM_EXIDX"),
+        (abi::EM_ARM, abi::SHT_ARM_PREEMPTMAP) => Some("SHT_ARM_PREEMPTMAP"),
+        (abi::EM_ARM, abi::SHT_ARM_ATTRIBUTES) => Some("SHT_ARM_ATTRIBUTES"),
+        (abi::EM_ARM, abi::SHT_ARM_DEBUGOVERLAY) => Some("SHT_ARM_DEBUGOVERLAY"),
+        (abi::EM_ARM, abi::SHT_ARM_OVERLAYSECTION) => Some("SHT_ARM_OVERLAYSECTION"),
+        (abi::EM_AARCH64, abi::SHT_AARCH64_ATTRIBUTES) => Some("SHT_AARCH64_ATTRIBUTES"),
+        (abi::EM_QDSP6, abi::SHT_HEX_ORDERED) => Some("SHT_HEX_ORDERED"),
+        (abi::EM_MIPS, abi::SHT_MIPS_LIBLIST) => Some("SHT_MIPS_LIBLIST"),
+        (abi::EM_MIPS, abi::SHT_MIPS_MSYM) => Some("SHT_MIPS_MSYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_CONFLICT) => Some("SHT_MIPS_CONFLICT"),
+        (abi::EM_MIPS, abi::SHT_MIPS_GPTAB) => Some("SHT_MIPS_GPTAB"),
+        (abi::EM_MIPS, abi::SHT_MIPS_UCODE) => Some("SHT_MIPS_UCODE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DEBUG) => Some("SHT_MIPS_DEBUG"),
+        (abi::EM_MIPS, abi::SHT_MIPS_REGINFO) => Some("SHT_MIPS_REGINFO"),
+        (abi::EM_MIPS, abi::SHT_MIPS_PACKAGE) => Some("SHT_MIPS_PACKAGE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_PACKSYM) => Some("SHT_MIPS_PACKSYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_RELD) => Some("SHT_MIPS_RELD"),
+        (abi::EM_MIPS, abi::SHT_MIPS_IFACE) => Some("SHT_MIPS_IFACE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_CONTENT) => Some("SHT_MIPS_CONTENT"),
+        (abi::EM_MIPS, abi::SHT_MIPS_OPTIONS) => Some("SHT_MIPS_OPTIONS"),
+        (abi::EM_MIPS, abi::SHT_MIPS_SHDR) => Some("SHT_MIPS_SHDR"),
+        (abi::EM_MIPS, abi::SHT_MIPS_FDESC) => Some("SHT_MIPS_FDESC"),
+        (abi::EM_MIPS, abi::SHT_MIPS_EXTSYM) => Some("SHT_MIPS_EXTSYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DENSE) => Some("SHT_MIPS_DENSE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_PDESC) => Some("SHT_MIPS_PDESC"),
+        (abi::EM_MIPS, abi::SHT_MIPS_LOCSYM) => Some("SHT_MIPS_LOCSYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_AUXSYM) => Some("SHT_MIPS_AUXSYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_OPTSYM) => Some("SHT_MIPS_OPTSYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_LOCSTR) => Some("SHT_MIPS_LOCSTR"),
+        (abi::EM_MIPS, abi::SHT_MIPS_LINE) => Some("SHT_MIPS_LINE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_RFDESC) => Some("SHT_MIPS_RFDESC"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DELTASYM) => Some("SHT_MIPS_DELTASYM"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DELTAINST) => Some("SHT_MIPS_DELTAINST"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DELTACLASS) => Some("SHT_MIPS_DELTACLASS"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DWARF) => Some("SHT_MIPS_DWARF"),
+        (abi::EM_MIPS, abi::SHT_MIPS_DELTADECL) => Some("SHT_MIPS_DELTADECL"),
+        (abi::EM_MIPS, abi::SHT_MIPS_SYMBOL_LIB) => Some("SHT_MIPS_SYMBOL_LIB"),
+        (abi::EM_MIPS, abi::SHT_MIPS_EVENTS) => Some("SHT_MIPS_EVENTS"),
+        (abi::EM_MIPS, abi::SHT_MIPS_TRANSLATE) => Some("SHT_MIPS_TRANSLATE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_PIXIE) => Some("SHT_MIPS_PIXIE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_XLATE) => Some("SHT_MIPS_XLATE"),
+        (abi::EM_MIPS, abi::SHT_MIPS_XLATE_DEBUG) => Some("SHT_MIPS_XLATE_DEBUG"),
+        (abi::EM_MIPS, abi::SHT_MIPS_WHIRL) => Some("SHT_MIPS_WHIRL"),
+        (abi::EM_MIPS, abi::SHT_MIPS_EH_REGION) => Some("SHT_MIPS_EH_REGION"),
+        (abi::EM_MIPS, abi::SHT_MIPS_XLATE_OLD) => Some("SHT_MIPS_XLATE_OLD"),
+        (abi::EM_MIPS, abi::SHT_MIPS_PDR_EXCEPTION) => Some("SHT_MIPS_PDR_EXCEPTION"),
+        (abi::EM_MIPS, abi::SHT_MIPS_XHASH) => Some("SHT_MIPS_XHASH"),
+        (abi::EM_MIPS, abi::SHT_MIPS_ABIFLAGS) => Some("SHT_MIPS_ABIFLAGS"),
+        (abi::EM_RISCV, abi::SHT_RISCV_ATTRIBUTES) => Some("SHT_RISCV_ATTRIBUTES"),
+        (abi::EM_X86_64, abi::SHT_X86_64_UNWIND) => Some("SHT_X86_64_UNWIND"),
+        _ => None,
+    };
+    name.or_else(|| sh_type_to_str(sh_type))
+}
+
+#[cfg(feature = "alloc")]
+pub fn machine_sh_type_to_string(e_machine: u16, sh_type: u32) -> String {
+    match machine_sh_type_to_str(e_machine, sh_type) {
+        Some(s) => s.to_string(),
+        None => format!("sh_type({sh_type:#x})"),
+    }
+}
+
 #[cfg(feature = "alloc")]
 pub fn p_flags_to_string(p_flags: u32) -> String {
     match p_flags < 8 {
@@ -536,6 +603,32 @@ pub fn p_type_to_string(p_type: u32) -> String {
     }
 }
 
+/// Like [p_type_to_str], but also names the processor-specific values for the given `e_machine`.
+pub fn machine_p_type_to_str(e_machine: u16, p_type: u32) -> Option<&'static str> {
+    let name = match (e_machine, p_type) {
+        (abi::EM_ARM, abi::PT_ARM_ARCHEXT) => Some("PT_ARM_ARCHEXT"),
+        (abi::EM_ARM, abi::PT_ARM_EXIDX) => Some("PT_ARM_EXIDX"),
+        (abi::EM_AARCH64, abi::PT_AARCH64_ARCHEXT) => Some("PT_AARCH64_ARCHEXT"),
+        (abi::EM_AARCH64, abi::PT_AARCH64_UNWIND) => Some("PT_AARCH64_UNWIND"),
+        (abi::EM_AARCH64, abi::PT_AARCH64_MEMTAG_MTE) => Some("PT_AARCH64_MEMTAG_MTE"),
+        (abi::EM_MIPS, abi::PT_MIPS_REGINFO) => Some("PT_MIPS_REGINFO"),
+        (abi::EM_MIPS, abi::PT_MIPS_RTPROC) => Some("PT_MIPS_RTPROC"),
+        (abi::EM_MIPS, abi::PT_MIPS_OPTIONS) => Some("PT_MIPS_OPTIONS"),
+        (abi::EM_MIPS, abi::PT_MIPS_ABIFLAGS) => Some("PT_MIPS_ABIFLAGS"),
+        (abi::EM_RISCV, abi::PT_RISCV_ATTRIBUTES) => Some("PT_RISCV_ATTRIBUTES"),
+        _ => None,
+    };
+    name.or_else(|| p_type_to_str(p_type))
+}
+
+#[cfg(feature = "alloc")]
+pub fn machine_p_type_to_string(e_machine: u16, p_type: u32) -> String {
+    match machine_p_type_to_str(e_machine, p_type) {
+        Some(s) => s.to_string(),
+        None => format!("p_type({p_type:#x})"),
+    }
+}
+
 pub fn st_symtype_to_str(st_symtype: u8) -> Option<&'static str> {
     match st_symtype {
         abi::STT_NOTYPE => Some("STT_NOTYPE"),
@@ -648,6 +741,9 @@ pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
         abi::DT_PREINIT_ARRAY => Some("DT_PREINIT_ARRAY"),
         abi::DT_PREINIT_ARRAYSZ => Some("DT_PREINIT_ARRAYSZ"),
         abi::DT_SYMTAB_SHNDX => Some("DT_SYMTAB_SHNDX"),
+        abi::DT_RELRSZ => Some("DT_RELRSZ"),
+        abi::DT_RELR => Some("DT_RELR"),
+        abi::DT_RELRENT => Some("DT_RELRENT"),
         abi::DT_GUILE_GC_ROOT => Some("DT_GUILE_GC_ROOT"),
         abi::DT_GUILE_GC_ROOT_SZ => Some("DT_GUILE_GC_ROOT_SZ"),
         abi::DT_GUILE_ENTRY => Some("DT_GUILE_ENTRY"),
@@ -690,3 +786,1304 @@ pub fn d_tag_to_str(d_tag: i64) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// Like [d_tag_to_str], but also names the processor-specific values for the given `e_machine`.
+pub fn machine_d_tag_to_str(e_machine: u16, d_tag: i64) -> Option<&'static str> {
+    let name = match (e_machine, d_tag) {
+        (abi::EM_ARM, abi::DT_ARM_SYMTABSZ) => Some("DT_ARM_SYMTABSZ"),
+        (abi::EM_ARM, abi::DT_ARM_PREEMPTMAP) => Some("DT_ARM_PREEMPTMAP"),
+        (abi::EM_AARCH64, abi::DT_AARCH64_BTI_PLT) => Some("DT_AARCH64_BTI_PLT"),
+        (abi::EM_AARCH64, abi::DT_AARCH64_PAC_PLT) => Some("DT_AARCH64_PAC_PLT"),
+        (abi::EM_AARCH64, abi::DT_AARCH64_VARIANT_PCS) => Some("DT_AARCH64_VARIANT_PCS"),
+        (abi::EM_MIPS, abi::DT_MIPS_RLD_VERSION) => Some("DT_MIPS_RLD_VERSION"),
+        (abi::EM_MIPS, abi::DT_MIPS_TIME_STAMP) => Some("DT_MIPS_TIME_STAMP"),
+        (abi::EM_MIPS, abi::DT_MIPS_ICHECKSUM) => Some("DT_MIPS_ICHECKSUM"),
+        (abi::EM_MIPS, abi::DT_MIPS_IVERSION) => Some("DT_MIPS_IVERSION"),
+        (abi::EM_MIPS, abi::DT_MIPS_FLAGS) => Some("DT_MIPS_FLAGS"),
+        (abi::EM_MIPS, abi::DT_MIPS_BASE_ADDRESS) => Some("DT_MIPS_BASE_ADDRESS"),
+        (abi::EM_MIPS, abi::DT_MIPS_MSYM) => Some("DT_MIPS_MSYM"),
+        (abi::EM_MIPS, abi::DT_MIPS_CONFLICT) => Some("DT_MIPS_CONFLICT"),
+        (abi::EM_MIPS, abi::DT_MIPS_LIBLIST) => Some("DT_MIPS_LIBLIST"),
+        (abi::EM_MIPS, abi::DT_MIPS_LOCAL_GOTNO) => Some("DT_MIPS_LOCAL_GOTNO"),
+        (abi::EM_MIPS, abi::DT_MIPS_CONFLICTNO) => Some("DT_MIPS_CONFLICTNO"),
+        (abi::EM_MIPS, abi::DT_MIPS_LIBLISTNO) => Some("DT_MIPS_LIBLISTNO"),
+        (abi::EM_MIPS, abi::DT_MIPS_SYMTABNO) => Some("DT_MIPS_SYMTABNO"),
+        (abi::EM_MIPS, abi::DT_MIPS_UNREFEXTNO) => Some("DT_MIPS_UNREFEXTNO"),
+        (abi::EM_MIPS, abi::DT_MIPS_GOTSYM) => Some("DT_MIPS_GOTSYM"),
+        (abi::EM_MIPS, abi::DT_MIPS_HIPAGENO) => Some("DT_MIPS_HIPAGENO"),
+        (abi::EM_MIPS, abi::DT_MIPS_RLD_MAP) => Some("DT_MIPS_RLD_MAP"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_CLASS) => Some("DT_MIPS_DELTA_CLASS"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_CLASS_NO) => Some("DT_MIPS_DELTA_CLASS_NO"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_INSTANCE) => Some("DT_MIPS_DELTA_INSTANCE"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_INSTANCE_NO) => Some("DT_MIPS_DELTA_INSTANCE_NO"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_RELOC) => Some("DT_MIPS_DELTA_RELOC"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_RELOC_NO) => Some("DT_MIPS_DELTA_RELOC_NO"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_SYM) => Some("DT_MIPS_DELTA_SYM"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_SYM_NO) => Some("DT_MIPS_DELTA_SYM_NO"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_CLASSSYM) => Some("DT_MIPS_DELTA_CLASSSYM"),
+        (abi::EM_MIPS, abi::DT_MIPS_DELTA_CLASSSYM_NO) => Some("DT_MIPS_DELTA_CLASSSYM_NO"),
+        (abi::EM_MIPS, abi::DT_MIPS_CXX_FLAGS) => Some("DT_MIPS_CXX_FLAGS"),
+        (abi::EM_MIPS, abi::DT_MIPS_PIXIE_INIT) => Some("DT_MIPS_PIXIE_INIT"),
+        (abi::EM_MIPS, abi::DT_MIPS_SYMBOL_LIB) => Some("DT_MIPS_SYMBOL_LIB"),
+        (abi::EM_MIPS, abi::DT_MIPS_LOCALPAGE_GOTIDX) => Some("DT_MIPS_LOCALPAGE_GOTIDX"),
+        (abi::EM_MIPS, abi::DT_MIPS_LOCAL_GOTIDX) => Some("DT_MIPS_LOCAL_GOTIDX"),
+        (abi::EM_MIPS, abi::DT_MIPS_HIDDEN_GOTIDX) => Some("DT_MIPS_HIDDEN_GOTIDX"),
+        (abi::EM_MIPS, abi::DT_MIPS_PROTECTED_GOTIDX) => Some("DT_MIPS_PROTECTED_GOTIDX"),
+        (abi::EM_MIPS, abi::DT_MIPS_OPTIONS) => Some("DT_MIPS_OPTIONS"),
+        (abi::EM_MIPS, abi::DT_MIPS_INTERFACE) => Some("DT_MIPS_INTERFACE"),
+        (abi::EM_MIPS, abi::DT_MIPS_DYNSTR_ALIGN) => Some("DT_MIPS_DYNSTR_ALIGN"),
+        (abi::EM_MIPS, abi::DT_MIPS_INTERFACE_SIZE) => Some("DT_MIPS_INTERFACE_SIZE"),
+        (abi::EM_MIPS, abi::DT_MIPS_RLD_TEXT_RESOLVE_ADDR) => Some("DT_MIPS_RLD_TEXT_RESOLVE_ADDR"),
+        (abi::EM_MIPS, abi::DT_MIPS_PERF_SUFFIX) => Some("DT_MIPS_PERF_SUFFIX"),
+        (abi::EM_MIPS, abi::DT_MIPS_COMPACT_SIZE) => Some("DT_MIPS_COMPACT_SIZE"),
+        (abi::EM_MIPS, abi::DT_MIPS_GP_VALUE) => Some("DT_MIPS_GP_VALUE"),
+        (abi::EM_MIPS, abi::DT_MIPS_AUX_DYNAMIC) => Some("DT_MIPS_AUX_DYNAMIC"),
+        (abi::EM_MIPS, abi::DT_MIPS_PLTGOT) => Some("DT_MIPS_PLTGOT"),
+        (abi::EM_MIPS, abi::DT_MIPS_RWPLT) => Some("DT_MIPS_RWPLT"),
+        (abi::EM_MIPS, abi::DT_MIPS_RLD_MAP_REL) => Some("DT_MIPS_RLD_MAP_REL"),
+        (abi::EM_MIPS, abi::DT_MIPS_XHASH) => Some("DT_MIPS_XHASH"),
+        (abi::EM_PPC, abi::DT_PPC_GOT) => Some("DT_PPC_GOT"),
+        (abi::EM_PPC, abi::DT_PPC_OPT) => Some("DT_PPC_OPT"),
+        (abi::EM_PPC64, abi::DT_PPC64_GLINK) => Some("DT_PPC64_GLINK"),
+        (abi::EM_PPC64, abi::DT_PPC64_OPD) => Some("DT_PPC64_OPD"),
+        (abi::EM_PPC64, abi::DT_PPC64_OPDSZ) => Some("DT_PPC64_OPDSZ"),
+        (abi::EM_PPC64, abi::DT_PPC64_OPT) => Some("DT_PPC64_OPT"),
+        (abi::EM_RISCV, abi::DT_RISCV_VARIANT_CC) => Some("DT_RISCV_VARIANT_CC"),
+        (abi::EM_SPARC | abi::EM_SPARC32PLUS | abi::EM_SPARCV9, abi::DT_SPARC_REGISTER) => {
+            Some("DT_SPARC_REGISTER")
+        }
+        _ => None,
+    };
+    name.or_else(|| d_tag_to_str(d_tag))
+}
+
+#[cfg(feature = "alloc")]
+pub fn machine_d_tag_to_string(e_machine: u16, d_tag: i64) -> String {
+    match machine_d_tag_to_str(e_machine, d_tag) {
+        Some(s) => s.to_string(),
+        None => format!("d_tag({d_tag:#x})"),
+    }
+}
+
+pub fn r_type_to_str(e_machine: u16, r_type: u32) -> Option<&'static str> {
+    match e_machine {
+        abi::EM_AARCH64 => aarch64_r_type_to_str(r_type),
+        abi::EM_ARM => arm_r_type_to_str(r_type),
+        abi::EM_QDSP6 => hexagon_r_type_to_str(r_type),
+        abi::EM_386 => i386_r_type_to_str(r_type),
+        abi::EM_LOONGARCH => loongarch_r_type_to_str(r_type),
+        abi::EM_MIPS => mips_r_type_to_str(r_type),
+        abi::EM_PPC => ppc_r_type_to_str(r_type),
+        abi::EM_PPC64 => ppc64_r_type_to_str(r_type),
+        abi::EM_RISCV => riscv_r_type_to_str(r_type),
+        abi::EM_S390 => s390_r_type_to_str(r_type),
+        abi::EM_SPARC | abi::EM_SPARC32PLUS | abi::EM_SPARCV9 => sparc_r_type_to_str(r_type),
+        abi::EM_X86_64 => x86_64_r_type_to_str(r_type),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn r_type_to_string(e_machine: u16, r_type: u32) -> String {
+    match r_type_to_str(e_machine, r_type) {
+        Some(s) => s.to_string(),
+        None => format!("r_type({r_type:#x})"),
+    }
+}
+
+fn aarch64_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_AARCH64_NONE => Some("R_AARCH64_NONE"),
+        abi::R_AARCH64_P32_ABS32 => Some("R_AARCH64_P32_ABS32"),
+        abi::R_AARCH64_P32_COPY => Some("R_AARCH64_P32_COPY"),
+        abi::R_AARCH64_P32_GLOB_DAT => Some("R_AARCH64_P32_GLOB_DAT"),
+        abi::R_AARCH64_P32_JUMP_SLOT => Some("R_AARCH64_P32_JUMP_SLOT"),
+        abi::R_AARCH64_P32_RELATIVE => Some("R_AARCH64_P32_RELATIVE"),
+        abi::R_AARCH64_P32_TLS_DTPMOD => Some("R_AARCH64_P32_TLS_DTPMOD"),
+        abi::R_AARCH64_P32_TLS_DTPREL => Some("R_AARCH64_P32_TLS_DTPREL"),
+        abi::R_AARCH64_P32_TLS_TPREL => Some("R_AARCH64_P32_TLS_TPREL"),
+        abi::R_AARCH64_P32_TLSDESC => Some("R_AARCH64_P32_TLSDESC"),
+        abi::R_AARCH64_P32_IRELATIVE => Some("R_AARCH64_P32_IRELATIVE"),
+        abi::R_AARCH64_ABS64 => Some("R_AARCH64_ABS64"),
+        abi::R_AARCH64_ABS32 => Some("R_AARCH64_ABS32"),
+        abi::R_AARCH64_ABS16 => Some("R_AARCH64_ABS16"),
+        abi::R_AARCH64_PREL64 => Some("R_AARCH64_PREL64"),
+        abi::R_AARCH64_PREL32 => Some("R_AARCH64_PREL32"),
+        abi::R_AARCH64_PREL16 => Some("R_AARCH64_PREL16"),
+        abi::R_AARCH64_MOVW_UABS_G0 => Some("R_AARCH64_MOVW_UABS_G0"),
+        abi::R_AARCH64_MOVW_UABS_G0_NC => Some("R_AARCH64_MOVW_UABS_G0_NC"),
+        abi::R_AARCH64_MOVW_UABS_G1 => Some("R_AARCH64_MOVW_UABS_G1"),
+        abi::R_AARCH64_MOVW_UABS_G1_NC => Some("R_AARCH64_MOVW_UABS_G1_NC"),
+        abi::R_AARCH64_MOVW_UABS_G2 => Some("R_AARCH64_MOVW_UABS_G2"),
+        abi::R_AARCH64_MOVW_UABS_G2_NC => Some("R_AARCH64_MOVW_UABS_G2_NC"),
+        abi::R_AARCH64_MOVW_UABS_G3 => Some("R_AARCH64_MOVW_UABS_G3"),
+        abi::R_AARCH64_MOVW_SABS_G0 => Some("R_AARCH64_MOVW_SABS_G0"),
+        abi::R_AARCH64_MOVW_SABS_G1 => Some("R_AARCH64_MOVW_SABS_G1"),
+        abi::R_AARCH64_MOVW_SABS_G2 => Some("R_AARCH64_MOVW_SABS_G2"),
+        abi::R_AARCH64_LD_PREL_LO19 => Some("R_AARCH64_LD_PREL_LO19"),
+        abi::R_AARCH64_ADR_PREL_LO21 => Some("R_AARCH64_ADR_PREL_LO21"),
+        abi::R_AARCH64_ADR_PREL_PG_HI21 => Some("R_AARCH64_ADR_PREL_PG_HI21"),
+        abi::R_AARCH64_ADR_PREL_PG_HI21_NC => Some("R_AARCH64_ADR_PREL_PG_HI21_NC"),
+        abi::R_AARCH64_ADD_ABS_LO12_NC => Some("R_AARCH64_ADD_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST8_ABS_LO12_NC => Some("R_AARCH64_LDST8_ABS_LO12_NC"),
+        abi::R_AARCH64_TSTBR14 => Some("R_AARCH64_TSTBR14"),
+        abi::R_AARCH64_CONDBR19 => Some("R_AARCH64_CONDBR19"),
+        abi::R_AARCH64_JUMP26 => Some("R_AARCH64_JUMP26"),
+        abi::R_AARCH64_CALL26 => Some("R_AARCH64_CALL26"),
+        abi::R_AARCH64_LDST16_ABS_LO12_NC => Some("R_AARCH64_LDST16_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST32_ABS_LO12_NC => Some("R_AARCH64_LDST32_ABS_LO12_NC"),
+        abi::R_AARCH64_LDST64_ABS_LO12_NC => Some("R_AARCH64_LDST64_ABS_LO12_NC"),
+        abi::R_AARCH64_MOVW_PREL_G0 => Some("R_AARCH64_MOVW_PREL_G0"),
+        abi::R_AARCH64_MOVW_PREL_G0_NC => Some("R_AARCH64_MOVW_PREL_G0_NC"),
+        abi::R_AARCH64_MOVW_PREL_G1 => Some("R_AARCH64_MOVW_PREL_G1"),
+        abi::R_AARCH64_MOVW_PREL_G1_NC => Some("R_AARCH64_MOVW_PREL_G1_NC"),
+        abi::R_AARCH64_MOVW_PREL_G2 => Some("R_AARCH64_MOVW_PREL_G2"),
+        abi::R_AARCH64_MOVW_PREL_G2_NC => Some("R_AARCH64_MOVW_PREL_G2_NC"),
+        abi::R_AARCH64_MOVW_PREL_G3 => Some("R_AARCH64_MOVW_PREL_G3"),
+        abi::R_AARCH64_LDST128_ABS_LO12_NC => Some("R_AARCH64_LDST128_ABS_LO12_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G0 => Some("R_AARCH64_MOVW_GOTOFF_G0"),
+        abi::R_AARCH64_MOVW_GOTOFF_G0_NC => Some("R_AARCH64_MOVW_GOTOFF_G0_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G1 => Some("R_AARCH64_MOVW_GOTOFF_G1"),
+        abi::R_AARCH64_MOVW_GOTOFF_G1_NC => Some("R_AARCH64_MOVW_GOTOFF_G1_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G2 => Some("R_AARCH64_MOVW_GOTOFF_G2"),
+        abi::R_AARCH64_MOVW_GOTOFF_G2_NC => Some("R_AARCH64_MOVW_GOTOFF_G2_NC"),
+        abi::R_AARCH64_MOVW_GOTOFF_G3 => Some("R_AARCH64_MOVW_GOTOFF_G3"),
+        abi::R_AARCH64_GOTREL64 => Some("R_AARCH64_GOTREL64"),
+        abi::R_AARCH64_GOTREL32 => Some("R_AARCH64_GOTREL32"),
+        abi::R_AARCH64_GOT_LD_PREL19 => Some("R_AARCH64_GOT_LD_PREL19"),
+        abi::R_AARCH64_LD64_GOTOFF_LO15 => Some("R_AARCH64_LD64_GOTOFF_LO15"),
+        abi::R_AARCH64_ADR_GOT_PAGE => Some("R_AARCH64_ADR_GOT_PAGE"),
+        abi::R_AARCH64_LD64_GOT_LO12_NC => Some("R_AARCH64_LD64_GOT_LO12_NC"),
+        abi::R_AARCH64_LD64_GOTPAGE_LO15 => Some("R_AARCH64_LD64_GOTPAGE_LO15"),
+        abi::R_AARCH64_TLSGD_ADR_PREL21 => Some("R_AARCH64_TLSGD_ADR_PREL21"),
+        abi::R_AARCH64_TLSGD_ADR_PAGE21 => Some("R_AARCH64_TLSGD_ADR_PAGE21"),
+        abi::R_AARCH64_TLSGD_ADD_LO12_NC => Some("R_AARCH64_TLSGD_ADD_LO12_NC"),
+        abi::R_AARCH64_TLSGD_MOVW_G1 => Some("R_AARCH64_TLSGD_MOVW_G1"),
+        abi::R_AARCH64_TLSGD_MOVW_G0_NC => Some("R_AARCH64_TLSGD_MOVW_G0_NC"),
+        abi::R_AARCH64_TLSLD_ADR_PREL21 => Some("R_AARCH64_TLSLD_ADR_PREL21"),
+        abi::R_AARCH64_TLSLD_ADR_PAGE21 => Some("R_AARCH64_TLSLD_ADR_PAGE21"),
+        abi::R_AARCH64_TLSLD_ADD_LO12_NC => Some("R_AARCH64_TLSLD_ADD_LO12_NC"),
+        abi::R_AARCH64_TLSLD_MOVW_G1 => Some("R_AARCH64_TLSLD_MOVW_G1"),
+        abi::R_AARCH64_TLSLD_MOVW_G0_NC => Some("R_AARCH64_TLSLD_MOVW_G0_NC"),
+        abi::R_AARCH64_TLSLD_LD_PREL19 => Some("R_AARCH64_TLSLD_LD_PREL19"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G2 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G2"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G1 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G1"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G0 => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G0"),
+        abi::R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC => Some("R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_HI12 => Some("R_AARCH64_TLSLD_ADD_DTPREL_HI12"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_LO12 => Some("R_AARCH64_TLSLD_ADD_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST8_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST8_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST16_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST16_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST32_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST32_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST64_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST64_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC => Some("R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSIE_MOVW_GOTTPREL_G1 => Some("R_AARCH64_TLSIE_MOVW_GOTTPREL_G1"),
+        abi::R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC => Some("R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC"),
+        abi::R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21 => Some("R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21"),
+        abi::R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC => Some("R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC"),
+        abi::R_AARCH64_TLSIE_LD_GOTTPREL_PREL19 => Some("R_AARCH64_TLSIE_LD_GOTTPREL_PREL19"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G2 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G2"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G1 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G1"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G1_NC => Some("R_AARCH64_TLSLE_MOVW_TPREL_G1_NC"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G0 => Some("R_AARCH64_TLSLE_MOVW_TPREL_G0"),
+        abi::R_AARCH64_TLSLE_MOVW_TPREL_G0_NC => Some("R_AARCH64_TLSLE_MOVW_TPREL_G0_NC"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_HI12 => Some("R_AARCH64_TLSLE_ADD_TPREL_HI12"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_LO12 => Some("R_AARCH64_TLSLE_ADD_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_ADD_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_ADD_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST8_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST8_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST16_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST16_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST32_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST32_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLE_LDST64_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST64_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSDESC_LD_PREL19 => Some("R_AARCH64_TLSDESC_LD_PREL19"),
+        abi::R_AARCH64_TLSDESC_ADR_PREL21 => Some("R_AARCH64_TLSDESC_ADR_PREL21"),
+        abi::R_AARCH64_TLSDESC_ADR_PAGE21 => Some("R_AARCH64_TLSDESC_ADR_PAGE21"),
+        abi::R_AARCH64_TLSDESC_LD64_LO12 => Some("R_AARCH64_TLSDESC_LD64_LO12"),
+        abi::R_AARCH64_TLSDESC_ADD_LO12 => Some("R_AARCH64_TLSDESC_ADD_LO12"),
+        abi::R_AARCH64_TLSDESC_OFF_G1 => Some("R_AARCH64_TLSDESC_OFF_G1"),
+        abi::R_AARCH64_TLSDESC_OFF_G0_NC => Some("R_AARCH64_TLSDESC_OFF_G0_NC"),
+        abi::R_AARCH64_TLSDESC_LDR => Some("R_AARCH64_TLSDESC_LDR"),
+        abi::R_AARCH64_TLSDESC_ADD => Some("R_AARCH64_TLSDESC_ADD"),
+        abi::R_AARCH64_TLSDESC_CALL => Some("R_AARCH64_TLSDESC_CALL"),
+        abi::R_AARCH64_TLSLE_LDST128_TPREL_LO12 => Some("R_AARCH64_TLSLE_LDST128_TPREL_LO12"),
+        abi::R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC => Some("R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC"),
+        abi::R_AARCH64_TLSLD_LDST128_DTPREL_LO12 => Some("R_AARCH64_TLSLD_LDST128_DTPREL_LO12"),
+        abi::R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC => {
+            Some("R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC")
+        }
+        abi::R_AARCH64_COPY => Some("R_AARCH64_COPY"),
+        abi::R_AARCH64_GLOB_DAT => Some("R_AARCH64_GLOB_DAT"),
+        abi::R_AARCH64_JUMP_SLOT => Some("R_AARCH64_JUMP_SLOT"),
+        abi::R_AARCH64_RELATIVE => Some("R_AARCH64_RELATIVE"),
+        abi::R_AARCH64_TLS_DTPMOD => Some("R_AARCH64_TLS_DTPMOD"),
+        abi::R_AARCH64_TLS_DTPREL => Some("R_AARCH64_TLS_DTPREL"),
+        abi::R_AARCH64_TLS_TPREL => Some("R_AARCH64_TLS_TPREL"),
+        abi::R_AARCH64_TLSDESC => Some("R_AARCH64_TLSDESC"),
+        abi::R_AARCH64_IRELATIVE => Some("R_AARCH64_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn arm_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_ARM_NONE => Some("R_ARM_NONE"),
+        abi::R_ARM_PC24 => Some("R_ARM_PC24"),
+        abi::R_ARM_ABS32 => Some("R_ARM_ABS32"),
+        abi::R_ARM_REL32 => Some("R_ARM_REL32"),
+        abi::R_ARM_LDR_PC_G0 => Some("R_ARM_LDR_PC_G0"),
+        abi::R_ARM_ABS16 => Some("R_ARM_ABS16"),
+        abi::R_ARM_ABS12 => Some("R_ARM_ABS12"),
+        abi::R_ARM_THM_ABS5 => Some("R_ARM_THM_ABS5"),
+        abi::R_ARM_ABS8 => Some("R_ARM_ABS8"),
+        abi::R_ARM_SBREL32 => Some("R_ARM_SBREL32"),
+        abi::R_ARM_THM_CALL => Some("R_ARM_THM_CALL"),
+        abi::R_ARM_THM_PC8 => Some("R_ARM_THM_PC8"),
+        abi::R_ARM_BREL_ADJ => Some("R_ARM_BREL_ADJ"),
+        abi::R_ARM_TLS_DESC => Some("R_ARM_TLS_DESC"),
+        abi::R_ARM_THM_SWI8 => Some("R_ARM_THM_SWI8"),
+        abi::R_ARM_XPC25 => Some("R_ARM_XPC25"),
+        abi::R_ARM_THM_XPC22 => Some("R_ARM_THM_XPC22"),
+        abi::R_ARM_TLS_DTPMOD32 => Some("R_ARM_TLS_DTPMOD32"),
+        abi::R_ARM_TLS_DTPOFF32 => Some("R_ARM_TLS_DTPOFF32"),
+        abi::R_ARM_TLS_TPOFF32 => Some("R_ARM_TLS_TPOFF32"),
+        abi::R_ARM_COPY => Some("R_ARM_COPY"),
+        abi::R_ARM_GLOB_DAT => Some("R_ARM_GLOB_DAT"),
+        abi::R_ARM_JUMP_SLOT => Some("R_ARM_JUMP_SLOT"),
+        abi::R_ARM_RELATIVE => Some("R_ARM_RELATIVE"),
+        abi::R_ARM_GOTOFF32 => Some("R_ARM_GOTOFF32"),
+        abi::R_ARM_BASE_PREL => Some("R_ARM_BASE_PREL"),
+        abi::R_ARM_BASE_BREL => Some("R_ARM_BASE_BREL"),
+        abi::R_ARM_PLT32 => Some("R_ARM_PLT32"),
+        abi::R_ARM_CALL => Some("R_ARM_CALL"),
+        abi::R_ARM_JUMP24 => Some("R_ARM_JUMP24"),
+        abi::R_ARM_THM_JUMP24 => Some("R_ARM_THM_JUMP24"),
+        abi::R_ARM_BASE_ABS => Some("R_ARM_BASE_ABS"),
+        abi::R_ARM_ALU_PCREL_7_0 => Some("R_ARM_ALU_PCREL_7_0"),
+        abi::R_ARM_ALU_PCREL_15_8 => Some("R_ARM_ALU_PCREL_15_8"),
+        abi::R_ARM_ALU_PCREL_23_15 => Some("R_ARM_ALU_PCREL_23_15"),
+        abi::R_ARM_LDR_SBREL_11_0 => Some("R_ARM_LDR_SBREL_11_0"),
+        abi::R_ARM_ALU_SBREL_19_12 => Some("R_ARM_ALU_SBREL_19_12"),
+        abi::R_ARM_ALU_SBREL_27_20 => Some("R_ARM_ALU_SBREL_27_20"),
+        abi::R_ARM_TARGET1 => Some("R_ARM_TARGET1"),
+        abi::R_ARM_SBREL31 => Some("R_ARM_SBREL31"),
+        abi::R_ARM_V4BX => Some("R_ARM_V4BX"),
+        abi::R_ARM_TARGET2 => Some("R_ARM_TARGET2"),
+        abi::R_ARM_PREL31 => Some("R_ARM_PREL31"),
+        abi::R_ARM_MOVW_ABS_NC => Some("R_ARM_MOVW_ABS_NC"),
+        abi::R_ARM_MOVT_ABS => Some("R_ARM_MOVT_ABS"),
+        abi::R_ARM_MOVW_PREL_NC => Some("R_ARM_MOVW_PREL_NC"),
+        abi::R_ARM_MOVT_PREL => Some("R_ARM_MOVT_PREL"),
+        abi::R_ARM_THM_MOVW_ABS_NC => Some("R_ARM_THM_MOVW_ABS_NC"),
+        abi::R_ARM_THM_MOVT_ABS => Some("R_ARM_THM_MOVT_ABS"),
+        abi::R_ARM_THM_MOVW_PREL_NC => Some("R_ARM_THM_MOVW_PREL_NC"),
+        abi::R_ARM_THM_MOVT_PREL => Some("R_ARM_THM_MOVT_PREL"),
+        abi::R_ARM_THM_JUMP19 => Some("R_ARM_THM_JUMP19"),
+        abi::R_ARM_THM_JUMP6 => Some("R_ARM_THM_JUMP6"),
+        abi::R_ARM_THM_ALU_PREL_11_0 => Some("R_ARM_THM_ALU_PREL_11_0"),
+        abi::R_ARM_THM_PC12 => Some("R_ARM_THM_PC12"),
+        abi::R_ARM_ABS32_NOI => Some("R_ARM_ABS32_NOI"),
+        abi::R_ARM_REL32_NOI => Some("R_ARM_REL32_NOI"),
+        abi::R_ARM_ALU_PC_G0_NC => Some("R_ARM_ALU_PC_G0_NC"),
+        abi::R_ARM_ALU_PC_G0 => Some("R_ARM_ALU_PC_G0"),
+        abi::R_ARM_ALU_PC_G1_NC => Some("R_ARM_ALU_PC_G1_NC"),
+        abi::R_ARM_ALU_PC_G1 => Some("R_ARM_ALU_PC_G1"),
+        abi::R_ARM_ALU_PC_G2 => Some("R_ARM_ALU_PC_G2"),
+        abi::R_ARM_LDR_PC_G1 => Some("R_ARM_LDR_PC_G1"),
+        abi::R_ARM_LDR_PC_G2 => Some("R_ARM_LDR_PC_G2"),
+        abi::R_ARM_LDRS_PC_G0 => Some("R_ARM_LDRS_PC_G0"),
+        abi::R_ARM_LDRS_PC_G1 => Some("R_ARM_LDRS_PC_G1"),
+        abi::R_ARM_LDRS_PC_G2 => Some("R_ARM_LDRS_PC_G2"),
+        abi::R_ARM_LDC_PC_G0 => Some("R_ARM_LDC_PC_G0"),
+        abi::R_ARM_LDC_PC_G1 => Some("R_ARM_LDC_PC_G1"),
+        abi::R_ARM_LDC_PC_G2 => Some("R_ARM_LDC_PC_G2"),
+        abi::R_ARM_ALU_SB_G0_NC => Some("R_ARM_ALU_SB_G0_NC"),
+        abi::R_ARM_ALU_SB_G0 => Some("R_ARM_ALU_SB_G0"),
+        abi::R_ARM_ALU_SB_G1_NC => Some("R_ARM_ALU_SB_G1_NC"),
+        abi::R_ARM_ALU_SB_G1 => Some("R_ARM_ALU_SB_G1"),
+        abi::R_ARM_ALU_SB_G2 => Some("R_ARM_ALU_SB_G2"),
+        abi::R_ARM_LDR_SB_G0 => Some("R_ARM_LDR_SB_G0"),
+        abi::R_ARM_LDR_SB_G1 => Some("R_ARM_LDR_SB_G1"),
+        abi::R_ARM_LDR_SB_G2 => Some("R_ARM_LDR_SB_G2"),
+        abi::R_ARM_LDRS_SB_G0 => Some("R_ARM_LDRS_SB_G0"),
+        abi::R_ARM_LDRS_SB_G1 => Some("R_ARM_LDRS_SB_G1"),
+        abi::R_ARM_LDRS_SB_G2 => Some("R_ARM_LDRS_SB_G2"),
+        abi::R_ARM_LDC_SB_G0 => Some("R_ARM_LDC_SB_G0"),
+        abi::R_ARM_LDC_SB_G1 => Some("R_ARM_LDC_SB_G1"),
+        abi::R_ARM_LDC_SB_G2 => Some("R_ARM_LDC_SB_G2"),
+        abi::R_ARM_MOVW_BREL_NC => Some("R_ARM_MOVW_BREL_NC"),
+        abi::R_ARM_MOVT_BREL => Some("R_ARM_MOVT_BREL"),
+        abi::R_ARM_MOVW_BREL => Some("R_ARM_MOVW_BREL"),
+        abi::R_ARM_THM_MOVW_BREL_NC => Some("R_ARM_THM_MOVW_BREL_NC"),
+        abi::R_ARM_THM_MOVT_BREL => Some("R_ARM_THM_MOVT_BREL"),
+        abi::R_ARM_THM_MOVW_BREL => Some("R_ARM_THM_MOVW_BREL"),
+        abi::R_ARM_TLS_GOTDESC => Some("R_ARM_TLS_GOTDESC"),
+        abi::R_ARM_TLS_CALL => Some("R_ARM_TLS_CALL"),
+        abi::R_ARM_TLS_DESCSEQ => Some("R_ARM_TLS_DESCSEQ"),
+        abi::R_ARM_THM_TLS_CALL => Some("R_ARM_THM_TLS_CALL"),
+        abi::R_ARM_PLT32_ABS => Some("R_ARM_PLT32_ABS"),
+        abi::R_ARM_GOT_ABS => Some("R_ARM_GOT_ABS"),
+        abi::R_ARM_GOT_PREL => Some("R_ARM_GOT_PREL"),
+        abi::R_ARM_GOT_BREL12 => Some("R_ARM_GOT_BREL12"),
+        abi::R_ARM_GOTOFF12 => Some("R_ARM_GOTOFF12"),
+        abi::R_ARM_GOTRELAX => Some("R_ARM_GOTRELAX"),
+        abi::R_ARM_GNU_VTENTRY => Some("R_ARM_GNU_VTENTRY"),
+        abi::R_ARM_GNU_VTINHERIT => Some("R_ARM_GNU_VTINHERIT"),
+        abi::R_ARM_THM_JUMP11 => Some("R_ARM_THM_JUMP11"),
+        abi::R_ARM_THM_JUMP8 => Some("R_ARM_THM_JUMP8"),
+        abi::R_ARM_TLS_GD32 => Some("R_ARM_TLS_GD32"),
+        abi::R_ARM_TLS_LDM32 => Some("R_ARM_TLS_LDM32"),
+        abi::R_ARM_TLS_LDO32 => Some("R_ARM_TLS_LDO32"),
+        abi::R_ARM_TLS_IE32 => Some("R_ARM_TLS_IE32"),
+        abi::R_ARM_TLS_LE32 => Some("R_ARM_TLS_LE32"),
+        abi::R_ARM_TLS_LDO12 => Some("R_ARM_TLS_LDO12"),
+        abi::R_ARM_TLS_LE12 => Some("R_ARM_TLS_LE12"),
+        abi::R_ARM_TLS_IE12GP => Some("R_ARM_TLS_IE12GP"),
+        abi::R_ARM_ME_TOO => Some("R_ARM_ME_TOO"),
+        abi::R_ARM_THM_TLS_DESCSEQ16 => Some("R_ARM_THM_TLS_DESCSEQ16"),
+        abi::R_ARM_THM_TLS_DESCSEQ32 => Some("R_ARM_THM_TLS_DESCSEQ32"),
+        abi::R_ARM_THM_GOT_BREL12 => Some("R_ARM_THM_GOT_BREL12"),
+        abi::R_ARM_THM_ALU_ABS_G0_NC => Some("R_ARM_THM_ALU_ABS_G0_NC"),
+        abi::R_ARM_THM_ALU_ABS_G1_NC => Some("R_ARM_THM_ALU_ABS_G1_NC"),
+        abi::R_ARM_THM_ALU_ABS_G2_NC => Some("R_ARM_THM_ALU_ABS_G2_NC"),
+        abi::R_ARM_THM_ALU_ABS_G3 => Some("R_ARM_THM_ALU_ABS_G3"),
+        abi::R_ARM_THM_BF16 => Some("R_ARM_THM_BF16"),
+        abi::R_ARM_THM_BF12 => Some("R_ARM_THM_BF12"),
+        abi::R_ARM_THM_BF18 => Some("R_ARM_THM_BF18"),
+        abi::R_ARM_IRELATIVE => Some("R_ARM_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn hexagon_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_HEX_NONE => Some("R_HEX_NONE"),
+        abi::R_HEX_B22_PCREL => Some("R_HEX_B22_PCREL"),
+        abi::R_HEX_B15_PCREL => Some("R_HEX_B15_PCREL"),
+        abi::R_HEX_B7_PCREL => Some("R_HEX_B7_PCREL"),
+        abi::R_HEX_LO16 => Some("R_HEX_LO16"),
+        abi::R_HEX_HI16 => Some("R_HEX_HI16"),
+        abi::R_HEX_32 => Some("R_HEX_32"),
+        abi::R_HEX_16 => Some("R_HEX_16"),
+        abi::R_HEX_8 => Some("R_HEX_8"),
+        abi::R_HEX_GPREL16_0 => Some("R_HEX_GPREL16_0"),
+        abi::R_HEX_GPREL16_1 => Some("R_HEX_GPREL16_1"),
+        abi::R_HEX_GPREL16_2 => Some("R_HEX_GPREL16_2"),
+        abi::R_HEX_GPREL16_3 => Some("R_HEX_GPREL16_3"),
+        abi::R_HEX_HL16 => Some("R_HEX_HL16"),
+        abi::R_HEX_B13_PCREL => Some("R_HEX_B13_PCREL"),
+        abi::R_HEX_B9_PCREL => Some("R_HEX_B9_PCREL"),
+        abi::R_HEX_B32_PCREL_X => Some("R_HEX_B32_PCREL_X"),
+        abi::R_HEX_32_6_X => Some("R_HEX_32_6_X"),
+        abi::R_HEX_B22_PCREL_X => Some("R_HEX_B22_PCREL_X"),
+        abi::R_HEX_B15_PCREL_X => Some("R_HEX_B15_PCREL_X"),
+        abi::R_HEX_B13_PCREL_X => Some("R_HEX_B13_PCREL_X"),
+        abi::R_HEX_B9_PCREL_X => Some("R_HEX_B9_PCREL_X"),
+        abi::R_HEX_B7_PCREL_X => Some("R_HEX_B7_PCREL_X"),
+        abi::R_HEX_16_X => Some("R_HEX_16_X"),
+        abi::R_HEX_12_X => Some("R_HEX_12_X"),
+        abi::R_HEX_11_X => Some("R_HEX_11_X"),
+        abi::R_HEX_10_X => Some("R_HEX_10_X"),
+        abi::R_HEX_9_X => Some("R_HEX_9_X"),
+        abi::R_HEX_8_X => Some("R_HEX_8_X"),
+        abi::R_HEX_7_X => Some("R_HEX_7_X"),
+        abi::R_HEX_6_X => Some("R_HEX_6_X"),
+        abi::R_HEX_32_PCREL => Some("R_HEX_32_PCREL"),
+        abi::R_HEX_COPY => Some("R_HEX_COPY"),
+        abi::R_HEX_GLOB_DAT => Some("R_HEX_GLOB_DAT"),
+        abi::R_HEX_JMP_SLOT => Some("R_HEX_JMP_SLOT"),
+        abi::R_HEX_RELATIVE => Some("R_HEX_RELATIVE"),
+        abi::R_HEX_PLT_B22_PCREL => Some("R_HEX_PLT_B22_PCREL"),
+        abi::R_HEX_GOTREL_LO16 => Some("R_HEX_GOTREL_LO16"),
+        abi::R_HEX_GOTREL_HI16 => Some("R_HEX_GOTREL_HI16"),
+        abi::R_HEX_GOTREL_32 => Some("R_HEX_GOTREL_32"),
+        abi::R_HEX_GOT_LO16 => Some("R_HEX_GOT_LO16"),
+        abi::R_HEX_GOT_HI16 => Some("R_HEX_GOT_HI16"),
+        abi::R_HEX_GOT_32 => Some("R_HEX_GOT_32"),
+        abi::R_HEX_GOT_16 => Some("R_HEX_GOT_16"),
+        abi::R_HEX_DTPMOD_32 => Some("R_HEX_DTPMOD_32"),
+        abi::R_HEX_DTPREL_LO16 => Some("R_HEX_DTPREL_LO16"),
+        abi::R_HEX_DTPREL_HI16 => Some("R_HEX_DTPREL_HI16"),
+        abi::R_HEX_DTPREL_32 => Some("R_HEX_DTPREL_32"),
+        abi::R_HEX_DTPREL_16 => Some("R_HEX_DTPREL_16"),
+        abi::R_HEX_GD_PLT_B22_PCREL => Some("R_HEX_GD_PLT_B22_PCREL"),
+        abi::R_HEX_GD_GOT_LO16 => Some("R_HEX_GD_GOT_LO16"),
+        abi::R_HEX_GD_GOT_HI16 => Some("R_HEX_GD_GOT_HI16"),
+        abi::R_HEX_GD_GOT_32 => Some("R_HEX_GD_GOT_32"),
+        abi::R_HEX_GD_GOT_16 => Some("R_HEX_GD_GOT_16"),
+        abi::R_HEX_IE_LO16 => Some("R_HEX_IE_LO16"),
+        abi::R_HEX_IE_HI16 => Some("R_HEX_IE_HI16"),
+        abi::R_HEX_IE_32 => Some("R_HEX_IE_32"),
+        abi::R_HEX_IE_GOT_LO16 => Some("R_HEX_IE_GOT_LO16"),
+        abi::R_HEX_IE_GOT_HI16 => Some("R_HEX_IE_GOT_HI16"),
+        abi::R_HEX_IE_GOT_32 => Some("R_HEX_IE_GOT_32"),
+        abi::R_HEX_IE_GOT_16 => Some("R_HEX_IE_GOT_16"),
+        abi::R_HEX_TPREL_LO16 => Some("R_HEX_TPREL_LO16"),
+        abi::R_HEX_TPREL_HI16 => Some("R_HEX_TPREL_HI16"),
+        abi::R_HEX_TPREL_32 => Some("R_HEX_TPREL_32"),
+        abi::R_HEX_TPREL_16 => Some("R_HEX_TPREL_16"),
+        abi::R_HEX_6_PCREL_X => Some("R_HEX_6_PCREL_X"),
+        abi::R_HEX_GOTREL_32_6_X => Some("R_HEX_GOTREL_32_6_X"),
+        abi::R_HEX_GOTREL_16_X => Some("R_HEX_GOTREL_16_X"),
+        abi::R_HEX_GOTREL_11_X => Some("R_HEX_GOTREL_11_X"),
+        abi::R_HEX_GOT_32_6_X => Some("R_HEX_GOT_32_6_X"),
+        abi::R_HEX_GOT_16_X => Some("R_HEX_GOT_16_X"),
+        abi::R_HEX_GOT_11_X => Some("R_HEX_GOT_11_X"),
+        abi::R_HEX_DTPREL_32_6_X => Some("R_HEX_DTPREL_32_6_X"),
+        abi::R_HEX_DTPREL_16_X => Some("R_HEX_DTPREL_16_X"),
+        abi::R_HEX_DTPREL_11_X => Some("R_HEX_DTPREL_11_X"),
+        abi::R_HEX_GD_GOT_32_6_X => Some("R_HEX_GD_GOT_32_6_X"),
+        abi::R_HEX_GD_GOT_16_X => Some("R_HEX_GD_GOT_16_X"),
+        abi::R_HEX_GD_GOT_11_X => Some("R_HEX_GD_GOT_11_X"),
+        abi::R_HEX_IE_32_6_X => Some("R_HEX_IE_32_6_X"),
+        abi::R_HEX_IE_16_X => Some("R_HEX_IE_16_X"),
+        abi::R_HEX_IE_GOT_32_6_X => Some("R_HEX_IE_GOT_32_6_X"),
+        abi::R_HEX_IE_GOT_16_X => Some("R_HEX_IE_GOT_16_X"),
+        abi::R_HEX_IE_GOT_11_X => Some("R_HEX_IE_GOT_11_X"),
+        abi::R_HEX_TPREL_32_6_X => Some("R_HEX_TPREL_32_6_X"),
+        abi::R_HEX_TPREL_16_X => Some("R_HEX_TPREL_16_X"),
+        abi::R_HEX_TPREL_11_X => Some("R_HEX_TPREL_11_X"),
+        abi::R_HEX_LD_PLT_B22_PCREL => Some("R_HEX_LD_PLT_B22_PCREL"),
+        abi::R_HEX_LD_GOT_LO16 => Some("R_HEX_LD_GOT_LO16"),
+        abi::R_HEX_LD_GOT_HI16 => Some("R_HEX_LD_GOT_HI16"),
+        abi::R_HEX_LD_GOT_32 => Some("R_HEX_LD_GOT_32"),
+        abi::R_HEX_LD_GOT_16 => Some("R_HEX_LD_GOT_16"),
+        abi::R_HEX_LD_GOT_32_6_X => Some("R_HEX_LD_GOT_32_6_X"),
+        abi::R_HEX_LD_GOT_16_X => Some("R_HEX_LD_GOT_16_X"),
+        abi::R_HEX_LD_GOT_11_X => Some("R_HEX_LD_GOT_11_X"),
+        abi::R_HEX_23_REG => Some("R_HEX_23_REG"),
+        abi::R_HEX_GD_PLT_B22_PCREL_X => Some("R_HEX_GD_PLT_B22_PCREL_X"),
+        abi::R_HEX_GD_PLT_B32_PCREL_X => Some("R_HEX_GD_PLT_B32_PCREL_X"),
+        abi::R_HEX_LD_PLT_B22_PCREL_X => Some("R_HEX_LD_PLT_B22_PCREL_X"),
+        abi::R_HEX_LD_PLT_B32_PCREL_X => Some("R_HEX_LD_PLT_B32_PCREL_X"),
+        abi::R_HEX_27_REG => Some("R_HEX_27_REG"),
+        _ => None,
+    }
+}
+
+fn i386_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_386_NONE => Some("R_386_NONE"),
+        abi::R_386_32 => Some("R_386_32"),
+        abi::R_386_PC32 => Some("R_386_PC32"),
+        abi::R_386_GOT32 => Some("R_386_GOT32"),
+        abi::R_386_PLT32 => Some("R_386_PLT32"),
+        abi::R_386_COPY => Some("R_386_COPY"),
+        abi::R_386_GLOB_DAT => Some("R_386_GLOB_DAT"),
+        abi::R_386_JMP_SLOT => Some("R_386_JMP_SLOT"),
+        abi::R_386_RELATIVE => Some("R_386_RELATIVE"),
+        abi::R_386_GOTOFF => Some("R_386_GOTOFF"),
+        abi::R_386_GOTPC => Some("R_386_GOTPC"),
+        abi::R_386_32PLT => Some("R_386_32PLT"),
+        abi::R_386_TLS_TPOFF => Some("R_386_TLS_TPOFF"),
+        abi::R_386_TLS_IE => Some("R_386_TLS_IE"),
+        abi::R_386_TLS_GOTIE => Some("R_386_TLS_GOTIE"),
+        abi::R_386_TLS_LE => Some("R_386_TLS_LE"),
+        abi::R_386_TLS_GD => Some("R_386_TLS_GD"),
+        abi::R_386_TLS_LDM => Some("R_386_TLS_LDM"),
+        abi::R_386_16 => Some("R_386_16"),
+        abi::R_386_PC16 => Some("R_386_PC16"),
+        abi::R_386_8 => Some("R_386_8"),
+        abi::R_386_PC8 => Some("R_386_PC8"),
+        abi::R_386_TLS_GD_32 => Some("R_386_TLS_GD_32"),
+        abi::R_386_TLS_GD_PUSH => Some("R_386_TLS_GD_PUSH"),
+        abi::R_386_TLS_GD_CALL => Some("R_386_TLS_GD_CALL"),
+        abi::R_386_TLS_GD_POP => Some("R_386_TLS_GD_POP"),
+        abi::R_386_TLS_LDM_32 => Some("R_386_TLS_LDM_32"),
+        abi::R_386_TLS_LDM_PUSH => Some("R_386_TLS_LDM_PUSH"),
+        abi::R_386_TLS_LDM_CALL => Some("R_386_TLS_LDM_CALL"),
+        abi::R_386_TLS_LDM_POP => Some("R_386_TLS_LDM_POP"),
+        abi::R_386_TLS_LDO_32 => Some("R_386_TLS_LDO_32"),
+        abi::R_386_TLS_IE_32 => Some("R_386_TLS_IE_32"),
+        abi::R_386_TLS_LE_32 => Some("R_386_TLS_LE_32"),
+        abi::R_386_TLS_DTPMOD32 => Some("R_386_TLS_DTPMOD32"),
+        abi::R_386_TLS_DTPOFF32 => Some("R_386_TLS_DTPOFF32"),
+        abi::R_386_TLS_TPOFF32 => Some("R_386_TLS_TPOFF32"),
+        abi::R_386_SIZE32 => Some("R_386_SIZE32"),
+        abi::R_386_TLS_GOTDESC => Some("R_386_TLS_GOTDESC"),
+        abi::R_386_TLS_DESC_CALL => Some("R_386_TLS_DESC_CALL"),
+        abi::R_386_TLS_DESC => Some("R_386_TLS_DESC"),
+        abi::R_386_IRELATIVE => Some("R_386_IRELATIVE"),
+        abi::R_386_GOT32X => Some("R_386_GOT32X"),
+        _ => None,
+    }
+}
+
+fn loongarch_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_LARCH_NONE => Some("R_LARCH_NONE"),
+        abi::R_LARCH_32 => Some("R_LARCH_32"),
+        abi::R_LARCH_64 => Some("R_LARCH_64"),
+        abi::R_LARCH_RELATIVE => Some("R_LARCH_RELATIVE"),
+        abi::R_LARCH_COPY => Some("R_LARCH_COPY"),
+        abi::R_LARCH_JUMP_SLOT => Some("R_LARCH_JUMP_SLOT"),
+        abi::R_LARCH_TLS_DTPMOD32 => Some("R_LARCH_TLS_DTPMOD32"),
+        abi::R_LARCH_TLS_DTPMOD64 => Some("R_LARCH_TLS_DTPMOD64"),
+        abi::R_LARCH_TLS_DTPREL32 => Some("R_LARCH_TLS_DTPREL32"),
+        abi::R_LARCH_TLS_DTPREL64 => Some("R_LARCH_TLS_DTPREL64"),
+        abi::R_LARCH_TLS_TPREL32 => Some("R_LARCH_TLS_TPREL32"),
+        abi::R_LARCH_TLS_TPREL64 => Some("R_LARCH_TLS_TPREL64"),
+        abi::R_LARCH_IRELATIVE => Some("R_LARCH_IRELATIVE"),
+        abi::R_LARCH_TLS_DESC32 => Some("R_LARCH_TLS_DESC32"),
+        abi::R_LARCH_TLS_DESC64 => Some("R_LARCH_TLS_DESC64"),
+        abi::R_LARCH_MARK_LA => Some("R_LARCH_MARK_LA"),
+        abi::R_LARCH_MARK_PCREL => Some("R_LARCH_MARK_PCREL"),
+        abi::R_LARCH_SOP_PUSH_PCREL => Some("R_LARCH_SOP_PUSH_PCREL"),
+        abi::R_LARCH_SOP_PUSH_ABSOLUTE => Some("R_LARCH_SOP_PUSH_ABSOLUTE"),
+        abi::R_LARCH_SOP_PUSH_DUP => Some("R_LARCH_SOP_PUSH_DUP"),
+        abi::R_LARCH_SOP_PUSH_GPREL => Some("R_LARCH_SOP_PUSH_GPREL"),
+        abi::R_LARCH_SOP_PUSH_TLS_TPREL => Some("R_LARCH_SOP_PUSH_TLS_TPREL"),
+        abi::R_LARCH_SOP_PUSH_TLS_GOT => Some("R_LARCH_SOP_PUSH_TLS_GOT"),
+        abi::R_LARCH_SOP_PUSH_TLS_GD => Some("R_LARCH_SOP_PUSH_TLS_GD"),
+        abi::R_LARCH_SOP_PUSH_PLT_PCREL => Some("R_LARCH_SOP_PUSH_PLT_PCREL"),
+        abi::R_LARCH_SOP_ASSERT => Some("R_LARCH_SOP_ASSERT"),
+        abi::R_LARCH_SOP_NOT => Some("R_LARCH_SOP_NOT"),
+        abi::R_LARCH_SOP_SUB => Some("R_LARCH_SOP_SUB"),
+        abi::R_LARCH_SOP_SL => Some("R_LARCH_SOP_SL"),
+        abi::R_LARCH_SOP_SR => Some("R_LARCH_SOP_SR"),
+        abi::R_LARCH_SOP_ADD => Some("R_LARCH_SOP_ADD"),
+        abi::R_LARCH_SOP_AND => Some("R_LARCH_SOP_AND"),
+        abi::R_LARCH_SOP_IF_ELSE => Some("R_LARCH_SOP_IF_ELSE"),
+        abi::R_LARCH_SOP_POP_32_S_10_5 => Some("R_LARCH_SOP_POP_32_S_10_5"),
+        abi::R_LARCH_SOP_POP_32_U_10_12 => Some("R_LARCH_SOP_POP_32_U_10_12"),
+        abi::R_LARCH_SOP_POP_32_S_10_12 => Some("R_LARCH_SOP_POP_32_S_10_12"),
+        abi::R_LARCH_SOP_POP_32_S_10_16 => Some("R_LARCH_SOP_POP_32_S_10_16"),
+        abi::R_LARCH_SOP_POP_32_S_10_16_S2 => Some("R_LARCH_SOP_POP_32_S_10_16_S2"),
+        abi::R_LARCH_SOP_POP_32_S_5_20 => Some("R_LARCH_SOP_POP_32_S_5_20"),
+        abi::R_LARCH_SOP_POP_32_S_0_5_10_16_S2 => Some("R_LARCH_SOP_POP_32_S_0_5_10_16_S2"),
+        abi::R_LARCH_SOP_POP_32_S_0_10_10_16_S2 => Some("R_LARCH_SOP_POP_32_S_0_10_10_16_S2"),
+        abi::R_LARCH_SOP_POP_32_U => Some("R_LARCH_SOP_POP_32_U"),
+        abi::R_LARCH_ADD8 => Some("R_LARCH_ADD8"),
+        abi::R_LARCH_ADD16 => Some("R_LARCH_ADD16"),
+        abi::R_LARCH_ADD24 => Some("R_LARCH_ADD24"),
+        abi::R_LARCH_ADD32 => Some("R_LARCH_ADD32"),
+        abi::R_LARCH_ADD64 => Some("R_LARCH_ADD64"),
+        abi::R_LARCH_SUB8 => Some("R_LARCH_SUB8"),
+        abi::R_LARCH_SUB16 => Some("R_LARCH_SUB16"),
+        abi::R_LARCH_SUB24 => Some("R_LARCH_SUB24"),
+        abi::R_LARCH_SUB32 => Some("R_LARCH_SUB32"),
+        abi::R_LARCH_SUB64 => Some("R_LARCH_SUB64"),
+        abi::R_LARCH_GNU_VTINHERIT => Some("R_LARCH_GNU_VTINHERIT"),
+        abi::R_LARCH_GNU_VTENTRY => Some("R_LARCH_GNU_VTENTRY"),
+        abi::R_LARCH_B16 => Some("R_LARCH_B16"),
+        abi::R_LARCH_B21 => Some("R_LARCH_B21"),
+        abi::R_LARCH_B26 => Some("R_LARCH_B26"),
+        abi::R_LARCH_ABS_HI20 => Some("R_LARCH_ABS_HI20"),
+        abi::R_LARCH_ABS_LO12 => Some("R_LARCH_ABS_LO12"),
+        abi::R_LARCH_ABS64_LO20 => Some("R_LARCH_ABS64_LO20"),
+        abi::R_LARCH_ABS64_HI12 => Some("R_LARCH_ABS64_HI12"),
+        abi::R_LARCH_PCALA_HI20 => Some("R_LARCH_PCALA_HI20"),
+        abi::R_LARCH_PCALA_LO12 => Some("R_LARCH_PCALA_LO12"),
+        abi::R_LARCH_PCALA64_LO20 => Some("R_LARCH_PCALA64_LO20"),
+        abi::R_LARCH_PCALA64_HI12 => Some("R_LARCH_PCALA64_HI12"),
+        abi::R_LARCH_GOT_PC_HI20 => Some("R_LARCH_GOT_PC_HI20"),
+        abi::R_LARCH_GOT_PC_LO12 => Some("R_LARCH_GOT_PC_LO12"),
+        abi::R_LARCH_GOT64_PC_LO20 => Some("R_LARCH_GOT64_PC_LO20"),
+        abi::R_LARCH_GOT64_PC_HI12 => Some("R_LARCH_GOT64_PC_HI12"),
+        abi::R_LARCH_GOT_HI20 => Some("R_LARCH_GOT_HI20"),
+        abi::R_LARCH_GOT_LO12 => Some("R_LARCH_GOT_LO12"),
+        abi::R_LARCH_GOT64_LO20 => Some("R_LARCH_GOT64_LO20"),
+        abi::R_LARCH_GOT64_HI12 => Some("R_LARCH_GOT64_HI12"),
+        abi::R_LARCH_TLS_LE_HI20 => Some("R_LARCH_TLS_LE_HI20"),
+        abi::R_LARCH_TLS_LE_LO12 => Some("R_LARCH_TLS_LE_LO12"),
+        abi::R_LARCH_TLS_LE64_LO20 => Some("R_LARCH_TLS_LE64_LO20"),
+        abi::R_LARCH_TLS_LE64_HI12 => Some("R_LARCH_TLS_LE64_HI12"),
+        abi::R_LARCH_TLS_IE_PC_HI20 => Some("R_LARCH_TLS_IE_PC_HI20"),
+        abi::R_LARCH_TLS_IE_PC_LO12 => Some("R_LARCH_TLS_IE_PC_LO12"),
+        abi::R_LARCH_TLS_IE64_PC_LO20 => Some("R_LARCH_TLS_IE64_PC_LO20"),
+        abi::R_LARCH_TLS_IE64_PC_HI12 => Some("R_LARCH_TLS_IE64_PC_HI12"),
+        abi::R_LARCH_TLS_IE_HI20 => Some("R_LARCH_TLS_IE_HI20"),
+        abi::R_LARCH_TLS_IE_LO12 => Some("R_LARCH_TLS_IE_LO12"),
+        abi::R_LARCH_TLS_IE64_LO20 => Some("R_LARCH_TLS_IE64_LO20"),
+        abi::R_LARCH_TLS_IE64_HI12 => Some("R_LARCH_TLS_IE64_HI12"),
+        abi::R_LARCH_TLS_LD_PC_HI20 => Some("R_LARCH_TLS_LD_PC_HI20"),
+        abi::R_LARCH_TLS_LD_HI20 => Some("R_LARCH_TLS_LD_HI20"),
+        abi::R_LARCH_TLS_GD_PC_HI20 => Some("R_LARCH_TLS_GD_PC_HI20"),
+        abi::R_LARCH_TLS_GD_HI20 => Some("R_LARCH_TLS_GD_HI20"),
+        abi::R_LARCH_32_PCREL => Some("R_LARCH_32_PCREL"),
+        abi::R_LARCH_RELAX => Some("R_LARCH_RELAX"),
+        abi::R_LARCH_ALIGN => Some("R_LARCH_ALIGN"),
+        abi::R_LARCH_PCREL20_S2 => Some("R_LARCH_PCREL20_S2"),
+        abi::R_LARCH_ADD6 => Some("R_LARCH_ADD6"),
+        abi::R_LARCH_SUB6 => Some("R_LARCH_SUB6"),
+        abi::R_LARCH_ADD_ULEB128 => Some("R_LARCH_ADD_ULEB128"),
+        abi::R_LARCH_SUB_ULEB128 => Some("R_LARCH_SUB_ULEB128"),
+        abi::R_LARCH_64_PCREL => Some("R_LARCH_64_PCREL"),
+        abi::R_LARCH_CALL36 => Some("R_LARCH_CALL36"),
+        abi::R_LARCH_TLS_DESC_PC_HI20 => Some("R_LARCH_TLS_DESC_PC_HI20"),
+        abi::R_LARCH_TLS_DESC_PC_LO12 => Some("R_LARCH_TLS_DESC_PC_LO12"),
+        abi::R_LARCH_TLS_DESC64_PC_LO20 => Some("R_LARCH_TLS_DESC64_PC_LO20"),
+        abi::R_LARCH_TLS_DESC64_PC_HI12 => Some("R_LARCH_TLS_DESC64_PC_HI12"),
+        abi::R_LARCH_TLS_DESC_HI20 => Some("R_LARCH_TLS_DESC_HI20"),
+        abi::R_LARCH_TLS_DESC_LO12 => Some("R_LARCH_TLS_DESC_LO12"),
+        abi::R_LARCH_TLS_DESC64_LO20 => Some("R_LARCH_TLS_DESC64_LO20"),
+        abi::R_LARCH_TLS_DESC64_HI12 => Some("R_LARCH_TLS_DESC64_HI12"),
+        abi::R_LARCH_TLS_DESC_LD => Some("R_LARCH_TLS_DESC_LD"),
+        abi::R_LARCH_TLS_DESC_CALL => Some("R_LARCH_TLS_DESC_CALL"),
+        abi::R_LARCH_TLS_LE_HI20_R => Some("R_LARCH_TLS_LE_HI20_R"),
+        abi::R_LARCH_TLS_LE_ADD_R => Some("R_LARCH_TLS_LE_ADD_R"),
+        abi::R_LARCH_TLS_LE_LO12_R => Some("R_LARCH_TLS_LE_LO12_R"),
+        abi::R_LARCH_TLS_LD_PCREL20_S2 => Some("R_LARCH_TLS_LD_PCREL20_S2"),
+        abi::R_LARCH_TLS_GD_PCREL20_S2 => Some("R_LARCH_TLS_GD_PCREL20_S2"),
+        abi::R_LARCH_TLS_DESC_PCREL20_S2 => Some("R_LARCH_TLS_DESC_PCREL20_S2"),
+        _ => None,
+    }
+}
+
+fn mips_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_MIPS_NONE => Some("R_MIPS_NONE"),
+        abi::R_MIPS_16 => Some("R_MIPS_16"),
+        abi::R_MIPS_32 => Some("R_MIPS_32"),
+        abi::R_MIPS_REL32 => Some("R_MIPS_REL32"),
+        abi::R_MIPS_26 => Some("R_MIPS_26"),
+        abi::R_MIPS_HI16 => Some("R_MIPS_HI16"),
+        abi::R_MIPS_LO16 => Some("R_MIPS_LO16"),
+        abi::R_MIPS_GPREL16 => Some("R_MIPS_GPREL16"),
+        abi::R_MIPS_LITERAL => Some("R_MIPS_LITERAL"),
+        abi::R_MIPS_GOT16 => Some("R_MIPS_GOT16"),
+        abi::R_MIPS_PC16 => Some("R_MIPS_PC16"),
+        abi::R_MIPS_CALL16 => Some("R_MIPS_CALL16"),
+        abi::R_MIPS_GPREL32 => Some("R_MIPS_GPREL32"),
+        abi::R_MIPS_SHIFT5 => Some("R_MIPS_SHIFT5"),
+        abi::R_MIPS_SHIFT6 => Some("R_MIPS_SHIFT6"),
+        abi::R_MIPS_64 => Some("R_MIPS_64"),
+        abi::R_MIPS_GOT_DISP => Some("R_MIPS_GOT_DISP"),
+        abi::R_MIPS_GOT_PAGE => Some("R_MIPS_GOT_PAGE"),
+        abi::R_MIPS_GOT_OFST => Some("R_MIPS_GOT_OFST"),
+        abi::R_MIPS_GOT_HI16 => Some("R_MIPS_GOT_HI16"),
+        abi::R_MIPS_GOT_LO16 => Some("R_MIPS_GOT_LO16"),
+        abi::R_MIPS_SUB => Some("R_MIPS_SUB"),
+        abi::R_MIPS_INSERT_A => Some("R_MIPS_INSERT_A"),
+        abi::R_MIPS_INSERT_B => Some("R_MIPS_INSERT_B"),
+        abi::R_MIPS_DELETE => Some("R_MIPS_DELETE"),
+        abi::R_MIPS_HIGHER => Some("R_MIPS_HIGHER"),
+        abi::R_MIPS_HIGHEST => Some("R_MIPS_HIGHEST"),
+        abi::R_MIPS_CALL_HI16 => Some("R_MIPS_CALL_HI16"),
+        abi::R_MIPS_CALL_LO16 => Some("R_MIPS_CALL_LO16"),
+        abi::R_MIPS_SCN_DISP => Some("R_MIPS_SCN_DISP"),
+        abi::R_MIPS_REL16 => Some("R_MIPS_REL16"),
+        abi::R_MIPS_ADD_IMMEDIATE => Some("R_MIPS_ADD_IMMEDIATE"),
+        abi::R_MIPS_PJUMP => Some("R_MIPS_PJUMP"),
+        abi::R_MIPS_RELGOT => Some("R_MIPS_RELGOT"),
+        abi::R_MIPS_JALR => Some("R_MIPS_JALR"),
+        abi::R_MIPS_TLS_DTPMOD32 => Some("R_MIPS_TLS_DTPMOD32"),
+        abi::R_MIPS_TLS_DTPREL32 => Some("R_MIPS_TLS_DTPREL32"),
+        abi::R_MIPS_TLS_DTPMOD64 => Some("R_MIPS_TLS_DTPMOD64"),
+        abi::R_MIPS_TLS_DTPREL64 => Some("R_MIPS_TLS_DTPREL64"),
+        abi::R_MIPS_TLS_GD => Some("R_MIPS_TLS_GD"),
+        abi::R_MIPS_TLS_LDM => Some("R_MIPS_TLS_LDM"),
+        abi::R_MIPS_TLS_DTPREL_HI16 => Some("R_MIPS_TLS_DTPREL_HI16"),
+        abi::R_MIPS_TLS_DTPREL_LO16 => Some("R_MIPS_TLS_DTPREL_LO16"),
+        abi::R_MIPS_TLS_GOTTPREL => Some("R_MIPS_TLS_GOTTPREL"),
+        abi::R_MIPS_TLS_TPREL32 => Some("R_MIPS_TLS_TPREL32"),
+        abi::R_MIPS_TLS_TPREL64 => Some("R_MIPS_TLS_TPREL64"),
+        abi::R_MIPS_TLS_TPREL_HI16 => Some("R_MIPS_TLS_TPREL_HI16"),
+        abi::R_MIPS_TLS_TPREL_LO16 => Some("R_MIPS_TLS_TPREL_LO16"),
+        abi::R_MIPS_GLOB_DAT => Some("R_MIPS_GLOB_DAT"),
+        abi::R_MIPS_COPY => Some("R_MIPS_COPY"),
+        abi::R_MIPS_JUMP_SLOT => Some("R_MIPS_JUMP_SLOT"),
+        _ => None,
+    }
+}
+
+fn ppc_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_PPC_NONE => Some("R_PPC_NONE"),
+        abi::R_PPC_ADDR32 => Some("R_PPC_ADDR32"),
+        abi::R_PPC_ADDR24 => Some("R_PPC_ADDR24"),
+        abi::R_PPC_ADDR16 => Some("R_PPC_ADDR16"),
+        abi::R_PPC_ADDR16_LO => Some("R_PPC_ADDR16_LO"),
+        abi::R_PPC_ADDR16_HI => Some("R_PPC_ADDR16_HI"),
+        abi::R_PPC_ADDR16_HA => Some("R_PPC_ADDR16_HA"),
+        abi::R_PPC_ADDR14 => Some("R_PPC_ADDR14"),
+        abi::R_PPC_ADDR14_BRTAKEN => Some("R_PPC_ADDR14_BRTAKEN"),
+        abi::R_PPC_ADDR14_BRNTAKEN => Some("R_PPC_ADDR14_BRNTAKEN"),
+        abi::R_PPC_REL24 => Some("R_PPC_REL24"),
+        abi::R_PPC_REL14 => Some("R_PPC_REL14"),
+        abi::R_PPC_REL14_BRTAKEN => Some("R_PPC_REL14_BRTAKEN"),
+        abi::R_PPC_REL14_BRNTAKEN => Some("R_PPC_REL14_BRNTAKEN"),
+        abi::R_PPC_GOT16 => Some("R_PPC_GOT16"),
+        abi::R_PPC_GOT16_LO => Some("R_PPC_GOT16_LO"),
+        abi::R_PPC_GOT16_HI => Some("R_PPC_GOT16_HI"),
+        abi::R_PPC_GOT16_HA => Some("R_PPC_GOT16_HA"),
+        abi::R_PPC_PLTREL24 => Some("R_PPC_PLTREL24"),
+        abi::R_PPC_COPY => Some("R_PPC_COPY"),
+        abi::R_PPC_GLOB_DAT => Some("R_PPC_GLOB_DAT"),
+        abi::R_PPC_JMP_SLOT => Some("R_PPC_JMP_SLOT"),
+        abi::R_PPC_RELATIVE => Some("R_PPC_RELATIVE"),
+        abi::R_PPC_LOCAL24PC => Some("R_PPC_LOCAL24PC"),
+        abi::R_PPC_UADDR32 => Some("R_PPC_UADDR32"),
+        abi::R_PPC_UADDR16 => Some("R_PPC_UADDR16"),
+        abi::R_PPC_REL32 => Some("R_PPC_REL32"),
+        abi::R_PPC_PLT32 => Some("R_PPC_PLT32"),
+        abi::R_PPC_PLTREL32 => Some("R_PPC_PLTREL32"),
+        abi::R_PPC_PLT16_LO => Some("R_PPC_PLT16_LO"),
+        abi::R_PPC_PLT16_HI => Some("R_PPC_PLT16_HI"),
+        abi::R_PPC_PLT16_HA => Some("R_PPC_PLT16_HA"),
+        abi::R_PPC_SDAREL16 => Some("R_PPC_SDAREL16"),
+        abi::R_PPC_SECTOFF => Some("R_PPC_SECTOFF"),
+        abi::R_PPC_SECTOFF_LO => Some("R_PPC_SECTOFF_LO"),
+        abi::R_PPC_SECTOFF_HI => Some("R_PPC_SECTOFF_HI"),
+        abi::R_PPC_SECTOFF_HA => Some("R_PPC_SECTOFF_HA"),
+        abi::R_PPC_TLS => Some("R_PPC_TLS"),
+        abi::R_PPC_DTPMOD32 => Some("R_PPC_DTPMOD32"),
+        abi::R_PPC_TPREL16 => Some("R_PPC_TPREL16"),
+        abi::R_PPC_TPREL16_LO => Some("R_PPC_TPREL16_LO"),
+        abi::R_PPC_TPREL16_HI => Some("R_PPC_TPREL16_HI"),
+        abi::R_PPC_TPREL16_HA => Some("R_PPC_TPREL16_HA"),
+        abi::R_PPC_TPREL32 => Some("R_PPC_TPREL32"),
+        abi::R_PPC_DTPREL16 => Some("R_PPC_DTPREL16"),
+        abi::R_PPC_DTPREL16_LO => Some("R_PPC_DTPREL16_LO"),
+        abi::R_PPC_DTPREL16_HI => Some("R_PPC_DTPREL16_HI"),
+        abi::R_PPC_DTPREL16_HA => Some("R_PPC_DTPREL16_HA"),
+        abi::R_PPC_DTPREL32 => Some("R_PPC_DTPREL32"),
+        abi::R_PPC_GOT_TLSGD16 => Some("R_PPC_GOT_TLSGD16"),
+        abi::R_PPC_GOT_TLSGD16_LO => Some("R_PPC_GOT_TLSGD16_LO"),
+        abi::R_PPC_GOT_TLSGD16_HI => Some("R_PPC_GOT_TLSGD16_HI"),
+        abi::R_PPC_GOT_TLSGD16_HA => Some("R_PPC_GOT_TLSGD16_HA"),
+        abi::R_PPC_GOT_TLSLD16 => Some("R_PPC_GOT_TLSLD16"),
+        abi::R_PPC_GOT_TLSLD16_LO => Some("R_PPC_GOT_TLSLD16_LO"),
+        abi::R_PPC_GOT_TLSLD16_HI => Some("R_PPC_GOT_TLSLD16_HI"),
+        abi::R_PPC_GOT_TLSLD16_HA => Some("R_PPC_GOT_TLSLD16_HA"),
+        abi::R_PPC_GOT_TPREL16 => Some("R_PPC_GOT_TPREL16"),
+        abi::R_PPC_GOT_TPREL16_LO => Some("R_PPC_GOT_TPREL16_LO"),
+        abi::R_PPC_GOT_TPREL16_HI => Some("R_PPC_GOT_TPREL16_HI"),
+        abi::R_PPC_GOT_TPREL16_HA => Some("R_PPC_GOT_TPREL16_HA"),
+        abi::R_PPC_GOT_DTPREL16 => Some("R_PPC_GOT_DTPREL16"),
+        abi::R_PPC_GOT_DTPREL16_LO => Some("R_PPC_GOT_DTPREL16_LO"),
+        abi::R_PPC_GOT_DTPREL16_HI => Some("R_PPC_GOT_DTPREL16_HI"),
+        abi::R_PPC_GOT_DTPREL16_HA => Some("R_PPC_GOT_DTPREL16_HA"),
+        abi::R_PPC_TLSGD => Some("R_PPC_TLSGD"),
+        abi::R_PPC_TLSLD => Some("R_PPC_TLSLD"),
+        abi::R_PPC_EMB_NADDR32 => Some("R_PPC_EMB_NADDR32"),
+        abi::R_PPC_EMB_NADDR16 => Some("R_PPC_EMB_NADDR16"),
+        abi::R_PPC_EMB_NADDR16_LO => Some("R_PPC_EMB_NADDR16_LO"),
+        abi::R_PPC_EMB_NADDR16_HI => Some("R_PPC_EMB_NADDR16_HI"),
+        abi::R_PPC_EMB_NADDR16_HA => Some("R_PPC_EMB_NADDR16_HA"),
+        abi::R_PPC_EMB_SDAI16 => Some("R_PPC_EMB_SDAI16"),
+        abi::R_PPC_EMB_SDA2I16 => Some("R_PPC_EMB_SDA2I16"),
+        abi::R_PPC_EMB_SDA2REL => Some("R_PPC_EMB_SDA2REL"),
+        abi::R_PPC_EMB_SDA21 => Some("R_PPC_EMB_SDA21"),
+        abi::R_PPC_EMB_MRKREF => Some("R_PPC_EMB_MRKREF"),
+        abi::R_PPC_EMB_RELSEC16 => Some("R_PPC_EMB_RELSEC16"),
+        abi::R_PPC_EMB_RELST_LO => Some("R_PPC_EMB_RELST_LO"),
+        abi::R_PPC_EMB_RELST_HI => Some("R_PPC_EMB_RELST_HI"),
+        abi::R_PPC_EMB_RELST_HA => Some("R_PPC_EMB_RELST_HA"),
+        abi::R_PPC_EMB_BIT_FLD => Some("R_PPC_EMB_BIT_FLD"),
+        abi::R_PPC_EMB_RELSDA => Some("R_PPC_EMB_RELSDA"),
+        abi::R_PPC_DIAB_SDA21_LO => Some("R_PPC_DIAB_SDA21_LO"),
+        abi::R_PPC_DIAB_SDA21_HI => Some("R_PPC_DIAB_SDA21_HI"),
+        abi::R_PPC_DIAB_SDA21_HA => Some("R_PPC_DIAB_SDA21_HA"),
+        abi::R_PPC_DIAB_RELSDA_LO => Some("R_PPC_DIAB_RELSDA_LO"),
+        abi::R_PPC_DIAB_RELSDA_HI => Some("R_PPC_DIAB_RELSDA_HI"),
+        abi::R_PPC_DIAB_RELSDA_HA => Some("R_PPC_DIAB_RELSDA_HA"),
+        abi::R_PPC_IRELATIVE => Some("R_PPC_IRELATIVE"),
+        abi::R_PPC_REL16 => Some("R_PPC_REL16"),
+        abi::R_PPC_REL16_LO => Some("R_PPC_REL16_LO"),
+        abi::R_PPC_REL16_HI => Some("R_PPC_REL16_HI"),
+        abi::R_PPC_REL16_HA => Some("R_PPC_REL16_HA"),
+        abi::R_PPC_TOC16 => Some("R_PPC_TOC16"),
+        _ => None,
+    }
+}
+
+fn ppc64_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_PPC64_NONE => Some("R_PPC64_NONE"),
+        abi::R_PPC64_ADDR32 => Some("R_PPC64_ADDR32"),
+        abi::R_PPC64_ADDR24 => Some("R_PPC64_ADDR24"),
+        abi::R_PPC64_ADDR16 => Some("R_PPC64_ADDR16"),
+        abi::R_PPC64_ADDR16_LO => Some("R_PPC64_ADDR16_LO"),
+        abi::R_PPC64_ADDR16_HI => Some("R_PPC64_ADDR16_HI"),
+        abi::R_PPC64_ADDR16_HA => Some("R_PPC64_ADDR16_HA"),
+        abi::R_PPC64_ADDR14 => Some("R_PPC64_ADDR14"),
+        abi::R_PPC64_ADDR14_BRTAKEN => Some("R_PPC64_ADDR14_BRTAKEN"),
+        abi::R_PPC64_ADDR14_BRNTAKEN => Some("R_PPC64_ADDR14_BRNTAKEN"),
+        abi::R_PPC64_REL24 => Some("R_PPC64_REL24"),
+        abi::R_PPC64_REL14 => Some("R_PPC64_REL14"),
+        abi::R_PPC64_REL14_BRTAKEN => Some("R_PPC64_REL14_BRTAKEN"),
+        abi::R_PPC64_REL14_BRNTAKEN => Some("R_PPC64_REL14_BRNTAKEN"),
+        abi::R_PPC64_GOT16 => Some("R_PPC64_GOT16"),
+        abi::R_PPC64_GOT16_LO => Some("R_PPC64_GOT16_LO"),
+        abi::R_PPC64_GOT16_HI => Some("R_PPC64_GOT16_HI"),
+        abi::R_PPC64_GOT16_HA => Some("R_PPC64_GOT16_HA"),
+        abi::R_PPC64_COPY => Some("R_PPC64_COPY"),
+        abi::R_PPC64_GLOB_DAT => Some("R_PPC64_GLOB_DAT"),
+        abi::R_PPC64_JMP_SLOT => Some("R_PPC64_JMP_SLOT"),
+        abi::R_PPC64_RELATIVE => Some("R_PPC64_RELATIVE"),
+        abi::R_PPC64_UADDR32 => Some("R_PPC64_UADDR32"),
+        abi::R_PPC64_UADDR16 => Some("R_PPC64_UADDR16"),
+        abi::R_PPC64_REL32 => Some("R_PPC64_REL32"),
+        abi::R_PPC64_PLT32 => Some("R_PPC64_PLT32"),
+        abi::R_PPC64_PLTREL32 => Some("R_PPC64_PLTREL32"),
+        abi::R_PPC64_PLT16_LO => Some("R_PPC64_PLT16_LO"),
+        abi::R_PPC64_PLT16_HI => Some("R_PPC64_PLT16_HI"),
+        abi::R_PPC64_PLT16_HA => Some("R_PPC64_PLT16_HA"),
+        abi::R_PPC64_SECTOFF => Some("R_PPC64_SECTOFF"),
+        abi::R_PPC64_SECTOFF_LO => Some("R_PPC64_SECTOFF_LO"),
+        abi::R_PPC64_SECTOFF_HI => Some("R_PPC64_SECTOFF_HI"),
+        abi::R_PPC64_SECTOFF_HA => Some("R_PPC64_SECTOFF_HA"),
+        abi::R_PPC64_ADDR30 => Some("R_PPC64_ADDR30"),
+        abi::R_PPC64_ADDR64 => Some("R_PPC64_ADDR64"),
+        abi::R_PPC64_ADDR16_HIGHER => Some("R_PPC64_ADDR16_HIGHER"),
+        abi::R_PPC64_ADDR16_HIGHERA => Some("R_PPC64_ADDR16_HIGHERA"),
+        abi::R_PPC64_ADDR16_HIGHEST => Some("R_PPC64_ADDR16_HIGHEST"),
+        abi::R_PPC64_ADDR16_HIGHESTA => Some("R_PPC64_ADDR16_HIGHESTA"),
+        abi::R_PPC64_UADDR64 => Some("R_PPC64_UADDR64"),
+        abi::R_PPC64_REL64 => Some("R_PPC64_REL64"),
+        abi::R_PPC64_PLT64 => Some("R_PPC64_PLT64"),
+        abi::R_PPC64_PLTREL64 => Some("R_PPC64_PLTREL64"),
+        abi::R_PPC64_TOC16 => Some("R_PPC64_TOC16"),
+        abi::R_PPC64_TOC16_LO => Some("R_PPC64_TOC16_LO"),
+        abi::R_PPC64_TOC16_HI => Some("R_PPC64_TOC16_HI"),
+        abi::R_PPC64_TOC16_HA => Some("R_PPC64_TOC16_HA"),
+        abi::R_PPC64_TOC => Some("R_PPC64_TOC"),
+        abi::R_PPC64_PLTGOT16 => Some("R_PPC64_PLTGOT16"),
+        abi::R_PPC64_PLTGOT16_LO => Some("R_PPC64_PLTGOT16_LO"),
+        abi::R_PPC64_PLTGOT16_HI => Some("R_PPC64_PLTGOT16_HI"),
+        abi::R_PPC64_PLTGOT16_HA => Some("R_PPC64_PLTGOT16_HA"),
+        abi::R_PPC64_ADDR16_DS => Some("R_PPC64_ADDR16_DS"),
+        abi::R_PPC64_ADDR16_LO_DS => Some("R_PPC64_ADDR16_LO_DS"),
+        abi::R_PPC64_GOT16_DS => Some("R_PPC64_GOT16_DS"),
+        abi::R_PPC64_GOT16_LO_DS => Some("R_PPC64_GOT16_LO_DS"),
+        abi::R_PPC64_PLT16_LO_DS => Some("R_PPC64_PLT16_LO_DS"),
+        abi::R_PPC64_SECTOFF_DS => Some("R_PPC64_SECTOFF_DS"),
+        abi::R_PPC64_SECTOFF_LO_DS => Some("R_PPC64_SECTOFF_LO_DS"),
+        abi::R_PPC64_TOC16_DS => Some("R_PPC64_TOC16_DS"),
+        abi::R_PPC64_TOC16_LO_DS => Some("R_PPC64_TOC16_LO_DS"),
+        abi::R_PPC64_PLTGOT16_DS => Some("R_PPC64_PLTGOT16_DS"),
+        abi::R_PPC64_PLTGOT16_LO_DS => Some("R_PPC64_PLTGOT16_LO_DS"),
+        abi::R_PPC64_TLS => Some("R_PPC64_TLS"),
+        abi::R_PPC64_DTPMOD64 => Some("R_PPC64_DTPMOD64"),
+        abi::R_PPC64_TPREL16 => Some("R_PPC64_TPREL16"),
+        abi::R_PPC64_TPREL16_LO => Some("R_PPC64_TPREL16_LO"),
+        abi::R_PPC64_TPREL16_HI => Some("R_PPC64_TPREL16_HI"),
+        abi::R_PPC64_TPREL16_HA => Some("R_PPC64_TPREL16_HA"),
+        abi::R_PPC64_TPREL64 => Some("R_PPC64_TPREL64"),
+        abi::R_PPC64_DTPREL16 => Some("R_PPC64_DTPREL16"),
+        abi::R_PPC64_DTPREL16_LO => Some("R_PPC64_DTPREL16_LO"),
+        abi::R_PPC64_DTPREL16_HI => Some("R_PPC64_DTPREL16_HI"),
+        abi::R_PPC64_DTPREL16_HA => Some("R_PPC64_DTPREL16_HA"),
+        abi::R_PPC64_DTPREL64 => Some("R_PPC64_DTPREL64"),
+        abi::R_PPC64_GOT_TLSGD16 => Some("R_PPC64_GOT_TLSGD16"),
+        abi::R_PPC64_GOT_TLSGD16_LO => Some("R_PPC64_GOT_TLSGD16_LO"),
+        abi::R_PPC64_GOT_TLSGD16_HI => Some("R_PPC64_GOT_TLSGD16_HI"),
+        abi::R_PPC64_GOT_TLSGD16_HA => Some("R_PPC64_GOT_TLSGD16_HA"),
+        abi::R_PPC64_GOT_TLSLD16 => Some("R_PPC64_GOT_TLSLD16"),
+        abi::R_PPC64_GOT_TLSLD16_LO => Some("R_PPC64_GOT_TLSLD16_LO"),
+        abi::R_PPC64_GOT_TLSLD16_HI => Some("R_PPC64_GOT_TLSLD16_HI"),
+        abi::R_PPC64_GOT_TLSLD16_HA => Some("R_PPC64_GOT_TLSLD16_HA"),
+        abi::R_PPC64_GOT_TPREL16_DS => Some("R_PPC64_GOT_TPREL16_DS"),
+        abi::R_PPC64_GOT_TPREL16_LO_DS => Some("R_PPC64_GOT_TPREL16_LO_DS"),
+        abi::R_PPC64_GOT_TPREL16_HI => Some("R_PPC64_GOT_TPREL16_HI"),
+        abi::R_PPC64_GOT_TPREL16_HA => Some("R_PPC64_GOT_TPREL16_HA"),
+        abi::R_PPC64_GOT_DTPREL16_DS => Some("R_PPC64_GOT_DTPREL16_DS"),
+        abi::R_PPC64_GOT_DTPREL16_LO_DS => Some("R_PPC64_GOT_DTPREL16_LO_DS"),
+        abi::R_PPC64_GOT_DTPREL16_HI => Some("R_PPC64_GOT_DTPREL16_HI"),
+        abi::R_PPC64_GOT_DTPREL16_HA => Some("R_PPC64_GOT_DTPREL16_HA"),
+        abi::R_PPC64_TPREL16_DS => Some("R_PPC64_TPREL16_DS"),
+        abi::R_PPC64_TPREL16_LO_DS => Some("R_PPC64_TPREL16_LO_DS"),
+        abi::R_PPC64_TPREL16_HIGHER => Some("R_PPC64_TPREL16_HIGHER"),
+        abi::R_PPC64_TPREL16_HIGHERA => Some("R_PPC64_TPREL16_HIGHERA"),
+        abi::R_PPC64_TPREL16_HIGHEST => Some("R_PPC64_TPREL16_HIGHEST"),
+        abi::R_PPC64_TPREL16_HIGHESTA => Some("R_PPC64_TPREL16_HIGHESTA"),
+        abi::R_PPC64_DTPREL16_DS => Some("R_PPC64_DTPREL16_DS"),
+        abi::R_PPC64_DTPREL16_LO_DS => Some("R_PPC64_DTPREL16_LO_DS"),
+        abi::R_PPC64_DTPREL16_HIGHER => Some("R_PPC64_DTPREL16_HIGHER"),
+        abi::R_PPC64_DTPREL16_HIGHERA => Some("R_PPC64_DTPREL16_HIGHERA"),
+        abi::R_PPC64_DTPREL16_HIGHEST => Some("R_PPC64_DTPREL16_HIGHEST"),
+        abi::R_PPC64_DTPREL16_HIGHESTA => Some("R_PPC64_DTPREL16_HIGHESTA"),
+        abi::R_PPC64_TLSGD => Some("R_PPC64_TLSGD"),
+        abi::R_PPC64_TLSLD => Some("R_PPC64_TLSLD"),
+        abi::R_PPC64_TOCSAVE => Some("R_PPC64_TOCSAVE"),
+        abi::R_PPC64_ADDR16_HIGH => Some("R_PPC64_ADDR16_HIGH"),
+        abi::R_PPC64_ADDR16_HIGHA => Some("R_PPC64_ADDR16_HIGHA"),
+        abi::R_PPC64_TPREL16_HIGH => Some("R_PPC64_TPREL16_HIGH"),
+        abi::R_PPC64_TPREL16_HIGHA => Some("R_PPC64_TPREL16_HIGHA"),
+        abi::R_PPC64_DTPREL16_HIGH => Some("R_PPC64_DTPREL16_HIGH"),
+        abi::R_PPC64_DTPREL16_HIGHA => Some("R_PPC64_DTPREL16_HIGHA"),
+        abi::R_PPC64_JMP_IREL => Some("R_PPC64_JMP_IREL"),
+        abi::R_PPC64_IRELATIVE => Some("R_PPC64_IRELATIVE"),
+        abi::R_PPC64_REL16 => Some("R_PPC64_REL16"),
+        abi::R_PPC64_REL16_LO => Some("R_PPC64_REL16_LO"),
+        abi::R_PPC64_REL16_HI => Some("R_PPC64_REL16_HI"),
+        abi::R_PPC64_REL16_HA => Some("R_PPC64_REL16_HA"),
+        _ => None,
+    }
+}
+
+fn riscv_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_RISCV_NONE => Some("R_RISCV_NONE"),
+        abi::R_RISCV_32 => Some("R_RISCV_32"),
+        abi::R_RISCV_64 => Some("R_RISCV_64"),
+        abi::R_RISCV_RELATIVE => Some("R_RISCV_RELATIVE"),
+        abi::R_RISCV_COPY => Some("R_RISCV_COPY"),
+        abi::R_RISCV_JUMP_SLOT => Some("R_RISCV_JUMP_SLOT"),
+        abi::R_RISCV_TLS_DTPMOD32 => Some("R_RISCV_TLS_DTPMOD32"),
+        abi::R_RISCV_TLS_DTPMOD64 => Some("R_RISCV_TLS_DTPMOD64"),
+        abi::R_RISCV_TLS_DTPREL32 => Some("R_RISCV_TLS_DTPREL32"),
+        abi::R_RISCV_TLS_DTPREL64 => Some("R_RISCV_TLS_DTPREL64"),
+        abi::R_RISCV_TLS_TPREL32 => Some("R_RISCV_TLS_TPREL32"),
+        abi::R_RISCV_TLS_TPREL64 => Some("R_RISCV_TLS_TPREL64"),
+        abi::R_RISCV_BRANCH => Some("R_RISCV_BRANCH"),
+        abi::R_RISCV_JAL => Some("R_RISCV_JAL"),
+        abi::R_RISCV_CALL => Some("R_RISCV_CALL"),
+        abi::R_RISCV_CALL_PLT => Some("R_RISCV_CALL_PLT"),
+        abi::R_RISCV_GOT_HI20 => Some("R_RISCV_GOT_HI20"),
+        abi::R_RISCV_TLS_GOT_HI20 => Some("R_RISCV_TLS_GOT_HI20"),
+        abi::R_RISCV_TLS_GD_HI20 => Some("R_RISCV_TLS_GD_HI20"),
+        abi::R_RISCV_PCREL_HI20 => Some("R_RISCV_PCREL_HI20"),
+        abi::R_RISCV_PCREL_LO12_I => Some("R_RISCV_PCREL_LO12_I"),
+        abi::R_RISCV_PCREL_LO12_S => Some("R_RISCV_PCREL_LO12_S"),
+        abi::R_RISCV_HI20 => Some("R_RISCV_HI20"),
+        abi::R_RISCV_LO12_I => Some("R_RISCV_LO12_I"),
+        abi::R_RISCV_LO12_S => Some("R_RISCV_LO12_S"),
+        abi::R_RISCV_TPREL_HI20 => Some("R_RISCV_TPREL_HI20"),
+        abi::R_RISCV_TPREL_LO12_I => Some("R_RISCV_TPREL_LO12_I"),
+        abi::R_RISCV_TPREL_LO12_S => Some("R_RISCV_TPREL_LO12_S"),
+        abi::R_RISCV_TPREL_ADD => Some("R_RISCV_TPREL_ADD"),
+        abi::R_RISCV_ADD8 => Some("R_RISCV_ADD8"),
+        abi::R_RISCV_ADD16 => Some("R_RISCV_ADD16"),
+        abi::R_RISCV_ADD32 => Some("R_RISCV_ADD32"),
+        abi::R_RISCV_ADD64 => Some("R_RISCV_ADD64"),
+        abi::R_RISCV_SUB8 => Some("R_RISCV_SUB8"),
+        abi::R_RISCV_SUB16 => Some("R_RISCV_SUB16"),
+        abi::R_RISCV_SUB32 => Some("R_RISCV_SUB32"),
+        abi::R_RISCV_SUB64 => Some("R_RISCV_SUB64"),
+        abi::R_RISCV_ALIGN => Some("R_RISCV_ALIGN"),
+        abi::R_RISCV_RVC_BRANCH => Some("R_RISCV_RVC_BRANCH"),
+        abi::R_RISCV_RVC_JUMP => Some("R_RISCV_RVC_JUMP"),
+        abi::R_RISCV_RVC_LUI => Some("R_RISCV_RVC_LUI"),
+        abi::R_RISCV_RELAX => Some("R_RISCV_RELAX"),
+        abi::R_RISCV_SUB6 => Some("R_RISCV_SUB6"),
+        abi::R_RISCV_SET6 => Some("R_RISCV_SET6"),
+        abi::R_RISCV_SET8 => Some("R_RISCV_SET8"),
+        abi::R_RISCV_SET16 => Some("R_RISCV_SET16"),
+        abi::R_RISCV_SET32 => Some("R_RISCV_SET32"),
+        abi::R_RISCV_32_PCREL => Some("R_RISCV_32_PCREL"),
+        abi::R_RISCV_IRELATIVE => Some("R_RISCV_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn s390_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_390_NONE => Some("R_390_NONE"),
+        abi::R_390_8 => Some("R_390_8"),
+        abi::R_390_12 => Some("R_390_12"),
+        abi::R_390_16 => Some("R_390_16"),
+        abi::R_390_32 => Some("R_390_32"),
+        abi::R_390_PC32 => Some("R_390_PC32"),
+        abi::R_390_GOT12 => Some("R_390_GOT12"),
+        abi::R_390_GOT32 => Some("R_390_GOT32"),
+        abi::R_390_PLT32 => Some("R_390_PLT32"),
+        abi::R_390_COPY => Some("R_390_COPY"),
+        abi::R_390_GLOB_DAT => Some("R_390_GLOB_DAT"),
+        abi::R_390_JMP_SLOT => Some("R_390_JMP_SLOT"),
+        abi::R_390_RELATIVE => Some("R_390_RELATIVE"),
+        abi::R_390_GOTOFF32 => Some("R_390_GOTOFF32"),
+        abi::R_390_GOTPC => Some("R_390_GOTPC"),
+        abi::R_390_GOT16 => Some("R_390_GOT16"),
+        abi::R_390_PC16 => Some("R_390_PC16"),
+        abi::R_390_PC16DBL => Some("R_390_PC16DBL"),
+        abi::R_390_PLT16DBL => Some("R_390_PLT16DBL"),
+        abi::R_390_PC32DBL => Some("R_390_PC32DBL"),
+        abi::R_390_PLT32DBL => Some("R_390_PLT32DBL"),
+        abi::R_390_GOTPCDBL => Some("R_390_GOTPCDBL"),
+        abi::R_390_64 => Some("R_390_64"),
+        abi::R_390_PC64 => Some("R_390_PC64"),
+        abi::R_390_GOT64 => Some("R_390_GOT64"),
+        abi::R_390_PLT64 => Some("R_390_PLT64"),
+        abi::R_390_GOTENT => Some("R_390_GOTENT"),
+        abi::R_390_GOTOFF16 => Some("R_390_GOTOFF16"),
+        abi::R_390_GOTOFF64 => Some("R_390_GOTOFF64"),
+        abi::R_390_GOTPLT12 => Some("R_390_GOTPLT12"),
+        abi::R_390_GOTPLT16 => Some("R_390_GOTPLT16"),
+        abi::R_390_GOTPLT32 => Some("R_390_GOTPLT32"),
+        abi::R_390_GOTPLT64 => Some("R_390_GOTPLT64"),
+        abi::R_390_GOTPLTENT => Some("R_390_GOTPLTENT"),
+        abi::R_390_PLTOFF16 => Some("R_390_PLTOFF16"),
+        abi::R_390_PLTOFF32 => Some("R_390_PLTOFF32"),
+        abi::R_390_PLTOFF64 => Some("R_390_PLTOFF64"),
+        abi::R_390_TLS_LOAD => Some("R_390_TLS_LOAD"),
+        abi::R_390_TLS_GDCALL => Some("R_390_TLS_GDCALL"),
+        abi::R_390_TLS_LDCALL => Some("R_390_TLS_LDCALL"),
+        abi::R_390_TLS_GD32 => Some("R_390_TLS_GD32"),
+        abi::R_390_TLS_GD64 => Some("R_390_TLS_GD64"),
+        abi::R_390_TLS_GOTIE12 => Some("R_390_TLS_GOTIE12"),
+        abi::R_390_TLS_GOTIE32 => Some("R_390_TLS_GOTIE32"),
+        abi::R_390_TLS_GOTIE64 => Some("R_390_TLS_GOTIE64"),
+        abi::R_390_TLS_LDM32 => Some("R_390_TLS_LDM32"),
+        abi::R_390_TLS_LDM64 => Some("R_390_TLS_LDM64"),
+        abi::R_390_TLS_IE32 => Some("R_390_TLS_IE32"),
+        abi::R_390_TLS_IE64 => Some("R_390_TLS_IE64"),
+        abi::R_390_TLS_IEENT => Some("R_390_TLS_IEENT"),
+        abi::R_390_TLS_LE32 => Some("R_390_TLS_LE32"),
+        abi::R_390_TLS_LE64 => Some("R_390_TLS_LE64"),
+        abi::R_390_TLS_LDO32 => Some("R_390_TLS_LDO32"),
+        abi::R_390_TLS_LDO64 => Some("R_390_TLS_LDO64"),
+        abi::R_390_TLS_DTPMOD => Some("R_390_TLS_DTPMOD"),
+        abi::R_390_TLS_DTPOFF => Some("R_390_TLS_DTPOFF"),
+        abi::R_390_TLS_TPOFF => Some("R_390_TLS_TPOFF"),
+        abi::R_390_20 => Some("R_390_20"),
+        abi::R_390_GOT20 => Some("R_390_GOT20"),
+        abi::R_390_GOTPLT20 => Some("R_390_GOTPLT20"),
+        abi::R_390_TLS_GOTIE20 => Some("R_390_TLS_GOTIE20"),
+        abi::R_390_IRELATIVE => Some("R_390_IRELATIVE"),
+        _ => None,
+    }
+}
+
+fn sparc_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_SPARC_NONE => Some("R_SPARC_NONE"),
+        abi::R_SPARC_8 => Some("R_SPARC_8"),
+        abi::R_SPARC_16 => Some("R_SPARC_16"),
+        abi::R_SPARC_32 => Some("R_SPARC_32"),
+        abi::R_SPARC_DISP8 => Some("R_SPARC_DISP8"),
+        abi::R_SPARC_DISP16 => Some("R_SPARC_DISP16"),
+        abi::R_SPARC_DISP32 => Some("R_SPARC_DISP32"),
+        abi::R_SPARC_WDISP30 => Some("R_SPARC_WDISP30"),
+        abi::R_SPARC_WDISP22 => Some("R_SPARC_WDISP22"),
+        abi::R_SPARC_HI22 => Some("R_SPARC_HI22"),
+        abi::R_SPARC_22 => Some("R_SPARC_22"),
+        abi::R_SPARC_13 => Some("R_SPARC_13"),
+        abi::R_SPARC_LO10 => Some("R_SPARC_LO10"),
+        abi::R_SPARC_GOT10 => Some("R_SPARC_GOT10"),
+        abi::R_SPARC_GOT13 => Some("R_SPARC_GOT13"),
+        abi::R_SPARC_GOT22 => Some("R_SPARC_GOT22"),
+        abi::R_SPARC_PC10 => Some("R_SPARC_PC10"),
+        abi::R_SPARC_PC22 => Some("R_SPARC_PC22"),
+        abi::R_SPARC_WPLT30 => Some("R_SPARC_WPLT30"),
+        abi::R_SPARC_COPY => Some("R_SPARC_COPY"),
+        abi::R_SPARC_GLOB_DAT => Some("R_SPARC_GLOB_DAT"),
+        abi::R_SPARC_JMP_SLOT => Some("R_SPARC_JMP_SLOT"),
+        abi::R_SPARC_RELATIVE => Some("R_SPARC_RELATIVE"),
+        abi::R_SPARC_UA32 => Some("R_SPARC_UA32"),
+        abi::R_SPARC_PLT32 => Some("R_SPARC_PLT32"),
+        abi::R_SPARC_HIPLT22 => Some("R_SPARC_HIPLT22"),
+        abi::R_SPARC_LOPLT10 => Some("R_SPARC_LOPLT10"),
+        abi::R_SPARC_PCPLT32 => Some("R_SPARC_PCPLT32"),
+        abi::R_SPARC_PCPLT22 => Some("R_SPARC_PCPLT22"),
+        abi::R_SPARC_PCPLT10 => Some("R_SPARC_PCPLT10"),
+        abi::R_SPARC_10 => Some("R_SPARC_10"),
+        abi::R_SPARC_11 => Some("R_SPARC_11"),
+        abi::R_SPARC_64 => Some("R_SPARC_64"),
+        abi::R_SPARC_OLO10 => Some("R_SPARC_OLO10"),
+        abi::R_SPARC_HH22 => Some("R_SPARC_HH22"),
+        abi::R_SPARC_HM10 => Some("R_SPARC_HM10"),
+        abi::R_SPARC_LM22 => Some("R_SPARC_LM22"),
+        abi::R_SPARC_PC_HH22 => Some("R_SPARC_PC_HH22"),
+        abi::R_SPARC_PC_HM10 => Some("R_SPARC_PC_HM10"),
+        abi::R_SPARC_PC_LM22 => Some("R_SPARC_PC_LM22"),
+        abi::R_SPARC_WDISP16 => Some("R_SPARC_WDISP16"),
+        abi::R_SPARC_WDISP19 => Some("R_SPARC_WDISP19"),
+        abi::R_SPARC_GLOB_JMP => Some("R_SPARC_GLOB_JMP"),
+        abi::R_SPARC_7 => Some("R_SPARC_7"),
+        abi::R_SPARC_5 => Some("R_SPARC_5"),
+        abi::R_SPARC_6 => Some("R_SPARC_6"),
+        abi::R_SPARC_DISP64 => Some("R_SPARC_DISP64"),
+        abi::R_SPARC_PLT64 => Some("R_SPARC_PLT64"),
+        abi::R_SPARC_HIX22 => Some("R_SPARC_HIX22"),
+        abi::R_SPARC_LOX10 => Some("R_SPARC_LOX10"),
+        abi::R_SPARC_H44 => Some("R_SPARC_H44"),
+        abi::R_SPARC_M44 => Some("R_SPARC_M44"),
+        abi::R_SPARC_L44 => Some("R_SPARC_L44"),
+        abi::R_SPARC_REGISTER => Some("R_SPARC_REGISTER"),
+        abi::R_SPARC_UA64 => Some("R_SPARC_UA64"),
+        abi::R_SPARC_UA16 => Some("R_SPARC_UA16"),
+        abi::R_SPARC_TLS_GD_HI22 => Some("R_SPARC_TLS_GD_HI22"),
+        abi::R_SPARC_TLS_GD_LO10 => Some("R_SPARC_TLS_GD_LO10"),
+        abi::R_SPARC_TLS_GD_ADD => Some("R_SPARC_TLS_GD_ADD"),
+        abi::R_SPARC_TLS_GD_CALL => Some("R_SPARC_TLS_GD_CALL"),
+        abi::R_SPARC_TLS_LDM_HI22 => Some("R_SPARC_TLS_LDM_HI22"),
+        abi::R_SPARC_TLS_LDM_LO10 => Some("R_SPARC_TLS_LDM_LO10"),
+        abi::R_SPARC_TLS_LDM_ADD => Some("R_SPARC_TLS_LDM_ADD"),
+        abi::R_SPARC_TLS_LDM_CALL => Some("R_SPARC_TLS_LDM_CALL"),
+        abi::R_SPARC_TLS_LDO_HIX22 => Some("R_SPARC_TLS_LDO_HIX22"),
+        abi::R_SPARC_TLS_LDO_LOX10 => Some("R_SPARC_TLS_LDO_LOX10"),
+        abi::R_SPARC_TLS_LDO_ADD => Some("R_SPARC_TLS_LDO_ADD"),
+        abi::R_SPARC_TLS_IE_HI22 => Some("R_SPARC_TLS_IE_HI22"),
+        abi::R_SPARC_TLS_IE_LO10 => Some("R_SPARC_TLS_IE_LO10"),
+        abi::R_SPARC_TLS_IE_LD => Some("R_SPARC_TLS_IE_LD"),
+        abi::R_SPARC_TLS_IE_LDX => Some("R_SPARC_TLS_IE_LDX"),
+        abi::R_SPARC_TLS_IE_ADD => Some("R_SPARC_TLS_IE_ADD"),
+        abi::R_SPARC_TLS_LE_HIX22 => Some("R_SPARC_TLS_LE_HIX22"),
+        abi::R_SPARC_TLS_LE_LOX10 => Some("R_SPARC_TLS_LE_LOX10"),
+        abi::R_SPARC_TLS_DTPMOD32 => Some("R_SPARC_TLS_DTPMOD32"),
+        abi::R_SPARC_TLS_DTPMOD64 => Some("R_SPARC_TLS_DTPMOD64"),
+        abi::R_SPARC_TLS_DTPOFF32 => Some("R_SPARC_TLS_DTPOFF32"),
+        abi::R_SPARC_TLS_DTPOFF64 => Some("R_SPARC_TLS_DTPOFF64"),
+        abi::R_SPARC_TLS_TPOFF32 => Some("R_SPARC_TLS_TPOFF32"),
+        abi::R_SPARC_TLS_TPOFF64 => Some("R_SPARC_TLS_TPOFF64"),
+        abi::R_SPARC_GOTDATA_HIX22 => Some("R_SPARC_GOTDATA_HIX22"),
+        abi::R_SPARC_GOTDATA_LOX10 => Some("R_SPARC_GOTDATA_LOX10"),
+        abi::R_SPARC_GOTDATA_OP_HIX22 => Some("R_SPARC_GOTDATA_OP_HIX22"),
+        abi::R_SPARC_GOTDATA_OP_LOX10 => Some("R_SPARC_GOTDATA_OP_LOX10"),
+        abi::R_SPARC_GOTDATA_OP => Some("R_SPARC_GOTDATA_OP"),
+        abi::R_SPARC_H34 => Some("R_SPARC_H34"),
+        abi::R_SPARC_SIZE32 => Some("R_SPARC_SIZE32"),
+        abi::R_SPARC_SIZE64 => Some("R_SPARC_SIZE64"),
+        abi::R_SPARC_WDISP10 => Some("R_SPARC_WDISP10"),
+        abi::R_SPARC_JMP_IREL => Some("R_SPARC_JMP_IREL"),
+        abi::R_SPARC_IRELATIVE => Some("R_SPARC_IRELATIVE"),
+        abi::R_SPARC_GNU_VTINHERIT => Some("R_SPARC_GNU_VTINHERIT"),
+        abi::R_SPARC_GNU_VTENTRY => Some("R_SPARC_GNU_VTENTRY"),
+        abi::R_SPARC_REV32 => Some("R_SPARC_REV32"),
+        _ => None,
+    }
+}
+
+fn x86_64_r_type_to_str(r_type: u32) -> Option<&'static str> {
+    match r_type {
+        abi::R_X86_64_NONE => Some("R_X86_64_NONE"),
+        abi::R_X86_64_64 => Some("R_X86_64_64"),
+        abi::R_X86_64_PC32 => Some("R_X86_64_PC32"),
+        abi::R_X86_64_GOT32 => Some("R_X86_64_GOT32"),
+        abi::R_X86_64_PLT32 => Some("R_X86_64_PLT32"),
+        abi::R_X86_64_COPY => Some("R_X86_64_COPY"),
+        abi::R_X86_64_GLOB_DAT => Some("R_X86_64_GLOB_DAT"),
+        abi::R_X86_64_JUMP_SLOT => Some("R_X86_64_JUMP_SLOT"),
+        abi::R_X86_64_RELATIVE => Some("R_X86_64_RELATIVE"),
+        abi::R_X86_64_GOTPCREL => Some("R_X86_64_GOTPCREL"),
+        abi::R_X86_64_32 => Some("R_X86_64_32"),
+        abi::R_X86_64_32S => Some("R_X86_64_32S"),
+        abi::R_X86_64_16 => Some("R_X86_64_16"),
+        abi::R_X86_64_PC16 => Some("R_X86_64_PC16"),
+        abi::R_X86_64_8 => Some("R_X86_64_8"),
+        abi::R_X86_64_PC8 => Some("R_X86_64_PC8"),
+        abi::R_X86_64_DTPMOD64 => Some("R_X86_64_DTPMOD64"),
+        abi::R_X86_64_DTPOFF64 => Some("R_X86_64_DTPOFF64"),
+        abi::R_X86_64_TPOFF64 => Some("R_X86_64_TPOFF64"),
+        abi::R_X86_64_TLSGD => Some("R_X86_64_TLSGD"),
+        abi::R_X86_64_TLSLD => Some("R_X86_64_TLSLD"),
+        abi::R_X86_64_DTPOFF32 => Some("R_X86_64_DTPOFF32"),
+        abi::R_X86_64_GOTTPOFF => Some("R_X86_64_GOTTPOFF"),
+        abi::R_X86_64_TPOFF32 => Some("R_X86_64_TPOFF32"),
+        abi::R_X86_64_PC64 => Some("R_X86_64_PC64"),
+        abi::R_X86_64_GOTOFF64 => Some("R_X86_64_GOTOFF64"),
+        abi::R_X86_64_GOTPC32 => Some("R_X86_64_GOTPC32"),
+        abi::R_X86_64_GOT64 => Some("R_X86_64_GOT64"),
+        abi::R_X86_64_GOTPCREL64 => Some("R_X86_64_GOTPCREL64"),
+        abi::R_X86_64_GOTPC64 => Some("R_X86_64_GOTPC64"),
+        abi::R_X86_64_PLTOFF64 => Some("R_X86_64_PLTOFF64"),
+        abi::R_X86_64_SIZE32 => Some("R_X86_64_SIZE32"),
+        abi::R_X86_64_SIZE64 => Some("R_X86_64_SIZE64"),
+        abi::R_X86_64_GOTPC32_TLSDESC => Some("R_X86_64_GOTPC32_TLSDESC"),
+        abi::R_X86_64_TLSDESC_CALL => Some("R_X86_64_TLSDESC_CALL"),
+        abi::R_X86_64_TLSDESC => Some("R_X86_64_TLSDESC"),
+        abi::R_X86_64_IRELATIVE => Some("R_X86_64_IRELATIVE"),
+        abi::R_X86_64_RELATIVE64 => Some("R_X86_64_RELATIVE64"),
+        abi::R_X86_64_GOTPCRELX => Some("R_X86_64_GOTPCRELX"),
+        abi::R_X86_64_REX_GOTPCRELX => Some("R_X86_64_REX_GOTPCRELX"),
+        _ => None,
+    }
+}
+
+pub fn arm_attr_tag_to_str(tag: u64) -> Option<&'static str> {
+    match tag {
+        abi::TAG_COMPATIBILITY => Some("Tag_compatibility"),
+        abi::TAG_ARM_CPU_RAW_NAME => Some("Tag_CPU_raw_name"),
+        abi::TAG_ARM_CPU_NAME => Some("Tag_CPU_name"),
+        abi::TAG_ARM_CPU_ARCH => Some("Tag_CPU_arch"),
+        abi::TAG_ARM_CPU_ARCH_PROFILE => Some("Tag_CPU_arch_profile"),
+        abi::TAG_ARM_ARM_ISA_USE => Some("Tag_ARM_ISA_use"),
+        abi::TAG_ARM_THUMB_ISA_USE => Some("Tag_THUMB_ISA_use"),
+        abi::TAG_ARM_FP_ARCH => Some("Tag_FP_arch"),
+        abi::TAG_ARM_WMMX_ARCH => Some("Tag_WMMX_arch"),
+        abi::TAG_ARM_ADVANCED_SIMD_ARCH => Some("Tag_Advanced_SIMD_arch"),
+        abi::TAG_ARM_PCS_CONFIG => Some("Tag_PCS_config"),
+        abi::TAG_ARM_ABI_PCS_R9_USE => Some("Tag_ABI_PCS_R9_use"),
+        abi::TAG_ARM_ABI_PCS_RW_DATA => Some("Tag_ABI_PCS_RW_data"),
+        abi::TAG_ARM_ABI_PCS_RO_DATA => Some("Tag_ABI_PCS_RO_data"),
+        abi::TAG_ARM_ABI_PCS_GOT_USE => Some("Tag_ABI_PCS_GOT_use"),
+        abi::TAG_ARM_ABI_PCS_WCHAR_T => Some("Tag_ABI_PCS_wchar_t"),
+        abi::TAG_ARM_ABI_FP_ROUNDING => Some("Tag_ABI_FP_rounding"),
+        abi::TAG_ARM_ABI_FP_DENORMAL => Some("Tag_ABI_FP_denormal"),
+        abi::TAG_ARM_ABI_FP_EXCEPTIONS => Some("Tag_ABI_FP_exceptions"),
+        abi::TAG_ARM_ABI_FP_USER_EXCEPTIONS => Some("Tag_ABI_FP_user_exceptions"),
+        abi::TAG_ARM_ABI_FP_NUMBER_MODEL => Some("Tag_ABI_FP_number_model"),
+        abi::TAG_ARM_ABI_ALIGN_NEEDED => Some("Tag_ABI_align_needed"),
+        abi::TAG_ARM_ABI_ALIGN_PRESERVED => Some("Tag_ABI_align_preserved"),
+        abi::TAG_ARM_ABI_ENUM_SIZE => Some("Tag_ABI_enum_size"),
+        abi::TAG_ARM_ABI_HARDFP_USE => Some("Tag_ABI_HardFP_use"),
+        abi::TAG_ARM_ABI_VFP_ARGS => Some("Tag_ABI_VFP_args"),
+        abi::TAG_ARM_ABI_WMMX_ARGS => Some("Tag_ABI_WMMX_args"),
+        abi::TAG_ARM_ABI_OPTIMIZATION_GOALS => Some("Tag_ABI_optimization_goals"),
+        abi::TAG_ARM_ABI_FP_OPTIMIZATION_GOALS => Some("Tag_ABI_FP_optimization_goals"),
+        abi::TAG_ARM_CPU_UNALIGNED_ACCESS => Some("Tag_CPU_unaligned_access"),
+        abi::TAG_ARM_FP_HP_EXTENSION => Some("Tag_FP_HP_extension"),
+        abi::TAG_ARM_ABI_FP_16BIT_FORMAT => Some("Tag_ABI_FP_16bit_format"),
+        abi::TAG_ARM_MPEXTENSION_USE => Some("Tag_MPextension_use"),
+        abi::TAG_ARM_DIV_USE => Some("Tag_DIV_use"),
+        abi::TAG_ARM_DSP_EXTENSION => Some("Tag_DSP_extension"),
+        abi::TAG_ARM_MVE_ARCH => Some("Tag_MVE_arch"),
+        abi::TAG_ARM_PAC_EXTENSION => Some("Tag_PAC_extension"),
+        abi::TAG_ARM_BTI_EXTENSION => Some("Tag_BTI_extension"),
+        abi::TAG_ARM_NODEFAULTS => Some("Tag_nodefaults"),
+        abi::TAG_ARM_ALSO_COMPATIBLE_WITH => Some("Tag_also_compatible_with"),
+        abi::TAG_ARM_T2EE_USE => Some("Tag_T2EE_use"),
+        abi::TAG_ARM_CONFORMANCE => Some("Tag_conformance"),
+        abi::TAG_ARM_VIRTUALIZATION_USE => Some("Tag_Virtualization_use"),
+        abi::TAG_ARM_FRAMEPOINTER_USE => Some("Tag_FramePointer_use"),
+        abi::TAG_ARM_BTI_USE => Some("Tag_BTI_use"),
+        abi::TAG_ARM_PACRET_USE => Some("Tag_PACRET_use"),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn arm_attr_tag_to_string(tag: u64) -> String {
+    match arm_attr_tag_to_str(tag) {
+        Some(s) => s.to_string(),
+        None => format!("Tag_unknown_{tag}"),
+    }
+}
+
+pub fn riscv_attr_tag_to_str(tag: u64) -> Option<&'static str> {
+    match tag {
+        abi::TAG_RISCV_STACK_ALIGN => Some("Tag_RISCV_stack_align"),
+        abi::TAG_RISCV_ARCH => Some("Tag_RISCV_arch"),
+        abi::TAG_RISCV_UNALIGNED_ACCESS => Some("Tag_RISCV_unaligned_access"),
+        abi::TAG_RISCV_PRIV_SPEC => Some("Tag_RISCV_priv_spec"),
+        abi::TAG_RISCV_PRIV_SPEC_MINOR => Some("Tag_RISCV_priv_spec_minor"),
+        abi::TAG_RISCV_PRIV_SPEC_REVISION => Some("Tag_RISCV_priv_spec_revision"),
+        abi::TAG_RISCV_ATOMIC_ABI => Some("Tag_RISCV_atomic_abi"),
+        abi::TAG_RISCV_X3_REG_USAGE => Some("Tag_RISCV_x3_reg_usage"),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn riscv_attr_tag_to_string(tag: u64) -> String {
+    match riscv_attr_tag_to_str(tag) {
+        Some(s) => s.to_string(),
+        None => format!("Tag_unknown_{tag}"),
+    }
+}
+
+#[cfg(test)]
+mod to_str_tests {
+    use super::*;
+
+    #[test]
+    fn ppc64_tprel16_lo() {
+        // R_PPC64_TPREL16_LO is 70 in the ELFv1 and ELFv2 ABIs. It used to be defined as 60,
+        // which is R_PPC64_PLT16_LO_DS.
+        assert_eq!(abi::R_PPC64_TPREL16_LO, 70);
+        assert_eq!(r_type_to_str(abi::EM_PPC64, 70), Some("R_PPC64_TPREL16_LO"));
+        assert_eq!(
+            r_type_to_str(abi::EM_PPC64, 60),
+            Some("R_PPC64_PLT16_LO_DS")
+        );
+    }
+}
@@ -0,0 +1,550 @@
+//! Structural validation of ELF objects
+//!
+//! Parsing is lazy and fails on the first bad structure it's asked to interpret. [validate]
+//! instead walks the whole object and collects every structural problem it finds as a list
+//! of [Finding]s, which makes it suitable for linting build artifacts:
+//!
+//! ```
+//! use elf::endian::AnyEndian;
+//! use elf::ElfBytes;
+//!
+//! let path = std::path::PathBuf::from("sample-objects/basic.x86_64");
+//! let file_data = std::fs::read(path).expect("Could not read file.");
+//! let mut file = ElfBytes::<AnyEndian>::minimal_parse(&file_data).expect("Open test1");
+//!
+//! let findings = elf::validate::validate(&mut file);
+//! assert!(findings.is_empty(), "{findings:?}");
+//! ```
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::abi;
+use crate::dynamic::Dyn;
+use crate::elf_reader::ElfReader;
+use crate::file::{Class, ELF32_EHDR_TAILSIZE, ELF64_EHDR_TAILSIZE};
+use crate::parse::{ParseAt, ParseError};
+use crate::relocation::{Rel, Rela};
+use crate::section::SectionHeader;
+use crate::segment::ProgramHeader;
+use crate::symbol::Symbol;
+
+/// A structural problem found by [validate]
+#[derive(Debug)]
+pub enum Finding {
+    /// A structure couldn't be parsed, so the checks which depend on it were skipped
+    Unparseable(ParseError),
+
+    /// The ELF header's `e_ehsize` doesn't match the size of the header for its class
+    BadEhsize { found: u16, expected: u16 },
+
+    /// The ELF header's `e_phentsize` doesn't match the size of a program header for its class
+    BadPhentsize(ParseError),
+
+    /// The section at `index` has file data which extends past the end of the file
+    SectionOutOfFile {
+        index: usize,
+        offset: u64,
+        size: u64,
+    },
+
+    /// The file data of the sections at `first` and `second` overlap
+    OverlappingSections { first: usize, second: usize },
+
+    /// The section at `index` links to a section which doesn't exist
+    BadSectionLink { index: usize, sh_link: u32 },
+
+    /// The section at `index` refers to a section through its `sh_info` which doesn't exist
+    BadSectionInfo { index: usize, sh_info: u32 },
+
+    /// The section at `index` has an `sh_entsize` which doesn't match its entries' size
+    BadSectionEntsize { index: usize, error: ParseError },
+
+    /// The `PT_LOAD` segment at `index` has `p_offset` and `p_vaddr` which aren't congruent
+    /// modulo `p_align`, so it can't be mapped
+    UnalignedLoadSegment { index: usize },
+
+    /// The `PT_LOAD` segment at `index` has a lower `p_vaddr` than the `PT_LOAD` before it
+    UnsortedLoadSegment { index: usize },
+
+    /// The dynamic table isn't terminated by a `DT_NULL` entry
+    MissingDtNull,
+
+    /// The sized symbol at `index` in the symbol table section at `symtab` starts before or
+    /// extends past the end of the section it's defined in (or that section doesn't exist)
+    SymbolOutsideSection { symtab: usize, index: usize },
+}
+
+impl core::fmt::Display for Finding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Finding::Unparseable(ref err) => write!(f, "Could not parse: {err}"),
+            Finding::BadEhsize { found, expected } => {
+                write!(f, "Invalid e_ehsize. Expected: {expected:#X}, Found: {found:#X}")
+            }
+            Finding::BadPhentsize(ref err) => write!(f, "Invalid e_phentsize: {err}"),
+            Finding::SectionOutOfFile {
+                index,
+                offset,
+                size,
+            } => write!(
+                f,
+                "Section {index} data ({offset:#X}, size {size:#X}) extends past the end of the file"
+            ),
+            Finding::OverlappingSections { first, second } => {
+                write!(f, "Sections {first} and {second} overlap")
+            }
+            Finding::BadSectionLink { index, sh_link } => {
+                write!(f, "Section {index} has out-of-bounds sh_link: {sh_link}")
+            }
+            Finding::BadSectionInfo { index, sh_info } => {
+                write!(f, "Section {index} has out-of-bounds sh_info: {sh_info}")
+            }
+            Finding::BadSectionEntsize { index, ref error } => {
+                write!(f, "Section {index} has bad sh_entsize: {error}")
+            }
+            Finding::UnalignedLoadSegment { index } => {
+                write!(f, "PT_LOAD segment {index} is not congruent modulo p_align")
+            }
+            Finding::UnsortedLoadSegment { index } => {
+                write!(f, "PT_LOAD segment {index} is out of p_vaddr order")
+            }
+            Finding::MissingDtNull => write!(f, "Dynamic table has no DT_NULL entry"),
+            Finding::SymbolOutsideSection { symtab, index } => {
+                write!(f, "Symbol {index} in section {symtab} lies outside its section")
+            }
+        }
+    }
+}
+
+/// Walk the given ELF object and collect all of the structural problems found in it.
+///
+/// An empty list means no problems were found.
+pub fn validate<R: ElfReader>(reader: &mut R) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    check_ehdr(reader, &mut findings);
+    check_segments(reader, &mut findings);
+
+    let shdrs: Vec<SectionHeader> = match reader.section_header_table() {
+        Ok(Some(shdrs)) => shdrs.iter().collect(),
+        Ok(None) => Vec::new(),
+        Err(err) => {
+            findings.push(Finding::Unparseable(err));
+            Vec::new()
+        }
+    };
+    let class = reader.ehdr().class;
+    check_sections(class, reader.file_size(), &shdrs, &mut findings);
+    check_symbols(reader, &shdrs, &mut findings);
+
+    match reader.dynamic() {
+        Ok(Some(dynamic)) => {
+            if !dynamic.iter().any(|d| d.d_tag == abi::DT_NULL) {
+                findings.push(Finding::MissingDtNull);
+            }
+        }
+        Ok(None) => {}
+        Err(err) => findings.push(Finding::Unparseable(err)),
+    }
+
+    findings
+}
+
+fn check_ehdr<R: ElfReader>(reader: &mut R, findings: &mut Vec<Finding>) {
+    let ehdr = reader.ehdr();
+    let expected = abi::EI_NIDENT
+        + match ehdr.class {
+            Class::ELF32 => ELF32_EHDR_TAILSIZE,
+            Class::ELF64 => ELF64_EHDR_TAILSIZE,
+        };
+    if ehdr.e_ehsize as usize != expected {
+        findings.push(Finding::BadEhsize {
+            found: ehdr.e_ehsize,
+            expected: expected as u16,
+        });
+    }
+
+    // Objects without program headers commonly leave e_phentsize zeroed
+    if ehdr.e_phnum != 0 || ehdr.e_phentsize != 0 {
+        if let Err(err) = ProgramHeader::validate_entsize(ehdr.class, ehdr.e_phentsize as usize) {
+            findings.push(Finding::BadPhentsize(err));
+        }
+    }
+}
+
+fn check_segments<R: ElfReader>(reader: &mut R, findings: &mut Vec<Finding>) {
+    let phdrs = match reader.segment_table() {
+        Ok(Some(phdrs)) => phdrs,
+        Ok(None) => return,
+        Err(err) => {
+            findings.push(Finding::Unparseable(err));
+            return;
+        }
+    };
+
+    let mut prev_vaddr = None;
+    for (index, phdr) in phdrs.iter().enumerate() {
+        if phdr.p_type != abi::PT_LOAD {
+            continue;
+        }
+        if phdr.p_align > 1 && phdr.p_offset % phdr.p_align != phdr.p_vaddr % phdr.p_align {
+            findings.push(Finding::UnalignedLoadSegment { index });
+        }
+        if prev_vaddr.is_some_and(|prev| phdr.p_vaddr < prev) {
+            findings.push(Finding::UnsortedLoadSegment { index });
+        }
+        prev_vaddr = Some(phdr.p_vaddr);
+    }
+}
+
+fn check_sections(
+    class: Class,
+    file_size: u64,
+    shdrs: &[SectionHeader],
+    findings: &mut Vec<Finding>,
+) {
+    let shnum = shdrs.len();
+    let mut ranges = Vec::new();
+    for (index, shdr) in shdrs.iter().enumerate() {
+        if shdr.sh_link != 0 && shdr.sh_link as usize >= shnum {
+            findings.push(Finding::BadSectionLink {
+                index,
+                sh_link: shdr.sh_link,
+            });
+        }
+
+        // sh_info only refers to a section for relocation sections and SHF_INFO_LINK
+        let info_is_index = shdr.sh_flags & abi::SHF_INFO_LINK as u64 != 0
+            || shdr.sh_type == abi::SHT_REL
+            || shdr.sh_type == abi::SHT_RELA;
+        if info_is_index && shdr.sh_info != 0 && shdr.sh_info as usize >= shnum {
+            findings.push(Finding::BadSectionInfo {
+                index,
+                sh_info: shdr.sh_info,
+            });
+        }
+
+        let entsize = match shdr.sh_entsize.try_into() {
+            Ok(entsize) => entsize,
+            Err(_) => usize::MAX,
+        };
+        let entsize_check = match shdr.sh_type {
+            abi::SHT_SYMTAB | abi::SHT_DYNSYM => Symbol::validate_entsize(class, entsize),
+            abi::SHT_DYNAMIC => Dyn::validate_entsize(class, entsize),
+            abi::SHT_REL => Rel::validate_entsize(class, entsize),
+            abi::SHT_RELA => Rela::validate_entsize(class, entsize),
+            _ => Ok(entsize),
+        };
+        if let Err(error) = entsize_check {
+            findings.push(Finding::BadSectionEntsize { index, error });
+        }
+
+        if shdr.sh_type == abi::SHT_NULL || shdr.sh_type == abi::SHT_NOBITS {
+            continue;
+        }
+        match shdr.get_data_range() {
+            Ok((start, end)) if end as u64 <= file_size => {
+                if start != end {
+                    ranges.push((start, end, index));
+                }
+            }
+            _ => findings.push(Finding::SectionOutOfFile {
+                index,
+                offset: shdr.sh_offset,
+                size: shdr.sh_size,
+            }),
+        }
+    }
+
+    // Any overlap shows up between neighbours once the ranges are sorted by start offset
+    ranges.sort_unstable();
+    let mut furthest: Option<(usize, usize)> = None;
+    for (start, end, index) in ranges {
+        if let Some((prev_end, prev_index)) = furthest {
+            if start < prev_end {
+                findings.push(Finding::OverlappingSections {
+                    first: prev_index.min(index),
+                    second: prev_index.max(index),
+                });
+            }
+            if end <= prev_end {
+                continue;
+            }
+        }
+        furthest = Some((end, index));
+    }
+}
+
+fn check_symbols<R: ElfReader>(
+    reader: &mut R,
+    shdrs: &[SectionHeader],
+    findings: &mut Vec<Finding>,
+) {
+    let ehdr = reader.ehdr();
+    let relocatable = ehdr.e_type == abi::ET_REL;
+    let arm = ehdr.e_machine == abi::EM_ARM;
+    // ELFv1 function symbols point at their descriptor in .opd, but have the size of their code
+    let ppc64_elfv1 = ehdr.e_machine == abi::EM_PPC64 && ehdr.e_flags & abi::EF_PPC64_ABI != 2;
+
+    for (symtab, shdr) in shdrs.iter().enumerate() {
+        if shdr.sh_type != abi::SHT_SYMTAB && shdr.sh_type != abi::SHT_DYNSYM {
+            continue;
+        }
+        // Bad links and entsizes were already reported above
+        let strtab_shdr = match shdrs.get(shdr.sh_link as usize) {
+            Some(strtab_shdr) => strtab_shdr,
+            None => continue,
+        };
+        let symbols = match reader.section_data_as_symbol_table(shdr, strtab_shdr) {
+            Ok((symbols, _)) => symbols,
+            Err(_) => continue,
+        };
+
+        for (index, sym) in symbols.iter().enumerate() {
+            let symtype = sym.st_symtype();
+            // Unsized symbols are markers (like __bss_start or .TOC.) which can name any address
+            if sym.st_size == 0
+                || sym.st_shndx == abi::SHN_UNDEF
+                || sym.st_shndx >= abi::SHN_LORESERVE
+                || symtype == abi::STT_SECTION
+                || symtype == abi::STT_FILE
+                // TLS symbol values are offsets into the TLS template, not addresses
+                || (!relocatable && symtype == abi::STT_TLS)
+                || (ppc64_elfv1 && symtype == abi::STT_FUNC)
+            {
+                continue;
+            }
+
+            let mut value = sym.st_value;
+            if arm && symtype == abi::STT_FUNC {
+                // The low bit marks Thumb functions
+                value &= !1;
+            }
+
+            let inside = match shdrs.get(sym.st_shndx as usize) {
+                Some(section) => {
+                    // Symbol values are section offsets in relocatable objects, and addresses otherwise
+                    let start = if relocatable { 0 } else { section.sh_addr };
+                    match (
+                        value.checked_add(sym.st_size),
+                        start.checked_add(section.sh_size),
+                    ) {
+                        (Some(sym_end), Some(section_end)) => {
+                            value >= start && sym_end <= section_end
+                        }
+                        _ => false,
+                    }
+                }
+                None => false,
+            };
+            if !inside {
+                findings.push(Finding::SymbolOutsideSection { symtab, index });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use super::*;
+    use crate::endian::AnyEndian;
+    use crate::{ElfBytes, ElfStream};
+
+    const SHOFF: usize = 0x12c0;
+    const SHENTSIZE: usize = 0x40;
+    const PHOFF: usize = 0x40;
+    const PHENTSIZE: usize = 0x38;
+
+    fn basic() -> Vec<u8> {
+        std::fs::read("sample-objects/basic.x86_64").expect("Could not read file.")
+    }
+
+    fn patch(data: &mut [u8], offset: usize, value: &[u8]) {
+        data[offset..offset + value.len()].copy_from_slice(value);
+    }
+
+    /// Patch the field at `field` in the section header at `index`
+    fn patch_shdr(data: &mut [u8], index: usize, field: usize, value: &[u8]) {
+        patch(data, SHOFF + index * SHENTSIZE + field, value);
+    }
+
+    fn findings(data: &[u8]) -> Vec<Finding> {
+        let mut file = ElfBytes::<AnyEndian>::minimal_parse(data).expect("Open test1");
+        validate(&mut file)
+    }
+
+    #[test]
+    fn clean_objects() {
+        for name in [
+            "basic.x86_64",
+            "symver.x86_64.so",
+            "symver.armhf.so",
+            "symver.powerpc64.so",
+            "symver.powerpc64le.so",
+            "loader.x86_64",
+        ] {
+            let path = std::path::PathBuf::from("sample-objects").join(name);
+            let data = std::fs::read(&path).expect("Could not read file.");
+            assert!(findings(&data).is_empty(), "{name}: {:?}", findings(&data));
+
+            let mut stream = ElfStream::<AnyEndian, _>::open_stream(std::io::Cursor::new(&data))
+                .expect("Open test1");
+            let findings = stream.validate();
+            assert!(findings.is_empty(), "{name}: {findings:?}");
+        }
+    }
+
+    #[test]
+    fn ehdr() {
+        let mut data = basic();
+        patch(&mut data, 52, &0x30u16.to_le_bytes());
+        // Drop the phdrs so that the bad phentsize doesn't fail the parse
+        patch(&mut data, 54, &0x30u16.to_le_bytes());
+        patch(&mut data, 32, &0u64.to_le_bytes());
+        patch(&mut data, 56, &0u16.to_le_bytes());
+
+        let findings = findings(&data);
+        assert!(matches!(
+            findings.as_slice(),
+            [
+                Finding::BadEhsize {
+                    found: 0x30,
+                    expected: 0x40
+                },
+                Finding::BadPhentsize(ParseError::BadEntsize((0x30, 0x38))),
+            ]
+        ));
+        assert_eq!(
+            findings[0].to_string(),
+            "Invalid e_ehsize. Expected: 0x40, Found: 0x30"
+        );
+    }
+
+    #[test]
+    fn segments() {
+        let mut data = basic();
+        // Move the second PT_LOAD below the first one, keeping it congruent
+        patch(
+            &mut data,
+            PHOFF + 3 * PHENTSIZE + 16,
+            &0x6c0u64.to_le_bytes(),
+        );
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::UnsortedLoadSegment { index: 3 }]
+        ));
+
+        let mut data = basic();
+        patch(
+            &mut data,
+            PHOFF + 3 * PHENTSIZE + 16,
+            &0x6006c8u64.to_le_bytes(),
+        );
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::UnalignedLoadSegment { index: 3 }]
+        ));
+    }
+
+    #[test]
+    fn section_ranges() {
+        let mut data = basic();
+        // .comment
+        patch_shdr(&mut data, 27, 32, &0x10000u64.to_le_bytes());
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::SectionOutOfFile {
+                index: 27,
+                offset: 0x8e8,
+                size: 0x10000
+            }]
+        ));
+
+        let mut data = basic();
+        // Move .comment on top of .data
+        patch_shdr(&mut data, 27, 24, &0x8d8u64.to_le_bytes());
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::OverlappingSections {
+                first: 25,
+                second: 27
+            }]
+        ));
+    }
+
+    #[test]
+    fn section_links() {
+        let mut data = basic();
+        // .dynamic's sh_link and .rela.plt's sh_info
+        patch_shdr(&mut data, 22, 40, &100u32.to_le_bytes());
+        patch_shdr(&mut data, 10, 44, &200u32.to_le_bytes());
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [
+                Finding::BadSectionInfo {
+                    index: 10,
+                    sh_info: 200
+                },
+                Finding::BadSectionLink {
+                    index: 22,
+                    sh_link: 100
+                },
+            ]
+        ));
+    }
+
+    #[test]
+    fn section_entsize() {
+        let mut data = basic();
+        // .symtab
+        patch_shdr(&mut data, 29, 56, &0x10u64.to_le_bytes());
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::BadSectionEntsize {
+                index: 29,
+                error: ParseError::BadEntsize((0x10, 0x18))
+            }]
+        ));
+    }
+
+    #[test]
+    fn missing_dt_null() {
+        let mut data = basic();
+        // Retag every DT_NULL in .dynamic as DT_DEBUG
+        for entry in data[0x6d8..0x6d8 + 0x1d0].chunks_mut(0x10) {
+            if entry[..8] == [0; 8] {
+                entry[..8].copy_from_slice(&abi::DT_DEBUG.to_le_bytes());
+            }
+        }
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::MissingDtNull]
+        ));
+    }
+
+    #[test]
+    fn symbol_outside_section() {
+        let mut data = basic();
+        // Grow the size of symbol 30 in .symtab (__JCR_LIST__ in .jcr)
+        patch(&mut data, 0x920 + 30 * 0x18 + 16, &0x10u64.to_le_bytes());
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::SymbolOutsideSection {
+                symtab: 29,
+                index: 30
+            }]
+        ));
+
+        // Move it to start before .jcr, while still ending inside it
+        let mut data = basic();
+        patch(&mut data, 0x920 + 30 * 0x18 + 8, &0x6006c8u64.to_le_bytes());
+        patch(&mut data, 0x920 + 30 * 0x18 + 16, &0x10u64.to_le_bytes());
+        assert!(matches!(
+            findings(&data).as_slice(),
+            [Finding::SymbolOutsideSection {
+                symtab: 29,
+                index: 30
+            }]
+        ));
+    }
+}